@@ -37,6 +37,13 @@ fn main() -> u64 {
         _ => 0,
     };
 
+    // Match several patterns in one arm, and add conditions to arms with guards.
+    let w = match a {
+        0 | 1 => 0,
+        n if n > 50 => 2,
+        _ => 1,
+    };
+
     // Match as expression used for a return.
     match 42 {
         0 => 24,
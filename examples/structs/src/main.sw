@@ -50,6 +50,12 @@ fn shorthand_instantiation() -> Foo {
     foo
 }
 
+fn update_instantiation(foo: Foo) -> Foo {
+    // Instantiate a copy of `foo` with a different value for "bar",
+    // the fields that are not listed are taken from `foo`
+    Foo { bar: 0, ..foo }
+}
+
 fn struct_destructuring() {
     let point1 = Point { x: 0, y: 0 };
     // Destructure the values from the struct into variables
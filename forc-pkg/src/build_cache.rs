@@ -0,0 +1,506 @@
+//! A persistent cache of compiled packages.
+//!
+//! Upon successfully compiling a package whose `BuiltPackage` is required, e.g. a workspace member
+//! or a contract dependency, the result is written to the user's build cache directory at
+//! `$HOME/.forc/cache`. Subsequent builds reuse the cached result rather than re-compiling the
+//! package, as long as none of its inputs have changed.
+//!
+//! The cache key of a package is the SHA-256 hash of:
+//!
+//! - the version of the compiler along with the modification time of the running executable,
+//! - the package's name and pinned source,
+//! - the package's manifest and sway source files,
+//! - the build profile and the features enabled for the package,
+//! - the cache keys of all of its dependencies, along with the kind of each dependency.
+//!
+//! The typed namespace of a library package refers to types and declarations within the engines of
+//! the build in which it was type-checked, and so is never written to the cache on disk. Instead,
+//! a [NamespaceCache] keeps the engines alive along with the namespaces of the library packages
+//! compiled with them, under the same keys. Passing the same [NamespaceCache] to successive
+//! builds within a process, e.g. [crate::build_with_cache], means that the library dependencies
+//! of a package that must be compiled are only type-checked again when their inputs change.
+//!
+//! Namespaces are only ever cached in memory, so they do not outlive the process. A new process,
+//! e.g. every run of `forc build`, type-checks the library dependencies of the packages it must
+//! compile again, even when their `BuiltPackage`s are found in the cache on disk.
+
+use crate::{
+    manifest::BuildProfile,
+    pkg::{BuildPlan, BuiltPackage, DepKind, NodeIx, Pinned},
+};
+use forc_util::build_cache_directory;
+use petgraph::{visit::EdgeRef, Direction};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, fs, path::PathBuf, time::UNIX_EPOCH};
+use sway_core::{
+    declaration_engine::DeclarationEngine, fuel_prelude::fuel_tx::StorageSlot,
+    language::parsed::TreeType, semantic_analysis::namespace, source_map::SourceMap, Engines,
+    FinalizedEntry, TypeEngine,
+};
+use sway_types::{Ident, Span};
+
+/// The number of namespaces that a [NamespaceCache] holds by default.
+const NAMESPACE_CACHE_CAPACITY: usize = 64;
+
+/// The typed namespaces of the library packages compiled by previous builds, along with the
+/// engines that they were type-checked with.
+///
+/// Namespaces are looked up by the same key as compiled packages within the cache on disk, so a
+/// namespace is only reused as long as none of the inputs of its package have changed. Once the
+/// cache holds its capacity, the least recently used namespace is evicted.
+///
+/// The engines only ever grow, so evicting a namespace does not free the types and declarations
+/// that it refers to, nor do those of the packages that are compiled without caching their
+/// namespace, such as workspace members. Instead, the engines are replaced along with all cached
+/// namespaces at the start of a build, once the packages compiled with them that are no longer
+/// cached outnumber the capacity of the cache. Each set of engines is a generation of the cache.
+pub struct NamespaceCache {
+    type_engine: TypeEngine,
+    declaration_engine: DeclarationEngine,
+    namespaces: CachedNamespaces,
+    /// The number of times the engines have been replaced.
+    generation: u64,
+}
+
+/// The namespaces held by a [NamespaceCache], by package key.
+pub(crate) struct CachedNamespaces {
+    entries: HashMap<String, CachedNamespace>,
+    capacity: usize,
+    /// Incremented on every use of an entry, in order to find the least recently used one.
+    uses: u64,
+    /// The number of lookups that found a namespace.
+    hits: usize,
+    /// The number of packages compiled with the engines of the current generation.
+    compiled: usize,
+}
+
+struct CachedNamespace {
+    pkg: Pinned,
+    namespace: namespace::Module,
+    last_used: u64,
+}
+
+/// The serializable subset of a `BuiltPackage`.
+#[derive(Serialize, Deserialize)]
+struct CachedPackage {
+    json_abi_program: fuels_types::ProgramABI,
+    storage_slots: Vec<StorageSlot>,
+    bytecode: Vec<u8>,
+    entries: Vec<CachedEntry>,
+    tree_type: CachedTreeType,
+    source_map: SourceMap,
+    pkg_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedEntry {
+    fn_name: String,
+    imm: u64,
+    selector: Option<[u8; 4]>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum CachedTreeType {
+    Predicate,
+    Script,
+    Contract,
+    Library(String),
+}
+
+/// Whether or not compiled packages may be read from and written to the cache when building
+/// with the given profile.
+///
+/// Profiles that request printing any of the intermediate compilation results always compile
+/// every package.
+pub(crate) fn is_enabled(profile: &BuildProfile) -> bool {
+    !profile.prints_intermediates()
+}
+
+/// Compute the cache key for the package at `node`.
+///
+/// The keys of all of the package's dependencies must already be present within `keys`.
+pub(crate) fn package_key(
+    plan: &BuildPlan,
+    node: NodeIx,
+    profile: &BuildProfile,
+    keys: &HashMap<NodeIx, String>,
+) -> String {
+    let graph = plan.graph();
+    let pkg = &graph[node];
+    let manifest = &plan.manifest_map()[&pkg.id()];
+    let mut hasher = Sha256::new();
+    hasher.update(compiler_id());
+    hasher.update(&pkg.name);
+    hasher.update(pkg.source.to_string());
+    hasher.update(fs::read(manifest.path()).unwrap_or_default());
+    let mut files = sway_utils::helpers::get_sway_files(manifest.dir().to_path_buf());
+    files.sort();
+    for file in files {
+        hasher.update(file.to_string_lossy().as_bytes());
+        hasher.update(fs::read(&file).unwrap_or_default());
+    }
+    hasher.update(serde_json::to_string(profile).unwrap_or_default());
+    for feature in plan.node_features(node) {
+        hasher.update(feature);
+    }
+    let mut deps: Vec<_> = graph
+        .edges_directed(node, Direction::Outgoing)
+        .map(|edge| {
+            let kind = match &edge.weight().kind {
+                DepKind::Library => "library".to_string(),
+                DepKind::Contract { salt } => format!("contract {}", salt),
+            };
+            let dep_key = keys.get(&edge.target()).cloned().unwrap_or_default();
+            format!("{} {} {}", edge.weight().name, kind, dep_key)
+        })
+        .collect();
+    deps.sort();
+    for dep in deps {
+        hasher.update(dep);
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Load the cached result of compiling the package with the given name and key.
+pub(crate) fn load(pkg_name: &str, key: &str) -> Option<BuiltPackage> {
+    let contents = fs::read(cache_path(pkg_name, key)).ok()?;
+    let cached: CachedPackage = serde_json::from_slice(&contents).ok()?;
+    let tree_type = match cached.tree_type {
+        CachedTreeType::Predicate => TreeType::Predicate,
+        CachedTreeType::Script => TreeType::Script,
+        CachedTreeType::Contract => TreeType::Contract,
+        CachedTreeType::Library(name) => TreeType::Library {
+            name: Ident::new(Span::from_string(name)),
+        },
+    };
+    let entries = cached
+        .entries
+        .into_iter()
+        .map(|entry| FinalizedEntry {
+            fn_name: entry.fn_name,
+            imm: entry.imm,
+            selector: entry.selector,
+            test_decl_id: None,
+        })
+        .collect();
+    Some(BuiltPackage {
+        json_abi_program: cached.json_abi_program,
+        storage_slots: cached.storage_slots,
+        bytecode: cached.bytecode,
+        entries,
+        tree_type,
+        source_map: cached.source_map,
+        pkg_name: cached.pkg_name,
+        declaration_engine: DeclarationEngine::default(),
+        has_warnings: false,
+    })
+}
+
+impl NamespaceCache {
+    /// A cache holding at most `capacity` namespaces.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            type_engine: TypeEngine::default(),
+            declaration_engine: DeclarationEngine::default(),
+            namespaces: CachedNamespaces {
+                entries: HashMap::new(),
+                capacity,
+                uses: 0,
+                hits: 0,
+                compiled: 0,
+            },
+            generation: 0,
+        }
+    }
+
+    /// The engines that the cached namespaces were type-checked with, along with the namespaces.
+    ///
+    /// Packages compiled with a namespace from the cache must be compiled with these engines, and
+    /// must be counted with [CachedNamespaces::count_compiled]. This starts a new generation if
+    /// the current one has outgrown the capacity of the cache.
+    pub(crate) fn engines_and_namespaces(&mut self) -> (Engines<'_>, &mut CachedNamespaces) {
+        let namespaces = &mut self.namespaces;
+        if namespaces.compiled.saturating_sub(namespaces.entries.len()) >= namespaces.capacity {
+            self.type_engine = TypeEngine::default();
+            self.declaration_engine = DeclarationEngine::default();
+            namespaces.entries.clear();
+            namespaces.compiled = 0;
+            self.generation += 1;
+        }
+        let engines = Engines::new(&self.type_engine, &self.declaration_engine);
+        (engines, &mut self.namespaces)
+    }
+}
+
+impl Default for NamespaceCache {
+    fn default() -> Self {
+        Self::with_capacity(NAMESPACE_CACHE_CAPACITY)
+    }
+}
+
+impl CachedNamespaces {
+    /// The namespace of the library package with the given key, if it is cached.
+    pub(crate) fn get(&mut self, key: &str) -> Option<namespace::Module> {
+        let entry = self.entries.get_mut(key)?;
+        self.uses += 1;
+        self.hits += 1;
+        entry.last_used = self.uses;
+        Some(entry.namespace.clone())
+    }
+
+    /// Count a package that is compiled with the engines of the cache, whether or not its
+    /// namespace is cached.
+    pub(crate) fn count_compiled(&mut self) {
+        self.compiled += 1;
+    }
+
+    /// Cache the namespace of the library package `pkg` under the given key.
+    ///
+    /// Namespaces cached for the same package under other keys are outdated and so are removed.
+    pub(crate) fn insert(&mut self, pkg: &Pinned, key: String, namespace: namespace::Module) {
+        self.entries.retain(|_, entry| entry.pkg != *pkg);
+        while !self.entries.is_empty() && self.entries.len() >= self.capacity {
+            let lru_key = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+                .expect("entries are not empty");
+            self.entries.remove(&lru_key);
+        }
+        if self.capacity == 0 {
+            return;
+        }
+        self.uses += 1;
+        let entry = CachedNamespace {
+            pkg: pkg.clone(),
+            namespace,
+            last_used: self.uses,
+        };
+        self.entries.insert(key, entry);
+    }
+}
+
+/// Write the result of compiling a package to the cache under the given key.
+///
+/// Packages that produced warnings are not cached so that their warnings are reported on every
+/// build. Packages with test entries are not cached either, as test entries refer to declarations
+/// within the build's declaration engine.
+///
+/// Failing to write to the cache is not an error, the package is simply compiled again next time.
+pub(crate) fn store(key: &str, built: &BuiltPackage) {
+    if built.has_warnings || built.entries.iter().any(|e| e.test_decl_id.is_some()) {
+        return;
+    }
+    let tree_type = match &built.tree_type {
+        TreeType::Predicate => CachedTreeType::Predicate,
+        TreeType::Script => CachedTreeType::Script,
+        TreeType::Contract => CachedTreeType::Contract,
+        TreeType::Library { name } => CachedTreeType::Library(name.as_str().to_string()),
+    };
+    let entries = built
+        .entries
+        .iter()
+        .map(|entry| CachedEntry {
+            fn_name: entry.fn_name.clone(),
+            imm: entry.imm,
+            selector: entry.selector,
+        })
+        .collect();
+    let cached = CachedPackage {
+        json_abi_program: built.json_abi_program.clone(),
+        storage_slots: built.storage_slots.clone(),
+        bytecode: built.bytecode.clone(),
+        entries,
+        tree_type,
+        source_map: built.source_map.clone(),
+        pkg_name: built.pkg_name.clone(),
+    };
+    let path = cache_path(&built.pkg_name, key);
+    let contents = match serde_json::to_vec(&cached) {
+        Ok(contents) => contents,
+        Err(_) => return,
+    };
+    // Write to a temporary file first so that concurrent builds never observe a partial entry.
+    let tmp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    let written = fs::create_dir_all(build_cache_directory())
+        .and_then(|_| fs::write(&tmp_path, contents))
+        .and_then(|_| fs::rename(&tmp_path, &path));
+    if written.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+}
+
+fn cache_path(pkg_name: &str, key: &str) -> PathBuf {
+    build_cache_directory().join(format!("{}-{}.json", pkg_name, key))
+}
+
+/// Identifies the compiler, so that cached packages are invalidated whenever the compiler changes.
+fn compiler_id() -> String {
+    let modified = std::env::current_exe()
+        .and_then(fs::metadata)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .unwrap_or_default();
+    format!("{} {}", env!("CARGO_PKG_VERSION"), modified.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        manifest::ManifestFile,
+        pkg::{build_with_cache, SourcePinned},
+    };
+    use std::{collections::HashSet, path::Path};
+
+    /// Write a package with the given name, entry and source, along with its dependencies.
+    fn write_pkg(dir: &Path, name: &str, entry: &str, src: &str, deps: &str) {
+        let pkg_dir = dir.join(name);
+        fs::create_dir_all(pkg_dir.join("src")).unwrap();
+        let manifest = format!(
+            "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"{entry}\"\nlicense = \"Apache-2.0\"\n\
+             name = \"{name}\"\nimplicit-std = false\n\n[dependencies]\n{deps}"
+        );
+        fs::write(pkg_dir.join("Forc.toml"), manifest).unwrap();
+        fs::write(pkg_dir.join("src").join(entry), src).unwrap();
+    }
+
+    fn write_foo(dir: &Path, src: &str) {
+        write_pkg(dir, "foo", "lib.sw", src, "");
+    }
+
+    fn write_app(dir: &Path, src: &str) {
+        let deps = "foo = { path = \"../foo\" }\n";
+        write_pkg(dir, "app", "main.sw", src, deps);
+    }
+
+    fn build(dir: &Path, cache: &mut NamespaceCache) {
+        let manifest_file = ManifestFile::from_dir(&dir.join("app")).unwrap();
+        let member_manifests = manifest_file.member_manifests().unwrap();
+        let lock_path = manifest_file.lock_path().unwrap();
+        let plan =
+            BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, false, true).unwrap();
+        let outputs: HashSet<_> = plan.member_nodes().collect();
+        build_with_cache(&plan, &BuildProfile::debug(), &outputs, cache).unwrap();
+    }
+
+    /// The keys of the namespaces cached for the package with the given name.
+    fn cached_keys(cache: &NamespaceCache, name: &str) -> Vec<String> {
+        cache
+            .namespaces
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.pkg.name == name)
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    const FOO: &str = "library foo;\n\npub fn foo() -> u64 {\n    1\n}\n";
+
+    #[test]
+    fn test_namespace_reused_across_builds() {
+        let dir = tempfile::tempdir().unwrap();
+        write_foo(dir.path(), FOO);
+        write_app(
+            dir.path(),
+            "script;\n\nuse foo::foo;\n\nfn main() -> u64 {\n    foo()\n}\n",
+        );
+        let mut cache = NamespaceCache::default();
+
+        build(dir.path(), &mut cache);
+        assert_eq!(cache.namespaces.hits, 0);
+        let keys = cached_keys(&cache, "foo");
+        assert_eq!(keys.len(), 1);
+
+        // Editing the member compiles it again against the cached namespace of `foo`.
+        write_app(
+            dir.path(),
+            "script;\n\nuse foo::foo;\n\nfn main() -> u64 {\n    let x = foo();\n    x\n}\n",
+        );
+        build(dir.path(), &mut cache);
+        assert_eq!(cache.namespaces.hits, 1);
+        assert_eq!(cached_keys(&cache, "foo"), keys);
+    }
+
+    #[test]
+    fn test_namespace_invalidated_by_dependency_change() {
+        let dir = tempfile::tempdir().unwrap();
+        write_foo(dir.path(), FOO);
+        write_app(
+            dir.path(),
+            "script;\n\nuse foo::foo;\n\nfn main() -> u64 {\n    foo()\n}\n",
+        );
+        let mut cache = NamespaceCache::default();
+
+        build(dir.path(), &mut cache);
+        let keys = cached_keys(&cache, "foo");
+        assert_eq!(keys.len(), 1);
+
+        // Editing the source of `foo` compiles it again, replacing its outdated namespace.
+        write_foo(dir.path(), &FOO.replace("1\n", "2\n"));
+        build(dir.path(), &mut cache);
+        assert_eq!(cache.namespaces.hits, 0);
+        let new_keys = cached_keys(&cache, "foo");
+        assert_eq!(new_keys.len(), 1);
+        assert_ne!(new_keys, keys);
+    }
+
+    #[test]
+    fn test_engines_replaced_once_outgrown() {
+        let dir = tempfile::tempdir().unwrap();
+        write_foo(dir.path(), FOO);
+        let main = |value: u64| {
+            format!(
+                "script;\n\nuse foo::foo;\n\nfn main() -> u64 {{\n    let x{value} = foo();\n    \
+                 x{value}\n}}\n"
+            )
+        };
+        write_app(dir.path(), &main(0));
+        let mut cache = NamespaceCache::with_capacity(2);
+
+        // `foo` and `app` are compiled, but only the namespace of `foo` is cached.
+        build(dir.path(), &mut cache);
+        assert_eq!((cache.generation, cache.namespaces.compiled), (0, 2));
+
+        // Only `app` is compiled again, against the cached namespace of `foo`.
+        write_app(dir.path(), &main(1));
+        build(dir.path(), &mut cache);
+        assert_eq!(cache.namespaces.hits, 1);
+        assert_eq!((cache.generation, cache.namespaces.compiled), (0, 3));
+
+        // The two compilations of `app` that are not cached fill the capacity of the cache, so the
+        // next build starts with new engines, and compiles `foo` again.
+        write_app(dir.path(), &main(2));
+        build(dir.path(), &mut cache);
+        assert_eq!(cache.namespaces.hits, 1);
+        assert_eq!((cache.generation, cache.namespaces.compiled), (1, 2));
+        assert_eq!(cached_keys(&cache, "foo").len(), 1);
+    }
+
+    #[test]
+    fn test_least_recently_used_namespace_evicted() {
+        let mut cache = NamespaceCache::with_capacity(2);
+        let pkg = |name: &str| Pinned {
+            name: name.to_string(),
+            source: SourcePinned::Member,
+        };
+        let namespaces = &mut cache.namespaces;
+        namespaces.insert(&pkg("a"), "a1".to_string(), namespace::Module::default());
+        namespaces.insert(&pkg("b"), "b1".to_string(), namespace::Module::default());
+        assert!(namespaces.get("a1").is_some());
+        namespaces.insert(&pkg("c"), "c1".to_string(), namespace::Module::default());
+        assert!(namespaces.get("b1").is_none());
+        assert!(namespaces.get("a1").is_some());
+        assert!(namespaces.get("c1").is_some());
+
+        // A new key for the same package replaces the previous one.
+        namespaces.insert(&pkg("a"), "a2".to_string(), namespace::Module::default());
+        assert!(namespaces.get("a1").is_none());
+        assert!(namespaces.get("a2").is_some());
+        assert!(namespaces.get("c1").is_some());
+        assert_eq!(namespaces.entries.len(), 2);
+    }
+}
@@ -4,10 +4,14 @@
 //! The project should consist of one or more Sway modules under a `src` directory. It may also
 //! declare a set of forc package dependencies within its manifest.
 
+mod build_cache;
 pub mod lock;
 pub mod manifest;
 mod pkg;
+pub mod registry;
+pub mod vendor;
 
+pub use build_cache::NamespaceCache;
 pub use lock::Lock;
 pub use manifest::{
     BuildProfile, PackageManifest, PackageManifestFile, WorkspaceManifest, WorkspaceManifestFile,
@@ -19,12 +19,17 @@ pub struct Lock {
     pub(crate) package: BTreeSet<PkgLock>,
 }
 
-/// Packages that have been removed and added between two `Lock` instances.
+/// Packages that have been removed, added and updated between two `Lock` instances.
 ///
 /// The result of `new_lock.diff(&old_lock)`.
 pub struct Diff<'a> {
     pub removed: BTreeSet<&'a PkgLock>,
     pub added: BTreeSet<&'a PkgLock>,
+    /// Packages whose lock entry changed between the two locks, as `(old, new)` pairs.
+    ///
+    /// A package is considered updated when exactly one entry with its name was removed and
+    /// exactly one was added. These entries do not appear in `removed` or `added`.
+    pub updated: BTreeSet<(&'a PkgLock, &'a PkgLock)>,
 }
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
@@ -37,8 +42,12 @@ pub struct PkgLock {
     version: Option<semver::Version>,
     // Short-hand string describing where this package is sourced from.
     source: String,
+    // The SHA-256 hash of the source tarball for registry packages.
+    checksum: Option<String>,
     dependencies: Option<Vec<PkgDepLine>>,
     contract_dependencies: Option<Vec<PkgDepLine>>,
+    // The features of this package enabled by its dependents.
+    features: Option<Vec<String>>,
 }
 
 /// `PkgDepLine` is a terse, single-line, git-diff-friendly description of a package's
@@ -56,15 +65,23 @@ pub struct PkgLock {
 pub type PkgDepLine = String;
 
 impl PkgLock {
-    /// Construct a package lock given a package's entry in the package graph.
-    pub fn from_node(graph: &pkg::Graph, node: pkg::NodeIx, disambiguate: &HashSet<&str>) -> Self {
+    /// Construct a package lock given a package's entry in the package graph, for the lock file
+    /// within `lock_dir`.
+    pub fn from_node(
+        graph: &pkg::Graph,
+        node: pkg::NodeIx,
+        disambiguate: &HashSet<&str>,
+        lock_dir: &Path,
+    ) -> Self {
         let pinned = &graph[node];
         let name = pinned.name.clone();
-        let version = match &pinned.source {
-            pkg::SourcePinned::Registry(reg) => Some(reg.source.version.clone()),
-            _ => None,
+        let (version, checksum) = match &pinned.source {
+            pkg::SourcePinned::Registry(reg) => {
+                (Some(reg.version.clone()), Some(reg.checksum.clone()))
+            }
+            _ => (None, None),
         };
-        let source = pinned.source.to_string();
+        let source = source_string(&pinned.source, lock_dir);
         // Collection of all dependencies, so this includes both contract-dependencies and
         // lib-dependencies
         let all_dependencies: Vec<(String, DepKind)> = graph
@@ -87,6 +104,7 @@ impl PkgLock {
                         &dep_pkg.source,
                         dep_kind,
                         disambiguate,
+                        lock_dir,
                     ),
                     dep_kind.clone(),
                 )
@@ -117,12 +135,24 @@ impl PkgLock {
             None
         };
 
+        let features: BTreeSet<&String> = graph
+            .edges_directed(node, Direction::Incoming)
+            .flat_map(|edge| &edge.weight().features)
+            .collect();
+        let features = if !features.is_empty() {
+            Some(features.into_iter().cloned().collect())
+        } else {
+            None
+        };
+
         Self {
             name,
             version,
             source,
+            checksum,
             dependencies,
             contract_dependencies,
+            features,
         }
     }
 
@@ -159,21 +189,22 @@ impl Lock {
         toml::de::from_str(&string).map_err(|e| anyhow!("failed to parse lock file: {}", e))
     }
 
-    /// Given a graph of pinned packages, create a `Lock` representing the `Forc.lock` file
-    /// structure.
-    pub fn from_graph(graph: &pkg::Graph) -> Self {
+    /// Given a graph of pinned packages, create a `Lock` representing the structure of the
+    /// `Forc.lock` file within `lock_dir`.
+    pub fn from_graph(graph: &pkg::Graph, lock_dir: &Path) -> Self {
         let names = graph.node_indices().map(|n| &graph[n].name[..]);
         let disambiguate: HashSet<_> = names_requiring_disambiguation(names).collect();
         // Collect the packages.
         let package: BTreeSet<_> = graph
             .node_indices()
-            .map(|node| PkgLock::from_node(graph, node, &disambiguate))
+            .map(|node| PkgLock::from_node(graph, node, &disambiguate, lock_dir))
             .collect();
         Self { package }
     }
 
-    /// Given a `Lock` loaded from a `Forc.lock` file, produce the graph of pinned dependencies.
-    pub fn to_graph(&self) -> Result<pkg::Graph> {
+    /// Given a `Lock` loaded from the `Forc.lock` file within `lock_dir`, produce the graph of
+    /// pinned dependencies.
+    pub fn to_graph(&self, lock_dir: &Path) -> Result<pkg::Graph> {
         let mut graph = pkg::Graph::new();
 
         // Track the names which need to be disambiguated in the dependency list.
@@ -188,9 +219,18 @@ impl Lock {
             // required.
             let key = pkg.name_disambiguated(&disambiguate).into_owned();
             let name = pkg.name.clone();
-            let source: pkg::SourcePinned = pkg.source.parse().map_err(|e| {
+            let mut source: pkg::SourcePinned = pkg.source.parse().map_err(|e| {
                 anyhow!("invalid 'source' entry for package {} lock: {:?}", name, e)
             })?;
+            if let pkg::SourcePinned::Registry(ref mut reg) = source {
+                reg.source.index = reg.source.index.resolved_from(lock_dir);
+                reg.checksum = pkg.checksum.clone().ok_or_else(|| {
+                    anyhow!(
+                        "missing 'checksum' entry for registry package {} lock",
+                        name
+                    )
+                })?;
+            }
             let pkg = pkg::Pinned { name, source };
             let node = graph.add_node(pkg);
             pkg_to_node.insert(key, node);
@@ -243,9 +283,38 @@ impl Lock {
     ///
     /// Useful for showing the user which dependencies are out of date, or which have been updated.
     pub fn diff<'a>(&'a self, old: &'a Self) -> Diff<'a> {
-        let added = self.package.difference(&old.package).collect();
-        let removed = old.package.difference(&self.package).collect();
-        Diff { added, removed }
+        let mut added: BTreeSet<_> = self.package.difference(&old.package).collect();
+        let mut removed: BTreeSet<_> = old.package.difference(&self.package).collect();
+        let count = |set: &BTreeSet<&PkgLock>, name: &str| {
+            set.iter().filter(|pkg| pkg.name == name).count()
+        };
+        let updated: BTreeSet<_> = removed
+            .iter()
+            .filter(|old_pkg| count(&removed, &old_pkg.name) == 1)
+            .filter_map(|&old_pkg| {
+                if count(&added, &old_pkg.name) != 1 {
+                    return None;
+                }
+                let new_pkg = *added.iter().find(|pkg| pkg.name == old_pkg.name)?;
+                Some((old_pkg, new_pkg))
+            })
+            .collect();
+        for (old_pkg, new_pkg) in &updated {
+            removed.remove(old_pkg);
+            added.remove(new_pkg);
+        }
+        Diff {
+            removed,
+            added,
+            updated,
+        }
+    }
+}
+
+impl<'a> Diff<'a> {
+    /// Whether or not the two locks are identical.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty() && self.updated.is_empty()
     }
 }
 
@@ -275,9 +344,10 @@ fn pkg_dep_line(
     source: &pkg::SourcePinned,
     dep_kind: &DepKind,
     disambiguate: bool,
+    lock_dir: &Path,
 ) -> PkgDepLine {
     // Only include the full unique string in the case that this dep requires disambiguation.
-    let source_string = source.to_string();
+    let source_string = source_string(source, lock_dir);
     let pkg_string = pkg_name_disambiguated(name, &source_string, disambiguate);
     // Prefix the dependency name if it differs from the package name.
     let pkg_string = match dep_name {
@@ -297,6 +367,20 @@ fn pkg_dep_line(
     }
 }
 
+/// The string representation of `source` within the lock file in `lock_dir`.
+///
+/// See [crate::registry::RegistryIndex::relative_to] for details.
+fn source_string(source: &pkg::SourcePinned, lock_dir: &Path) -> String {
+    match source {
+        pkg::SourcePinned::Registry(reg) => {
+            let mut reg = reg.clone();
+            reg.source.index = reg.source.index.relative_to(lock_dir);
+            reg.to_string()
+        }
+        _ => source.to_string(),
+    }
+}
+
 type ParsedPkgLine<'a> = (Option<&'a str>, &'a str, Option<fuel_tx::Salt>);
 // Parse the given `PkgDepLine` into its dependency name and unique string segments.
 //
@@ -341,6 +425,7 @@ fn parse_pkg_dep_line(pkg_dep_line: &str) -> anyhow::Result<ParsedPkgLine> {
 pub fn print_diff(member_names: &HashSet<String>, diff: &Diff) {
     print_removed_pkgs(member_names, diff.removed.iter().cloned());
     print_added_pkgs(member_names, diff.added.iter().cloned());
+    print_updated_pkgs(member_names, diff.updated.iter().cloned());
 }
 
 pub fn print_removed_pkgs<'a, I>(member_names: &HashSet<String>, removed: I)
@@ -367,6 +452,34 @@ where
     }
 }
 
+pub fn print_updated_pkgs<'a, I>(member_names: &HashSet<String>, updated: I)
+where
+    I: IntoIterator<Item = (&'a PkgLock, &'a PkgLock)>,
+{
+    for (old, new) in updated {
+        if !member_names.contains(&new.name) {
+            match (pinned_revision(old), pinned_revision(new)) {
+                (Some(old_rev), Some(new_rev)) if old_rev != new_rev => println_green(&format!(
+                    "  Updating {} {} -> {}",
+                    new.name, old_rev, new_rev
+                )),
+                _ => println_green(&format!("  Updating {}", new.name)),
+            }
+        }
+    }
+}
+
+// The commit hash of git packages, or the version of registry packages.
+fn pinned_revision(pkg: &PkgLock) -> Option<String> {
+    if let Some(version) = &pkg.version {
+        return Some(format!("v{}", version));
+    }
+    match pkg.source.starts_with(pkg::SourceGitPinned::PREFIX) {
+        true => pkg.source.rsplit('#').next().map(str::to_string),
+        false => None,
+    }
+}
+
 // Only includes source after the name for git sources for friendlier printing.
 fn name_or_git_unique_string(pkg: &PkgLock) -> Cow<str> {
     match pkg.source.starts_with(pkg::SourceGitPinned::PREFIX) {
@@ -379,7 +492,13 @@ fn name_or_git_unique_string(pkg: &PkgLock) -> Cow<str> {
 mod tests {
     use sway_core::fuel_prelude::fuel_tx;
 
-    use super::parse_pkg_dep_line;
+    use super::{parse_pkg_dep_line, pinned_revision, Lock};
+    use crate::{
+        pkg::{self, SourceRegistry, SourceRegistryPinned},
+        registry::RegistryIndex,
+        DepKind, Edge,
+    };
+    use std::fs;
 
     #[test]
     fn test_parse_pkg_line_with_salt_with_dep_name() {
@@ -424,4 +543,102 @@ mod tests {
         let pkg_dep_line = "std path+from-root (1)";
         parse_pkg_dep_line(pkg_dep_line).unwrap();
     }
+
+    #[test]
+    fn test_diff_pairs_updated_packages() {
+        let old: Lock = toml::de::from_str(
+            r#"
+            [[package]]
+            name = 'app'
+            source = 'member'
+            dependencies = ['bar', 'foo']
+
+            [[package]]
+            name = 'bar'
+            source = 'git+https://example.com/bar?branch=master#1111111111111111111111111111111111111111'
+
+            [[package]]
+            name = 'foo'
+            source = 'git+https://example.com/foo?branch=master#2222222222222222222222222222222222222222'
+            "#,
+        )
+        .unwrap();
+        let new: Lock = toml::de::from_str(
+            r#"
+            [[package]]
+            name = 'app'
+            source = 'member'
+            dependencies = ['bar', 'foo']
+
+            [[package]]
+            name = 'bar'
+            source = 'git+https://example.com/bar?branch=master#1111111111111111111111111111111111111111'
+
+            [[package]]
+            name = 'foo'
+            source = 'git+https://example.com/foo?branch=master#3333333333333333333333333333333333333333'
+            "#,
+        )
+        .unwrap();
+        let diff = new.diff(&old);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.updated.len(), 1);
+        let (old_foo, new_foo) = diff.updated.iter().next().unwrap();
+        assert_eq!(
+            pinned_revision(old_foo).unwrap(),
+            "2222222222222222222222222222222222222222"
+        );
+        assert_eq!(
+            pinned_revision(new_foo).unwrap(),
+            "3333333333333333333333333333333333333333"
+        );
+        assert!(new.diff(&new).is_empty());
+    }
+
+    #[test]
+    fn test_registry_index_path_relative_to_lock() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path().canonicalize().unwrap();
+        let index = root.join("index");
+        let lock_dir = root.join("app");
+        fs::create_dir_all(&index).unwrap();
+        fs::create_dir_all(&lock_dir).unwrap();
+
+        let mut graph = pkg::Graph::new();
+        let app = graph.add_node(pkg::Pinned {
+            name: "app".to_string(),
+            source: pkg::SourcePinned::Member,
+        });
+        let foo = graph.add_node(pkg::Pinned {
+            name: "foo".to_string(),
+            source: pkg::SourcePinned::Registry(SourceRegistryPinned {
+                source: SourceRegistry {
+                    index: RegistryIndex::Path(index),
+                    version: semver::VersionReq::parse("^0.1").unwrap(),
+                },
+                version: semver::Version::new(0, 1, 0),
+                checksum: "00".to_string(),
+            }),
+        });
+        graph.add_edge(app, foo, Edge::new("foo".to_string(), DepKind::Library));
+
+        let lock = Lock::from_graph(&graph, &lock_dir);
+        let string = toml::ser::to_string_pretty(&lock).unwrap();
+        assert!(string.contains("source = 'registry+path+../index?^0.1#0.1.0'"));
+        assert!(!string.contains(&root.display().to_string()));
+
+        // The lock resolves to the same index from the same directory.
+        let lock: Lock = toml::de::from_str(&string).unwrap();
+        let new_graph = lock.to_graph(&lock_dir).unwrap();
+        let sources = |graph: &pkg::Graph| {
+            let mut sources: Vec<_> = graph
+                .node_indices()
+                .map(|n| graph[n].source.clone())
+                .collect();
+            sources.sort_by_key(|source| source.to_string());
+            sources
+        };
+        assert_eq!(sources(&graph), sources(&new_graph));
+    }
 }
@@ -1,15 +1,19 @@
-use crate::pkg::{manifest_file_missing, parsing_failed, wrong_program_type};
+use crate::{
+    pkg::{manifest_file_missing, parsing_failed, wrong_program_type},
+    registry::RegistryConfig,
+};
 use anyhow::{anyhow, bail, Context, Result};
 use forc_tracing::println_yellow_err;
 use forc_util::{find_manifest_dir, validate_name};
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     path::{Path, PathBuf},
     sync::Arc,
 };
 
 use sway_core::{fuel_prelude::fuel_tx, language::parsed::TreeType, parse_tree_type};
+use sway_error::warning::{LintLevel, LINT_NAMES};
 pub use sway_types::ConfigTimeConstant;
 use sway_utils::constants;
 
@@ -113,6 +117,20 @@ impl ManifestFile {
         Ok(member_manifest_files)
     }
 
+    /// The directory from which pinned git and registry dependencies are resolved, in the case
+    /// that the manifest enables `vendored`.
+    ///
+    /// See the [crate::vendor] module for details.
+    pub fn vendor_dir(&self) -> Option<PathBuf> {
+        let vendored = match self {
+            ManifestFile::Package(pkg_manifest) => pkg_manifest.project.vendored,
+            ManifestFile::Workspace(workspace_manifest) => workspace_manifest.workspace.vendored,
+        };
+        vendored
+            .unwrap_or(false)
+            .then(|| self.dir().join(crate::vendor::VENDOR_DIR_NAME))
+    }
+
     /// Returns the path of the lock file for the given ManifestFile
     pub fn lock_path(&self) -> Result<PathBuf> {
         match self {
@@ -145,6 +163,13 @@ pub struct PackageManifest {
     pub constants: Option<BTreeMap<String, ConfigTimeConstant>>,
     build_profile: Option<BTreeMap<String, BuildProfile>>,
     pub contract_dependencies: Option<BTreeMap<String, ContractDependency>>,
+    /// Package-wide lint levels, mapping lint names to one of `allow`, `warn` or `deny`.
+    pub lints: Option<BTreeMap<String, String>>,
+    /// The package indices against which registry dependencies are resolved, by registry name.
+    pub registries: Option<BTreeMap<String, RegistryConfig>>,
+    /// The features that may be enabled for the package, mapping each feature name to the list of
+    /// features that it in turn enables.
+    pub features: Option<BTreeMap<String, Vec<String>>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -158,6 +183,7 @@ pub struct Project {
     pub entry: String,
     pub implicit_std: Option<bool>,
     pub forc_version: Option<semver::Version>,
+    pub vendored: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -198,6 +224,9 @@ pub struct DependencyDetails {
     pub(crate) tag: Option<String>,
     pub(crate) package: Option<String>,
     pub(crate) rev: Option<String>,
+    pub(crate) registry: Option<String>,
+    /// The features to enable for the dependency.
+    pub(crate) features: Option<Vec<String>>,
 }
 
 /// Parameters to pass through to the `sway_core::BuildConfig` during compilation.
@@ -212,6 +241,11 @@ pub struct BuildProfile {
     pub terse: bool,
     pub time_phases: bool,
     pub include_tests: bool,
+    #[serde(default)]
+    pub deny_warnings: bool,
+    /// Values that override those of the `[constants]` tables of the workspace members.
+    #[serde(default)]
+    pub constants: BTreeMap<String, String>,
 }
 
 impl Dependency {
@@ -222,6 +256,14 @@ impl Dependency {
             Self::Detailed(ref det) => det.package.as_deref(),
         }
     }
+
+    /// The features to enable for the dependency, if any are specified.
+    pub fn features(&self) -> &[String] {
+        match *self {
+            Self::Simple(_) => &[],
+            Self::Detailed(ref det) => det.features.as_deref().unwrap_or_default(),
+        }
+    }
 }
 
 impl PackageManifestFile {
@@ -352,6 +394,28 @@ impl PackageManifestFile {
         self.constants.as_ref().cloned().unwrap_or_default()
     }
 
+    /// The config time constants on the manifest, with the values of those named within
+    /// `overrides` replaced. The declared type and visibility of each constant are kept.
+    pub fn config_time_constants_with_overrides(
+        &self,
+        overrides: &BTreeMap<String, String>,
+    ) -> BTreeMap<String, ConfigTimeConstant> {
+        let mut constants = self.config_time_constants();
+        for (name, value) in overrides {
+            if let Some(constant) = constants.get_mut(name) {
+                constant.value = value.clone();
+            }
+        }
+        constants
+    }
+
+    /// Whether or not the `[constants]` table declares a constant with the given name.
+    pub fn has_constant(&self, name: &str) -> bool {
+        self.constants
+            .as_ref()
+            .is_some_and(|constants| constants.contains_key(name))
+    }
+
     /// Returns the workspace manifest file if this `PackageManifestFile` is one of the members.
     pub fn workspace(&self) -> Result<Option<WorkspaceManifestFile>> {
         let parent_dir = match self.dir().parent() {
@@ -406,39 +470,80 @@ impl PackageManifest {
     /// implicitly. In this case, the git tag associated with the version of this crate is used to
     /// specify the pinned commit at which we fetch `std`.
     pub fn from_file(path: &Path) -> Result<Self> {
-        // While creating a `ManifestFile` we need to check if the given path corresponds to a
-        // package or a workspace. While doing so, we should be printing the warnings if the given
-        // file parses so that we only see warnings for the correct type of manifest.
-        let mut warnings = vec![];
         let manifest_str = std::fs::read_to_string(path)
             .map_err(|e| anyhow!("failed to read manifest at {:?}: {}", path, e))?;
-        let toml_de = &mut toml::de::Deserializer::new(&manifest_str);
-        let mut manifest: Self = serde_ignored::deserialize(toml_de, |path| {
-            let warning = format!("  WARNING! unused manifest key: {}", path);
-            warnings.push(warning);
-        })
-        .map_err(|e| anyhow!("failed to parse manifest: {}.", e))?;
-        for warning in warnings {
-            println_yellow_err(&warning);
-        }
-        manifest.implicitly_include_std_if_missing();
-        manifest.implicitly_include_default_build_profiles_if_missing();
-        manifest.validate()?;
-        Ok(manifest)
+        manifest_str.parse()
     }
 
     /// Validate the `PackageManifest`.
     ///
     /// This checks the project and organization names against a set of reserved/restricted
-    /// keywords and patterns.
+    /// keywords and patterns, along with the names of lints and features.
     pub fn validate(&self) -> Result<()> {
         validate_name(&self.project.name, "package name")?;
         if let Some(ref org) = self.project.organization {
             validate_name(org, "organization name")?;
         }
+        for (lint, level) in self.lints.iter().flatten() {
+            if !LINT_NAMES.contains(&lint.as_str()) {
+                bail!("unknown lint \"{lint}\" in the `[lints]` table");
+            }
+            level
+                .parse::<LintLevel>()
+                .map_err(|e| anyhow!("{e} for lint \"{lint}\" in the `[lints]` table"))?;
+        }
+        for (feature, implied) in self.features.iter().flatten() {
+            forc_util::restricted::contains_invalid_char(feature, "feature name")?;
+            for implied in implied {
+                if !self.has_feature(implied) {
+                    bail!(
+                        "unknown feature \"{implied}\" enabled by feature \"{feature}\" \
+                        in the `[features]` table"
+                    );
+                }
+            }
+        }
         Ok(())
     }
 
+    /// Whether or not the given feature is declared within the `[features]` table.
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features
+            .as_ref()
+            .is_some_and(|features| features.contains_key(feature))
+    }
+
+    /// The full set of features enabled for the package when enabling the given features,
+    /// including those enabled in turn by the `[features]` table.
+    ///
+    /// Features that are not declared within the `[features]` table are ignored.
+    pub fn resolve_features<'a>(
+        &self,
+        features: impl IntoIterator<Item = &'a String>,
+    ) -> BTreeSet<String> {
+        let mut resolved = BTreeSet::new();
+        let mut to_visit: Vec<&String> = features.into_iter().collect();
+        while let Some(feature) = to_visit.pop() {
+            let implied = match self.features.as_ref().and_then(|fs| fs.get(feature)) {
+                Some(implied) => implied,
+                None => continue,
+            };
+            if resolved.insert(feature.clone()) {
+                to_visit.extend(implied);
+            }
+        }
+        resolved
+    }
+
+    /// The package-wide lint levels specified in the `[lints]` table.
+    pub fn lint_levels(&self) -> BTreeMap<String, LintLevel> {
+        self.lints
+            .iter()
+            .flatten()
+            .filter_map(|(lint, level)| Some((lint.clone(), level.parse().ok()?)))
+            .collect()
+    }
+
     /// Given a directory to a forc project containing a `Forc.toml`, read the manifest.
     ///
     /// This is short for `PackageManifest::from_file`, but takes care of constructing the path to the
@@ -555,6 +660,13 @@ impl PackageManifest {
             .and_then(|patches| patches.get(patch_name))
     }
 
+    /// Retrieve the registry with the given name declared under `[registries]`.
+    pub fn registry(&self, registry_name: &str) -> Option<&RegistryConfig> {
+        self.registries
+            .as_ref()
+            .and_then(|registries| registries.get(registry_name))
+    }
+
     /// Retrieve a reference to the contract dependency with the given name.
     pub fn contract_dep(&self, contract_dep_name: &str) -> Option<&ContractDependency> {
         self.contract_dependencies
@@ -588,6 +700,34 @@ impl PackageManifest {
     }
 }
 
+impl std::str::FromStr for PackageManifest {
+    type Err = anyhow::Error;
+
+    /// Parse a `PackageManifest` from the contents of a `Forc.toml`.
+    ///
+    /// Like `from_file`, this implicitly includes `std` and the default build profiles and
+    /// `validate`s the resulting manifest.
+    fn from_str(manifest_str: &str) -> Result<Self> {
+        // While creating a `ManifestFile` we need to check if the given path corresponds to a
+        // package or a workspace. While doing so, we should be printing the warnings if the given
+        // file parses so that we only see warnings for the correct type of manifest.
+        let mut warnings = vec![];
+        let toml_de = &mut toml::de::Deserializer::new(manifest_str);
+        let mut manifest: Self = serde_ignored::deserialize(toml_de, |path| {
+            let warning = format!("  WARNING! unused manifest key: {}", path);
+            warnings.push(warning);
+        })
+        .map_err(|e| anyhow!("failed to parse manifest: {}.", e))?;
+        for warning in warnings {
+            println_yellow_err(&warning);
+        }
+        manifest.implicitly_include_std_if_missing();
+        manifest.implicitly_include_default_build_profiles_if_missing();
+        manifest.validate()?;
+        Ok(manifest)
+    }
+}
+
 impl BuildProfile {
     pub const DEBUG: &'static str = "debug";
     pub const RELEASE: &'static str = "release";
@@ -603,6 +743,8 @@ impl BuildProfile {
             terse: false,
             time_phases: false,
             include_tests: false,
+            deny_warnings: false,
+            constants: BTreeMap::new(),
         }
     }
 
@@ -616,8 +758,21 @@ impl BuildProfile {
             terse: false,
             time_phases: false,
             include_tests: false,
+            deny_warnings: false,
+            constants: BTreeMap::new(),
         }
     }
+
+    /// Whether or not the profile requests printing any of the intermediate compilation results,
+    /// or the time taken by each compilation phase.
+    pub fn prints_intermediates(&self) -> bool {
+        self.print_ast
+            || self.print_dca_graph
+            || self.print_ir
+            || self.print_finalized_asm
+            || self.print_intermediate_asm
+            || self.time_phases
+    }
 }
 
 impl std::ops::Deref for PackageManifestFile {
@@ -633,6 +788,16 @@ impl Default for BuildProfile {
     }
 }
 
+/// Parse a `NAME=VALUE` constant override, as given to the `--constant` option of forc commands.
+pub fn parse_constant_override(s: &str) -> Result<(String, String)> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => bail!("invalid constant override \"{s}\", expected `NAME=VALUE`"),
+    }
+}
+
 /// The definition for the implicit `std` dependency.
 fn implicit_std_dep() -> Dependency {
     // Here, we use the `forc-pkg` crate version formatted with the `v` prefix (e.g. "v1.2.3"),
@@ -699,6 +864,7 @@ pub struct WorkspaceManifest {
 #[serde(rename_all = "kebab-case")]
 pub struct Workspace {
     pub members: Vec<PathBuf>,
+    pub vendored: Option<bool>,
 }
 
 impl WorkspaceManifestFile {
@@ -1,10 +1,12 @@
 use crate::{
+    build_cache::{self, NamespaceCache},
     lock::Lock,
     manifest::{
         BuildProfile, ConfigTimeConstant, Dependency, ManifestFile, MemberManifestFiles,
         PackageManifest, PackageManifestFile,
     },
-    CORE, PRELUDE, STD,
+    registry::{self, RegistryIndex, DEFAULT_REGISTRY_NAME},
+    vendor, CORE, PRELUDE, STD,
 };
 use anyhow::{anyhow, bail, Context, Error, Result};
 use forc_util::{
@@ -18,6 +20,7 @@ use petgraph::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
+    cmp::Ordering,
     collections::{hash_map, BTreeMap, BTreeSet, HashMap, HashSet},
     fmt,
     fs::{self, File},
@@ -31,7 +34,7 @@ use sway_core::{
         fuel_crypto,
         fuel_tx::{self, Contract, ContractId, StorageSlot},
     },
-    Engines, TypeEngine,
+    Engines,
 };
 use sway_core::{
     language::{
@@ -42,7 +45,10 @@ use sway_core::{
     source_map::SourceMap,
     CompileResult, CompiledBytecode, FinalizedEntry,
 };
-use sway_error::error::CompileError;
+use sway_error::{
+    error::CompileError,
+    warning::{CompileWarning, LintLevel},
+};
 use sway_types::Ident;
 use sway_utils::constants;
 use tracing::{info, warn};
@@ -56,6 +62,8 @@ pub struct Edge {
     /// This may differ from the package name as declared under the dependency package's manifest.
     pub name: String,
     pub kind: DepKind,
+    /// The features of the dependency enabled by the dependent package.
+    pub features: BTreeSet<String>,
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -93,9 +101,11 @@ pub struct BuiltPackage {
     pub bytecode: Vec<u8>,
     pub entries: Vec<FinalizedEntry>,
     pub tree_type: TreeType,
-    source_map: SourceMap,
+    pub(crate) source_map: SourceMap,
     pub pkg_name: String,
     pub declaration_engine: DeclarationEngine,
+    /// Whether or not compiling the package produced any warnings.
+    pub(crate) has_warnings: bool,
 }
 
 /// The result of successfully compiling a workspace.
@@ -140,7 +150,7 @@ pub enum Source {
     Git(SourceGit),
     /// A path to a directory with a `Forc.toml` manifest at its root.
     Path(PathBuf),
-    /// A forc project hosted on the official registry.
+    /// A forc project published to a package index.
     Registry(SourceRegistry),
 }
 
@@ -165,11 +175,13 @@ pub enum GitReference {
     DefaultBranch,
 }
 
-/// A package from the official registry.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+/// A package published to a package index.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct SourceRegistry {
-    /// The base version specified for the package.
-    pub version: semver::Version,
+    /// The index from which the package is fetched.
+    pub index: RegistryIndex,
+    /// The version requirement specified for the package.
+    pub version: semver::VersionReq,
 }
 
 /// A pinned instance of a git source.
@@ -200,10 +212,15 @@ pub struct SourcePathPinned {
 /// A pinned instance of the registry source.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct SourceRegistryPinned {
-    /// The registry package with base version.
+    /// The registry package with its version requirement.
     pub source: SourceRegistry,
     /// The pinned version.
     pub version: semver::Version,
+    /// The hex-encoded SHA-256 hash of the pinned version's source tarball.
+    ///
+    /// This is recorded under its own `checksum` field within the lock file, rather than within
+    /// the source string.
+    pub checksum: String,
 }
 
 /// A pinned instance of the package source.
@@ -224,6 +241,8 @@ pub struct BuildPlan {
     graph: Graph,
     manifest_map: ManifestMap,
     compilation_order: Vec<NodeIx>,
+    /// The features enabled for the workspace members, e.g. via `--features`.
+    member_features: BTreeSet<String>,
 }
 
 /// Error returned upon failed parsing of `PinnedId::from_str`.
@@ -234,6 +253,15 @@ pub struct PinnedIdParseError;
 #[derive(Clone, Debug)]
 pub struct SourcePathPinnedParseError;
 
+/// Error returned upon failed parsing of `SourceRegistryPinned::from_str`.
+#[derive(Clone, Debug)]
+pub enum SourceRegistryPinnedParseError {
+    Prefix,
+    Index,
+    VersionReq,
+    Version,
+}
+
 /// Error returned upon failed parsing of `SourceGitPinned::from_str`.
 #[derive(Clone, Debug)]
 pub enum SourceGitPinnedParseError {
@@ -325,6 +353,24 @@ pub struct BuildOpts {
     pub time_phases: bool,
     /// Include all test functions within the build.
     pub tests: bool,
+    /// Treat warnings that are not explicitly allowed as errors.
+    pub deny_warnings: bool,
+    /// The workspace members to build.
+    pub member_filter: MemberFilter,
+    /// The features to enable for the workspace members that declare them.
+    pub features: Vec<String>,
+    /// Values that override those of the `[constants]` tables of the workspace members, taking
+    /// precedence over those of the selected build profile.
+    pub constants: BTreeMap<String, String>,
+}
+
+/// Selects a subset of the members of a workspace by name.
+#[derive(Clone, Debug, Default)]
+pub struct MemberFilter {
+    /// The names of the members to select. If empty, all members are selected.
+    pub packages: Vec<String>,
+    /// The names of the members to leave out of the selection.
+    pub exclude: Vec<String>,
 }
 
 impl GitSourceIndex {
@@ -338,7 +384,21 @@ impl GitSourceIndex {
 
 impl Edge {
     pub fn new(name: String, kind: DepKind) -> Edge {
-        Edge { name, kind }
+        let features = BTreeSet::new();
+        Edge {
+            name,
+            kind,
+            features,
+        }
+    }
+
+    /// Enable the given features of the dependency.
+    pub fn with_features<I>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        self.features.extend(features);
+        self
     }
 }
 
@@ -475,12 +535,13 @@ impl BuildPlan {
         fetch_graph(manifests, offline, &mut graph, &mut manifest_map)?;
         // Validate the graph, since we constructed the graph from scratch the paths will not be a
         // problem but the version check is still needed
-        validate_graph(&graph, manifests)?;
+        validate_graph(&graph, manifests, None)?;
         let compilation_order = compilation_order(&graph)?;
         Ok(Self {
             graph,
             manifest_map,
             compilation_order,
+            member_features: BTreeSet::new(),
         })
     }
 
@@ -496,6 +557,9 @@ impl BuildPlan {
     ///
     /// The resulting build plan should always be in a valid state that is ready for building or
     /// checking.
+    ///
+    /// In the case that the root manifest enables `vendored`, git and registry packages are
+    /// resolved from the project's vendor directory. See the [crate::vendor] module.
     // TODO: Currently (if `--locked` isn't specified) this writes the updated lock directly. This
     // probably should not be the role of the `BuildPlan` constructor - instead, we should return
     // the manifest alongside some lock diff type that can be used to optionally write the updated
@@ -505,6 +569,30 @@ impl BuildPlan {
         manifests: &MemberManifestFiles,
         locked: bool,
         offline: bool,
+    ) -> Result<Self> {
+        let vendor_dir = vendor::vendor_dir(lock_path)?;
+        Self::from_lock_and_manifests_vendored(
+            lock_path,
+            manifests,
+            locked,
+            offline,
+            vendor_dir.as_deref(),
+        )
+    }
+
+    /// The same as [BuildPlan::from_lock_and_manifests], but git and registry packages are
+    /// resolved from the given `vendor_dir` if one is specified, rather than from the user's
+    /// `$HOME/.forc` directory.
+    ///
+    /// Packages are never fetched when resolving from a vendor directory. Instead, an error is
+    /// produced if the vendor directory is missing any of the git or registry packages required
+    /// by the manifests.
+    pub(crate) fn from_lock_and_manifests_vendored(
+        lock_path: &Path,
+        manifests: &MemberManifestFiles,
+        locked: bool,
+        offline: bool,
+        vendor_dir: Option<&Path>,
     ) -> Result<Self> {
         // Check toolchain version
         validate_version(manifests)?;
@@ -522,7 +610,8 @@ impl BuildPlan {
         });
 
         // Next, construct the package graph from the lock.
-        let mut graph = lock.to_graph().unwrap_or_else(|e| {
+        let lock_dir = lock_path.parent().unwrap_or_else(|| Path::new(""));
+        let mut graph = lock.to_graph(lock_dir).unwrap_or_else(|e| {
             new_lock_cause = Some(anyhow!("Invalid lock: {}", e));
             Graph::default()
         });
@@ -532,7 +621,7 @@ impl BuildPlan {
         // might have edited the `Forc.lock` file when they shouldn't have, a path dependency no
         // longer exists at its specified location, etc. We must first remove all invalid nodes
         // before we can determine what we need to fetch.
-        let invalid_deps = validate_graph(&graph, manifests)?;
+        let invalid_deps = validate_graph(&graph, manifests, vendor_dir)?;
         let members: HashSet<String> = manifests
             .iter()
             .map(|(member_name, _)| member_name.clone())
@@ -541,10 +630,30 @@ impl BuildPlan {
 
         // We know that the remaining nodes have valid paths, otherwise they would have been
         // removed. We can safely produce an initial `manifest_map`.
-        let mut manifest_map = graph_to_manifest_map(manifests, &graph)?;
-
-        // Attempt to fetch the remainder of the graph.
-        let _added = fetch_graph(manifests, offline, &mut graph, &mut manifest_map)?;
+        let mut manifest_map = graph_to_manifest_map(manifests, &graph, vendor_dir)?;
+
+        // Attempt to fetch the remainder of the graph. Vendored packages are never fetched, so
+        // any git or registry package that is still missing is not present in the vendor dir.
+        let offline = offline || vendor_dir.is_some();
+        let added = fetch_graph(manifests, offline, &mut graph, &mut manifest_map);
+        if let Some(vendor_dir) = vendor_dir {
+            let unvendored = match &added {
+                Err(e) => Some(e.to_string()),
+                Ok(added) => added
+                    .iter()
+                    .find(|&&n| vendor::pkg_dir(vendor_dir, &graph[n]).is_some())
+                    .map(|&n| format!("package `{}` is not vendored", graph[n].name)),
+            };
+            if let Some(cause) = unvendored {
+                bail!(
+                    "failed to resolve dependencies from the vendor directory {} (Cause: {}). \
+                    Run `forc vendor` to update the vendored dependencies.",
+                    vendor_dir.display(),
+                    cause,
+                );
+            }
+        }
+        added?;
 
         // Determine the compilation order.
         let compilation_order = compilation_order(&graph)?;
@@ -553,12 +662,13 @@ impl BuildPlan {
             graph,
             manifest_map,
             compilation_order,
+            member_features: BTreeSet::new(),
         };
 
         // Construct the new lock and check the diff.
-        let new_lock = Lock::from_graph(plan.graph());
+        let new_lock = Lock::from_graph(plan.graph(), lock_dir);
         let lock_diff = new_lock.diff(&lock);
-        if !lock_diff.removed.is_empty() || !lock_diff.added.is_empty() {
+        if !lock_diff.is_empty() {
             new_lock_cause.get_or_insert(anyhow!("lock file did not match manifest"));
         }
 
@@ -588,6 +698,79 @@ impl BuildPlan {
         Ok(plan)
     }
 
+    /// Create a new build plan from an existing lock within `lock_dir`, re-pinning only the
+    /// dependency with the given package name.
+    ///
+    /// All other pinned packages within the lock are retained. Dependencies of the re-pinned
+    /// package are only fetched and pinned if its new version requires a source that is not
+    /// already pinned within the lock.
+    ///
+    /// If `precise` is specified, the dependency is pinned to the given commit (for git
+    /// dependencies) or version (for registry dependencies) rather than the latest commit or
+    /// version allowed by the manifest.
+    pub fn from_lock_and_manifests_with_update(
+        lock: &Lock,
+        lock_dir: &Path,
+        manifests: &MemberManifestFiles,
+        dep_name: &str,
+        precise: Option<&str>,
+        offline: bool,
+    ) -> Result<Self> {
+        validate_version(manifests)?;
+        let mut graph = lock.to_graph(lock_dir)?;
+        if !graph
+            .node_indices()
+            .any(|n| graph[n].name == dep_name && graph[n].source != SourcePinned::Member)
+        {
+            bail!(
+                "package `{}` is not a dependency in the lock file",
+                dep_name
+            );
+        }
+
+        // Remove invalid dependencies as we would when loading the lock for a build.
+        let invalid_deps = validate_graph(&graph, manifests, None)?;
+        let members: HashSet<String> = manifests.keys().cloned().collect();
+        remove_deps(&mut graph, &members, &invalid_deps);
+        let mut manifest_map = graph_to_manifest_map(manifests, &graph, None)?;
+
+        // Remove the target package so that it is re-pinned on the following fetch. Its
+        // dependencies are retained so that they may be re-used if still required.
+        let targets: Vec<NodeIx> = graph
+            .node_indices()
+            .filter(|&n| graph[n].name == dep_name && graph[n].source != SourcePinned::Member)
+            .collect();
+        if precise.is_some() && targets.len() > 1 {
+            bail!(
+                "cannot update package `{}` with `--precise` as it is pinned to more than one \
+                source",
+                dep_name
+            );
+        }
+        for target in targets {
+            let pinned = graph[target].clone();
+            let unpinned = pinned.unpinned(manifest_map[&pinned.id()].dir());
+            // Parents are re-linked to the new node during the fetch.
+            graph.remove_node(target);
+            if let Some(precise) = precise {
+                let fetch_id =
+                    fetch_id(manifest_map[&pinned.id()].dir(), std::time::Instant::now());
+                let pinned = pin_precise(fetch_id, &unpinned, precise, &mut manifest_map, offline)?;
+                graph.add_node(pinned);
+            }
+        }
+
+        fetch_graph(manifests, offline, &mut graph, &mut manifest_map)?;
+        remove_unreachable_nodes(&mut graph);
+        let compilation_order = compilation_order(&graph)?;
+        Ok(Self {
+            graph,
+            manifest_map,
+            compilation_order,
+            member_features: BTreeSet::new(),
+        })
+    }
+
     /// Produce an iterator yielding all workspace member nodes in order of compilation.
     ///
     /// In the case that this `BuildPlan` was constructed for a single package,
@@ -620,6 +803,122 @@ impl BuildPlan {
             .find(|node_ix| self.graph[*node_ix].name == member_name)
     }
 
+    /// Resolve the members selected by the given filter, returning their indices in compilation
+    /// order.
+    ///
+    /// Produces an error if the filter names a package that is not a member, or if it leaves no
+    /// members to select.
+    pub fn selected_members(&self, filter: &MemberFilter) -> Result<Vec<NodeIx>> {
+        let find_member = |name: &String| {
+            self.find_member_index(name)
+                .ok_or_else(|| anyhow!("No member named `{}` was found in the workspace", name))
+        };
+        let packages: HashSet<NodeIx> = filter
+            .packages
+            .iter()
+            .map(find_member)
+            .collect::<Result<_>>()?;
+        let excluded: HashSet<NodeIx> = filter
+            .exclude
+            .iter()
+            .map(find_member)
+            .collect::<Result<_>>()?;
+        let selected: Vec<NodeIx> = self
+            .member_nodes()
+            .filter(|node| filter.packages.is_empty() || packages.contains(node))
+            .filter(|node| !excluded.contains(node))
+            .collect();
+        if selected.is_empty() {
+            bail!("All of the selected members were excluded");
+        }
+        Ok(selected)
+    }
+
+    /// Produce a build plan containing only the given members and their dependencies.
+    pub fn member_subset(&self, members: &[NodeIx]) -> Self {
+        let required: HashSet<NodeIx> = members
+            .iter()
+            .flat_map(|&member| self.node_deps(member))
+            .collect();
+        let mut graph = self.graph.clone();
+        graph.retain_nodes(|_, node| required.contains(&node));
+        let manifest_map = graph
+            .node_indices()
+            .map(|node| {
+                let id = graph[node].id();
+                (id, self.manifest_map[&id].clone())
+            })
+            .collect();
+        let compilation_order = self
+            .compilation_order
+            .iter()
+            .cloned()
+            .filter(|node| required.contains(node))
+            .collect();
+        Self {
+            graph,
+            manifest_map,
+            compilation_order,
+            member_features: self.member_features.clone(),
+        }
+    }
+
+    /// Enable the given features for the workspace members that declare them.
+    ///
+    /// Produces an error if a feature is not declared by any member.
+    pub fn enable_member_features(&mut self, features: &[String]) -> Result<()> {
+        for feature in features {
+            let declared = self
+                .member_nodes()
+                .any(|node| self.manifest_map[&self.graph[node].id()].has_feature(feature));
+            if !declared {
+                bail!(
+                    "feature \"{}\" is not declared in the `[features]` table of any workspace \
+                    member",
+                    feature
+                );
+            }
+        }
+        self.member_features.extend(features.iter().cloned());
+        Ok(())
+    }
+
+    /// Ensure that each of the given constant overrides names a constant declared in the
+    /// `[constants]` table of at least one workspace member.
+    pub fn validate_constant_overrides(&self, constants: &BTreeMap<String, String>) -> Result<()> {
+        for name in constants.keys() {
+            let declared = self
+                .member_nodes()
+                .any(|node| self.manifest_map[&self.graph[node].id()].has_constant(name));
+            if !declared {
+                bail!(
+                    "constant \"{}\" is not declared in the `[constants]` table of any workspace \
+                    member",
+                    name
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The full set of features enabled for the package at the given node.
+    ///
+    /// This includes the features enabled by each of the package's dependents, the member
+    /// features in the case that the package is a workspace member, and all features enabled in
+    /// turn by the package's `[features]` table.
+    pub fn node_features(&self, node: NodeIx) -> BTreeSet<String> {
+        let pinned = &self.graph[node];
+        let dependent_features = self
+            .graph
+            .edges_directed(node, Direction::Incoming)
+            .flat_map(|edge| &edge.weight().features);
+        let member_features = self
+            .member_features
+            .iter()
+            .filter(|_| pinned.source == SourcePinned::Member);
+        self.manifest_map[&pinned.id()].resolve_features(dependent_features.chain(member_features))
+    }
+
     /// Produce an iterator yielding indices for the given node and its dependencies in BFS order.
     pub fn node_deps(&self, n: NodeIx) -> impl '_ + Iterator<Item = NodeIx> {
         let bfs = Bfs::new(&self.graph, n);
@@ -706,7 +1005,11 @@ fn member_nodes(g: &Graph) -> impl Iterator<Item = NodeIx> + '_ {
 /// Validates the state of the pinned package graph against the given ManifestFile.
 ///
 /// Returns the set of invalid dependency edges.
-fn validate_graph(graph: &Graph, manifests: &MemberManifestFiles) -> Result<BTreeSet<EdgeIx>> {
+fn validate_graph(
+    graph: &Graph,
+    manifests: &MemberManifestFiles,
+    vendor_dir: Option<&Path>,
+) -> Result<BTreeSet<EdgeIx>> {
     let mut member_pkgs: HashMap<&String, &PackageManifestFile> = manifests.iter().collect();
     let member_nodes: Vec<_> = member_nodes(graph)
         .filter_map(|n| member_pkgs.remove(&graph[n].name).map(|pkg| (n, pkg)))
@@ -718,10 +1021,17 @@ fn validate_graph(graph: &Graph, manifests: &MemberManifestFiles) -> Result<BTre
     }
 
     let mut visited = HashSet::new();
-    let edges = member_nodes
-        .into_iter()
-        .flat_map(move |(n, _)| validate_deps(graph, n, manifests, &mut visited))
-        .collect();
+    let mut edges = BTreeSet::new();
+    for (n, pkg_manifest) in member_nodes {
+        edges.extend(validate_deps(
+            graph,
+            n,
+            pkg_manifest,
+            manifests,
+            vendor_dir,
+            &mut visited,
+        )?);
+    }
 
     Ok(edges)
 }
@@ -729,30 +1039,50 @@ fn validate_graph(graph: &Graph, manifests: &MemberManifestFiles) -> Result<BTre
 /// Recursively validate all dependencies of the given `node`.
 ///
 /// Returns the set of invalid dependency edges.
+///
+/// Registry packages whose source tarball does not match the checksum pinned within the lock file
+/// are never considered invalid dependencies, and instead produce an error.
 fn validate_deps(
     graph: &Graph,
     node: NodeIx,
+    node_manifest: &PackageManifestFile,
     manifests: &MemberManifestFiles,
+    vendor_dir: Option<&Path>,
     visited: &mut HashSet<NodeIx>,
-) -> BTreeSet<EdgeIx> {
+) -> Result<BTreeSet<EdgeIx>> {
     let mut remove = BTreeSet::default();
     for edge in graph.edges_directed(node, Direction::Outgoing) {
         let dep_name = edge.weight();
         let dep_node = edge.target();
-        match validate_dep(graph, manifests, dep_name, dep_node) {
+        match validate_dep(
+            graph,
+            node_manifest,
+            manifests,
+            vendor_dir,
+            dep_name,
+            dep_node,
+        ) {
+            Err(e) if e.is::<registry::ChecksumMismatch>() => return Err(e),
             Err(_) => {
                 remove.insert(edge.id());
             }
-            Ok(_) => {
+            Ok(dep_manifest) => {
                 if visited.insert(dep_node) {
-                    let rm = validate_deps(graph, dep_node, manifests, visited);
+                    let rm = validate_deps(
+                        graph,
+                        dep_node,
+                        &dep_manifest,
+                        manifests,
+                        vendor_dir,
+                        visited,
+                    )?;
                     remove.extend(rm);
                 }
                 continue;
             }
         }
     }
-    remove
+    Ok(remove)
 }
 
 /// Check the validity of a node's dependency within the graph.
@@ -760,31 +1090,50 @@ fn validate_deps(
 /// Returns the `ManifestFile` in the case that the dependency is valid.
 fn validate_dep(
     graph: &Graph,
+    node_manifest: &PackageManifestFile,
     manifests: &MemberManifestFiles,
+    vendor_dir: Option<&Path>,
     dep_edge: &Edge,
     dep_node: NodeIx,
 ) -> Result<PackageManifestFile> {
     let dep_name = &dep_edge.name;
-    let node_manifest = manifests
-        .get(dep_name)
-        .ok_or_else(|| anyhow!("Couldn't find manifest file for {}", dep_name))?;
+    // Find the dependency's entry within the parent manifest.
+    let dep_entry = match &dep_edge.kind {
+        DepKind::Library => node_manifest.dep(dep_name),
+        DepKind::Contract { salt } => node_manifest
+            .contract_dep(dep_name)
+            .filter(|contract_dep| contract_dep.salt == *salt)
+            .map(|contract_dep| &contract_dep.dependency),
+    }
+    .ok_or_else(|| anyhow!("no entry in parent manifest"))?;
+    let name = dep_entry.package().unwrap_or(dep_name);
+    let dep_source = dep_to_source_patched(node_manifest, name, dep_entry, manifests)?;
+
+    // Registry packages are fetched while constructing their path, so check their source first to
+    // avoid fetching packages that are no longer required.
+    if let SourcePinned::Registry(reg) = &graph[dep_node].source {
+        if dep_source != Source::Registry(reg.source.clone()) {
+            bail!("dependency node's source does not match manifest entry");
+        }
+    }
+
     // Check the validity of the dependency path, including its path root.
-    let dep_path = dep_path(graph, node_manifest, dep_node, manifests).map_err(|e| {
-        anyhow!(
-            "failed to construct path for dependency {:?}: {}",
-            dep_name,
-            e
-        )
-    })?;
+    let dep_path =
+        dep_path(graph, node_manifest, dep_node, manifests, vendor_dir).map_err(|e| {
+            match e.downcast::<registry::ChecksumMismatch>() {
+                Ok(mismatch) => mismatch.into(),
+                Err(e) => anyhow!(
+                    "failed to construct path for dependency {:?}: {}",
+                    dep_name,
+                    e
+                ),
+            }
+        })?;
 
     // Ensure the manifest is accessible.
     let dep_manifest = PackageManifestFile::from_dir(&dep_path)?;
 
     // Check that the dependency's source matches the entry in the parent manifest.
-    let dep_entry = node_manifest
-        .dep(dep_name)
-        .ok_or_else(|| anyhow!("no entry in parent manifest"))?;
-    let dep_source = dep_to_source_patched(node_manifest, dep_name, dep_entry, manifests)?;
     let dep_pkg = graph[dep_node].unpinned(&dep_path);
     if dep_pkg.source != dep_source {
         bail!("dependency node's source does not match manifest entry");
@@ -823,6 +1172,18 @@ fn validate_dep_manifest(
         );
     }
     validate_pkg_version(dep_manifest)?;
+    // Ensure each of the enabled features is declared by the dependency.
+    if let Some(feature) = dep_edge
+        .features
+        .iter()
+        .find(|feature| !dep_manifest.has_feature(feature))
+    {
+        bail!(
+            "feature \"{}\" is not declared in the `[features]` table of package {:?}",
+            feature,
+            dep_manifest.project.name,
+        );
+    }
     Ok(())
 }
 
@@ -830,14 +1191,26 @@ fn validate_dep_manifest(
 ///
 /// Also returns `None` in the case that the dependency is a `Path` dependency and the path root is
 /// invalid.
+///
+/// Git and registry dependencies are found within the given `vendor_dir` if one is specified.
 fn dep_path(
     graph: &Graph,
     node_manifest: &PackageManifestFile,
     dep_node: NodeIx,
     manifests: &MemberManifestFiles,
+    vendor_dir: Option<&Path>,
 ) -> Result<PathBuf> {
     let dep = &graph[dep_node];
     let dep_name = &dep.name;
+    if let Some(pkg_dir) = vendor_dir.and_then(|dir| vendor::pkg_dir(dir, dep)) {
+        return find_dir_within(&pkg_dir, dep_name).ok_or_else(|| {
+            anyhow!(
+                "failed to find package `{}` in {}",
+                dep_name,
+                pkg_dir.display()
+            )
+        });
+    }
     match &dep.source {
         SourcePinned::Git(git) => {
             let repo_path = git_commit_path(&dep.name, &git.source.repo, &git.commit_hash);
@@ -881,7 +1254,7 @@ fn dep_path(
                 node_manifest.project.name
             )
         }
-        SourcePinned::Registry(_reg) => unreachable!("registry dependencies not yet supported"),
+        SourcePinned::Registry(reg) => fetch_registry(dep_name, reg),
         SourcePinned::Member => {
             // If a node has a root dependency it is a member of the workspace.
             manifests
@@ -930,6 +1303,15 @@ fn remove_deps(
     }
 }
 
+/// Remove all nodes that cannot be reached from any of the graph's member nodes.
+fn remove_unreachable_nodes(graph: &mut Graph) {
+    let mut reachable = HashSet::new();
+    for member in member_nodes(graph) {
+        reachable.extend(Bfs::new(&*graph, member).iter(&*graph));
+    }
+    graph.retain_nodes(|_, node| reachable.contains(&node));
+}
+
 fn has_parent(graph: &Graph, node: NodeIx) -> bool {
     graph
         .edges_directed(node, Direction::Incoming)
@@ -1027,6 +1409,23 @@ impl SourceGitPinned {
     pub const PREFIX: &'static str = "git";
 }
 
+impl SourceRegistryPinned {
+    pub const PREFIX: &'static str = "registry";
+}
+
+// The version requirement is not `Ord`, so compare its string representation instead.
+impl Ord for SourceRegistry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.index, self.version.to_string()).cmp(&(&other.index, other.version.to_string()))
+    }
+}
+
+impl PartialOrd for SourceRegistry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for PinnedId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Format the inner `u64` as hex.
@@ -1055,6 +1454,20 @@ impl fmt::Display for SourceGitPinned {
     }
 }
 
+impl fmt::Display for SourceRegistryPinned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // registry+<index>?<version_req>#<version>
+        write!(
+            f,
+            "{}+{}?{}#{}",
+            Self::PREFIX,
+            self.source.index,
+            self.source.version,
+            self.version
+        )
+    }
+}
+
 impl fmt::Display for GitReference {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -1072,7 +1485,7 @@ impl fmt::Display for SourcePinned {
             SourcePinned::Member => write!(f, "member"),
             SourcePinned::Path(src) => src.fmt(f),
             SourcePinned::Git(src) => src.fmt(f),
-            SourcePinned::Registry(_reg) => unimplemented!("pkg registries not yet implemented"),
+            SourcePinned::Registry(src) => src.fmt(f),
         }
     }
 }
@@ -1165,6 +1578,47 @@ impl FromStr for SourceGitPinned {
     }
 }
 
+impl FromStr for SourceRegistryPinned {
+    type Err = SourceRegistryPinnedParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // registry+<index>?<version_req>#<version>
+        let s = s.trim();
+
+        // Check for "registry+" at the start.
+        let prefix_plus = format!("{}+", Self::PREFIX);
+        if s.find(&prefix_plus) != Some(0) {
+            return Err(SourceRegistryPinnedParseError::Prefix);
+        }
+        let s = &s[prefix_plus.len()..];
+
+        // The version requirement and version never contain `?` or `#`, so split from the end.
+        let (s, version) = s
+            .rsplit_once('#')
+            .ok_or(SourceRegistryPinnedParseError::Version)?;
+        let version =
+            semver::Version::parse(version).map_err(|_| SourceRegistryPinnedParseError::Version)?;
+        let (index, version_req) = s
+            .rsplit_once('?')
+            .ok_or(SourceRegistryPinnedParseError::VersionReq)?;
+        let version_req = semver::VersionReq::parse(version_req)
+            .map_err(|_| SourceRegistryPinnedParseError::VersionReq)?;
+        let index = index
+            .parse()
+            .map_err(|_| SourceRegistryPinnedParseError::Index)?;
+
+        let source = SourceRegistry {
+            index,
+            version: version_req,
+        };
+        // The checksum is recorded separately within the lock file and filled in by `Lock`.
+        Ok(Self {
+            source,
+            version,
+            checksum: String::new(),
+        })
+    }
+}
+
 impl FromStr for SourcePinned {
     type Err = SourcePinnedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -1176,8 +1630,9 @@ impl FromStr for SourcePinned {
             SourcePinned::Path(src)
         } else if let Ok(src) = SourceGitPinned::from_str(s) {
             SourcePinned::Git(src)
+        } else if let Ok(src) = SourceRegistryPinned::from_str(s) {
+            SourcePinned::Registry(src)
         } else {
-            // TODO: Try parse registry source.
             return Err(SourcePinnedParseError);
         };
         Ok(source)
@@ -1240,11 +1695,17 @@ pub fn compilation_order(graph: &Graph) -> Result<Vec<NodeIx>> {
 /// Given a graph collects ManifestMap while taking in to account that manifest can be a
 /// ManifestFile::Workspace. In the case of a workspace each pkg manifest map is collected and
 /// their added node lists are merged.
-fn graph_to_manifest_map(manifests: &MemberManifestFiles, graph: &Graph) -> Result<ManifestMap> {
+fn graph_to_manifest_map(
+    manifests: &MemberManifestFiles,
+    graph: &Graph,
+    vendor_dir: Option<&Path>,
+) -> Result<ManifestMap> {
     let mut manifest_map = HashMap::new();
     for pkg_manifest in manifests.values() {
         let pkg_name = &pkg_manifest.project.name;
-        manifest_map.extend(pkg_graph_to_manifest_map(manifests, pkg_name, graph)?);
+        manifest_map.extend(pkg_graph_to_manifest_map(
+            manifests, pkg_name, graph, vendor_dir,
+        )?);
     }
     Ok(manifest_map)
 }
@@ -1260,6 +1721,7 @@ fn pkg_graph_to_manifest_map(
     manifests: &MemberManifestFiles,
     pkg_name: &str,
     graph: &Graph,
+    vendor_dir: Option<&Path>,
 ) -> Result<ManifestMap> {
     let proj_manifest = manifests
         .get(pkg_name)
@@ -1291,13 +1753,14 @@ fn pkg_graph_to_manifest_map(
             })
             .next()
             .ok_or_else(|| anyhow!("more than one root package detected in graph"))?;
-        let dep_path = dep_path(graph, parent_manifest, dep_node, manifests).map_err(|e| {
-            anyhow!(
-                "failed to construct path for dependency {:?}: {}",
-                dep_name,
-                e
-            )
-        })?;
+        let dep_path =
+            dep_path(graph, parent_manifest, dep_node, manifests, vendor_dir).map_err(|e| {
+                anyhow!(
+                    "failed to construct path for dependency {:?}: {}",
+                    dep_name,
+                    e
+                )
+            })?;
         let dep_manifest = PackageManifestFile::from_dir(&dep_path)?;
         let dep = &graph[dep_node];
         manifest_map.insert(dep.id(), dep_manifest);
@@ -1496,15 +1959,13 @@ fn fetch_deps(
             }
         };
 
-        let dep_edge = Edge::new(dep_name.to_string(), dep_kind.clone());
-        // Ensure we have an edge to the dependency.
+        let dep_edge = Edge::new(dep_name.to_string(), dep_kind.clone())
+            .with_features(dep.features().iter().cloned());
+        // Ensure we have an edge to the dependency. This also refreshes the features of edges
+        // loaded from the lock file, which only records the features enabled for each package.
         graph.update_edge(node, dep_node, dep_edge.clone());
 
-        // If we've visited this node during this traversal already, no need to traverse it again.
-        if !visited.insert(dep_node) {
-            continue;
-        }
-
+        // Each dependent may enable different features, so validate every edge.
         let dep_pinned = &graph[dep_node];
         let dep_pkg_id = dep_pinned.id();
         validate_dep_manifest(dep_pinned, &manifest_map[&dep_pkg_id], &dep_edge).map_err(|e| {
@@ -1517,6 +1978,11 @@ fn fetch_deps(
             )
         })?;
 
+        // If we've visited this node during this traversal already, no need to traverse it again.
+        if !visited.insert(dep_node) {
+            continue;
+        }
+
         let path_root = match dep_pinned.source {
             SourcePinned::Member | SourcePinned::Git(_) | SourcePinned::Registry(_) => dep_pkg_id,
             SourcePinned::Path(_) => path_root,
@@ -1609,7 +2075,12 @@ fn git_ref_to_refspecs(reference: &GitReference) -> (Vec<String>, bool) {
 
 /// Initializes a temporary git repo for the package and fetches only the reference associated with
 /// the given source.
-fn with_tmp_git_repo<F, O>(fetch_id: u64, name: &str, source: &SourceGit, f: F) -> Result<O>
+pub(crate) fn with_tmp_git_repo<F, O>(
+    fetch_id: u64,
+    name: &str,
+    source: &SourceGit,
+    f: F,
+) -> Result<O>
 where
     F: FnOnce(git2::Repository) -> Result<O>,
 {
@@ -1786,15 +2257,91 @@ fn pin_pkg(
             }
             pinned
         }
-        Source::Registry(ref _source) => {
-            if offline {
-                bail!("Unable to fetch pkg {:?} in offline mode", name);
+        Source::Registry(ref reg_source) => {
+            // Select the greatest version in the index that satisfies the requirement.
+            let index_path = reg_source.index.fetch(fetch_id, offline)?;
+            let entries = RegistryIndex::entries(&index_path, &name)?;
+            let entry = registry::select(&entries, &reg_source.version).ok_or_else(|| {
+                anyhow!(
+                    "failed to find a version of `{}` matching `{}` in registry index {}",
+                    name,
+                    reg_source.version,
+                    reg_source.index
+                )
+            })?;
+            let pinned_reg = SourceRegistryPinned {
+                source: reg_source.clone(),
+                version: entry.version.clone(),
+                checksum: entry.checksum.clone(),
+            };
+            let source = SourcePinned::Registry(pinned_reg.clone());
+            let pinned = Pinned { name, source };
+            let id = pinned.id();
+            if let hash_map::Entry::Vacant(entry) = manifest_map.entry(id) {
+                let path = fetch_registry(&pinned.name, &pinned_reg)?;
+                let manifest = PackageManifestFile::from_dir(&path)?;
+                entry.insert(manifest);
+            }
+            pinned
+        }
+    };
+    Ok(pinned)
+}
+
+/// Pin the given package to the precise git commit or registry version.
+///
+/// The resulting pinned source retains the package's original source so that the pin remains valid
+/// against the manifest.
+fn pin_precise(
+    fetch_id: u64,
+    pkg: &Pkg,
+    precise: &str,
+    manifest_map: &mut ManifestMap,
+    offline: bool,
+) -> Result<Pinned> {
+    let name = pkg.name.clone();
+    let precise_source = match &pkg.source {
+        Source::Git(git) => {
+            let mut git = git.clone();
+            git.reference = GitReference::Rev(precise.to_string());
+            Source::Git(git)
+        }
+        Source::Registry(reg) => {
+            let version = semver::Version::parse(precise)
+                .map_err(|e| anyhow!("invalid version `{}`: {}", precise, e))?;
+            if !reg.version.matches(&version) {
+                bail!(
+                    "version `{}` of `{}` does not match the requirement `{}`",
+                    version,
+                    name,
+                    reg.version
+                );
             }
-            // TODO: determine registry pkg git URL, fetch to determine latest available
-            // semver-compatible version
-            bail!("registry dependencies are not yet supported");
+            let mut reg = reg.clone();
+            reg.version = semver::VersionReq::parse(&format!("={}", version))?;
+            Source::Registry(reg)
         }
+        Source::Member(_) | Source::Path(_) => bail!(
+            "`{}` is not a git or registry dependency and cannot be pinned to a precise revision",
+            name
+        ),
+    };
+    let precise_pkg = Pkg {
+        name,
+        source: precise_source,
     };
+    // The path root is only required for path dependencies.
+    let path_root = PinnedId(0);
+    let mut pinned = pin_pkg(fetch_id, path_root, &precise_pkg, manifest_map, offline)?;
+    let manifest = manifest_map
+        .remove(&pinned.id())
+        .ok_or_else(|| anyhow!("failed to find manifest for `{}`", pinned.name))?;
+    match (&mut pinned.source, &pkg.source) {
+        (SourcePinned::Git(git), Source::Git(source)) => git.source = source.clone(),
+        (SourcePinned::Registry(reg), Source::Registry(source)) => reg.source = source.clone(),
+        _ => unreachable!("`pin_pkg` preserves the kind of source"),
+    }
+    manifest_map.insert(pinned.id(), manifest);
     Ok(pinned)
 }
 
@@ -1830,7 +2377,7 @@ fn fd_lock_path(path: &Path) -> PathBuf {
 /// Create an advisory lock over the given path.
 ///
 /// See [fd_lock_path] for details.
-fn path_lock(path: &Path) -> Result<fd_lock::RwLock<File>> {
+pub(crate) fn path_lock(path: &Path) -> Result<fd_lock::RwLock<File>> {
     let lock_path = fd_lock_path(path);
     let lock_dir = lock_path
         .parent()
@@ -1904,6 +2451,47 @@ pub fn fetch_git(fetch_id: u64, name: &str, pinned: &SourceGitPinned) -> Result<
     Ok(path)
 }
 
+/// Unpack the pinned version of the given registry package from its index, unless it has already
+/// been unpacked from a tarball with the pinned checksum.
+///
+/// Returns the location of the package.
+///
+/// The tarball is read from the local copy of the index, so this never requires network access.
+/// The checksum of the tarball is verified against the pinned checksum before unpacking, and is
+/// recorded alongside the unpacked package so that it is checked again whenever the package is
+/// reused. A package that was unpacked from any other tarball is unpacked again.
+pub fn fetch_registry(name: &str, pinned: &SourceRegistryPinned) -> Result<PathBuf> {
+    let index = &pinned.source.index;
+    let path = index.src_path(name, &pinned.version);
+    let find_pkg_dir = || {
+        find_dir_within(&path, name).ok_or_else(|| {
+            anyhow!(
+                "failed to find package `{}` in {}",
+                name,
+                pinned.to_string()
+            )
+        })
+    };
+    // Co-ordinate access to the package directory using an advisory file lock.
+    let mut lock = path_lock(&path)?;
+    {
+        let _guard = lock.write()?;
+        if !registry::is_unpacked(&path, &pinned.checksum) {
+            info!("  Fetching {} v{}", name, pinned.version);
+            registry::unpack(
+                &index.local_path(),
+                name,
+                &pinned.version,
+                &pinned.checksum,
+                &path,
+            )?;
+            registry::record_index(&find_pkg_dir()?, index)?;
+        }
+    }
+    let _guard = lock.read()?;
+    find_pkg_dir()
+}
+
 /// Search local checkout dir for git sources, for non-branch git references tries to find the
 /// exact match. For branch references, tries to find the most recent repo present locally with the given repo
 fn search_git_source_locally(
@@ -2035,21 +2623,18 @@ where
     Ok(())
 }
 
-/// Given the path to a package and a `Dependency` parsed from one of its forc dependencies,
-/// produce the `Source` for that dependendency.
+/// Given a package manifest and a `Dependency` parsed from one of its forc dependencies, produce
+/// the `Source` for that dependendency.
 fn dep_to_source(
-    pkg_path: &Path,
+    manifest: &PackageManifestFile,
     dep: &Dependency,
     member_manifests: &MemberManifestFiles,
 ) -> Result<Source> {
+    let pkg_path = manifest.dir();
     let source = match dep {
         Dependency::Simple(ref ver_str) => {
-            bail!(
-                "Unsupported dependency declaration in \"{}\": `{}` - \
-                currently only `git` and `path` dependencies are supported",
-                pkg_path.display(),
-                ver_str
-            )
+            let source = registry_source(manifest, DEFAULT_REGISTRY_NAME, ver_str)?;
+            Source::Registry(source)
         }
         Dependency::Detailed(ref det) => match (&det.path, &det.version, &det.git) {
             (Some(relative_path), _, _) => {
@@ -2082,6 +2667,11 @@ fn dep_to_source(
                 let source = SourceGit { repo, reference };
                 Source::Git(source)
             }
+            (None, Some(ver_str), None) => {
+                let registry = det.registry.as_deref().unwrap_or(DEFAULT_REGISTRY_NAME);
+                let source = registry_source(manifest, registry, ver_str)?;
+                Source::Registry(source)
+            }
             _ => {
                 bail!("unsupported set of fields for dependency: {:?}", dep);
             }
@@ -2090,6 +2680,24 @@ fn dep_to_source(
     Ok(source)
 }
 
+/// Produce the source for a dependency on the given version of a package from the named registry.
+fn registry_source(
+    manifest: &PackageManifestFile,
+    registry: &str,
+    ver_str: &str,
+) -> Result<SourceRegistry> {
+    let version = semver::VersionReq::parse(ver_str).map_err(|e| {
+        anyhow!(
+            "invalid version requirement `{}` in \"{}\": {}",
+            ver_str,
+            manifest.path().display(),
+            e
+        )
+    })?;
+    let index = registry::resolve_index(manifest, registry)?;
+    Ok(SourceRegistry { index, version })
+}
+
 /// If a patch exists for the given dependency source within the given project manifest, this
 /// returns the patch.
 fn dep_source_patch<'manifest>(
@@ -2118,7 +2726,7 @@ fn apply_patch(
     member_manifests: &MemberManifestFiles,
 ) -> Result<Source> {
     match dep_source_patch(manifest, dep_name, dep_source) {
-        Some(patch) => dep_to_source(manifest.dir(), patch, member_manifests),
+        Some(patch) => dep_to_source(manifest, patch, member_manifests),
         None => Ok(dep_source.clone()),
     }
 }
@@ -2131,16 +2739,20 @@ fn dep_to_source_patched(
     dep: &Dependency,
     member_manifests: &MemberManifestFiles,
 ) -> Result<Source> {
-    let unpatched = dep_to_source(manifest.dir(), dep, member_manifests)?;
+    let unpatched = dep_to_source(manifest, dep, member_manifests)?;
     apply_patch(manifest, dep_name, &unpatched, member_manifests)
 }
 
 /// Given a `forc_pkg::BuildProfile`, produce the necessary `sway_core::BuildConfig` required for
 /// compilation.
+///
+/// The given `features` are those enabled for the package, see [BuildPlan::node_features].
 pub fn sway_build_config(
     manifest_dir: &Path,
     entry_path: &Path,
     build_profile: &BuildProfile,
+    lints: BTreeMap<String, LintLevel>,
+    features: BTreeSet<String>,
 ) -> Result<sway_core::BuildConfig> {
     // Prepare the build config to pass through to the compiler.
     let file_name = find_file_name(manifest_dir, entry_path)?;
@@ -2152,7 +2764,10 @@ pub fn sway_build_config(
     .print_finalized_asm(build_profile.print_finalized_asm)
     .print_intermediate_asm(build_profile.print_intermediate_asm)
     .print_ir(build_profile.print_ir)
-    .include_tests(build_profile.include_tests);
+    .include_tests(build_profile.include_tests)
+    .lints(lints)
+    .features(features)
+    .deny_warnings(build_profile.deny_warnings);
     Ok(build_config)
 }
 
@@ -2288,11 +2903,17 @@ pub fn compile_ast(
     engines: Engines<'_>,
     manifest: &PackageManifestFile,
     build_profile: &BuildProfile,
+    features: &BTreeSet<String>,
     namespace: namespace::Module,
 ) -> Result<CompileResult<ty::TyProgram>> {
     let source = manifest.entry_string()?;
-    let sway_build_config =
-        sway_build_config(manifest.dir(), &manifest.entry_path(), build_profile)?;
+    let sway_build_config = sway_build_config(
+        manifest.dir(),
+        &manifest.entry_path(),
+        build_profile,
+        manifest.lint_levels(),
+        features.clone(),
+    )?;
     let ast_res = sway_core::compile_to_ast(engines, source, namespace, Some(&sway_build_config));
     Ok(ast_res)
 }
@@ -2319,9 +2940,71 @@ pub fn compile(
     pkg: &Pinned,
     manifest: &PackageManifestFile,
     build_profile: &BuildProfile,
+    features: &BTreeSet<String>,
     namespace: namespace::Module,
     engines: Engines<'_>,
     source_map: &mut SourceMap,
+) -> Result<(BuiltPackage, namespace::Root)> {
+    let mut diagnostics = None;
+    let res = compile_deferred(
+        pkg,
+        manifest,
+        build_profile,
+        features,
+        namespace,
+        engines,
+        source_map,
+        &mut diagnostics,
+    );
+    if let Some(diagnostics) = diagnostics {
+        diagnostics.print(build_profile.terse);
+    }
+    res
+}
+
+/// Diagnostics produced by compiling a package.
+///
+/// Printing is deferred so that the diagnostics of packages compiled in parallel may be printed in
+/// compilation order.
+#[derive(Debug)]
+enum PkgDiagnostics {
+    Success {
+        pkg_name: String,
+        warnings: Vec<CompileWarning>,
+        tree_type: TreeType,
+    },
+    Failure {
+        warnings: Vec<CompileWarning>,
+        errors: Vec<CompileError>,
+    },
+}
+
+impl PkgDiagnostics {
+    fn print(&self, terse_mode: bool) {
+        match self {
+            PkgDiagnostics::Success {
+                pkg_name,
+                warnings,
+                tree_type,
+            } => print_on_success(terse_mode, pkg_name, warnings, tree_type),
+            PkgDiagnostics::Failure { warnings, errors } => {
+                print_on_failure(terse_mode, warnings, errors)
+            }
+        }
+    }
+}
+
+/// Compile the given package like [compile], but produce its diagnostics rather than printing them.
+#[allow(clippy::too_many_arguments)]
+fn compile_deferred(
+    pkg: &Pinned,
+    manifest: &PackageManifestFile,
+    build_profile: &BuildProfile,
+    features: &BTreeSet<String>,
+    namespace: namespace::Module,
+    engines: Engines<'_>,
+    source_map: &mut SourceMap,
+    diagnostics: &mut Option<PkgDiagnostics>,
 ) -> Result<(BuiltPackage, namespace::Root)> {
     // Time the given expression and print the result if `build_config.time_phases` is true.
     macro_rules! time_expr {
@@ -2344,18 +3027,26 @@ pub fn compile(
     let entry_path = manifest.entry_path();
     let sway_build_config = time_expr!(
         "produce `sway_core::BuildConfig`",
-        sway_build_config(manifest.dir(), &entry_path, build_profile,)?
+        sway_build_config(
+            manifest.dir(),
+            &entry_path,
+            build_profile,
+            manifest.lint_levels(),
+            features.clone(),
+        )?
     );
-    let terse_mode = build_profile.terse;
-    let fail = |warnings, errors| {
-        print_on_failure(terse_mode, warnings, errors);
+    let mut fail = |warnings: &[CompileWarning], errors: &[CompileError]| {
+        *diagnostics = Some(PkgDiagnostics::Failure {
+            warnings: warnings.to_vec(),
+            errors: errors.to_vec(),
+        });
         bail!("Failed to compile {}", pkg.name);
     };
 
     // First, compile to an AST. We'll update the namespace and check for JSON ABI output.
     let ast_res = time_expr!(
         "compile to ast",
-        compile_ast(engines, manifest, build_profile, namespace)?
+        compile_ast(engines, manifest, build_profile, features, namespace)?
     );
     let typed_program = match ast_res.value.as_ref() {
         None => return fail(&ast_res.warnings, &ast_res.errors),
@@ -2397,7 +3088,12 @@ pub fn compile(
 
     match bc_res.value {
         Some(CompiledBytecode(bytes)) if bc_res.errors.is_empty() => {
-            print_on_success(terse_mode, &pkg.name, &bc_res.warnings, &tree_type);
+            let has_warnings = !bc_res.warnings.is_empty();
+            *diagnostics = Some(PkgDiagnostics::Success {
+                pkg_name: pkg.name.clone(),
+                warnings: bc_res.warnings,
+                tree_type: tree_type.clone(),
+            });
             let bytecode = bytes;
             let built_package = BuiltPackage {
                 json_abi_program,
@@ -2408,6 +3104,7 @@ pub fn compile(
                 source_map: source_map.to_owned(),
                 pkg_name: pkg.name.clone(),
                 declaration_engine: engines.de().clone(),
+                has_warnings,
             };
             Ok((built_package, namespace))
         }
@@ -2435,6 +3132,8 @@ fn build_profile_from_opts(
         release,
         time_phases,
         tests,
+        deny_warnings,
+        constants,
         ..
     } = build_options;
     let mut selected_build_profile = BuildProfile::DEBUG;
@@ -2478,6 +3177,10 @@ fn build_profile_from_opts(
     profile.terse |= pkg.terse;
     profile.time_phases |= time_phases;
     profile.include_tests |= tests;
+    profile.deny_warnings |= *deny_warnings;
+    profile
+        .constants
+        .extend(constants.iter().map(|(k, v)| (k.clone(), v.clone())));
 
     Ok((selected_build_profile.to_string(), profile))
 }
@@ -2507,28 +3210,25 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
         bail!("No member found to build")
     }
     let lock_path = manifest_file.lock_path()?;
-    let build_plan = BuildPlan::from_lock_and_manifests(
+    let mut build_plan = BuildPlan::from_lock_and_manifests(
         &lock_path,
         &member_manifests,
         build_options.pkg.locked,
         build_options.pkg.offline,
     )?;
+    build_plan.enable_member_features(&build_options.features)?;
     let graph = build_plan.graph();
     let manifest_map = build_plan.manifest_map();
     let build_profiles: HashMap<String, BuildProfile> = build_plan.build_profiles().collect();
     // Get the selected build profile using build options
     let (profile_name, build_profile) = build_profile_from_opts(&build_profiles, &build_options)?;
+    build_plan.validate_constant_overrides(&build_profile.constants)?;
 
-    // If this is a workspace we want to have all members in the output.
-    let outputs = match &manifest_file {
-        ManifestFile::Package(pkg_manifest) => std::iter::once(
-            build_plan
-                .find_member_index(&pkg_manifest.project.name)
-                .ok_or_else(|| anyhow!("Cannot found project node in the graph"))?,
-        )
-        .collect(),
-        ManifestFile::Workspace(_) => build_plan.member_nodes().collect(),
-    };
+    // If this is a workspace we want to have all selected members in the output.
+    let outputs = build_plan
+        .selected_members(&build_options.member_filter)?
+        .into_iter()
+        .collect();
     // Build it!
     let mut built_workspace = HashMap::new();
     let built_packages = build(&build_plan, &build_profile, &outputs)?;
@@ -2598,15 +3298,68 @@ fn validate_contract_deps(graph: &Graph) -> Result<()> {
     Ok(())
 }
 
+/// The stack size of the threads used to compile packages in parallel.
+///
+/// Type checking and IR generation recurse deeply, so this matches the stack size that the main
+/// thread is typically given rather than the much smaller default for spawned threads.
+const COMPILE_THREAD_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Notifies the build scheduler that the compilation thread for the package at `pos` has finished.
+///
+/// The notification is sent on drop so that the scheduler is woken up even if the thread panics,
+/// in which case the failure is reported when the thread is joined.
+struct CompileFinished {
+    pos: usize,
+    tx: std::sync::mpsc::Sender<usize>,
+}
+
+impl Drop for CompileFinished {
+    fn drop(&mut self) {
+        // The scheduler only stops listening once it has already given up on the build.
+        let _ = self.tx.send(self.pos);
+    }
+}
+
 /// Build an entire forc package and return the built_package output.
 ///
 /// This compiles all packages (including dependencies) in the order specified by the `BuildPlan`.
+/// Packages that do not depend on one another are compiled in parallel, however diagnostics are
+/// always printed in compilation order.
 ///
 /// Also returns the resulting `sway_core::SourceMap` which may be useful for debugging purposes.
 pub fn build(
     plan: &BuildPlan,
     profile: &BuildProfile,
     outputs: &HashSet<NodeIx>,
+) -> anyhow::Result<Vec<(NodeIx, BuiltPackage)>> {
+    build_with_cache(plan, profile, outputs, &mut NamespaceCache::default())
+}
+
+/// Build the packages in the build plan, reusing the namespaces of the library packages in `cache`
+/// whose inputs are unchanged since they were last compiled.
+///
+/// All packages are compiled with the engines of the cache. See [NamespaceCache] for details.
+pub fn build_with_cache(
+    plan: &BuildPlan,
+    profile: &BuildProfile,
+    outputs: &HashSet<NodeIx>,
+    cache: &mut NamespaceCache,
+) -> anyhow::Result<Vec<(NodeIx, BuiltPackage)>> {
+    let jobs = match profile.prints_intermediates() {
+        true => 1,
+        false => std::thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    build_with_jobs(plan, profile, outputs, cache, jobs)
+}
+
+/// Build the packages in the build plan like [build_with_cache], compiling at most `jobs` packages
+/// at a time.
+fn build_with_jobs(
+    plan: &BuildPlan,
+    profile: &BuildProfile,
+    outputs: &HashSet<NodeIx>,
+    cache: &mut NamespaceCache,
+    jobs: usize,
 ) -> anyhow::Result<Vec<(NodeIx, BuiltPackage)>> {
     let mut built_packages = Vec::new();
 
@@ -2615,58 +3368,289 @@ pub fn build(
         .flat_map(|output_node| plan.node_deps(*output_node))
         .collect();
 
-    let type_engine = TypeEngine::default();
-    let declaration_engine = DeclarationEngine::default();
-    let engines = Engines::new(&type_engine, &declaration_engine);
+    let (engines, cached_namespaces) = cache.engines_and_namespaces();
 
-    let mut lib_namespace_map = Default::default();
-    let mut compiled_contract_deps = HashMap::new();
+    // Denying warnings and overriding constants only applies to the workspace members, not to
+    // their dependencies.
+    let members: HashSet<NodeIx> = plan.member_nodes().collect();
+    let dep_profile = BuildProfile {
+        deny_warnings: false,
+        constants: BTreeMap::new(),
+        ..profile.clone()
+    };
+
+    // Compute the cache keys of all required packages.
+    let use_cache = build_cache::is_enabled(profile);
+    let mut cache_keys = HashMap::new();
+    if use_cache {
+        for &node in plan
+            .compilation_order
+            .iter()
+            .filter(|n| required.contains(n))
+        {
+            let profile = match members.contains(&node) {
+                true => profile,
+                false => &dep_profile,
+            };
+            let key = build_cache::package_key(plan, node, profile, &cache_keys);
+            cache_keys.insert(node, key);
+        }
+    }
+
+    // Determine which packages must be compiled, visiting dependents before their dependencies.
+    // A package whose `BuiltPackage` is required may be loaded from the cache on disk, while a
+    // library package whose namespace is required may be found in the namespace cache.
+    let mut cached = HashMap::new();
+    let mut lib_namespace_map = HashMap::new();
+    let mut to_compile = HashSet::new();
+    let mut built_required = outputs.clone();
+    let mut namespace_required = HashSet::new();
     for &node in plan
         .compilation_order
         .iter()
+        .rev()
         .filter(|node| required.contains(node))
     {
-        let mut source_map = SourceMap::new();
-        let pkg = &plan.graph()[node];
-        let manifest = &plan.manifest_map()[&pkg.id()];
-        let constants = manifest.config_time_constants();
-        let dep_namespace = match dependency_namespace(
-            &lib_namespace_map,
-            &compiled_contract_deps,
-            &plan.graph,
-            node,
-            constants,
-            engines,
-        ) {
-            Ok(o) => o,
-            Err(errs) => {
-                print_on_failure(profile.terse, &[], &errs);
-                bail!("Failed to compile {}", pkg.name);
+        let is_built_required = built_required.contains(&node);
+        let is_namespace_required = namespace_required.contains(&node);
+        if !is_built_required && !is_namespace_required {
+            continue;
+        }
+        let key = cache_keys.get(&node);
+        let pkg_name = &plan.graph()[node].name;
+        let built = key
+            .filter(|_| is_built_required)
+            .and_then(|key| build_cache::load(pkg_name, key));
+        let namespace = key
+            .filter(|_| is_namespace_required)
+            .and_then(|key| cached_namespaces.get(key));
+        if built.is_some() == is_built_required && namespace.is_some() == is_namespace_required {
+            if let Some(built) = built {
+                cached.insert(node, built);
             }
-        };
-        let res = compile(
-            pkg,
-            manifest,
-            profile,
-            dep_namespace,
-            engines,
-            &mut source_map,
-        )?;
-        let (mut built_package, namespace) = res;
-        // If the current node is a contract dependency, collect the contract_id
-        if plan
-            .graph()
+            if let Some(namespace) = namespace {
+                lib_namespace_map.insert(node, namespace);
+            }
+            continue;
+        }
+        to_compile.insert(node);
+        for edge in plan.graph().edges_directed(node, Direction::Outgoing) {
+            match edge.weight().kind {
+                DepKind::Library => namespace_required.insert(edge.target()),
+                DepKind::Contract { .. } => built_required.insert(edge.target()),
+            };
+        }
+    }
+
+    // Compile each package as soon as all of its dependencies are available, compiling independent
+    // packages in parallel. Diagnostics are printed in compilation order and stop at the first
+    // package that fails, so that the output is identical to that of compiling packages one by one.
+    let order: Vec<NodeIx> = plan
+        .compilation_order
+        .iter()
+        .cloned()
+        .filter(|node| cached.contains_key(node) || to_compile.contains(node))
+        .collect();
+    let positions: HashMap<NodeIx, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(pos, &node)| (node, pos))
+        .collect();
+    let is_contract_dep = |node: NodeIx| {
+        plan.graph()
             .edges_directed(node, Direction::Incoming)
             .any(|e| matches!(e.weight().kind, DepKind::Contract { .. }))
-        {
-            compiled_contract_deps.insert(node, built_package.clone());
+    };
+
+    // The number of dependencies of each package that are yet to be compiled.
+    let mut pending_deps: Vec<usize> = order
+        .iter()
+        .map(|&node| {
+            plan.graph()
+                .neighbors_directed(node, Direction::Outgoing)
+                .filter(|dep| to_compile.contains(dep))
+                .collect::<HashSet<_>>()
+                .len()
+        })
+        .collect();
+    let mut ready: BTreeSet<usize> = order
+        .iter()
+        .enumerate()
+        .filter(|&(pos, node)| to_compile.contains(node) && pending_deps[pos] == 0)
+        .map(|(pos, _)| pos)
+        .collect();
+    let mut results: Vec<Option<Result<BuiltPackage>>> = order.iter().map(|_| None).collect();
+    let mut diagnostics: Vec<Option<PkgDiagnostics>> = order.iter().map(|_| None).collect();
+    let mut first_failure: Option<usize> = None;
+
+    let mut compiled_contract_deps = HashMap::new();
+    for (pos, &node) in order.iter().enumerate() {
+        if let Some(built_package) = cached.remove(&node) {
+            if is_contract_dep(node) {
+                compiled_contract_deps.insert(node, built_package.clone());
+            }
+            results[pos] = Some(Ok(built_package));
         }
-        if let TreeType::Library { .. } = built_package.tree_type {
-            lib_namespace_map.insert(node, namespace.into());
+    }
+
+    std::thread::scope(|scope| -> Result<()> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut in_flight = HashMap::new();
+        let mut next_print = 0;
+        loop {
+            // Start compiling ready packages, preferring those that come first in compilation
+            // order. Packages that come after a failed package are never started.
+            while in_flight.len() < jobs {
+                let pos = match ready.iter().next() {
+                    Some(&pos) if first_failure.is_none_or(|failed| pos < failed) => pos,
+                    _ => break,
+                };
+                ready.remove(&pos);
+                let node = order[pos];
+                let pkg = &plan.graph()[node];
+                let manifest = &plan.manifest_map()[&pkg.id()];
+                let profile = match members.contains(&node) {
+                    true => profile,
+                    false => &dep_profile,
+                };
+                let constants = manifest.config_time_constants_with_overrides(&profile.constants);
+                let features = plan.node_features(node);
+                match dependency_namespace(
+                    &lib_namespace_map,
+                    &compiled_contract_deps,
+                    &plan.graph,
+                    node,
+                    constants,
+                    engines,
+                ) {
+                    Ok(dep_namespace) => {
+                        cached_namespaces.count_compiled();
+                        let finished = CompileFinished {
+                            pos,
+                            tx: tx.clone(),
+                        };
+                        let thread = std::thread::Builder::new()
+                            .name(format!("compile {}", pkg.name))
+                            .stack_size(COMPILE_THREAD_STACK_SIZE);
+                        let handle = thread.spawn_scoped(scope, move || {
+                            let _finished = finished;
+                            let mut source_map = SourceMap::new();
+                            let mut diagnostics = None;
+                            let res = compile_deferred(
+                                pkg,
+                                manifest,
+                                profile,
+                                &features,
+                                dep_namespace,
+                                engines,
+                                &mut source_map,
+                                &mut diagnostics,
+                            );
+                            source_map.insert_dependency(manifest.dir());
+                            (res, diagnostics)
+                        });
+                        let handle = handle.with_context(|| {
+                            format!("failed to spawn the compilation thread for `{}`", pkg.name)
+                        })?;
+                        in_flight.insert(pos, handle);
+                    }
+                    Err(errors) => {
+                        diagnostics[pos] = Some(PkgDiagnostics::Failure {
+                            warnings: vec![],
+                            errors: errors.into(),
+                        });
+                        results[pos] = Some(Err(anyhow!("Failed to compile {}", pkg.name)));
+                        first_failure = Some(first_failure.map_or(pos, |failed| failed.min(pos)));
+                    }
+                }
+            }
+
+            // Print the output of each finished package in compilation order.
+            while first_failure.is_none_or(|failed| next_print <= failed) {
+                let res = match results.get(next_print) {
+                    Some(Some(res)) => res,
+                    _ => break,
+                };
+                match (&diagnostics[next_print], res) {
+                    (Some(pkg_diagnostics), _) => pkg_diagnostics.print(profile.terse),
+                    (None, Ok(built_package)) if outputs.contains(&order[next_print]) => info!(
+                        "  Using cached {} {:?}.",
+                        built_package.tree_type, built_package.pkg_name
+                    ),
+                    _ => (),
+                }
+                next_print += 1;
+            }
+
+            if in_flight.is_empty() {
+                break;
+            }
+            let pos = rx
+                .recv()
+                .map_err(|_| anyhow!("all compilation threads hung up"))?;
+            let node = order[pos];
+            let handle = in_flight
+                .remove(&pos)
+                .ok_or_else(|| anyhow!("unexpected compilation thread for position {}", pos))?;
+            let (res, pkg_diagnostics) = handle.join().map_err(|_| {
+                anyhow!(
+                    "the compilation thread for `{}` panicked",
+                    plan.graph()[node].name
+                )
+            })?;
+            diagnostics[pos] = pkg_diagnostics;
+            let (mut built_package, namespace) = match res {
+                Ok(res) => res,
+                Err(err) => {
+                    results[pos] = Some(Err(err));
+                    first_failure = Some(first_failure.map_or(pos, |failed| failed.min(pos)));
+                    continue;
+                }
+            };
+            // If the current node is a contract dependency, collect the contract_id
+            if is_contract_dep(node) {
+                compiled_contract_deps.insert(node, built_package.clone());
+            }
+            if let TreeType::Library { .. } = built_package.tree_type {
+                let namespace: namespace::Module = namespace.into();
+                if let Some(key) = cache_keys.get(&node) {
+                    cached_namespaces.insert(&plan.graph()[node], key.clone(), namespace.clone());
+                }
+                lib_namespace_map.insert(node, namespace);
+            }
+            standardize_json_abi_types(&mut built_package.json_abi_program);
+            if built_required.contains(&node) {
+                if let Some(key) = cache_keys.get(&node) {
+                    build_cache::store(key, &built_package);
+                }
+            }
+            results[pos] = Some(Ok(built_package));
+
+            // Dependents of the package may now be ready to compile.
+            let dependents: HashSet<NodeIx> = plan
+                .graph()
+                .neighbors_directed(node, Direction::Incoming)
+                .filter(|dependent| to_compile.contains(dependent))
+                .collect();
+            for dependent in dependents {
+                let dependent_pos = positions[&dependent];
+                pending_deps[dependent_pos] -= 1;
+                if pending_deps[dependent_pos] == 0 {
+                    ready.insert(dependent_pos);
+                }
+            }
         }
-        source_map.insert_dependency(manifest.dir());
-        standardize_json_abi_types(&mut built_package.json_abi_program);
+        Ok(())
+    })?;
+
+    if let Some(failed) = first_failure {
+        if let Some(Some(Err(err))) = results.get_mut(failed).map(Option::take) {
+            return Err(err);
+        }
+    }
+    for (pos, node) in order.into_iter().enumerate() {
         if outputs.contains(&node) {
+            let built_package = results[pos].take().expect("all packages were built")?;
             built_packages.push((node, built_package));
         }
     }
@@ -2802,6 +3786,32 @@ fn update_json_type_declaration(
 /// A `CompileResult` thats type is a tuple containing a `ParseProgram` and `Option<ty::TyProgram>`
 type ParseAndTypedPrograms = CompileResult<(ParseProgram, Option<ty::TyProgram>)>;
 
+/// The typed namespaces of library packages that have already been checked.
+///
+/// Passing the same cache to successive calls to [check_with_cache] allows for skipping the
+/// library packages whose source, and the source of all of their dependencies, is unchanged.
+///
+/// The cached namespaces refer to the types and declarations within the engines that they were
+/// checked with, so a cache must only ever be used with the same [Engines].
+#[derive(Debug, Default)]
+pub struct CheckCache {
+    namespaces: HashMap<Pinned, CachedNamespace>,
+}
+
+#[derive(Debug)]
+struct CachedNamespace {
+    /// A fingerprint of the package's files along with the files of all of its dependencies.
+    source_hash: u64,
+    namespace: namespace::Module,
+}
+
+impl CheckCache {
+    /// Remove all cached namespaces, e.g. after the engines they were checked with are reset.
+    pub fn clear(&mut self) {
+        self.namespaces.clear();
+    }
+}
+
 /// Compile the entire forc package and return the parse and typed programs
 /// of the dependancies and project.
 /// The final item in the returned vector is the project.
@@ -2810,15 +3820,49 @@ pub fn check(
     terse_mode: bool,
     engines: Engines<'_>,
 ) -> anyhow::Result<Vec<ParseAndTypedPrograms>> {
-    let mut lib_namespace_map = Default::default();
+    let results = check_with_cache(plan, terse_mode, engines, &mut CheckCache::default())?;
+    Ok(results.into_iter().map(|(_, result)| result).collect())
+}
+
+/// Check the packages in the build plan, reusing the namespaces of the library packages in `cache`
+/// that are unchanged since they were last checked.
+///
+/// Only the packages that were checked appear in the returned vector, each along with the
+/// directory of its manifest, so the parse and typed programs of cached packages are omitted. The
+/// final package in the compilation order is always checked, so the final item in the returned
+/// vector is still the project.
+pub fn check_with_cache(
+    plan: &BuildPlan,
+    terse_mode: bool,
+    engines: Engines<'_>,
+    cache: &mut CheckCache,
+) -> anyhow::Result<Vec<(PathBuf, ParseAndTypedPrograms)>> {
+    let mut lib_namespace_map: HashMap<NodeIx, namespace::Module> = Default::default();
+    let mut source_hashes: HashMap<NodeIx, u64> = HashMap::new();
     let mut source_map = SourceMap::new();
     // During `check`, we don't compile so this stays empty.
     let compiled_contract_deps = HashMap::new();
 
     let mut results = vec![];
-    for &node in plan.compilation_order.iter() {
+    for (i, &node) in plan.compilation_order.iter().enumerate() {
         let pkg = &plan.graph[node];
         let manifest = &plan.manifest_map()[&pkg.id()];
+        let is_project = i == plan.compilation_order.len() - 1;
+
+        let features = plan.node_features(node);
+        let source_hash =
+            package_source_hash(manifest, &features, &plan.graph, node, &source_hashes);
+        source_hashes.insert(node, source_hash);
+        if !is_project {
+            if let Some(cached) = cache.namespaces.get(pkg) {
+                if cached.source_hash == source_hash {
+                    lib_namespace_map.insert(node, cached.namespace.clone());
+                    source_map.insert_dependency(manifest.dir());
+                    continue;
+                }
+            }
+        }
+
         let constants = manifest.config_time_constants();
         let dep_namespace = dependency_namespace(
             &lib_namespace_map,
@@ -2833,37 +3877,55 @@ pub fn check(
             value,
             mut warnings,
             mut errors,
-        } = parse(manifest, terse_mode, engines)?;
+        } = parse(manifest, &features, terse_mode, engines)?;
 
         let parse_program = match value {
             None => {
-                results.push(CompileResult::new(None, warnings, errors));
+                let result = CompileResult::new(None, warnings, errors);
+                results.push((manifest.dir().to_path_buf(), result));
                 return Ok(results);
             }
             Some(program) => program,
         };
 
-        let ast_result = sway_core::parsed_to_ast(engines, &parse_program, dep_namespace, None);
+        let sway_build_config = check_build_config(manifest, &features, terse_mode)?;
+        let ast_result = sway_core::parsed_to_ast(
+            engines,
+            &parse_program,
+            dep_namespace,
+            Some(&sway_build_config),
+        );
         warnings.extend(ast_result.warnings);
         errors.extend(ast_result.errors);
 
         let typed_program = match ast_result.value {
             None => {
                 let value = Some((parse_program, None));
-                results.push(CompileResult::new(value, warnings, errors));
+                let result = CompileResult::new(value, warnings, errors);
+                results.push((manifest.dir().to_path_buf(), result));
                 return Ok(results);
             }
             Some(typed_program) => typed_program,
         };
 
         if let TreeType::Library { .. } = typed_program.kind.tree_type() {
-            lib_namespace_map.insert(node, typed_program.root.namespace.clone());
+            let namespace = typed_program.root.namespace.clone();
+            // Only cache namespaces that type checked without errors.
+            if errors.is_empty() {
+                let cached = CachedNamespace {
+                    source_hash,
+                    namespace: namespace.clone(),
+                };
+                cache.namespaces.insert(pkg.clone(), cached);
+            }
+            lib_namespace_map.insert(node, namespace);
         }
 
         source_map.insert_dependency(manifest.dir());
 
         let value = Some((parse_program, Some(typed_program)));
-        results.push(CompileResult::new(value, warnings, errors));
+        let result = CompileResult::new(value, warnings, errors);
+        results.push((manifest.dir().to_path_buf(), result));
     }
 
     if results.is_empty() {
@@ -2873,19 +3935,73 @@ pub fn check(
     Ok(results)
 }
 
+/// Hash the manifest, the paths and modification times of the sway source files and the enabled
+/// features of the package at `node`, along with the previously computed hashes of all of its
+/// dependencies.
+///
+/// Only the metadata of the source files is read, so that the hashes of large dependencies such as
+/// `std` remain cheap to compute on every check.
+fn package_source_hash(
+    manifest: &PackageManifestFile,
+    features: &BTreeSet<String>,
+    graph: &Graph,
+    node: NodeIx,
+    source_hashes: &HashMap<NodeIx, u64>,
+) -> u64 {
+    let mut hasher = hash_map::DefaultHasher::default();
+    fs::read(manifest.path()).ok().hash(&mut hasher);
+    features.hash(&mut hasher);
+    let mut files = sway_utils::helpers::get_sway_files(manifest.dir().to_path_buf());
+    files.sort();
+    for file in files {
+        file.hash(&mut hasher);
+        let metadata = fs::metadata(&file).ok();
+        metadata.as_ref().map(fs::Metadata::len).hash(&mut hasher);
+        metadata
+            .and_then(|metadata| metadata.modified().ok())
+            .hash(&mut hasher);
+    }
+    let mut dep_hashes: Vec<_> = graph
+        .edges_directed(node, Direction::Outgoing)
+        .filter_map(|edge| source_hashes.get(&edge.target()))
+        .collect();
+    dep_hashes.sort();
+    dep_hashes.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Returns a parsed AST from the supplied [PackageManifestFile]
 pub fn parse(
     manifest: &PackageManifestFile,
+    features: &BTreeSet<String>,
     terse_mode: bool,
     engines: Engines<'_>,
 ) -> anyhow::Result<CompileResult<ParseProgram>> {
+    let source = manifest.entry_string()?;
+    let sway_build_config = check_build_config(manifest, features, terse_mode)?;
+    Ok(sway_core::parse(source, engines, Some(&sway_build_config)))
+}
+
+/// The `sway_core::BuildConfig` used to parse and type check packages without compiling them.
+///
+/// Tests are included, so that `#[test]` functions and `#[cfg(test)]` items are checked too.
+fn check_build_config(
+    manifest: &PackageManifestFile,
+    features: &BTreeSet<String>,
+    terse_mode: bool,
+) -> Result<sway_core::BuildConfig> {
     let profile = BuildProfile {
         terse: terse_mode,
+        include_tests: true,
         ..BuildProfile::debug()
     };
-    let source = manifest.entry_string()?;
-    let sway_build_config = sway_build_config(manifest.dir(), &manifest.entry_path(), &profile)?;
-    Ok(sway_core::parse(source, engines, Some(&sway_build_config)))
+    sway_build_config(
+        manifest.dir(),
+        &manifest.entry_path(),
+        &profile,
+        manifest.lint_levels(),
+        features.clone(),
+    )
 }
 
 /// Attempt to find a `Forc.toml` with the given project name within the given directory.
@@ -2932,6 +4048,333 @@ fn test_root_pkg_order() {
     assert_eq!(order, vec!["test_lib", "test_contract", "test_script"])
 }
 
+/// Write a script depending on the libraries `lib_a` to `lib_e`, which do not depend on one another.
+///
+/// Each library declares a different number of functions so that they take different amounts of
+/// time to compile, with `lib_e`, which is compiled first, taking the longest. The libraries named
+/// in `failing` fail to type check.
+#[cfg(test)]
+fn write_independent_libs(dir: &Path, failing: &[&str]) {
+    let write_pkg = |name: &str, entry: &str, src: &str, deps: &str| {
+        let pkg_dir = dir.join(name);
+        fs::create_dir_all(pkg_dir.join("src")).unwrap();
+        let manifest = format!(
+            "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"{entry}\"\nlicense = \"Apache-2.0\"\n\
+             name = \"{name}\"\nimplicit-std = false\n\n[dependencies]\n{deps}"
+        );
+        fs::write(pkg_dir.join("Forc.toml"), manifest).unwrap();
+        fs::write(pkg_dir.join("src").join(entry), src).unwrap();
+    };
+    let names = ["lib_a", "lib_b", "lib_c", "lib_d", "lib_e"];
+    let mut deps = String::new();
+    for (i, name) in names.iter().enumerate() {
+        let mut src = format!("library {name};\n");
+        for j in 0..(i + 1) * 40 {
+            src.push_str(&format!("\npub fn f{j}(x: u64) -> u64 {{\n    x\n}}\n"));
+        }
+        if failing.contains(name) {
+            src.push_str(&format!(
+                "\npub fn broken() -> u64 {{\n    {name}_missing\n}}\n"
+            ));
+        }
+        write_pkg(name, "lib.sw", &src, "");
+        deps.push_str(&format!("{name} = {{ path = \"../{name}\" }}\n"));
+    }
+    let main = "script;\n\nfn main() -> u64 {\n    lib_a::f0(lib_e::f0(1))\n}\n";
+    write_pkg("app", "main.sw", main, &deps);
+}
+
+/// The names of the packages in the compilation order of the package at `dir`.
+#[cfg(test)]
+fn compilation_order_names(dir: &Path) -> Vec<String> {
+    let manifest_file = ManifestFile::from_dir(&dir.join("app")).unwrap();
+    let member_manifests = manifest_file.member_manifests().unwrap();
+    let lock_path = manifest_file.lock_path().unwrap();
+    let plan =
+        BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, false, true).unwrap();
+    let graph = plan.graph();
+    plan.compilation_order()
+        .iter()
+        .map(|&node| graph[node].name.clone())
+        .collect()
+}
+
+/// The name, bytecode and JSON ABI of a built package.
+#[cfg(test)]
+type BuiltOutput = (String, Vec<u8>, String);
+
+/// Build the package at `dir` with the given number of jobs, producing the name, bytecode and
+/// JSON ABI of each built package or the error, along with everything that was printed.
+#[cfg(test)]
+fn build_printed(dir: &Path, jobs: usize) -> (Result<Vec<BuiltOutput>, String>, String) {
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Printed(Arc<Mutex<Vec<u8>>>);
+    impl std::io::Write for Printed {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let manifest_file = ManifestFile::from_dir(&dir.join("app")).unwrap();
+    let member_manifests = manifest_file.member_manifests().unwrap();
+    let lock_path = manifest_file.lock_path().unwrap();
+    let plan =
+        BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, false, true).unwrap();
+    let outputs: HashSet<_> = plan.member_nodes().collect();
+
+    let printed = Printed::default();
+    let writer = printed.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .without_time()
+        .with_target(false)
+        .with_level(false)
+        .finish();
+    let res = tracing::subscriber::with_default(subscriber, || {
+        let profile = BuildProfile::debug();
+        let cache = &mut NamespaceCache::default();
+        build_with_jobs(&plan, &profile, &outputs, cache, jobs)
+    });
+    let res = res
+        .map(|built| {
+            built
+                .into_iter()
+                .map(|(_, pkg)| {
+                    let abi = serde_json::to_string(&pkg.json_abi_program).unwrap();
+                    (pkg.pkg_name, pkg.bytecode, abi)
+                })
+                .collect()
+        })
+        .map_err(|e| e.to_string());
+    let printed = String::from_utf8(printed.0.lock().unwrap().clone()).unwrap();
+    (res, printed)
+}
+
+#[test]
+fn test_build_output_independent_of_jobs() {
+    let build = |jobs| {
+        // Built packages are cached on disk by the paths of their files, so build a fresh copy of
+        // the packages each time.
+        let dir = tempfile::tempdir().unwrap();
+        write_independent_libs(dir.path(), &[]);
+        build_printed(dir.path(), jobs)
+    };
+    let (res, printed) = build(1);
+    let names: Vec<_> = res
+        .iter()
+        .flatten()
+        .map(|(name, _, _)| name.as_str())
+        .collect();
+    assert_eq!(names, ["app"]);
+    // The packages are printed in compilation order, regardless of the order they finish in.
+    let dir = tempfile::tempdir().unwrap();
+    write_independent_libs(dir.path(), &[]);
+    let order = compilation_order_names(dir.path());
+    let compiled: Vec<_> = printed
+        .lines()
+        .filter(|line| line.contains("Compiled"))
+        .collect();
+    assert_eq!(compiled.len(), order.len(), "{printed}");
+    for (line, name) in compiled.iter().zip(&order) {
+        assert!(line.contains(&format!("{name:?}")), "{printed}");
+    }
+    // The bytecode and ABI of the built packages must not depend on the number of jobs either.
+    for jobs in [2, 3, 8] {
+        assert_eq!(build(jobs), (res.clone(), printed.clone()), "jobs: {jobs}");
+    }
+}
+
+#[test]
+fn test_build_failure_independent_of_jobs() {
+    // Failed builds are never cached, so the same packages are built each time.
+    let dir = tempfile::tempdir().unwrap();
+    write_independent_libs(dir.path(), &["lib_b", "lib_d"]);
+    let (res, printed) = build_printed(dir.path(), 1);
+    // Only the failure of the first failing package in compilation order is reported.
+    let order = compilation_order_names(dir.path());
+    let (first, second) = match order.iter().position(|name| name == "lib_b")
+        < order.iter().position(|name| name == "lib_d")
+    {
+        true => ("lib_b", "lib_d"),
+        false => ("lib_d", "lib_b"),
+    };
+    assert_eq!(res, Err(format!("Failed to compile {first}")));
+    assert!(printed.contains(&format!("{first}_missing")), "{printed}");
+    assert!(!printed.contains(&format!("{second}_missing")), "{printed}");
+    for jobs in [2, 3, 8] {
+        assert_eq!(
+            build_printed(dir.path(), jobs),
+            (res.clone(), printed.clone()),
+            "jobs: {jobs}"
+        );
+    }
+}
+
+/// Commit the library `name` to the `main` branch of the git repository at `repo_dir`, creating
+/// the repository if necessary, with a function returning `value`. Returns the commit hash.
+#[cfg(test)]
+fn commit_git_lib(repo_dir: &Path, name: &str, value: u64) -> String {
+    fs::create_dir_all(repo_dir.join("src")).unwrap();
+    let manifest = format!(
+        "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"lib.sw\"\nlicense = \"Apache-2.0\"\n\
+         name = \"{name}\"\nimplicit-std = false\n"
+    );
+    fs::write(repo_dir.join("Forc.toml"), manifest).unwrap();
+    let src = format!("library {name};\n\npub fn value() -> u64 {{\n    {value}\n}}\n");
+    fs::write(repo_dir.join("src/lib.sw"), src).unwrap();
+    let repo = git2::Repository::open(repo_dir)
+        .or_else(|_| git2::Repository::init(repo_dir))
+        .unwrap();
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Fuel Labs", "contact@fuel.sh").unwrap();
+    let parent = repo
+        .find_reference("refs/heads/main")
+        .ok()
+        .and_then(|reference| reference.peel_to_commit().ok());
+    let parents: Vec<_> = parent.iter().collect();
+    let commit = repo
+        .commit(
+            Some("refs/heads/main"),
+            &sig,
+            &sig,
+            &format!("{name} {value}"),
+            &tree,
+            &parents,
+        )
+        .unwrap();
+    repo.set_head("refs/heads/main").unwrap();
+    commit.to_string()
+}
+
+/// The entries of the given lock file, by package name.
+#[cfg(test)]
+fn lock_entries(lock: &str) -> BTreeMap<String, String> {
+    lock.split("[[package]]")
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let name = entry
+                .lines()
+                .find_map(|line| line.strip_prefix("name = "))
+                .unwrap()
+                .trim_matches('\'')
+                .to_string();
+            (name, entry.to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn test_update_single_git_dependency() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+    let alpha_dir = dir.join("alpha");
+    let beta_dir = dir.join("beta");
+    let alpha_1 = commit_git_lib(&alpha_dir, "alpha", 1);
+    let beta_1 = commit_git_lib(&beta_dir, "beta", 1);
+    let app_dir = dir.join("app");
+    fs::create_dir_all(app_dir.join("src")).unwrap();
+    let manifest = format!(
+        "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"main.sw\"\nlicense = \"Apache-2.0\"\n\
+         name = \"app\"\nimplicit-std = false\n\n[dependencies]\n\
+         alpha = {{ git = \"file://{}\", branch = \"main\" }}\n\
+         beta = {{ git = \"file://{}\", branch = \"main\" }}\n",
+        alpha_dir.display(),
+        beta_dir.display()
+    );
+    fs::write(app_dir.join("Forc.toml"), manifest).unwrap();
+    let main = "script;\n\nfn main() -> u64 {\n    alpha::value() + beta::value()\n}\n";
+    fs::write(app_dir.join("src/main.sw"), main).unwrap();
+
+    let manifest_file = ManifestFile::from_dir(&app_dir).unwrap();
+    let member_manifests = manifest_file.member_manifests().unwrap();
+    let lock_path = manifest_file.lock_path().unwrap();
+    let plan =
+        BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, false, false).unwrap();
+    let lock_string = fs::read_to_string(&lock_path).unwrap();
+    let lock = Lock::from_path(&lock_path).unwrap();
+    let update = |dep_name: &str, precise: Option<&str>| {
+        BuildPlan::from_lock_and_manifests_with_update(
+            &lock,
+            &app_dir,
+            &member_manifests,
+            dep_name,
+            precise,
+            false,
+        )
+        .map(|plan| {
+            let new_lock = Lock::from_graph(plan.graph(), &app_dir);
+            toml::ser::to_string_pretty(&new_lock).unwrap()
+        })
+    };
+    let commit_of = |lock: &str, name: &str| {
+        let entry = &lock_entries(lock)[name];
+        let source = entry
+            .lines()
+            .find_map(|line| line.strip_prefix("source = "))
+            .unwrap();
+        source
+            .trim_matches('\'')
+            .rsplit('#')
+            .next()
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(commit_of(&lock_string, "alpha"), alpha_1);
+    assert_eq!(commit_of(&lock_string, "beta"), beta_1);
+
+    // Updating `alpha` pins the latest commit of its branch, leaving all other entries as they
+    // were, even though `beta` has a newer commit too.
+    let alpha_2 = commit_git_lib(&alpha_dir, "alpha", 2);
+    let beta_2 = commit_git_lib(&beta_dir, "beta", 2);
+    let updated = update("alpha", None).unwrap();
+    assert_eq!(commit_of(&updated, "alpha"), alpha_2);
+    let (mut before, mut after) = (lock_entries(&lock_string), lock_entries(&updated));
+    assert_ne!(before.remove("alpha"), after.remove("alpha"));
+    assert_eq!(before, after);
+
+    // `--precise` pins exactly the given revision, even if it is not the latest.
+    commit_git_lib(&alpha_dir, "alpha", 3);
+    let updated = update("alpha", Some(&alpha_2)).unwrap();
+    assert_eq!(commit_of(&updated, "alpha"), alpha_2);
+    let (mut before, mut after) = (lock_entries(&lock_string), lock_entries(&updated));
+    before.remove("alpha");
+    after.remove("alpha");
+    assert_eq!(before, after);
+    let updated = update("beta", Some(&beta_2)).unwrap();
+    assert_eq!(commit_of(&updated, "beta"), beta_2);
+    assert_eq!(commit_of(&updated, "alpha"), alpha_1);
+
+    // Unknown revisions and packages are errors.
+    let unknown_rev = "0".repeat(40);
+    let err = update("alpha", Some(&unknown_rev)).unwrap_err().to_string();
+    assert!(err.contains("failed to resolve reference"), "{err}");
+    let err = update("gamma", None).unwrap_err().to_string();
+    assert_eq!(err, "package `gamma` is not a dependency in the lock file");
+    let err = update("app", None).unwrap_err().to_string();
+    assert_eq!(err, "package `app` is not a dependency in the lock file");
+
+    // Remove the checkouts of both repositories from the user's forc directory.
+    for node in plan.graph().node_indices() {
+        if let SourcePinned::Git(git) = &plan.graph()[node].source {
+            let name = &plan.graph()[node].name;
+            let checkout = git_commit_path(name, &git.source.repo, &git.commit_hash);
+            let _ = fs::remove_dir_all(checkout.parent().unwrap());
+        }
+    }
+}
+
 #[test]
 fn test_source_git_pinned_parsing() {
     let strings = [
@@ -2990,6 +4433,40 @@ fn test_source_git_pinned_parsing() {
     }
 }
 
+#[test]
+fn test_source_registry_pinned_parsing() {
+    let strings = [
+        "registry+path+/home/user/index?^0.1#0.1.5",
+        "registry+git+https://github.com/owner/index?>=1.0.0, <2.0.0#1.2.0",
+    ];
+
+    let expected = [
+        SourceRegistryPinned {
+            source: SourceRegistry {
+                index: RegistryIndex::Path(PathBuf::from("/home/user/index")),
+                version: semver::VersionReq::parse("0.1").unwrap(),
+            },
+            version: semver::Version::new(0, 1, 5),
+            checksum: String::new(),
+        },
+        SourceRegistryPinned {
+            source: SourceRegistry {
+                index: RegistryIndex::Git(Url::parse("https://github.com/owner/index").unwrap()),
+                version: semver::VersionReq::parse(">=1.0.0, <2.0.0").unwrap(),
+            },
+            version: semver::Version::new(1, 2, 0),
+            checksum: String::new(),
+        },
+    ];
+
+    for (&string, expected) in strings.iter().zip(&expected) {
+        let parsed = SourceRegistryPinned::from_str(string).unwrap();
+        assert_eq!(&parsed, expected);
+        let serialized = expected.to_string();
+        assert_eq!(&serialized, string);
+    }
+}
+
 /// Format an error message for an absent `Forc.toml`.
 pub fn manifest_file_missing(dir: &Path) -> anyhow::Error {
     let message = format!(
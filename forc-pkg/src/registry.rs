@@ -0,0 +1,795 @@
+//! Resolution of registry dependencies against a package index.
+//!
+//! A package index is either a directory on the local file system or a git repository. In both
+//! cases the index has the following layout:
+//!
+//! ```ignore
+//! <index>/packages/<name>               // One JSON entry per line for each published version.
+//! <index>/dl/<name>-<version>.tar.gz    // The gzip-compressed source tarball of each version.
+//! ```
+//!
+//! Each line of a `packages/<name>` file describes a single published version:
+//!
+//! ```ignore
+//! {"name":"foo","version":"0.1.0","checksum":"<hex-encoded sha256 of the tarball>"}
+//! ```
+//!
+//! Indices are declared by name under the `[registries]` table of either a package's `Forc.toml`
+//! or the user's forc config file at `$HOME/.forc/config.toml`:
+//!
+//! ```toml
+//! [registries.default]
+//! index = "../my-index"
+//!
+//! [registries.fuel]
+//! git = "https://github.com/owner/forc-index"
+//! ```
+
+use crate::{
+    manifest::PackageManifestFile,
+    pkg::{path_lock, with_tmp_git_repo, GitReference, SourceGit},
+};
+use anyhow::{anyhow, bail, Context, Result};
+use forc_util::{registry_directory, user_forc_directory};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{hash_map, BTreeMap},
+    fmt, fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
+use url::Url;
+
+/// The name of the registry used by dependencies that do not specify one.
+pub const DEFAULT_REGISTRY_NAME: &str = "default";
+
+/// The name of the user-wide forc config file within the user's `.forc` directory.
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// The directory within an index containing the list of published versions of each package.
+const PACKAGES_DIR_NAME: &str = "packages";
+
+/// The directory within an index containing the source tarballs.
+const TARBALLS_DIR_NAME: &str = "dl";
+
+/// The file written into each unpacked registry package, recording the index it came from.
+///
+/// This allows for resolving the registry dependencies of registry packages against the same
+/// index, as their manifests do not declare any `[registries]` of their own.
+const SOURCE_INDEX_FILE_NAME: &str = ".forc_registry";
+
+/// The file written alongside the contents of an unpacked tarball, recording the checksum of the
+/// tarball it was unpacked from.
+///
+/// This allows for reusing a previously unpacked package only if it was unpacked from the tarball
+/// that is pinned within the lock file.
+const CHECKSUM_FILE_NAME: &str = ".forc_checksum";
+
+/// The file written into the local copy of a git index, recording the ID of the fetch pass that
+/// last updated it.
+///
+/// This avoids fetching the index once for every package that is pinned during a single pass.
+const FETCH_ID_FILE_NAME: &str = ".forc_fetch_id";
+
+/// The location of a package index.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum RegistryIndex {
+    /// A directory on the local file system.
+    Path(PathBuf),
+    /// A git repository. A copy of the repository is kept under `$HOME/.forc/registry/index`.
+    Git(Url),
+}
+
+/// A registry declared under the `[registries]` table of a `Forc.toml` or the forc config file.
+///
+/// Exactly one of `index` or `git` must be specified.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct RegistryConfig {
+    /// The path to a local directory index, relative to the file in which it is declared.
+    pub index: Option<String>,
+    /// The URL of a git repository index.
+    pub git: Option<String>,
+}
+
+/// A direct mapping to the user-wide forc config file, `$HOME/.forc/config.toml`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct ForcConfig {
+    pub registries: Option<BTreeMap<String, RegistryConfig>>,
+}
+
+/// A single published version of a package as described by the index.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct IndexEntry {
+    pub name: String,
+    pub version: semver::Version,
+    /// The hex-encoded SHA-256 hash of the source tarball.
+    pub checksum: String,
+    /// Yanked versions are never selected for new dependencies, but remain available to packages
+    /// that already have them pinned within their lock file.
+    #[serde(default)]
+    pub yanked: bool,
+}
+
+/// The checksum of a source tarball did not match the expected checksum.
+///
+/// Unlike other failures during fetching, this is always reported to the user rather than
+/// resolved by re-pinning the package.
+#[derive(Debug)]
+pub struct ChecksumMismatch {
+    pub name: String,
+    pub version: semver::Version,
+    pub expected: String,
+    pub found: String,
+}
+
+impl RegistryIndex {
+    /// A file-system friendly name for the index, used for its directories within the user's
+    /// registry directory.
+    fn dir_name(&self) -> String {
+        let mut hasher = hash_map::DefaultHasher::new();
+        self.hash(&mut hasher);
+        let hash = hasher.finish();
+        let stem = match self {
+            RegistryIndex::Path(path) => path.file_name().and_then(|s| s.to_str()),
+            RegistryIndex::Git(url) => url.host_str(),
+        };
+        format!("{}-{:x}", stem.unwrap_or("index"), hash)
+    }
+
+    /// The local directory containing the index.
+    ///
+    /// For git indices this is the most recently fetched copy of the repository.
+    pub fn local_path(&self) -> PathBuf {
+        match self {
+            RegistryIndex::Path(path) => path.clone(),
+            RegistryIndex::Git(_) => registry_directory().join("index").join(self.dir_name()),
+        }
+    }
+
+    /// Produce the local directory containing the index, first fetching the latest copy of the
+    /// repository in the case of a git index.
+    ///
+    /// In offline mode the previously fetched copy of a git index is used.
+    pub fn fetch(&self, fetch_id: u64, offline: bool) -> Result<PathBuf> {
+        let path = self.local_path();
+        let repo = match self {
+            RegistryIndex::Path(_) if path.is_dir() => return Ok(path),
+            RegistryIndex::Path(_) => bail!("failed to find registry index at {}", path.display()),
+            RegistryIndex::Git(repo) => repo,
+        };
+
+        // Use the local copy if offline or if it was already fetched during this fetch pass.
+        let fetch_id_path = path.join(FETCH_ID_FILE_NAME);
+        let fetch_id_string = format!("{:x}", fetch_id);
+        if path.is_dir()
+            && (offline || fs::read_to_string(&fetch_id_path).ok() == Some(fetch_id_string.clone()))
+        {
+            return Ok(path);
+        }
+        if offline {
+            bail!("Unable to fetch registry index {} in offline mode", repo);
+        }
+
+        // Co-ordinate access to the index directory using an advisory file lock.
+        let mut lock = path_lock(&path)?;
+        let _guard = lock.write()?;
+        let source = SourceGit {
+            repo: repo.clone(),
+            reference: GitReference::DefaultBranch,
+        };
+        with_tmp_git_repo(fetch_id, &self.dir_name(), &source, |repo| {
+            let id = source.reference.resolve(&repo)?;
+            repo.set_head_detached(id)?;
+            if path.exists() {
+                let _ = fs::remove_dir_all(&path);
+            }
+            fs::create_dir_all(&path)?;
+            let mut checkout = git2::build::CheckoutBuilder::new();
+            checkout.force().target_dir(&path);
+            repo.checkout_head(Some(&mut checkout))?;
+            fs::write(&fetch_id_path, &fetch_id_string)?;
+            Ok(())
+        })?;
+        Ok(path)
+    }
+
+    /// The directory to which the given version of a package from this index is unpacked.
+    ///
+    /// The resulting directory is:
+    ///
+    /// ```ignore
+    /// $HOME/.forc/registry/src/<index-name>-<index-hash>/<name>-<version>
+    /// ```
+    pub fn src_path(&self, name: &str, version: &semver::Version) -> PathBuf {
+        registry_directory()
+            .join("src")
+            .join(self.dir_name())
+            .join(format!("{}-{}", name, version))
+    }
+
+    /// The index as it is written to a lock file within `lock_dir`.
+    ///
+    /// The paths of local indices are made relative to `lock_dir`, so that the lock file remains
+    /// valid when the project is moved or checked out on another machine.
+    pub fn relative_to(&self, lock_dir: &Path) -> Self {
+        match self {
+            RegistryIndex::Path(path) => {
+                let lock_dir = lock_dir
+                    .canonicalize()
+                    .unwrap_or_else(|_| lock_dir.to_path_buf());
+                RegistryIndex::Path(relative_path(path, &lock_dir))
+            }
+            RegistryIndex::Git(_) => self.clone(),
+        }
+    }
+
+    /// The index as it was read from a lock file within `lock_dir`, resolving the relative paths
+    /// of local indices against `lock_dir`.
+    ///
+    /// This is the inverse of [RegistryIndex::relative_to].
+    pub fn resolved_from(&self, lock_dir: &Path) -> Self {
+        match self {
+            RegistryIndex::Path(path) if path.is_relative() => {
+                let path = lock_dir.join(path);
+                RegistryIndex::Path(path.canonicalize().unwrap_or(path))
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Read all entries for the package with the given name from the index at `index_path`.
+    pub fn entries(index_path: &Path, name: &str) -> Result<Vec<IndexEntry>> {
+        let path = index_path.join(PACKAGES_DIR_NAME).join(name);
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("failed to find package `{}` in registry index", name))?;
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).with_context(|| {
+                    format!("failed to parse registry index entry in {}", path.display())
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for RegistryIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryIndex::Path(path) => write!(f, "path+{}", path.display()),
+            RegistryIndex::Git(url) => write!(f, "git+{}", url),
+        }
+    }
+}
+
+impl FromStr for RegistryIndex {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(path) = s.strip_prefix("path+") {
+            Ok(RegistryIndex::Path(PathBuf::from(path)))
+        } else if let Some(url) = s.strip_prefix("git+") {
+            Ok(RegistryIndex::Git(Url::parse(url)?))
+        } else {
+            bail!("registry index must start with either `path+` or `git+`")
+        }
+    }
+}
+
+impl RegistryConfig {
+    /// Produce the index location, resolving local paths relative to the given directory.
+    pub fn index(&self, dir: &Path) -> Result<RegistryIndex> {
+        match (&self.index, &self.git) {
+            (Some(index), None) => {
+                let path = dir.join(index);
+                let path = path
+                    .canonicalize()
+                    .map_err(|e| anyhow!("failed to find registry index at {:?}: {}", path, e))?;
+                Ok(RegistryIndex::Path(path))
+            }
+            (None, Some(git)) => Ok(RegistryIndex::Git(Url::parse(git)?)),
+            _ => bail!("registries must specify exactly one of either `index` or `git`"),
+        }
+    }
+}
+
+impl ForcConfig {
+    /// The path to the user-wide forc config file.
+    pub fn path() -> PathBuf {
+        user_forc_directory().join(CONFIG_FILE_NAME)
+    }
+
+    /// Read the user-wide forc config file, or produce the default config if there is none.
+    pub fn from_user_dir() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let string = fs::read_to_string(&path)
+            .map_err(|e| anyhow!("failed to read {}: {}", path.display(), e))?;
+        toml::de::from_str(&string)
+            .map_err(|e| anyhow!("failed to parse {}: {}", path.display(), e))
+    }
+}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "checksum mismatch for `{} v{}`: expected {}, found {}",
+            self.name, self.version, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ChecksumMismatch {}
+
+/// Find the index for the registry with the given name as it applies to the given manifest.
+///
+/// Registries declared within the manifest take precedence over those declared in the user's forc
+/// config file. Packages that were themselves fetched from a registry resolve all of their
+/// registry dependencies against the index they were fetched from.
+pub fn resolve_index(manifest: &PackageManifestFile, registry: &str) -> Result<RegistryIndex> {
+    if let Some(config) = manifest.registry(registry) {
+        return config.index(manifest.dir());
+    }
+    if let Ok(string) = fs::read_to_string(manifest.dir().join(SOURCE_INDEX_FILE_NAME)) {
+        return string.trim().parse();
+    }
+    let forc_config = ForcConfig::from_user_dir()?;
+    if let Some(config) = forc_config
+        .registries
+        .as_ref()
+        .and_then(|registries| registries.get(registry))
+    {
+        return config.index(&user_forc_directory());
+    }
+    bail!(
+        "no index is declared for registry `{}`; add a `[registries.{}]` table with either an \
+        `index` or `git` entry to `Forc.toml` or {}",
+        registry,
+        registry,
+        ForcConfig::path().display(),
+    )
+}
+
+/// Select the greatest version that is not yanked and matches the given requirement.
+pub fn select<'a>(entries: &'a [IndexEntry], req: &semver::VersionReq) -> Option<&'a IndexEntry> {
+    entries
+        .iter()
+        .filter(|entry| !entry.yanked && req.matches(&entry.version))
+        .max_by(|a, b| a.version.cmp(&b.version))
+}
+
+/// The hex-encoded SHA-256 hash of the given bytes.
+pub fn checksum(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Unpack the tarball for the given version of a package from the index at `index_path` into the
+/// `dst` directory, verifying its checksum.
+///
+/// NOTE: This function assumes that the caller has acquired an advisory lock to co-ordinate
+/// access to the `dst` directory.
+pub fn unpack(
+    index_path: &Path,
+    name: &str,
+    version: &semver::Version,
+    expected_checksum: &str,
+    dst: &Path,
+) -> Result<()> {
+    let tarball_path = index_path
+        .join(TARBALLS_DIR_NAME)
+        .join(format!("{}-{}.tar.gz", name, version));
+    let tarball = fs::read(&tarball_path)
+        .map_err(|e| anyhow!("failed to read {}: {}", tarball_path.display(), e))?;
+    let found = checksum(&tarball);
+    if found != expected_checksum {
+        return Err(ChecksumMismatch {
+            name: name.to_string(),
+            version: version.clone(),
+            expected: expected_checksum.to_string(),
+            found,
+        }
+        .into());
+    }
+
+    // Unpack to a temporary directory first so that a failure never leaves behind a partially
+    // unpacked package.
+    let tmp_dst = dst.with_extension("tmp");
+    if tmp_dst.exists() {
+        let _ = fs::remove_dir_all(&tmp_dst);
+    }
+    fs::create_dir_all(&tmp_dst)?;
+    unpack_tarball(&tarball, &tmp_dst)
+        .with_context(|| format!("failed to unpack {}", tarball_path.display()))?;
+    fs::write(tmp_dst.join(CHECKSUM_FILE_NAME), &found)?;
+    if dst.exists() {
+        let _ = fs::remove_dir_all(dst);
+    }
+    fs::rename(&tmp_dst, dst)?;
+    Ok(())
+}
+
+/// Whether the `dst` directory contains a package that was unpacked by [unpack] from a tarball
+/// with the given checksum.
+pub fn is_unpacked(dst: &Path, expected_checksum: &str) -> bool {
+    fs::read_to_string(dst.join(CHECKSUM_FILE_NAME))
+        .is_ok_and(|found| found.trim() == expected_checksum)
+}
+
+/// Record the index from which the package in the given directory was fetched.
+///
+/// See [resolve_index] for details.
+pub fn record_index(pkg_dir: &Path, index: &RegistryIndex) -> Result<()> {
+    fs::write(pkg_dir.join(SOURCE_INDEX_FILE_NAME), index.to_string())
+        .map_err(|e| anyhow!("failed to record registry index: {}", e))
+}
+
+/// The path to `path` relative to the directory `base`, where both are absolute.
+fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    let path_components: Vec<_> = path.components().collect();
+    let base_components: Vec<_> = base.components().collect();
+    let common = path_components
+        .iter()
+        .zip(&base_components)
+        .take_while(|(a, b)| a == b)
+        .count();
+    // Paths without a common root, e.g. on different drives, can only be absolute.
+    if common == 0 {
+        return path.to_path_buf();
+    }
+    let relative: PathBuf = base_components[common..]
+        .iter()
+        .map(|_| Component::ParentDir)
+        .chain(path_components[common..].iter().cloned())
+        .collect();
+    match relative.as_os_str().is_empty() {
+        true => PathBuf::from("."),
+        false => relative,
+    }
+}
+
+/// Unpack the given gzip-compressed tarball into the `dst` directory.
+///
+/// Only regular files and directories are unpacked. All other kinds of entries are skipped, while
+/// entries with paths that would escape `dst` are rejected.
+fn unpack_tarball(tarball: &[u8], dst: &Path) -> Result<()> {
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(tarball));
+    let entries = archive.entries().context("failed to read tarball")?;
+    for entry in entries {
+        let mut entry = entry.context("failed to read tarball entry")?;
+        // The path accounts for the long names of both GNU and pax extension headers.
+        let entry_path = entry
+            .path()
+            .context("invalid entry path in tarball")?
+            .into_owned();
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_file() {
+            let path = tarball_entry_path(dst, &entry_path)?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = fs::File::create(&path)?;
+            io::copy(&mut entry, &mut file)?;
+        } else if entry_type.is_dir() {
+            fs::create_dir_all(tarball_entry_path(dst, &entry_path)?)?;
+        }
+    }
+    Ok(())
+}
+
+/// The path within `dst` for the tarball entry with the given path.
+fn tarball_entry_path(dst: &Path, entry: &Path) -> Result<PathBuf> {
+    let is_relative = entry
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if !is_relative {
+        bail!("invalid entry path `{}` in tarball", entry.display());
+    }
+    Ok(dst.join(entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// Produce a gzip-compressed ustar archive of the given files.
+    ///
+    /// The paths are written as they are, without the validation of `tar::Header::set_path`.
+    fn tarball(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut tar = tar::Builder::new(vec![]);
+        for (path, contents) in files {
+            let mut header = tar::Header::new_ustar();
+            header.as_old_mut().name[..path.len()].copy_from_slice(path.as_bytes());
+            header.set_mode(0o644);
+            header.set_size(contents.len() as u64);
+            header.set_cksum();
+            tar.append(&header, contents.as_bytes()).unwrap();
+        }
+        gzip(tar)
+    }
+
+    /// Produce a gzip-compressed archive of the given tar entries.
+    fn gzip(tar: tar::Builder<Vec<u8>>) -> Vec<u8> {
+        let tar = tar.into_inner().unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(&tar).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn entry(version: &str, yanked: bool) -> IndexEntry {
+        IndexEntry {
+            name: "foo".to_string(),
+            version: semver::Version::parse(version).unwrap(),
+            checksum: String::new(),
+            yanked,
+        }
+    }
+
+    #[test]
+    fn test_select_greatest_matching_version() {
+        let entries = [
+            entry("0.1.0", false),
+            entry("0.1.3", false),
+            entry("0.1.4", true),
+            entry("0.2.0", false),
+            entry("1.0.0", false),
+        ];
+        let select_version = |req: &str| {
+            let req = semver::VersionReq::parse(req).unwrap();
+            select(&entries, &req).map(|entry| entry.version.to_string())
+        };
+        assert_eq!(select_version("0.1").as_deref(), Some("0.1.3"));
+        assert_eq!(select_version("=0.1.0").as_deref(), Some("0.1.0"));
+        assert_eq!(select_version("^0.2").as_deref(), Some("0.2.0"));
+        assert_eq!(select_version(">=0.1, <1.0").as_deref(), Some("0.2.0"));
+        assert_eq!(select_version("*").as_deref(), Some("1.0.0"));
+        assert_eq!(select_version("=0.1.4"), None);
+        assert_eq!(select_version("2"), None);
+    }
+
+    #[test]
+    fn test_index_entries() {
+        let index = tempfile::tempdir().unwrap();
+        fs::create_dir_all(index.path().join(PACKAGES_DIR_NAME)).unwrap();
+        fs::write(
+            index.path().join(PACKAGES_DIR_NAME).join("foo"),
+            "{\"name\":\"foo\",\"version\":\"0.1.0\",\"checksum\":\"00\"}\n\n\
+             {\"name\":\"foo\",\"version\":\"0.2.0\",\"checksum\":\"11\",\"yanked\":true}\n",
+        )
+        .unwrap();
+        let entries = RegistryIndex::entries(index.path(), "foo").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].version, semver::Version::new(0, 1, 0));
+        assert!(!entries[0].yanked);
+        assert_eq!(entries[1].checksum, "11");
+        assert!(entries[1].yanked);
+        assert!(RegistryIndex::entries(index.path(), "bar").is_err());
+    }
+
+    #[test]
+    fn test_unpack_verifies_checksum() {
+        let index = tempfile::tempdir().unwrap();
+        let dst = tempfile::tempdir().unwrap();
+        let dst = dst.path().join("foo-0.1.0");
+        let bytes = tarball(&[
+            ("foo-0.1.0/Forc.toml", "[project]\nname = \"foo\"\n"),
+            ("foo-0.1.0/src/lib.sw", "library foo;\n"),
+        ]);
+        fs::create_dir_all(index.path().join(TARBALLS_DIR_NAME)).unwrap();
+        fs::write(
+            index
+                .path()
+                .join(TARBALLS_DIR_NAME)
+                .join("foo-0.1.0.tar.gz"),
+            &bytes,
+        )
+        .unwrap();
+        let version = semver::Version::new(0, 1, 0);
+
+        let err = unpack(index.path(), "foo", &version, "00", &dst).unwrap_err();
+        assert!(err.is::<ChecksumMismatch>());
+        assert!(!dst.exists());
+
+        unpack(index.path(), "foo", &version, &checksum(&bytes), &dst).unwrap();
+        let lib = fs::read_to_string(dst.join("foo-0.1.0/src/lib.sw")).unwrap();
+        assert_eq!(lib, "library foo;\n");
+        assert!(is_unpacked(&dst, &checksum(&bytes)));
+        assert!(!is_unpacked(&dst, "00"));
+    }
+
+    /// Publish the given versions of the library `foo` to the local index at `index_dir`, each
+    /// returning its own version from `version`.
+    fn publish_foo(index_dir: &Path, versions: &[&str]) {
+        fs::create_dir_all(index_dir.join(PACKAGES_DIR_NAME)).unwrap();
+        fs::create_dir_all(index_dir.join(TARBALLS_DIR_NAME)).unwrap();
+        let mut entries = String::new();
+        for version in versions {
+            let (major, minor, patch) = {
+                let v = semver::Version::parse(version).unwrap();
+                (v.major, v.minor, v.patch)
+            };
+            let bytes = tarball(&[
+                (
+                    &format!("foo-{version}/Forc.toml"),
+                    "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"lib.sw\"\n\
+                     license = \"Apache-2.0\"\nname = \"foo\"\nimplicit-std = false\n",
+                ),
+                (
+                    &format!("foo-{version}/src/lib.sw"),
+                    &format!(
+                        "library foo;\n\npub fn version() -> (u64, u64, u64) {{\n    \
+                         ({major}, {minor}, {patch})\n}}\n"
+                    ),
+                ),
+            ]);
+            fs::write(
+                index_dir
+                    .join(TARBALLS_DIR_NAME)
+                    .join(format!("foo-{version}.tar.gz")),
+                &bytes,
+            )
+            .unwrap();
+            let entry = IndexEntry {
+                name: "foo".to_string(),
+                version: semver::Version::parse(version).unwrap(),
+                checksum: checksum(&bytes),
+                yanked: false,
+            };
+            entries.push_str(&serde_json::to_string(&entry).unwrap());
+            entries.push('\n');
+        }
+        fs::write(index_dir.join(PACKAGES_DIR_NAME).join("foo"), entries).unwrap();
+    }
+
+    fn build(app_dir: &Path, offline: bool, locked: bool) -> Result<crate::pkg::Built> {
+        crate::pkg::build_with_options(crate::BuildOpts {
+            pkg: crate::PkgOpts {
+                path: Some(app_dir.display().to_string()),
+                offline,
+                locked,
+                terse: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_build_from_local_index() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().canonicalize().unwrap();
+        let index_dir = dir.join("index");
+        publish_foo(&index_dir, &["0.1.0", "0.1.1", "0.2.0"]);
+        let app_dir = dir.join("app");
+        fs::create_dir_all(app_dir.join("src")).unwrap();
+        fs::write(
+            app_dir.join("Forc.toml"),
+            "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"main.sw\"\nlicense = \"Apache-2.0\"\n\
+             name = \"app\"\nimplicit-std = false\n\n[dependencies]\nfoo = \"0.1\"\n\n\
+             [registries.default]\nindex = \"../index\"\n",
+        )
+        .unwrap();
+        fs::write(
+            app_dir.join("src/main.sw"),
+            "script;\n\nfn main() -> (u64, u64, u64) {\n    foo::version()\n}\n",
+        )
+        .unwrap();
+        let index = RegistryIndex::Path(index_dir.clone());
+        let version = semver::Version::new(0, 1, 1);
+        let src_path = index.src_path("foo", &version);
+        let src_dir = src_path.parent().unwrap().to_path_buf();
+
+        // The greatest matching version is pinned, relative to the project, and unpacked.
+        build(&app_dir, false, false).unwrap();
+        let lock = fs::read_to_string(app_dir.join("Forc.lock")).unwrap();
+        let index_bytes =
+            fs::read(index_dir.join(TARBALLS_DIR_NAME).join("foo-0.1.1.tar.gz")).unwrap();
+        let pinned = "registry+path+../index?^0.1#0.1.1";
+        assert!(lock.contains(&format!("source = '{pinned}'")), "{lock}");
+        assert!(lock.contains(&checksum(&index_bytes)), "{lock}");
+        assert!(is_unpacked(&src_path, &checksum(&index_bytes)));
+
+        // The unpacked package is reused while building offline from the lock file.
+        build(&app_dir, true, true).unwrap();
+        assert_eq!(fs::read_to_string(app_dir.join("Forc.lock")).unwrap(), lock);
+
+        // A package that was not unpacked from the pinned tarball is unpacked again, verifying the
+        // tarball against the pinned checksum.
+        fs::write(src_path.join(CHECKSUM_FILE_NAME), "00").unwrap();
+        let tampered = tarball(&[("foo-0.1.1/src/lib.sw", "library foo;\n")]);
+        fs::write(
+            index_dir.join(TARBALLS_DIR_NAME).join("foo-0.1.1.tar.gz"),
+            tampered,
+        )
+        .unwrap();
+        let err = build(&app_dir, true, true).unwrap_err().to_string();
+        let _ = fs::remove_dir_all(&src_dir);
+        assert!(err.contains("checksum mismatch for `foo v0.1.1`"), "{err}");
+    }
+
+    #[test]
+    fn test_unpack_gnu_long_names() {
+        let dst = tempfile::tempdir().unwrap();
+        let path = format!("foo-0.1.0/src/{}/lib.sw", "nested".repeat(20));
+        let mut tar = tar::Builder::new(vec![]);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(13);
+        tar.append_data(&mut header, &path, &b"library foo;\n"[..])
+            .unwrap();
+        unpack_tarball(&gzip(tar), dst.path()).unwrap();
+        let lib = fs::read_to_string(dst.path().join(&path)).unwrap();
+        assert_eq!(lib, "library foo;\n");
+    }
+
+    #[test]
+    fn test_unpack_pax_long_names() {
+        let dst = tempfile::tempdir().unwrap();
+        let path = format!("foo-0.1.0/src/{}/lib.sw", "nested".repeat(20));
+        // A pax record is `<len> <key>=<value>\n`, where `<len>` includes its own digits.
+        let record = format!(" path={}\n", path);
+        let len = record.len() + 3;
+        let record = format!("{}{}", len, record);
+        assert_eq!(record.len(), len);
+
+        let mut tar = tar::Builder::new(vec![]);
+        let mut pax = tar::Header::new_ustar();
+        pax.set_path("PaxHeaders/lib.sw").unwrap();
+        pax.set_entry_type(tar::EntryType::XHeader);
+        pax.set_size(record.len() as u64);
+        pax.set_cksum();
+        tar.append(&pax, record.as_bytes()).unwrap();
+        let mut header = tar::Header::new_ustar();
+        header.set_path("truncated.sw").unwrap();
+        header.set_size(13);
+        header.set_cksum();
+        tar.append(&header, &b"library foo;\n"[..]).unwrap();
+
+        unpack_tarball(&gzip(tar), dst.path()).unwrap();
+        let lib = fs::read_to_string(dst.path().join(&path)).unwrap();
+        assert_eq!(lib, "library foo;\n");
+        assert!(!dst.path().join("truncated.sw").exists());
+    }
+
+    #[test]
+    fn test_unpack_rejects_escaping_paths() {
+        let dst = tempfile::tempdir().unwrap();
+        let bytes = tarball(&[("../escaped.sw", "library escaped;\n")]);
+        assert!(unpack_tarball(&bytes, dst.path()).is_err());
+        let bytes = tarball(&[("/tmp/escaped.sw", "library escaped;\n")]);
+        assert!(unpack_tarball(&bytes, dst.path()).is_err());
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let relative = |path: &str, base: &str| relative_path(Path::new(path), Path::new(base));
+        assert_eq!(relative("/a/index", "/a/app"), PathBuf::from("../index"));
+        assert_eq!(relative("/a/app/index", "/a/app"), PathBuf::from("index"));
+        assert_eq!(
+            relative("/a/b/index", "/a/c/d"),
+            PathBuf::from("../../b/index")
+        );
+        assert_eq!(relative("/a/app", "/a/app"), PathBuf::from("."));
+    }
+
+    #[test]
+    fn test_registry_index_parsing() {
+        let indices = [
+            RegistryIndex::Path(PathBuf::from("/home/user/index")),
+            RegistryIndex::Git(Url::parse("https://github.com/owner/index").unwrap()),
+        ];
+        for index in indices {
+            assert_eq!(index.to_string().parse::<RegistryIndex>().unwrap(), index);
+        }
+        assert!("https://github.com/owner/index"
+            .parse::<RegistryIndex>()
+            .is_err());
+    }
+}
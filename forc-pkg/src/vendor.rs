@@ -0,0 +1,290 @@
+//! Vendoring of a project's git and registry dependencies.
+//!
+//! `forc vendor` copies the source of every git and registry package pinned within a project's
+//! lock file into a `vendor` directory alongside the project's root `Forc.toml`:
+//!
+//! ```ignore
+//! <project>/vendor/<name>-<commit_hash>    // A git package pinned to the given commit.
+//! <project>/vendor/<name>-<version>        // A registry package pinned to the given version.
+//! ```
+//!
+//! Path dependencies of vendored packages are copied along with them, retaining their location
+//! relative to the root of the git repository or registry tarball.
+//!
+//! When the root manifest enables `vendored`, these packages are resolved from the `vendor`
+//! directory rather than from the user's `$HOME/.forc` directory and the network is never
+//! required. Vendored packages retain their pinned sources, so the lock file is unaffected.
+
+use crate::{
+    manifest::{ManifestFile, PackageManifestFile},
+    pkg::{git_commit_path, BuildPlan, Pinned, SourcePinned},
+};
+use anyhow::{anyhow, bail, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use tracing::info;
+use walkdir::WalkDir;
+
+/// The name of the directory alongside the root `Forc.toml` to which dependencies are vendored.
+pub const VENDOR_DIR_NAME: &str = "vendor";
+
+/// The directory to which the vendor directory is written prior to replacing the existing one.
+const TMP_VENDOR_DIR_NAME: &str = ".vendor-tmp";
+
+/// The directory within the given `vendor_dir` to which the given package is vendored.
+///
+/// Returns `None` for member and path packages, which are never vendored.
+pub fn pkg_dir(vendor_dir: &Path, pinned: &Pinned) -> Option<PathBuf> {
+    let dir_name = match &pinned.source {
+        SourcePinned::Git(git) => format!("{}-{}", pinned.name, git.commit_hash),
+        SourcePinned::Registry(reg) => format!("{}-{}", pinned.name, reg.version),
+        SourcePinned::Member | SourcePinned::Path(_) => return None,
+    };
+    Some(vendor_dir.join(dir_name))
+}
+
+/// The vendor directory of the project whose lock file is at the given path, in the case that the
+/// project's manifest enables `vendored`.
+///
+/// The lock file of a workspace member is the workspace's lock file, so it is the workspace
+/// manifest that determines whether or not members use vendored dependencies.
+pub(crate) fn vendor_dir(lock_path: &Path) -> Result<Option<PathBuf>> {
+    let dir = lock_path
+        .parent()
+        .ok_or_else(|| anyhow!("Cannot get parent dir of {:?}", lock_path))?;
+    Ok(ManifestFile::from_dir(dir)?.vendor_dir())
+}
+
+/// Copy the source of every git and registry package pinned within the lock file of the given
+/// project into the project's vendor directory, replacing any previously vendored sources.
+///
+/// Dependencies are always resolved from the user's `$HOME/.forc` directory while vendoring,
+/// regardless of whether or not the project enables `vendored`.
+///
+/// Returns the vendor directory.
+pub fn vendor(manifest_file: &ManifestFile, locked: bool, offline: bool) -> Result<PathBuf> {
+    let member_manifests = manifest_file.member_manifests()?;
+    let lock_path = manifest_file.lock_path()?;
+    let plan = BuildPlan::from_lock_and_manifests_vendored(
+        &lock_path,
+        &member_manifests,
+        locked,
+        offline,
+        None,
+    )?;
+
+    let vendor_dir = manifest_file.dir().join(VENDOR_DIR_NAME);
+    let tmp_dir = manifest_file.dir().join(TMP_VENDOR_DIR_NAME);
+    if tmp_dir.exists() {
+        fs::remove_dir_all(&tmp_dir)?;
+    }
+    fs::create_dir_all(&tmp_dir)?;
+
+    let graph = plan.graph();
+    for &node in plan.compilation_order() {
+        let pinned = &graph[node];
+        let dst_root = match pkg_dir(&tmp_dir, pinned) {
+            Some(dir) => dir,
+            None => continue,
+        };
+        let src_root = match &pinned.source {
+            SourcePinned::Git(git) => {
+                git_commit_path(&pinned.name, &git.source.repo, &git.commit_hash)
+            }
+            SourcePinned::Registry(reg) => reg.source.index.src_path(&pinned.name, &reg.version),
+            SourcePinned::Member | SourcePinned::Path(_) => continue,
+        }
+        .canonicalize()?;
+
+        // Copy the package along with any path dependencies that reside within the same source.
+        let pinned_id = pinned.id();
+        let path_deps = graph.node_indices().filter(|&n| match &graph[n].source {
+            SourcePinned::Path(src) => src.path_root == pinned_id,
+            _ => false,
+        });
+        for n in std::iter::once(node).chain(path_deps) {
+            let pkg_manifest: &PackageManifestFile = &plan.manifest_map()[&graph[n].id()];
+            let src_dir = pkg_manifest.dir();
+            let rel_path = src_dir.strip_prefix(&src_root).map_err(|_| {
+                anyhow!(
+                    "cannot vendor package `{}` as it resides outside of the source of `{}`: {}",
+                    graph[n].name,
+                    pinned.name,
+                    src_dir.display(),
+                )
+            })?;
+            copy_dir(src_dir, &dst_root.join(rel_path))?;
+        }
+        info!("   Vendored {} ({})", pinned.name, pinned.source);
+    }
+
+    if vendor_dir.exists() {
+        fs::remove_dir_all(&vendor_dir)?;
+    }
+    fs::rename(&tmp_dir, &vendor_dir)?;
+    Ok(vendor_dir)
+}
+
+/// Recursively copy the contents of the `src` directory into the `dst` directory.
+fn copy_dir(src: &Path, dst: &Path) -> Result<()> {
+    for entry in WalkDir::new(src) {
+        let entry = entry?;
+        let path = dst.join(entry.path().strip_prefix(src)?);
+        let file_type = entry.file_type();
+        if file_type.is_dir() {
+            fs::create_dir_all(&path)?;
+        } else if file_type.is_file() {
+            fs::copy(entry.path(), &path)?;
+        } else {
+            bail!(
+                "cannot vendor {}: not a file or directory",
+                entry.path().display()
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pkg_dir, vendor, VENDOR_DIR_NAME};
+    use crate::{
+        manifest::ManifestFile,
+        pkg::{self, git_commit_path, BuildOpts, PkgOpts, SourcePinned},
+        Lock,
+    };
+    use std::{fs, path::Path};
+
+    fn write_pkg(dir: &Path, name: &str, src: &str, deps: &str) {
+        fs::create_dir_all(dir.join("src")).unwrap();
+        let manifest = format!(
+            "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"main.sw\"\nlicense = \"Apache-2.0\"\n\
+             name = \"{name}\"\nimplicit-std = false\n\n[dependencies]\n{deps}"
+        );
+        fs::write(dir.join("Forc.toml"), manifest).unwrap();
+        fs::write(dir.join("src/main.sw"), src).unwrap();
+    }
+
+    /// Create a git repository containing the library `remote`, which depends on the library `util`
+    /// by path, and commit its contents to the `main` branch.
+    fn write_git_repo(repo_dir: &Path) {
+        write_pkg(
+            &repo_dir.join("remote"),
+            "remote",
+            "library remote;\n\npub fn remote_value() -> u64 {\n    util::util_value()\n}\n",
+            "util = { path = \"../util\" }\n",
+        );
+        write_pkg(
+            &repo_dir.join("util"),
+            "util",
+            "library util;\n\npub fn util_value() -> u64 {\n    42\n}\n",
+            "",
+        );
+        let repo = git2::Repository::init(repo_dir).unwrap();
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Fuel Labs", "contact@fuel.sh").unwrap();
+        repo.commit(Some("refs/heads/main"), &sig, &sig, "init", &tree, &[])
+            .unwrap();
+        repo.set_head("refs/heads/main").unwrap();
+    }
+
+    fn build(app_dir: &Path, offline: bool, locked: bool) -> anyhow::Result<pkg::Built> {
+        pkg::build_with_options(BuildOpts {
+            pkg: PkgOpts {
+                path: Some(app_dir.display().to_string()),
+                offline,
+                locked,
+                terse: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_vendor_and_build_offline() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().canonicalize().unwrap();
+        let repo_dir = dir.join("repo");
+        write_git_repo(&repo_dir);
+        write_pkg(
+            &dir.join("local"),
+            "local",
+            "library local;\n\npub fn local_value() -> u64 {\n    1\n}\n",
+            "",
+        );
+        let app_dir = dir.join("app");
+        let app_deps = format!(
+            "remote = {{ git = \"file://{}\", branch = \"main\" }}\nlocal = {{ path = \"../local\" }}\n",
+            repo_dir.display()
+        );
+        let app_src = "script;\n\nfn main() -> u64 {\n    remote::remote_value()\n}\n";
+        write_pkg(&app_dir, "app", app_src, &app_deps);
+
+        // Fetch the git dependency and write the lock file.
+        build(&app_dir, false, false).unwrap();
+
+        let manifest_file = ManifestFile::from_dir(&app_dir).unwrap();
+        let vendor_dir = vendor(&manifest_file, true, true).unwrap();
+        assert_eq!(vendor_dir, app_dir.join(VENDOR_DIR_NAME));
+
+        // The git package is vendored along with the path dependency within its repository, while
+        // the path dependency of the project itself is not.
+        let lock = Lock::from_path(&app_dir.join("Forc.lock")).unwrap();
+        let graph = lock.to_graph(&app_dir).unwrap();
+        let remote = graph
+            .node_weights()
+            .find(|pinned| pinned.name == "remote")
+            .unwrap();
+        let git = match &remote.source {
+            SourcePinned::Git(git) => git.clone(),
+            source => panic!("unexpected source for `remote`: {source}"),
+        };
+        let remote_dir = pkg_dir(&vendor_dir, remote).unwrap();
+        assert_eq!(
+            remote_dir,
+            vendor_dir.join(format!("remote-{}", git.commit_hash))
+        );
+        assert!(remote_dir.join("remote/Forc.toml").exists());
+        assert!(remote_dir.join("remote/src/main.sw").exists());
+        assert!(remote_dir.join("util/Forc.toml").exists());
+        assert!(remote_dir.join("util/src/main.sw").exists());
+        let vendored: Vec<_> = fs::read_dir(&vendor_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(vendored, [remote_dir.file_name().unwrap()]);
+
+        // Without the repository or its checkout, the build resolves from the vendor directory.
+        let lock_before = fs::read_to_string(app_dir.join("Forc.lock")).unwrap();
+        fs::remove_dir_all(&repo_dir).unwrap();
+        let checkout = git_commit_path("remote", &git.source.repo, &git.commit_hash);
+        fs::remove_dir_all(checkout.parent().unwrap()).unwrap();
+        let manifest = fs::read_to_string(app_dir.join("Forc.toml")).unwrap();
+        let manifest = manifest.replace(
+            "implicit-std = false",
+            "implicit-std = false\nvendored = true",
+        );
+        fs::write(app_dir.join("Forc.toml"), manifest).unwrap();
+        build(&app_dir, true, true).unwrap();
+        let lock_after = fs::read_to_string(app_dir.join("Forc.lock")).unwrap();
+        assert_eq!(lock_before, lock_after);
+
+        // Removing the vendored package is an error rather than a fetch, even when online.
+        fs::remove_dir_all(&remote_dir).unwrap();
+        let err = build(&app_dir, false, false).unwrap_err().to_string();
+        assert!(
+            err.starts_with(&format!(
+                "failed to resolve dependencies from the vendor directory {}",
+                vendor_dir.display()
+            )),
+            "{err}"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use clap::Parser;
+use forc_pkg::manifest::parse_constant_override;
 use fuel_gql_client::fuel_crypto::SecretKey;
 
 #[derive(Debug, Default, Parser)]
@@ -73,6 +74,20 @@ pub struct DeployCommand {
     /// Output the time elapsed over each part of the compilation process.
     #[clap(long)]
     pub time_phases: bool,
+    /// Treat warnings that are not explicitly allowed as errors.
+    #[clap(long)]
+    pub deny_warnings: bool,
+    /// Comma separated list of features to enable for the package.
+    ///
+    /// May be specified multiple times.
+    #[clap(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+    /// Override the value of a constant declared in the `[constants]` table of the manifest, in
+    /// the form `NAME=VALUE`.
+    ///
+    /// May be specified multiple times. Takes precedence over the constants of the build profile.
+    #[clap(long = "constant", value_name = "NAME=VALUE", parse(try_from_str = parse_constant_override))]
+    pub constants: Vec<(String, String)>,
     /// Do not sign the transaction
     #[clap(long)]
     pub unsigned: bool,
@@ -141,8 +141,12 @@ fn build_opts_from_cmd(cmd: &DeployCommand) -> pkg::BuildOpts {
         build_profile: cmd.build_profile.clone(),
         release: cmd.release,
         time_phases: cmd.time_phases,
+        deny_warnings: cmd.deny_warnings,
         binary_outfile: cmd.binary_outfile.clone(),
         debug_outfile: cmd.debug_outfile.clone(),
         tests: false,
+        member_filter: pkg::MemberFilter::default(),
+        features: cmd.features.clone(),
+        constants: cmd.constants.iter().cloned().collect(),
     }
 }
@@ -271,6 +271,37 @@ impl Descriptor {
                     }))
                 }
             }
+            TypeAliasDeclaration(ref decl_id) => {
+                let type_alias_decl =
+                    declaration_engine.get_type_alias(decl_id.clone(), &decl_id.span())?;
+                if !document_private_items && type_alias_decl.visibility.is_private() {
+                    Ok(Descriptor::NonDocumentable)
+                } else {
+                    let item_name = type_alias_decl.name.as_str().to_string();
+                    let attrs_opt = (!type_alias_decl.attributes.is_empty())
+                        .then(|| attrsmap_to_html_string(&type_alias_decl.attributes));
+
+                    Ok(Descriptor::Documentable(Document {
+                        module_prefix,
+                        item_header: ItemHeader {
+                            module_depth,
+                            module,
+                            friendly_name: ty_decl.friendly_name().to_string(),
+                            item_name: item_name.clone(),
+                        },
+                        item_body: ItemBody {
+                            module_depth,
+                            ty_decl: ty_decl.clone(),
+                            item_name,
+                            code_str: parse::parse_format::<sway_ast::ItemTypeAlias>(
+                                type_alias_decl.span.as_str(),
+                            ),
+                            attrs_opt,
+                            item_context: ItemContext { context: None },
+                        },
+                    }))
+                }
+            }
             _ => Ok(Descriptor::NonDocumentable),
         }
     }
@@ -497,6 +497,7 @@ impl Renderable for AllDoc {
         let mut storage_items: Vec<ItemPath> = Vec::new();
         let mut fn_items: Vec<ItemPath> = Vec::new();
         let mut const_items: Vec<ItemPath> = Vec::new();
+        let mut type_alias_items: Vec<ItemPath> = Vec::new();
 
         for doc_item in all_doc.clone() {
             let AllDocItem {
@@ -535,6 +536,10 @@ impl Renderable for AllDoc {
                     path_literal_str: path_str.to_string(),
                     qualified_file_path: qualified_file_path(&module_prefix, file_name.to_string()),
                 }),
+                TypeAliasDeclaration(_) => type_alias_items.push(ItemPath {
+                    path_literal_str: path_str.to_string(),
+                    qualified_file_path: qualified_file_path(&module_prefix, file_name.to_string()),
+                }),
                 _ => {}
             }
         }
@@ -611,6 +616,9 @@ impl Renderable for AllDoc {
                             @ if !const_items.is_empty() {
                                 : all_items_list("Constants".to_string(), const_items);
                             }
+                            @ if !type_alias_items.is_empty() {
+                                : all_items_list("Type Aliases".to_string(), type_alias_items);
+                            }
                         }
                     }
                 }
@@ -690,9 +698,9 @@ pub(crate) fn attrsmap_to_html_string(attributes: &AttributesMap) -> String {
     let mut docs = String::new();
 
     if let Some(vec_attrs) = attributes {
-        for ident in vec_attrs.iter().flat_map(|attribute| &attribute.args) {
-            writeln!(docs, "{}", ident.as_str())
-                .expect("problem appending `ident.as_str()` to `docs` with `writeln` macro.");
+        for arg in vec_attrs.iter().flat_map(|attribute| &attribute.args) {
+            writeln!(docs, "{}", arg.name.as_str())
+                .expect("problem appending `arg.name.as_str()` to `docs` with `writeln` macro.");
         }
     }
 
@@ -1,4 +1,9 @@
-use std::{collections::HashSet, fs, path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    path::PathBuf,
+    sync::Arc,
+};
 
 use forc_pkg as pkg;
 use fuel_tx as tx;
@@ -59,6 +64,16 @@ pub enum BuiltTests {
     Workspace(Vec<pkg::BuiltPackage>),
 }
 
+/// Used to filter the tests that are run by name.
+#[derive(Debug, Clone)]
+pub struct TestFilter<'a> {
+    /// The phrase used to select tests.
+    pub filter_phrase: &'a str,
+    /// If `true`, only tests whose name exactly matches the phrase are run. Otherwise, any test
+    /// whose name contains the phrase is run.
+    pub exact_match: bool,
+}
+
 /// The set of options provided to the `test` function.
 #[derive(Default)]
 pub struct Opts {
@@ -78,6 +93,14 @@ pub struct Opts {
     pub release: bool,
     /// Output the time elapsed over each part of the compilation process.
     pub time_phases: bool,
+    /// Treat warnings that are not explicitly allowed as errors.
+    pub deny_warnings: bool,
+    /// The workspace members to test.
+    pub member_filter: pkg::MemberFilter,
+    /// The features to enable for the workspace members that declare them.
+    pub features: Vec<String>,
+    /// Values that override those of the `[constants]` tables of the workspace members.
+    pub constants: BTreeMap<String, String>,
 }
 
 impl Opts {
@@ -93,6 +116,10 @@ impl Opts {
             release: self.release,
             time_phases: self.time_phases,
             tests: true,
+            deny_warnings: self.deny_warnings,
+            member_filter: self.member_filter,
+            features: self.features,
+            constants: self.constants,
         }
     }
 }
@@ -131,21 +158,44 @@ impl TestResult {
     }
 }
 
+impl TestFilter<'_> {
+    /// Whether or not the test with the given name is selected by this filter.
+    pub fn filter(&self, fn_name: &str) -> bool {
+        if self.exact_match {
+            fn_name == self.filter_phrase
+        } else {
+            fn_name.contains(self.filter_phrase)
+        }
+    }
+}
+
 impl BuiltTests {
-    /// The total number of tests.
-    pub fn test_count(&self) -> usize {
+    /// The total number of tests, or the number of tests selected by the given filter.
+    pub fn test_count(&self, test_filter: Option<&TestFilter>) -> usize {
         let pkgs: Vec<&BuiltPackage> = match self {
             BuiltTests::Package(pkg) => vec![pkg],
             BuiltTests::Workspace(workspace) => workspace.iter().collect(),
         };
         pkgs.iter()
-            .map(|pkg| pkg.entries.iter().filter(|e| e.is_test()).count())
+            .map(|pkg| {
+                pkg.entries
+                    .iter()
+                    .filter(|e| e.is_test() && is_selected(test_filter, &e.fn_name))
+                    .count()
+            })
             .sum()
     }
 
-    /// Run all built tests, return the result.
-    pub fn run(self) -> anyhow::Result<Tested> {
-        run_tests(self)
+    /// Run all built tests selected by the given filter, return the result.
+    pub fn run(self, test_filter: Option<TestFilter>) -> anyhow::Result<Tested> {
+        run_tests(self, test_filter.as_ref())
+    }
+}
+
+fn is_selected(test_filter: Option<&TestFilter>, fn_name: &str) -> bool {
+    match test_filter {
+        Some(filter) => filter.filter(fn_name),
+        None => true,
     }
 }
 
@@ -169,7 +219,7 @@ fn test_pass_condition(
         .get(&AttributeKind::Test)
         .expect("test declaration is missing test attribute")
         .iter()
-        .flat_map(|attr| attr.args.iter().map(|arg| arg.to_string()))
+        .flat_map(|attr| attr.args.iter().map(|arg| arg.name.to_string()))
         .collect();
     let test_name = &test_function_decl.name;
     if test_args.is_empty() {
@@ -182,29 +232,32 @@ fn test_pass_condition(
 }
 
 /// Build the the given package and run its tests, returning the results.
-fn run_tests(built: BuiltTests) -> anyhow::Result<Tested> {
+fn run_tests(built: BuiltTests, test_filter: Option<&TestFilter>) -> anyhow::Result<Tested> {
     match built {
         BuiltTests::Package(pkg) => {
-            let tested_pkg = run_pkg_tests(*pkg)?;
+            let tested_pkg = run_pkg_tests(*pkg, test_filter)?;
             Ok(Tested::Package(Box::new(tested_pkg)))
         }
         BuiltTests::Workspace(workspace) => {
             let tested_pkgs = workspace
                 .into_iter()
-                .map(run_pkg_tests)
+                .map(|pkg| run_pkg_tests(pkg, test_filter))
                 .collect::<anyhow::Result<Vec<TestedPackage>>>()?;
             Ok(Tested::Workspace(tested_pkgs))
         }
     }
 }
 
-fn run_pkg_tests(built_pkg: BuiltPackage) -> anyhow::Result<TestedPackage> {
-    // Run all tests and collect their results.
+fn run_pkg_tests(
+    built_pkg: BuiltPackage,
+    test_filter: Option<&TestFilter>,
+) -> anyhow::Result<TestedPackage> {
+    // Run all selected tests and collect their results.
     // TODO: We can easily parallelise this, but let's wait until testing is stable first.
     let tests = built_pkg
         .entries
         .iter()
-        .filter(|entry| entry.is_test())
+        .filter(|entry| entry.is_test() && is_selected(test_filter, &entry.fn_name))
         .map(|entry| {
             let offset = u32::try_from(entry.imm).expect("test instruction offset out of range");
             let name = entry.fn_name.clone();
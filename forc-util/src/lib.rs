@@ -125,6 +125,16 @@ pub fn git_checkouts_directory() -> PathBuf {
     user_forc_directory().join("git").join("checkouts")
 }
 
+/// The location at which `forc` will keep registry indices and unpacked registry packages.
+pub fn registry_directory() -> PathBuf {
+    user_forc_directory().join("registry")
+}
+
+/// The location at which `forc` will cache the results of compiling packages.
+pub fn build_cache_directory() -> PathBuf {
+    user_forc_directory().join("cache")
+}
+
 pub fn print_on_success(
     terse_mode: bool,
     proj_name: &str,
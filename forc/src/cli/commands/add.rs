@@ -0,0 +1,55 @@
+use crate::ops::forc_add;
+use anyhow::Result;
+use clap::Parser;
+
+/// Add a dependency to the manifest of the current or target package.
+///
+/// The dependency is added to the `[dependencies]` table, or to the `[contract-dependencies]` table
+/// if `--contract-dep` is specified. The existing formatting and comments of the `Forc.toml` are
+/// preserved, and the `Forc.lock` is updated to include the new dependency.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The name of the dependency.
+    pub name: String,
+    /// Path to the package, if not specified, current working directory will be used.
+    #[clap(long)]
+    pub manifest_path: Option<String>,
+    /// Add a path dependency located at the given path, relative to the package.
+    #[clap(long, conflicts_with_all = &["git", "version"])]
+    pub path: Option<String>,
+    /// Add a git dependency sourced from the repository at the given URL.
+    #[clap(long, conflicts_with = "version")]
+    pub git: Option<String>,
+    /// The git branch to use for the dependency.
+    #[clap(long, requires = "git", conflicts_with_all = &["tag", "rev"])]
+    pub branch: Option<String>,
+    /// The git tag to use for the dependency.
+    #[clap(long, requires = "git", conflicts_with = "rev")]
+    pub tag: Option<String>,
+    /// The git revision to use for the dependency.
+    #[clap(long, requires = "git")]
+    pub rev: Option<String>,
+    /// Add a registry dependency with the given version requirement.
+    #[clap(long)]
+    pub version: Option<String>,
+    /// The name of the registry from which the dependency is sourced.
+    #[clap(long, requires = "version")]
+    pub registry: Option<String>,
+    /// The name of the package within the dependency's source, if it differs from `name`.
+    #[clap(long)]
+    pub package: Option<String>,
+    /// Add the dependency to the `[contract-dependencies]` table.
+    #[clap(long)]
+    pub contract_dep: bool,
+    /// The salt used to deploy the contract dependency.
+    #[clap(long, requires = "contract-dep")]
+    pub salt: Option<String>,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_add::add(command)
+}
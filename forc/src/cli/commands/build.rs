@@ -16,6 +16,12 @@ use clap::Parser;
 ///
 /// - `contract` and `library` projects will also produce the public ABI in JSON format
 /// `<project-name>-abi.json`.
+///
+/// Compiled packages are cached under `~/.forc/cache`. A package whose sources, manifest,
+/// dependencies and build profile are unchanged since it was last compiled is loaded from the
+/// cache rather than being compiled again.
+///
+/// Packages that do not depend on one another are compiled in parallel.
 #[derive(Debug, Default, Parser)]
 pub struct Command {
     #[clap(flatten)]
@@ -10,8 +10,26 @@ use sway_core::{declaration_engine::DeclarationEngine, Engines, TypeEngine};
 #[derive(Debug, Default, Parser)]
 pub struct Command {
     /// Path to the project, if not specified, current working directory will be used.
+    ///
+    /// Note that `-p` is short for `--path`, not `--package`.
     #[clap(short, long)]
     pub path: Option<String>,
+    /// Only check the workspace members with the given names, along with their dependencies.
+    ///
+    /// May be specified multiple times. By default, all members are checked. There is no short
+    /// flag, as `-p` is kept as the short flag for `--path` for backward compatibility.
+    #[clap(long = "package")]
+    pub packages: Vec<String>,
+    /// Exclude the workspace members with the given names.
+    ///
+    /// May be specified multiple times.
+    #[clap(long)]
+    pub exclude: Vec<String>,
+    /// Comma separated list of features to enable for the workspace members that declare them.
+    ///
+    /// May be specified multiple times.
+    #[clap(long, value_delimiter = ',')]
+    pub features: Vec<String>,
     /// Offline mode, prevents Forc from using the network when managing dependencies.
     /// Meaning it will only try to use previously downloaded dependencies.
     #[clap(long = "offline")]
@@ -0,0 +1,26 @@
+use crate::ops::forc_remove;
+use anyhow::Result;
+use clap::Parser;
+
+/// Remove dependencies from the manifest of the current or target package.
+///
+/// Dependencies are removed from both the `[dependencies]` and `[contract-dependencies]` tables.
+/// The existing formatting and comments of the `Forc.toml` are preserved, and the `Forc.lock` is
+/// updated accordingly.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The names of the dependencies to remove.
+    #[clap(required = true)]
+    pub names: Vec<String>,
+    /// Path to the package, if not specified, current working directory will be used.
+    #[clap(long)]
+    pub manifest_path: Option<String>,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_remove::remove(command)
+}
@@ -29,18 +29,31 @@ pub struct Command {
     pub build: cli::shared::Build,
     /// When specified, only tests containing the given string will be executed.
     pub filter: Option<String>,
+    /// When specified, only the test exactly matching the given string will be executed.
+    #[clap(long)]
+    pub exact: bool,
 }
 
 pub(crate) fn exec(cmd: Command) -> Result<()> {
-    if let Some(ref _filter) = cmd.filter {
-        bail!("unit test filter not yet supported");
+    if cmd.exact && cmd.filter.is_none() {
+        bail!("`--exact` requires a test name filter");
     }
 
+    let filter = cmd.filter.clone();
+    let test_filter = filter
+        .as_deref()
+        .map(|filter_phrase| forc_test::TestFilter {
+            filter_phrase,
+            exact_match: cmd.exact,
+        });
     let opts = opts_from_cmd(cmd);
     let built_tests = forc_test::build(opts)?;
     let start = std::time::Instant::now();
-    info!("   Running {} tests", built_tests.test_count());
-    let tested = built_tests.run()?;
+    info!(
+        "   Running {} tests",
+        built_tests.test_count(test_filter.as_ref())
+    );
+    let tested = built_tests.run(test_filter)?;
     let duration = start.elapsed();
 
     // Eventually we'll print this in a fancy manner, but this will do for testing.
@@ -138,7 +151,14 @@ fn opts_from_cmd(cmd: Command) -> forc_test::Opts {
         build_profile: cmd.build.build_profile,
         release: cmd.build.release,
         time_phases: cmd.build.time_phases,
+        deny_warnings: cmd.build.deny_warnings,
         binary_outfile: cmd.build.binary_outfile,
         debug_outfile: cmd.build.debug_outfile,
+        member_filter: pkg::MemberFilter {
+            packages: cmd.build.packages,
+            exclude: cmd.build.exclude,
+        },
+        features: cmd.build.features,
+        constants: cmd.build.constants.into_iter().collect(),
     }
 }
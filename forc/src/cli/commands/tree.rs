@@ -0,0 +1,50 @@
+use crate::ops::forc_tree;
+use anyhow::Result;
+use clap::{ArgEnum, Parser};
+
+/// Display the dependency graph of the current or target project as a tree.
+///
+/// Each package is shown along with its pinned source: the directory of member and path packages,
+/// the repository and commit of git packages, and the version and index of registry packages.
+/// Contract dependencies are marked with `[contract]`, and dependencies declared under a name that
+/// differs from the package name are marked with that name.
+///
+/// Packages whose dependencies have already been displayed are marked with `(*)` and are not
+/// expanded again.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Display the packages that depend on the named package, rather than the dependencies of
+    /// each member.
+    ///
+    /// If the graph contains more than one package with the given name, e.g. two copies of `std`
+    /// pinned to different sources, a tree is displayed for each of them.
+    #[clap(short, long)]
+    pub invert: Option<String>,
+    /// Print the graph in the given format rather than as a tree.
+    #[clap(long, arg_enum)]
+    pub format: Option<Format>,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+}
+
+/// The formats in which the dependency graph may be printed.
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// A graph in the Graphviz DOT language.
+    Dot,
+    /// A JSON object containing a list of packages and a list of dependencies.
+    Json,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_tree::tree(command)
+}
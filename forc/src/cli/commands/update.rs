@@ -19,6 +19,11 @@ pub struct Command {
     /// ones are up-to-date and outdated.
     #[clap(short, long)]
     pub check: bool,
+
+    /// Update the dependency to exactly the given git revision or registry version.
+    /// Requires a dependency to be specified with `-d`.
+    #[clap(long, requires = "target-dependency")]
+    pub precise: Option<String>,
 }
 
 pub(crate) async fn exec(command: Command) -> Result<()> {
@@ -0,0 +1,30 @@
+use crate::ops::forc_vendor;
+use anyhow::Result;
+use clap::Parser;
+
+/// Copy all git and registry dependencies of the current or target project into its `vendor`
+/// directory.
+///
+/// Each package pinned within the `Forc.lock` file is copied from the user's `~/.forc` directory,
+/// fetching it first if necessary. Any previously vendored packages are replaced.
+///
+/// Set `vendored = true` under the `[project]` or `[workspace]` table of the `Forc.toml` to
+/// resolve these packages from the `vendor` directory when building, without network access.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_vendor::vendor(command)
+}
@@ -1,9 +1,10 @@
 use std::str::FromStr;
 
 use self::commands::{
-    addr2line, build, check, clean, completions, init, new, parse_bytecode, plugins, template,
-    test, update,
+    add, addr2line, build, check, clean, completions, init, new, parse_bytecode, plugins, remove,
+    template, test, tree, update, vendor,
 };
+pub use add::Command as AddCommand;
 use addr2line::Command as Addr2LineCommand;
 use anyhow::{anyhow, Result};
 pub use build::Command as BuildCommand;
@@ -16,10 +17,13 @@ pub use init::Command as InitCommand;
 pub use new::Command as NewCommand;
 use parse_bytecode::Command as ParseBytecodeCommand;
 pub use plugins::Command as PluginsCommand;
+pub use remove::Command as RemoveCommand;
 pub use template::Command as TemplateCommand;
 pub use test::Command as TestCommand;
 use tracing::metadata::LevelFilter;
+pub use tree::{Command as TreeCommand, Format as TreeFormat};
 pub use update::Command as UpdateCommand;
+pub use vendor::Command as VendorCommand;
 
 mod commands;
 mod plugin;
@@ -47,6 +51,7 @@ struct Opt {
 
 #[derive(Subcommand, Debug)]
 enum Forc {
+    Add(AddCommand),
     #[clap(name = "addr2line")]
     Addr2Line(Addr2LineCommand),
     #[clap(visible_alias = "b")]
@@ -61,7 +66,10 @@ enum Forc {
     Test(TestCommand),
     Update(UpdateCommand),
     Plugins(PluginsCommand),
+    Remove(RemoveCommand),
     Template(TemplateCommand),
+    Tree(TreeCommand),
+    Vendor(VendorCommand),
     /// This is a catch-all for unknown subcommands and their arguments.
     ///
     /// When we receive an unknown subcommand, we check for a plugin exe named
@@ -86,6 +94,7 @@ pub async fn run_cli() -> Result<()> {
     init_tracing_subscriber(tracing_options);
 
     match opt.command {
+        Forc::Add(command) => add::exec(command),
         Forc::Addr2Line(command) => addr2line::exec(command),
         Forc::Build(command) => build::exec(command),
         Forc::Check(command) => check::exec(command),
@@ -95,9 +104,12 @@ pub async fn run_cli() -> Result<()> {
         Forc::New(command) => new::exec(command),
         Forc::ParseBytecode(command) => parse_bytecode::exec(command),
         Forc::Plugins(command) => plugins::exec(command),
+        Forc::Remove(command) => remove::exec(command),
         Forc::Test(command) => test::exec(command),
         Forc::Update(command) => update::exec(command).await,
         Forc::Template(command) => template::exec(command),
+        Forc::Tree(command) => tree::exec(command),
+        Forc::Vendor(command) => vendor::exec(command),
         Forc::Plugin(args) => {
             let output = plugin::execute_external_subcommand(args)?;
             let code = output
@@ -108,3 +120,32 @@ pub async fn run_cli() -> Result<()> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Opt;
+    use clap::{CommandFactory, Parser};
+
+    #[test]
+    fn test_cli_flags_do_not_conflict() {
+        Opt::command().debug_assert();
+    }
+
+    #[test]
+    fn test_path_short_flag_is_consistent() {
+        for cmd in [
+            "build", "check", "test", "tree", "clean", "update", "vendor",
+        ] {
+            let opt = Opt::try_parse_from(["forc", cmd, "-p", "some/dir"]);
+            assert!(opt.is_ok(), "`forc {cmd} -p <path>` failed to parse");
+        }
+        let opt = Opt::try_parse_from(["forc", "check", "-p", "dir", "--package", "a"]).unwrap();
+        match opt.command {
+            super::Forc::Check(cmd) => {
+                assert_eq!(cmd.path.as_deref(), Some("dir"));
+                assert_eq!(cmd.packages, ["a"]);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -1,14 +1,39 @@
 //! Sets of arguments that are shared between commands.
 
 use clap::Parser;
+use forc_pkg::manifest::parse_constant_override;
 
 /// Args that can be shared between all commands that `build` a package. E.g. `build`, `test`,
 /// `deploy`.
 #[derive(Debug, Default, Parser)]
 pub struct Build {
     /// Path to the project, if not specified, current working directory will be used.
+    ///
+    /// Note that `-p` is short for `--path`, not `--package`.
     #[clap(short, long)]
     pub path: Option<String>,
+    /// Only build the workspace members with the given names, along with their dependencies.
+    ///
+    /// May be specified multiple times. By default, all members are built. There is no short
+    /// flag, as `-p` is kept as the short flag for `--path` for backward compatibility.
+    #[clap(long = "package")]
+    pub packages: Vec<String>,
+    /// Exclude the workspace members with the given names.
+    ///
+    /// May be specified multiple times.
+    #[clap(long)]
+    pub exclude: Vec<String>,
+    /// Comma separated list of features to enable for the workspace members that declare them.
+    ///
+    /// May be specified multiple times.
+    #[clap(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+    /// Override the value of a constant declared in the `[constants]` table of the manifest, in
+    /// the form `NAME=VALUE`.
+    ///
+    /// May be specified multiple times. Takes precedence over the constants of the build profile.
+    #[clap(long = "constant", value_name = "NAME=VALUE", parse(try_from_str = parse_constant_override))]
+    pub constants: Vec<(String, String)>,
     /// Print the generated Sway AST (Abstract Syntax Tree).
     #[clap(long)]
     pub print_ast: bool,
@@ -65,6 +90,11 @@ pub struct Build {
     /// Output the time elapsed over each part of the compilation process.
     #[clap(long)]
     pub time_phases: bool,
+    /// Treat warnings that are not explicitly allowed as errors.
+    ///
+    /// This applies to the workspace members only, not to their dependencies.
+    #[clap(long)]
+    pub deny_warnings: bool,
     /// If set, outputs a binary file representing the script bytes.
     #[clap(short = 'o')]
     pub binary_outfile: Option<String>,
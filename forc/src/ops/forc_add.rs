@@ -0,0 +1,83 @@
+use crate::{
+    cli::AddCommand,
+    utils::manifest_edit::{ManifestEdit, CONTRACT_DEPENDENCIES_TABLE, DEPENDENCIES_TABLE},
+};
+use anyhow::{anyhow, bail, Result};
+use std::{path::PathBuf, str::FromStr};
+use sway_core::fuel_prelude::fuel_tx;
+use tracing::info;
+
+/// Add the dependency described by the `AddCommand` to the package's manifest and update the lock
+/// file accordingly.
+///
+/// A dependency of the same name that is already listed within the target table is replaced.
+pub fn add(command: AddCommand) -> Result<()> {
+    let this_dir = match command.manifest_path {
+        Some(ref path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let mut edit = ManifestEdit::from_dir(&this_dir)?;
+    let table_name = match command.contract_dep {
+        true => CONTRACT_DEPENDENCIES_TABLE,
+        false => DEPENDENCIES_TABLE,
+    };
+    let dep = dependency_item(&command)?;
+    let replaced = edit
+        .table_mut(table_name)?
+        .insert(&command.name, dep)
+        .is_some();
+    let pkg_name = edit.manifest().project.name.clone();
+    edit.write(command.offline_mode)?;
+    match replaced {
+        true => info!(
+            "    Updated `{}` in [{}] of {}",
+            command.name, table_name, pkg_name
+        ),
+        false => info!(
+            "      Added `{}` to [{}] of {}",
+            command.name, table_name, pkg_name
+        ),
+    }
+    Ok(())
+}
+
+/// Produce the manifest entry for the dependency described by the given command.
+fn dependency_item(command: &AddCommand) -> Result<toml_edit::Item> {
+    let mut table = toml_edit::InlineTable::new();
+    if let Some(path) = &command.path {
+        table.insert("path", path.into());
+    } else if let Some(git) = &command.git {
+        table.insert("git", git.into());
+        let reference = [
+            ("branch", &command.branch),
+            ("tag", &command.tag),
+            ("rev", &command.rev),
+        ];
+        for (key, value) in reference {
+            if let Some(value) = value {
+                table.insert(key, value.into());
+            }
+        }
+    } else if let Some(version) = &command.version {
+        // Prefer the simple `name = "<version>"` form where possible.
+        if command.registry.is_none() && command.package.is_none() && !command.contract_dep {
+            return Ok(toml_edit::value(version));
+        }
+        table.insert("version", version.into());
+        if let Some(registry) = &command.registry {
+            table.insert("registry", registry.into());
+        }
+    } else {
+        bail!(
+            "a source for the dependency must be specified with `--path`, `--git` or `--version`"
+        );
+    }
+    if let Some(package) = &command.package {
+        table.insert("package", package.into());
+    }
+    if let Some(salt) = &command.salt {
+        fuel_tx::Salt::from_str(salt).map_err(|e| anyhow!("invalid salt `{}`: {}", salt, e))?;
+        table.insert("salt", salt.into());
+    }
+    Ok(toml_edit::value(table))
+}
@@ -31,8 +31,108 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
         build_profile: cmd.build.build_profile,
         release: cmd.build.release,
         time_phases: cmd.build.time_phases,
+        deny_warnings: cmd.build.deny_warnings,
         binary_outfile: cmd.build.binary_outfile,
         debug_outfile: cmd.build.debug_outfile,
         tests: cmd.tests,
+        member_filter: pkg::MemberFilter {
+            packages: cmd.build.packages,
+            exclude: cmd.build.exclude,
+        },
+        features: cmd.build.features,
+        constants: cmd.build.constants.into_iter().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::opts_from_cmd;
+    use crate::cli::BuildCommand;
+    use anyhow::Result;
+    use clap::Parser;
+    use forc_pkg::{self as pkg, manifest::ManifestFile};
+    use std::path::PathBuf;
+
+    fn workspace_dir() -> String {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("test/src/e2e_vm_tests/test_programs/should_pass/forc/workspace_building")
+            .display()
+            .to_string()
+    }
+
+    /// Parse `forc build` arguments and return the names of the members they select.
+    fn selected_members(args: &[&str]) -> Result<Vec<String>> {
+        let cmd = BuildCommand::try_parse_from(["forc-build"].iter().chain(args))?;
+        let opts = opts_from_cmd(cmd);
+        let manifest_file = ManifestFile::from_dir(&PathBuf::from(opts.pkg.path.unwrap()))?;
+        let member_manifests = manifest_file.member_manifests()?;
+        let lock_path = manifest_file.lock_path()?;
+        let plan =
+            pkg::BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, true, true)?;
+        let graph = plan.graph();
+        let members = plan.selected_members(&opts.member_filter)?;
+        Ok(members.into_iter().map(|n| graph[n].name.clone()).collect())
+    }
+
+    #[test]
+    fn test_path_short_flag() {
+        let dir = workspace_dir();
+        let cmd = BuildCommand::try_parse_from(["forc-build", "-p", &dir]).unwrap();
+        assert_eq!(cmd.build.path, Some(dir));
+        assert!(cmd.build.packages.is_empty());
+    }
+
+    #[test]
+    fn test_select_all_members_by_default() {
+        let dir = workspace_dir();
+        let members = selected_members(&["-p", &dir]).unwrap();
+        assert_eq!(members, ["test_lib", "test_contract", "test_script"]);
+    }
+
+    #[test]
+    fn test_select_packages() {
+        let dir = workspace_dir();
+        let args = [
+            "-p",
+            &dir,
+            "--package",
+            "test_script",
+            "--package",
+            "test_lib",
+        ];
+        let members = selected_members(&args).unwrap();
+        assert_eq!(members, ["test_lib", "test_script"]);
+    }
+
+    #[test]
+    fn test_exclude_packages() {
+        let dir = workspace_dir();
+        let members = selected_members(&["-p", &dir, "--exclude", "test_contract"]).unwrap();
+        assert_eq!(members, ["test_lib", "test_script"]);
+        let args = [
+            "-p",
+            &dir,
+            "--package",
+            "test_lib",
+            "--package",
+            "test_script",
+        ];
+        let members = selected_members(&[&args[..], &["--exclude", "test_lib"]].concat()).unwrap();
+        assert_eq!(members, ["test_script"]);
+    }
+
+    #[test]
+    fn test_invalid_selection() {
+        let dir = workspace_dir();
+        let err = selected_members(&["-p", &dir, "--package", "missing"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No member named `missing` was found in the workspace"
+        );
+        let args = ["-p", &dir, "--package", "test_lib", "--exclude", "test_lib"];
+        let err = selected_members(&args).unwrap_err();
+        assert_eq!(err.to_string(), "All of the selected members were excluded");
     }
 }
@@ -11,6 +11,9 @@ pub fn check(command: CheckCommand, engines: Engines<'_>) -> Result<CompileResul
         offline_mode: offline,
         terse_mode,
         locked,
+        packages,
+        exclude,
+        features,
     } = command;
 
     let this_dir = if let Some(ref path) = path {
@@ -23,6 +26,10 @@ pub fn check(command: CheckCommand, engines: Engines<'_>) -> Result<CompileResul
     let lock_path = manifest_file.lock_path()?;
     let plan =
         pkg::BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, locked, offline)?;
+    let member_filter = pkg::MemberFilter { packages, exclude };
+    let members = plan.selected_members(&member_filter)?;
+    let mut plan = plan.member_subset(&members);
+    plan.enable_member_features(&features)?;
 
     let mut v = pkg::check(&plan, terse_mode, engines)?;
     let res = v
@@ -0,0 +1,66 @@
+use crate::{
+    cli::RemoveCommand,
+    utils::manifest_edit::{ManifestEdit, CONTRACT_DEPENDENCIES_TABLE, DEPENDENCIES_TABLE},
+};
+use anyhow::{bail, Result};
+use std::path::PathBuf;
+use tracing::info;
+
+/// Remove the dependencies named by the `RemoveCommand` from the package's manifest and update the
+/// lock file accordingly.
+pub fn remove(command: RemoveCommand) -> Result<()> {
+    let RemoveCommand {
+        names,
+        manifest_path,
+        offline_mode: offline,
+    } = command;
+    let this_dir = match manifest_path {
+        Some(ref path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let mut edit = ManifestEdit::from_dir(&this_dir)?;
+    let pkg_name = edit.manifest().project.name.clone();
+    let mut removed = vec![];
+    for name in &names {
+        let mut found = false;
+        for table_name in [DEPENDENCIES_TABLE, CONTRACT_DEPENDENCIES_TABLE] {
+            let table = match edit.doc.get_mut(table_name) {
+                Some(item) => item.as_table_like_mut(),
+                None => None,
+            };
+            if let Some(table) = table {
+                if table.remove(name).is_some() {
+                    removed.push((name, table_name));
+                    found = true;
+                }
+            }
+        }
+        if !found {
+            bail!(
+                "the dependency `{}` could not be found in [{}] or [{}] of {}",
+                name,
+                DEPENDENCIES_TABLE,
+                CONTRACT_DEPENDENCIES_TABLE,
+                pkg_name
+            );
+        }
+    }
+    // Drop a `[contract-dependencies]` table left empty. The `[dependencies]` table is retained as
+    // it is declared by every new package's manifest.
+    let contract_deps_is_empty = edit
+        .doc
+        .get(CONTRACT_DEPENDENCIES_TABLE)
+        .and_then(|item| item.as_table_like())
+        .is_some_and(|table| table.is_empty());
+    if contract_deps_is_empty {
+        edit.doc.remove(CONTRACT_DEPENDENCIES_TABLE);
+    }
+    edit.write(offline)?;
+    for (name, table_name) in removed {
+        info!(
+            "    Removed `{}` from [{}] of {}",
+            name, table_name, pkg_name
+        );
+    }
+    Ok(())
+}
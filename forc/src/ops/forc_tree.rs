@@ -0,0 +1,449 @@
+use crate::cli::{TreeCommand, TreeFormat};
+use anyhow::{bail, Result};
+use forc_pkg::{
+    self as pkg, manifest::ManifestFile, BuildPlan, DepKind, Edge, NodeIx, SourcePinned,
+};
+use petgraph::{
+    visit::{Bfs, EdgeRef, Reversed, Walker},
+    Direction,
+};
+use serde::Serialize;
+use std::{collections::HashSet, path::PathBuf};
+use tracing::info;
+
+/// A package within the JSON representation of the dependency graph.
+#[derive(Serialize)]
+struct JsonPackage {
+    id: String,
+    name: String,
+    source: String,
+    path: String,
+}
+
+/// A dependency within the JSON representation of the dependency graph.
+#[derive(Serialize)]
+struct JsonDependency {
+    from: String,
+    to: String,
+    name: String,
+    kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    salt: Option<String>,
+}
+
+/// The JSON representation of the dependency graph.
+#[derive(Serialize)]
+struct JsonGraph {
+    packages: Vec<JsonPackage>,
+    dependencies: Vec<JsonDependency>,
+}
+
+/// Print the dependency graph of the project or workspace as described by the `TreeCommand`.
+pub fn tree(command: TreeCommand) -> Result<()> {
+    let TreeCommand {
+        path,
+        invert,
+        format,
+        offline_mode: offline,
+        locked,
+    } = command;
+
+    let this_dir = match path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest_file = ManifestFile::from_dir(&this_dir)?;
+    let member_manifests = manifest_file.member_manifests()?;
+    let lock_path = manifest_file.lock_path()?;
+    let plan =
+        pkg::BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, locked, offline)?;
+
+    let output = render(&plan, invert.as_deref(), format)?;
+    info!("{}", output.trim_end());
+    Ok(())
+}
+
+/// Render the dependency graph of the plan's members, or the packages depending on the package
+/// named by `invert`, either as a tree or in the given format.
+fn render(plan: &BuildPlan, invert: Option<&str>, format: Option<TreeFormat>) -> Result<String> {
+    // The packages from which the trees are displayed.
+    let mut roots: Vec<NodeIx> = match invert {
+        None => plan.member_nodes().collect(),
+        Some(name) => plan
+            .compilation_order()
+            .iter()
+            .cloned()
+            .filter(|&node| plan.graph()[node].name == name)
+            .collect(),
+    };
+    if let (Some(name), true) = (invert, roots.is_empty()) {
+        bail!(
+            "No package named `{}` was found in the dependency graph",
+            name
+        );
+    }
+    roots.sort_by_cached_key(|&node| node_label(plan, node));
+    let direction = match invert {
+        None => Direction::Outgoing,
+        Some(_) => Direction::Incoming,
+    };
+
+    let output = match format {
+        None => {
+            let trees: Vec<String> = roots
+                .iter()
+                .map(|&root| render_tree(plan, root, direction))
+                .collect();
+            trees.join("\n")
+        }
+        Some(format) => {
+            let nodes = reachable_nodes(plan, &roots, direction);
+            match format {
+                TreeFormat::Dot => render_dot(plan, &nodes),
+                TreeFormat::Json => render_json(plan, &nodes)?,
+            }
+        }
+    };
+    Ok(output)
+}
+
+/// Describe the package at the given node along with its pinned source.
+fn node_label(plan: &BuildPlan, node: NodeIx) -> String {
+    let pkg = &plan.graph()[node];
+    match &pkg.source {
+        SourcePinned::Member | SourcePinned::Path(_) => {
+            let manifest = &plan.manifest_map()[&pkg.id()];
+            format!("{} ({})", pkg.name, manifest.dir().display())
+        }
+        SourcePinned::Git(git) => {
+            format!("{} ({}#{})", pkg.name, git.source.repo, git.commit_hash)
+        }
+        SourcePinned::Registry(reg) => {
+            format!("{} v{} ({})", pkg.name, reg.version, reg.source.index)
+        }
+    }
+}
+
+/// Describe the dependency edge between a package and its dependency.
+fn edge_label(plan: &BuildPlan, dep_node: NodeIx, edge: &Edge) -> String {
+    let mut label = String::new();
+    if edge.name != plan.graph()[dep_node].name {
+        label.push_str(&format!(" as `{}`", edge.name));
+    }
+    if let DepKind::Contract { .. } = edge.kind {
+        label.push_str(" [contract]");
+    }
+    label
+}
+
+/// The neighbours of the given node in the given direction along with the connecting edges,
+/// ordered by the label of the neighbour.
+fn neighbours(plan: &BuildPlan, node: NodeIx, direction: Direction) -> Vec<(NodeIx, &Edge)> {
+    let mut neighbours: Vec<_> = plan
+        .graph()
+        .edges_directed(node, direction)
+        .map(|edge| match direction {
+            Direction::Outgoing => (edge.target(), edge.weight()),
+            Direction::Incoming => (edge.source(), edge.weight()),
+        })
+        .collect();
+    neighbours.sort_by_cached_key(|&(neighbour, edge)| (node_label(plan, neighbour), &edge.name));
+    neighbours
+}
+
+/// Render the tree of packages reachable from `root` in the given direction.
+///
+/// Packages that have already been expanded within the tree are marked with `(*)` rather than
+/// being expanded again.
+fn render_tree(plan: &BuildPlan, root: NodeIx, direction: Direction) -> String {
+    fn render_children(
+        plan: &BuildPlan,
+        node: NodeIx,
+        direction: Direction,
+        prefix: &str,
+        expanded: &mut HashSet<NodeIx>,
+        out: &mut String,
+    ) {
+        let children = neighbours(plan, node, direction);
+        let count = children.len();
+        for (i, (child, edge)) in children.into_iter().enumerate() {
+            let is_last = i + 1 == count;
+            let (branch, indent) = match is_last {
+                true => ("└── ", "    "),
+                false => ("├── ", "│   "),
+            };
+            // When inverted, the edge describes how the child depends upon the node.
+            let dep_node = match direction {
+                Direction::Outgoing => child,
+                Direction::Incoming => node,
+            };
+            let has_children = plan
+                .graph()
+                .edges_directed(child, direction)
+                .next()
+                .is_some();
+            let already_expanded = has_children && !expanded.insert(child);
+            out.push_str(&format!(
+                "{}{}{}{}{}\n",
+                prefix,
+                branch,
+                node_label(plan, child),
+                edge_label(plan, dep_node, edge),
+                if already_expanded { " (*)" } else { "" },
+            ));
+            if !already_expanded {
+                let prefix = format!("{}{}", prefix, indent);
+                render_children(plan, child, direction, &prefix, expanded, out);
+            }
+        }
+    }
+
+    let mut out = format!("{}\n", node_label(plan, root));
+    let mut expanded = HashSet::from([root]);
+    render_children(plan, root, direction, "", &mut expanded, &mut out);
+    out
+}
+
+/// Collect the nodes reachable from the given roots in the given direction, in compilation order.
+fn reachable_nodes(plan: &BuildPlan, roots: &[NodeIx], direction: Direction) -> Vec<NodeIx> {
+    let graph = plan.graph();
+    let mut reachable = HashSet::new();
+    for &root in roots {
+        match direction {
+            Direction::Outgoing => reachable.extend(Bfs::new(graph, root).iter(graph)),
+            Direction::Incoming => {
+                let reversed = Reversed(graph);
+                reachable.extend(Bfs::new(reversed, root).iter(reversed))
+            }
+        }
+    }
+    plan.compilation_order()
+        .iter()
+        .cloned()
+        .filter(|node| reachable.contains(node))
+        .collect()
+}
+
+/// The edges between the given nodes, ordered by their source and target nodes.
+fn edges_between<'a>(plan: &'a BuildPlan, nodes: &[NodeIx]) -> Vec<(NodeIx, NodeIx, &'a Edge)> {
+    let included: HashSet<NodeIx> = nodes.iter().cloned().collect();
+    nodes
+        .iter()
+        .flat_map(|&node| {
+            neighbours(plan, node, Direction::Outgoing)
+                .into_iter()
+                .filter(|(dep, _)| included.contains(dep))
+                .map(move |(dep, edge)| (node, dep, edge))
+        })
+        .collect()
+}
+
+/// Render the graph of the given nodes in the Graphviz DOT language.
+///
+/// Contract dependencies are drawn with dashed edges.
+fn render_dot(plan: &BuildPlan, nodes: &[NodeIx]) -> String {
+    let id = |node: NodeIx| plan.graph()[node].id().to_string();
+    let mut out = String::from("digraph {\n");
+    for &node in nodes {
+        out.push_str(&format!(
+            "    \"{}\" [label={:?}];\n",
+            id(node),
+            node_label(plan, node)
+        ));
+    }
+    for (node, dep, edge) in edges_between(plan, nodes) {
+        let style = match edge.kind {
+            DepKind::Library => "",
+            DepKind::Contract { .. } => ", style=dashed",
+        };
+        out.push_str(&format!(
+            "    \"{}\" -> \"{}\" [label={:?}{}];\n",
+            id(node),
+            id(dep),
+            edge.name,
+            style
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Render the graph of the given nodes as JSON.
+fn render_json(plan: &BuildPlan, nodes: &[NodeIx]) -> Result<String> {
+    let graph = plan.graph();
+    let packages = nodes
+        .iter()
+        .map(|&node| {
+            let pkg = &graph[node];
+            let manifest = &plan.manifest_map()[&pkg.id()];
+            JsonPackage {
+                id: pkg.id().to_string(),
+                name: pkg.name.clone(),
+                source: pkg.source.to_string(),
+                path: manifest.dir().display().to_string(),
+            }
+        })
+        .collect();
+    let dependencies = edges_between(plan, nodes)
+        .into_iter()
+        .map(|(node, dep, edge)| JsonDependency {
+            from: graph[node].id().to_string(),
+            to: graph[dep].id().to_string(),
+            name: edge.name.clone(),
+            kind: edge.kind.to_string(),
+            salt: match edge.kind {
+                DepKind::Library => None,
+                DepKind::Contract { salt } => Some(format!("{:#x}", salt)),
+            },
+        })
+        .collect();
+    let json_graph = JsonGraph {
+        packages,
+        dependencies,
+    };
+    Ok(serde_json::to_string_pretty(&json_graph)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::cli::TreeFormat;
+    use forc_pkg::{self as pkg, manifest::ManifestFile};
+    use std::{fs, path::Path};
+
+    fn write_pkg(dir: &Path, name: &str, kind: &str, deps: &str) {
+        let pkg_dir = dir.join(name);
+        fs::create_dir_all(pkg_dir.join("src")).unwrap();
+        let manifest = format!(
+            "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"main.sw\"\nlicense = \"Apache-2.0\"\n\
+             name = \"{name}\"\nimplicit-std = false\n\n{deps}"
+        );
+        fs::write(pkg_dir.join("Forc.toml"), manifest).unwrap();
+        fs::write(pkg_dir.join("src/main.sw"), format!("{kind};\n")).unwrap();
+    }
+
+    /// Write a workspace whose members `app` and `token` depend on the libraries `lib_a` to `lib_c`,
+    /// which lie outside of the workspace and depend on one another.
+    fn write_workspace(dir: &Path) {
+        let ws = dir.join("ws");
+        fs::create_dir_all(&ws).unwrap();
+        fs::write(
+            ws.join("Forc.toml"),
+            "[workspace]\nmembers = [\"app\", \"token\"]\n",
+        )
+        .unwrap();
+        write_pkg(
+            &ws,
+            "app",
+            "script",
+            "[dependencies]\nlib_a = { path = \"../../lib_a\" }\n\
+             c = { path = \"../../lib_c\", package = \"lib_c\" }\n\n\
+             [contract-dependencies]\ntoken = { path = \"../token\" }\n",
+        );
+        write_pkg(
+            &ws,
+            "token",
+            "contract",
+            "[dependencies]\nlib_a = { path = \"../../lib_a\" }\n",
+        );
+        write_pkg(
+            dir,
+            "lib_a",
+            "library lib_a",
+            "[dependencies]\nlib_b = { path = \"../lib_b\" }\n",
+        );
+        write_pkg(
+            dir,
+            "lib_b",
+            "library lib_b",
+            "[dependencies]\nlib_c = { path = \"../lib_c\" }\n",
+        );
+        write_pkg(dir, "lib_c", "library lib_c", "");
+    }
+
+    /// Render the graph of the project at `path`, replacing the temporary directory with `<dir>`.
+    fn render_at(
+        dir: &Path,
+        path: &str,
+        invert: Option<&str>,
+        format: Option<TreeFormat>,
+    ) -> String {
+        let manifest_file = ManifestFile::from_dir(&dir.join(path)).unwrap();
+        let member_manifests = manifest_file.member_manifests().unwrap();
+        let lock_path = manifest_file.lock_path().unwrap();
+        let plan =
+            pkg::BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, false, true)
+                .unwrap();
+        let output = render(&plan, invert, format).unwrap();
+        output.replace(&dir.display().to_string(), "<dir>")
+    }
+
+    #[test]
+    fn test_workspace_tree() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().canonicalize().unwrap();
+        write_workspace(&dir);
+        let expected = "\
+app (<dir>/ws/app)
+├── lib_a (<dir>/lib_a)
+│   └── lib_b (<dir>/lib_b)
+│       └── lib_c (<dir>/lib_c)
+├── lib_c (<dir>/lib_c) as `c`
+└── token (<dir>/ws/token) [contract]
+    └── lib_a (<dir>/lib_a) (*)
+
+token (<dir>/ws/token)
+└── lib_a (<dir>/lib_a)
+    └── lib_b (<dir>/lib_b)
+        └── lib_c (<dir>/lib_c)
+";
+        assert_eq!(render_at(&dir, "ws", None, None), expected);
+    }
+
+    #[test]
+    fn test_nested_dependency_tree() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().canonicalize().unwrap();
+        write_workspace(&dir);
+        let expected = "\
+lib_a (<dir>/lib_a)
+└── lib_b (<dir>/lib_b)
+    └── lib_c (<dir>/lib_c)
+";
+        assert_eq!(render_at(&dir, "lib_a", None, None), expected);
+    }
+
+    #[test]
+    fn test_inverted_tree() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().canonicalize().unwrap();
+        write_workspace(&dir);
+        let expected = "\
+lib_c (<dir>/lib_c)
+├── app (<dir>/ws/app) as `c`
+└── lib_b (<dir>/lib_b)
+    └── lib_a (<dir>/lib_a)
+        ├── app (<dir>/ws/app)
+        └── token (<dir>/ws/token)
+            └── app (<dir>/ws/app) [contract]
+";
+        assert_eq!(render_at(&dir, "ws", Some("lib_c"), None), expected);
+    }
+
+    #[test]
+    fn test_nested_dependency_dot() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().canonicalize().unwrap();
+        write_workspace(&dir);
+        let output = render_at(&dir, "lib_a", None, Some(TreeFormat::Dot));
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 7, "{output}");
+        assert_eq!(lines[0], "digraph {");
+        assert!(lines[1].ends_with("[label=\"lib_c (<dir>/lib_c)\"];"));
+        assert!(lines[2].ends_with("[label=\"lib_b (<dir>/lib_b)\"];"));
+        assert!(lines[3].ends_with("[label=\"lib_a (<dir>/lib_a)\"];"));
+        assert!(lines[4].ends_with("[label=\"lib_c\"];"));
+        assert!(lines[5].ends_with("[label=\"lib_b\"];"));
+        assert_eq!(lines[6], "}");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::cli::UpdateCommand;
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use forc_pkg::{self as pkg, lock, Lock};
 use forc_util::lock_path;
 use pkg::manifest::ManifestFile;
@@ -17,15 +17,15 @@ use tracing::info;
 /// Run `forc update --check` to perform a dry-run and produce a list of updates that will be
 /// performed across all dependencies without actually committing them to the lock file.
 ///
-/// Use the `--package <package-name>` flag to update only a specific package throughout the
-/// dependency graph.
+/// Use the `-d <package-name>` flag to update only a specific package throughout the dependency
+/// graph. All other entries within the lock file remain unchanged. Combine it with
+/// `--precise <rev|version>` to pin the package to a specific git revision or registry version.
 pub async fn update(command: UpdateCommand) -> Result<()> {
     let UpdateCommand {
         path,
         check,
-        // TODO: Use `package` here rather than `target_dependency`
-        target_dependency: _,
-        ..
+        target_dependency,
+        precise,
     } = command;
 
     let this_dir = match path {
@@ -35,11 +35,37 @@ pub async fn update(command: UpdateCommand) -> Result<()> {
 
     let manifest = ManifestFile::from_dir(&this_dir)?;
     let lock_path = lock_path(manifest.dir());
-    let old_lock = Lock::from_path(&lock_path).ok().unwrap_or_default();
     let offline = false;
     let member_manifests = manifest.member_manifests()?;
-    let new_plan = pkg::BuildPlan::from_manifests(&member_manifests, offline)?;
-    let new_lock = Lock::from_graph(new_plan.graph());
+    let (old_lock, new_plan) = match target_dependency {
+        Some(dep_name) => {
+            let old_lock = Lock::from_path(&lock_path).map_err(|e| {
+                anyhow!(
+                    "cannot update `{}` without an existing lock file: {}",
+                    dep_name,
+                    e
+                )
+            })?;
+            let new_plan = pkg::BuildPlan::from_lock_and_manifests_with_update(
+                &old_lock,
+                manifest.dir(),
+                &member_manifests,
+                &dep_name,
+                precise.as_deref(),
+                offline,
+            )?;
+            (old_lock, new_plan)
+        }
+        None => {
+            if precise.is_some() {
+                bail!("`--precise` requires a dependency to be specified with `-d`");
+            }
+            let old_lock = Lock::from_path(&lock_path).ok().unwrap_or_default();
+            let new_plan = pkg::BuildPlan::from_manifests(&member_manifests, offline)?;
+            (old_lock, new_plan)
+        }
+    };
+    let new_lock = Lock::from_graph(new_plan.graph(), manifest.dir());
     let diff = new_lock.diff(&old_lock);
     let member_names = member_manifests
         .values()
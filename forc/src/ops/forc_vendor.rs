@@ -0,0 +1,36 @@
+use crate::cli::VendorCommand;
+use anyhow::Result;
+use forc_pkg::{manifest::ManifestFile, vendor};
+use std::path::PathBuf;
+use tracing::info;
+
+/// Vendor the dependencies of the project or workspace as described by the `VendorCommand`.
+pub fn vendor(command: VendorCommand) -> Result<()> {
+    let VendorCommand {
+        path,
+        offline_mode: offline,
+        locked,
+    } = command;
+
+    let this_dir = match path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest_file = ManifestFile::from_dir(&this_dir)?;
+    let vendor_dir = vendor::vendor(&manifest_file, locked, offline)?;
+    info!("  Vendored dependencies to {}", vendor_dir.display());
+
+    if manifest_file.vendor_dir().is_none() {
+        let table = match manifest_file {
+            ManifestFile::Package(_) => "project",
+            ManifestFile::Workspace(_) => "workspace",
+        };
+        info!(
+            "To build using the vendored dependencies, add `vendored = true` to the `[{}]` \
+            table of {}",
+            table,
+            manifest_file.path().display(),
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,273 @@
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::{
+    self as pkg,
+    manifest::{ManifestFile, PackageManifest, PackageManifestFile},
+};
+use std::{fs, path::Path};
+
+/// The manifest table listing a package's library dependencies.
+pub(crate) const DEPENDENCIES_TABLE: &str = "dependencies";
+/// The manifest table listing a package's contract dependencies.
+pub(crate) const CONTRACT_DEPENDENCIES_TABLE: &str = "contract-dependencies";
+
+/// A package's `Forc.toml`, loaded as a `toml_edit::Document` so that edits retain the existing
+/// formatting and comments.
+pub(crate) struct ManifestEdit {
+    manifest_file: PackageManifestFile,
+    original: String,
+    pub(crate) doc: toml_edit::Document,
+}
+
+impl ManifestEdit {
+    /// Load the manifest of the package at, or containing, the given directory.
+    pub(crate) fn from_dir(dir: &Path) -> Result<Self> {
+        let manifest_file = match ManifestFile::from_dir(dir)? {
+            ManifestFile::Package(manifest_file) => *manifest_file,
+            ManifestFile::Workspace(_) => bail!(
+                "{} is a workspace manifest, expected the manifest of a package",
+                dir.display()
+            ),
+        };
+        let original = fs::read_to_string(manifest_file.path())?;
+        let doc = original
+            .parse::<toml_edit::Document>()
+            .map_err(|e| anyhow!("failed to parse manifest: {}", e))?;
+        Ok(Self {
+            manifest_file,
+            original,
+            doc,
+        })
+    }
+
+    /// The manifest as it was before any edits.
+    pub(crate) fn manifest(&self) -> &PackageManifestFile {
+        &self.manifest_file
+    }
+
+    /// Retrieve the table with the given name, inserting it at the end of the manifest if it does
+    /// not yet exist.
+    pub(crate) fn table_mut(&mut self, name: &str) -> Result<&mut dyn toml_edit::TableLike> {
+        self.doc
+            .entry(name)
+            .or_insert_with(toml_edit::table)
+            .as_table_like_mut()
+            .ok_or_else(|| anyhow!("expected `{}` to be a table", name))
+    }
+
+    /// Validate the edited manifest, write it and update the lock file to match.
+    ///
+    /// If the lock file cannot be updated for the edited manifest, e.g. because a new dependency
+    /// cannot be fetched, the original manifest is restored.
+    pub(crate) fn write(self, offline: bool) -> Result<()> {
+        let contents = self.doc.to_string();
+        contents
+            .parse::<PackageManifest>()
+            .map_err(|e| anyhow!("the edited manifest is invalid: {}", e))?;
+        let path = self.manifest_file.path();
+        fs::write(path, &contents)
+            .map_err(|e| anyhow!("failed to write {}: {}", path.display(), e))?;
+        if let Err(e) = update_lock(self.manifest_file.dir(), offline) {
+            fs::write(path, &self.original)
+                .map_err(|e| anyhow!("failed to restore {}: {}", path.display(), e))?;
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+fn update_lock(dir: &Path, offline: bool) -> Result<()> {
+    let manifest_file = ManifestFile::from_dir(dir)?;
+    let member_manifests = manifest_file.member_manifests()?;
+    let lock_path = manifest_file.lock_path()?;
+    let locked = false;
+    pkg::BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, locked, offline)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cli::{AddCommand, RemoveCommand},
+        ops::{forc_add, forc_remove},
+    };
+    use clap::Parser;
+    use std::{fs, path::Path};
+
+    const APP_MANIFEST: &str = r#"# The manifest of the app.
+[project]
+authors = ["Fuel Labs"]
+entry   = "main.sw"
+license = "Apache-2.0"
+name    = "app"
+implicit-std = false # no network in tests
+
+# Local libraries.
+[dependencies]
+foo = { path = "../foo" }    # the first library
+
+[build-profiles.debug]
+print-ir = false
+"#;
+
+    /// Create a library package with the given name within `dir`.
+    fn write_lib(dir: &Path, name: &str) {
+        let pkg_dir = dir.join(name);
+        fs::create_dir_all(pkg_dir.join("src")).unwrap();
+        fs::write(
+            pkg_dir.join("Forc.toml"),
+            format!(
+                "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"lib.sw\"\n\
+                 license = \"Apache-2.0\"\nname = \"{}\"\nimplicit-std = false\n",
+                name
+            ),
+        )
+        .unwrap();
+        fs::write(pkg_dir.join("src/lib.sw"), format!("library {};\n", name)).unwrap();
+    }
+
+    /// Create the `app` script package depending on the `foo` library, along with the `foo` and
+    /// `bar` libraries.
+    fn write_app(dir: &Path) {
+        write_lib(dir, "foo");
+        write_lib(dir, "bar");
+        let app_dir = dir.join("app");
+        fs::create_dir_all(app_dir.join("src")).unwrap();
+        fs::write(app_dir.join("Forc.toml"), APP_MANIFEST).unwrap();
+        fs::write(app_dir.join("src/main.sw"), "script;\n\nfn main() {}\n").unwrap();
+    }
+
+    fn add(app_dir: &Path, args: &[&str]) {
+        let manifest_path = app_dir.display().to_string();
+        let args = ["forc-add"].into_iter().chain(args.iter().copied()).chain([
+            "--manifest-path",
+            &manifest_path,
+            "--offline",
+        ]);
+        forc_add::add(AddCommand::parse_from(args)).unwrap();
+    }
+
+    fn remove(app_dir: &Path, args: &[&str]) {
+        let manifest_path = app_dir.display().to_string();
+        let args = ["forc-remove"]
+            .into_iter()
+            .chain(args.iter().copied())
+            .chain(["--manifest-path", &manifest_path, "--offline"]);
+        forc_remove::remove(RemoveCommand::parse_from(args)).unwrap();
+    }
+
+    fn manifest(app_dir: &Path) -> String {
+        fs::read_to_string(app_dir.join("Forc.toml")).unwrap()
+    }
+
+    #[test]
+    fn test_add_retains_formatting() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path());
+        let app_dir = dir.path().join("app");
+
+        add(&app_dir, &["bar", "--path", "../bar"]);
+        let expected = r#"# The manifest of the app.
+[project]
+authors = ["Fuel Labs"]
+entry   = "main.sw"
+license = "Apache-2.0"
+name    = "app"
+implicit-std = false # no network in tests
+
+# Local libraries.
+[dependencies]
+foo = { path = "../foo" }    # the first library
+bar = { path = "../bar" }
+
+[build-profiles.debug]
+print-ir = false
+"#;
+        assert_eq!(manifest(&app_dir), expected);
+        let lock = fs::read_to_string(app_dir.join("Forc.lock")).unwrap();
+        assert!(lock.contains("name = 'bar'"));
+
+        add(&app_dir, &["baz", "--path", "../bar", "--package", "bar"]);
+        add(&app_dir, &["bar", "--path", "../foo", "--package", "foo"]);
+        let expected = r#"# The manifest of the app.
+[project]
+authors = ["Fuel Labs"]
+entry   = "main.sw"
+license = "Apache-2.0"
+name    = "app"
+implicit-std = false # no network in tests
+
+# Local libraries.
+[dependencies]
+foo = { path = "../foo" }    # the first library
+bar = { path = "../foo", package = "foo" }
+baz = { path = "../bar", package = "bar" }
+
+[build-profiles.debug]
+print-ir = false
+"#;
+        assert_eq!(manifest(&app_dir), expected);
+    }
+
+    #[test]
+    fn test_add_contract_dependency_table() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path());
+        let app_dir = dir.path().join("app");
+        fs::write(
+            dir.path().join("bar/src/lib.sw"),
+            "contract;\n\nabi Bar {\n    fn bar();\n}\n\nimpl Bar for Contract {\n    fn bar() {}\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("bar/Forc.toml"),
+            "[project]\nauthors = [\"Fuel Labs\"]\nentry = \"lib.sw\"\n\
+             license = \"Apache-2.0\"\nname = \"bar\"\nimplicit-std = false\n",
+        )
+        .unwrap();
+
+        add(&app_dir, &["bar", "--path", "../bar", "--contract-dep"]);
+        let expected = format!(
+            "{}\n[contract-dependencies]\nbar = {{ path = \"../bar\" }}\n",
+            APP_MANIFEST
+        );
+        assert_eq!(manifest(&app_dir), expected);
+
+        // Removing the last contract dependency drops the table along with it.
+        remove(&app_dir, &["bar"]);
+        assert_eq!(manifest(&app_dir), APP_MANIFEST);
+    }
+
+    #[test]
+    fn test_remove_retains_formatting() {
+        let dir = tempfile::tempdir().unwrap();
+        write_app(dir.path());
+        let app_dir = dir.path().join("app");
+
+        add(&app_dir, &["bar", "--path", "../bar"]);
+        remove(&app_dir, &["foo"]);
+        let expected = r#"# The manifest of the app.
+[project]
+authors = ["Fuel Labs"]
+entry   = "main.sw"
+license = "Apache-2.0"
+name    = "app"
+implicit-std = false # no network in tests
+
+# Local libraries.
+[dependencies]
+bar = { path = "../bar" }
+
+[build-profiles.debug]
+print-ir = false
+"#;
+        assert_eq!(manifest(&app_dir), expected);
+        let lock = fs::read_to_string(app_dir.join("Forc.lock")).unwrap();
+        assert!(!lock.contains("name = 'foo'"));
+
+        // The `[dependencies]` table itself is retained once it is empty.
+        remove(&app_dir, &["bar"]);
+        let expected =
+            APP_MANIFEST.replace("foo = { path = \"../foo\" }    # the first library\n", "");
+        assert_eq!(manifest(&app_dir), expected);
+    }
+}
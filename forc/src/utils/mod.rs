@@ -1,2 +1,3 @@
 pub mod defaults;
+pub(crate) mod manifest_edit;
 pub mod program_type;
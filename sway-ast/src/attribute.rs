@@ -12,6 +12,10 @@ pub struct Annotated<T> {
 //    #[attribute()]
 //    #[attribute(value)]
 //    #[attribute(value0, value1, value2)]
+//
+// Each argument may also be assigned a literal:
+//
+//    #[attribute(name = "value")]
 
 #[derive(Clone, Debug)]
 pub struct AttributeDecl {
@@ -28,7 +32,7 @@ impl Spanned for AttributeDecl {
 #[derive(Clone, Debug)]
 pub struct Attribute {
     pub name: Ident,
-    pub args: Option<Parens<Punctuated<Ident, CommaToken>>>,
+    pub args: Option<Parens<Punctuated<AttributeArg, CommaToken>>>,
 }
 
 impl Spanned for Attribute {
@@ -39,3 +43,18 @@ impl Spanned for Attribute {
             .unwrap_or_else(|| self.name.span())
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct AttributeArg {
+    pub name: Ident,
+    pub value: Option<(EqToken, Literal)>,
+}
+
+impl Spanned for AttributeArg {
+    fn span(&self) -> Span {
+        self.value
+            .as_ref()
+            .map(|(_, value)| Span::join(self.name.span(), value.span()))
+            .unwrap_or_else(|| self.name.span())
+    }
+}
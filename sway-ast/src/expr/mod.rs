@@ -17,7 +17,7 @@ pub enum Expr {
     },
     Struct {
         path: PathExpr,
-        fields: Braces<Punctuated<ExprStructField, CommaToken>>,
+        fields: Braces<ExprStructFields>,
     },
     Tuple(Parens<ExprTupleDescriptor>),
     Parens(Parens<Box<Expr>>),
@@ -39,6 +39,13 @@ pub enum Expr {
         condition: Box<Expr>,
         block: Braces<CodeBlockContents>,
     },
+    For {
+        for_token: ForToken,
+        value_pattern: Pattern,
+        in_token: InToken,
+        iterable: ForIterable,
+        block: Braces<CodeBlockContents>,
+    },
     FuncApp {
         func: Box<Expr>,
         args: Parens<Punctuated<Expr, CommaToken>>,
@@ -65,6 +72,10 @@ pub enum Expr {
         field: BigUint,
         field_span: Span,
     },
+    Try {
+        expr: Box<Expr>,
+        question_mark_token: QuestionMarkToken,
+    },
     Ref {
         ref_token: RefToken,
         expr: Box<Expr>,
@@ -183,6 +194,10 @@ pub enum Expr {
     Continue {
         continue_token: ContinueToken,
     },
+    Closure {
+        params: ClosureParams,
+        body: Box<Expr>,
+    },
 }
 
 impl Spanned for Expr {
@@ -218,6 +233,9 @@ impl Spanned for Expr {
             Expr::While {
                 while_token, block, ..
             } => Span::join(while_token.span(), block.span()),
+            Expr::For {
+                for_token, block, ..
+            } => Span::join(for_token.span(), block.span()),
             Expr::FuncApp { func, args } => Span::join(func.span(), args.span()),
             Expr::Index { target, arg } => Span::join(target.span(), arg.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), args.span()),
@@ -225,6 +243,10 @@ impl Spanned for Expr {
             Expr::TupleFieldProjection {
                 target, field_span, ..
             } => Span::join(target.span(), field_span.clone()),
+            Expr::Try {
+                expr,
+                question_mark_token,
+            } => Span::join(expr.span(), question_mark_token.span()),
             Expr::Ref { ref_token, expr } => Span::join(ref_token.span(), expr.span()),
             Expr::Deref { deref_token, expr } => Span::join(deref_token.span(), expr.span()),
             Expr::Not { bang_token, expr } => Span::join(bang_token.span(), expr.span()),
@@ -252,6 +274,7 @@ impl Spanned for Expr {
             } => Span::join(assignable.span(), expr.span()),
             Expr::Break { break_token } => break_token.span(),
             Expr::Continue { continue_token } => continue_token.span(),
+            Expr::Closure { params, body } => Span::join(params.span(), body.span()),
         }
     }
 }
@@ -271,6 +294,11 @@ pub enum ReassignmentOpVariant {
     DivEquals,
     ShlEquals,
     ShrEquals,
+    ModEquals,
+    BitAndEquals,
+    BitOrEquals,
+    BitXorEquals,
+    PowEquals,
 }
 
 impl ReassignmentOpVariant {
@@ -283,6 +311,75 @@ impl ReassignmentOpVariant {
             ReassignmentOpVariant::DivEquals => "divide",
             ReassignmentOpVariant::ShlEquals => "lsh",
             ReassignmentOpVariant::ShrEquals => "rsh",
+            ReassignmentOpVariant::ModEquals => "modulo",
+            ReassignmentOpVariant::BitAndEquals => "binary_and",
+            ReassignmentOpVariant::BitOrEquals => "binary_or",
+            ReassignmentOpVariant::BitXorEquals => "binary_xor",
+            ReassignmentOpVariant::PowEquals => "pow",
+        }
+    }
+}
+
+/// The values that a `for` loop iterates over.
+#[derive(Clone, Debug)]
+pub enum ForIterable {
+    /// A half-open range of integers, `start..end`.
+    Range {
+        start: Box<Expr>,
+        double_dot_token: DoubleDotToken,
+        end: Box<Expr>,
+    },
+    /// An array, or a value that implements the standard library's `Iterator` trait.
+    Expr(Box<Expr>),
+}
+
+impl Spanned for ForIterable {
+    fn span(&self) -> Span {
+        match self {
+            ForIterable::Range { start, end, .. } => Span::join(start.span(), end.span()),
+            ForIterable::Expr(expr) => expr.span(),
+        }
+    }
+}
+
+/// The parameters of a closure, either `||` or a list of parameters between pipes, as in `|a, b|`.
+#[derive(Clone, Debug)]
+pub enum ClosureParams {
+    Empty {
+        double_pipe_token: DoublePipeToken,
+    },
+    List {
+        open_pipe_token: PipeToken,
+        params: Punctuated<ClosureParam, CommaToken>,
+        close_pipe_token: PipeToken,
+    },
+}
+
+impl Spanned for ClosureParams {
+    fn span(&self) -> Span {
+        match self {
+            ClosureParams::Empty { double_pipe_token } => double_pipe_token.span(),
+            ClosureParams::List {
+                open_pipe_token,
+                close_pipe_token,
+                ..
+            } => Span::join(open_pipe_token.span(), close_pipe_token.span()),
+        }
+    }
+}
+
+/// A closure parameter, with an optional type annotation.
+#[derive(Clone, Debug)]
+pub struct ClosureParam {
+    pub name: Ident,
+    pub ty_opt: Option<(ColonToken, Ty)>,
+}
+
+impl Spanned for ClosureParam {
+    fn span(&self) -> Span {
+        match &self.ty_opt {
+            Some((_colon_token, ty)) => Span::join(self.name.span(), ty.span()),
+            None => self.name.span(),
         }
     }
 }
@@ -354,6 +451,7 @@ pub enum ExprArrayDescriptor {
 #[derive(Clone, Debug)]
 pub struct MatchBranch {
     pub pattern: Pattern,
+    pub guard_opt: Option<(IfToken, Expr)>,
     pub fat_right_arrow_token: FatRightArrowToken,
     pub kind: MatchBranchKind,
 }
@@ -415,6 +513,14 @@ impl Spanned for ExprStructField {
     }
 }
 
+/// The contents of the braces of a struct expression: a list of field initializers optionally
+/// followed by the base of a struct update, as in `Foo { a: 1, ..base }`.
+#[derive(Clone, Debug)]
+pub struct ExprStructFields {
+    pub fields: Punctuated<ExprStructField, CommaToken>,
+    pub base_opt: Option<(DoubleDotToken, Box<Expr>)>,
+}
+
 impl Expr {
     pub fn try_into_assignable(self) -> Result<Assignable, Expr> {
         match self {
@@ -478,7 +584,8 @@ impl Expr {
                 | Expr::Asm(..)
                 | Expr::If(..)
                 | Expr::Match { .. }
-                | Expr::While { .. },
+                | Expr::While { .. }
+                | Expr::For { .. },
         )
     }
 }
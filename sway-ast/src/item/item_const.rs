@@ -6,8 +6,7 @@ pub struct ItemConst {
     pub const_token: ConstToken,
     pub name: Ident,
     pub ty_opt: Option<(ColonToken, Ty)>,
-    pub eq_token: EqToken,
-    pub expr: Expr,
+    pub expr_opt: Option<(EqToken, Expr)>,
     pub semicolon_token: SemicolonToken,
 }
 
@@ -7,7 +7,7 @@ pub struct ItemImpl {
     pub trait_opt: Option<(PathType, ForToken)>,
     pub ty: Ty,
     pub where_clause_opt: Option<WhereClause>,
-    pub contents: Braces<Vec<Annotated<ItemFn>>>,
+    pub contents: Braces<Vec<Annotated<ItemImplItem>>>,
 }
 
 impl Spanned for ItemImpl {
@@ -15,3 +15,21 @@ impl Spanned for ItemImpl {
         Span::join(self.impl_token.span(), self.contents.span())
     }
 }
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum ItemImplItem {
+    Fn(ItemFn),
+    Const(ItemConst),
+    Type(ItemTypeAlias),
+}
+
+impl Spanned for ItemImplItem {
+    fn span(&self) -> Span {
+        match self {
+            ItemImplItem::Fn(item_fn) => item_fn.span(),
+            ItemImplItem::Const(item_const) => item_const.span(),
+            ItemImplItem::Type(item_type_alias) => item_type_alias.span(),
+        }
+    }
+}
@@ -8,7 +8,7 @@ pub struct ItemTrait {
     pub generics: Option<GenericParams>,
     pub where_clause_opt: Option<WhereClause>,
     pub super_traits: Option<(ColonToken, Traits)>,
-    pub trait_items: Braces<Vec<(Annotated<FnSignature>, SemicolonToken)>>,
+    pub trait_items: Braces<Vec<Annotated<ItemTraitItem>>>,
     pub trait_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>>,
 }
 
@@ -26,6 +26,41 @@ impl Spanned for ItemTrait {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum ItemTraitItem {
+    Fn(FnSignature, SemicolonToken),
+    Const(ItemConst),
+    Type(TraitType),
+}
+
+impl Spanned for ItemTraitItem {
+    fn span(&self) -> Span {
+        match self {
+            ItemTraitItem::Fn(fn_signature, semicolon_token) => {
+                Span::join(fn_signature.span(), semicolon_token.span())
+            }
+            ItemTraitItem::Const(item_const) => item_const.span(),
+            ItemTraitItem::Type(trait_type) => trait_type.span(),
+        }
+    }
+}
+
+/// An associated type declared in a trait, e.g. `type Item;` or `type Item = u64;`.
+#[derive(Clone, Debug)]
+pub struct TraitType {
+    pub type_token: TypeToken,
+    pub name: Ident,
+    pub ty_opt: Option<(EqToken, Ty)>,
+    pub semicolon_token: SemicolonToken,
+}
+
+impl Spanned for TraitType {
+    fn span(&self) -> Span {
+        Span::join(self.type_token.span(), self.semicolon_token.span())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Traits {
     pub prefix: PathType,
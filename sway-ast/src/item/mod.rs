@@ -8,6 +8,7 @@ pub mod item_impl;
 pub mod item_storage;
 pub mod item_struct;
 pub mod item_trait;
+pub mod item_type_alias;
 pub mod item_use;
 
 pub type Item = Annotated<ItemKind>;
@@ -34,6 +35,7 @@ pub enum ItemKind {
     Abi(ItemAbi),
     Const(ItemConst),
     Storage(ItemStorage),
+    TypeAlias(ItemTypeAlias),
 }
 
 impl Spanned for ItemKind {
@@ -49,6 +51,7 @@ impl Spanned for ItemKind {
             ItemKind::Abi(item_abi) => item_abi.span(),
             ItemKind::Const(item_const) => item_const.span(),
             ItemKind::Storage(item_storage) => item_storage.span(),
+            ItemKind::TypeAlias(item_type_alias) => item_type_alias.span(),
         }
     }
 }
@@ -63,6 +63,7 @@ define_keyword!(ImplToken, "impl");
 define_keyword!(ForToken, "for");
 define_keyword!(AbiToken, "abi");
 define_keyword!(ConstToken, "const");
+define_keyword!(TypeToken, "type");
 define_keyword!(StorageToken, "storage");
 define_keyword!(StrToken, "str");
 define_keyword!(AsmToken, "asm");
@@ -73,6 +74,7 @@ define_keyword!(MatchToken, "match");
 define_keyword!(MutToken, "mut");
 define_keyword!(LetToken, "let");
 define_keyword!(WhileToken, "while");
+define_keyword!(InToken, "in");
 define_keyword!(WhereToken, "where");
 define_keyword!(RefToken, "ref");
 define_keyword!(DerefToken, "deref");
@@ -143,8 +145,8 @@ define_token!(
     [Colon, Colon],
     [Colon]
 );
-define_token!(StarToken, "an asterisk (*)", [Star], [Equals]);
-define_token!(DoubleStarToken, "`**`", [Star, Star], []);
+define_token!(StarToken, "an asterisk (*)", [Star], [Star, Equals]);
+define_token!(DoubleStarToken, "`**`", [Star, Star], [Equals]);
 define_token!(CommaToken, "a comma", [Comma], []);
 define_token!(ColonToken, "a colon", [Colon], [Colon]);
 define_token!(
@@ -169,16 +171,21 @@ define_token!(StarEqToken, "`*=`", [Star, Equals], []);
 define_token!(DivEqToken, "`/=`", [ForwardSlash, Equals], []);
 define_token!(ShlEqToken, "`<<=`", [LessThan, LessThan, Equals], []);
 define_token!(ShrEqToken, "`>>=`", [GreaterThan, GreaterThan, Equals], []);
+define_token!(PercentEqToken, "`%=`", [Percent, Equals], []);
+define_token!(AmpersandEqToken, "`&=`", [Ampersand, Equals], []);
+define_token!(PipeEqToken, "`|=`", [Pipe, Equals], []);
+define_token!(CaretEqToken, "`^=`", [Caret, Equals], []);
+define_token!(DoubleStarEqToken, "`**=`", [Star, Star, Equals], []);
 define_token!(
     FatRightArrowToken,
     "`=>`",
     [Equals, GreaterThan],
     [GreaterThan, Equals]
 );
-define_token!(DotToken, "`.`", [Dot], []);
+define_token!(DotToken, "`.`", [Dot], [Dot]);
 define_token!(DoubleDotToken, "`..`", [Dot, Dot], [Dot]);
 define_token!(BangToken, "`!`", [Bang], [Equals]);
-define_token!(PercentToken, "`%`", [Percent], []);
+define_token!(PercentToken, "`%`", [Percent], [Equals]);
 define_token!(AddToken, "`+`", [Add], [Equals]);
 define_token!(SubToken, "`-`", [Sub], [Equals]);
 define_token!(
@@ -188,9 +195,9 @@ define_token!(
     [GreaterThan, Equals]
 );
 define_token!(ShlToken, "`<<`", [LessThan, LessThan], [LessThan, Equals]);
-define_token!(AmpersandToken, "`&`", [Ampersand], [Ampersand]);
-define_token!(CaretToken, "`^`", [Caret], []);
-define_token!(PipeToken, "`|`", [Pipe], [Pipe]);
+define_token!(AmpersandToken, "`&`", [Ampersand], [Ampersand, Equals]);
+define_token!(CaretToken, "`^`", [Caret], [Equals]);
+define_token!(PipeToken, "`|`", [Pipe], [Pipe, Equals]);
 define_token!(
     DoubleEqToken,
     "`==`",
@@ -219,3 +226,4 @@ define_token!(
 define_token!(DoublePipeToken, "`||`", [Pipe, Pipe], [Pipe]);
 define_token!(UnderscoreToken, "`_`", [Underscore], [Underscore]);
 define_token!(HashToken, "`#`", [Sharp], []);
+define_token!(QuestionMarkToken, "`?`", [QuestionMark], []);
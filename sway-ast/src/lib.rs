@@ -26,8 +26,9 @@ pub use crate::{
     expr::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
-        AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        AbiCastArgs, ClosureParam, ClosureParams, CodeBlockContents, Expr, ExprArrayDescriptor,
+        ExprStructField, ExprStructFields, ExprTupleDescriptor, ForIterable, IfCondition, IfExpr,
+        MatchBranch, MatchBranchKind,
     },
     generics::{GenericArgs, GenericParams},
     intrinsics::*,
@@ -36,10 +37,11 @@ pub use crate::{
         item_const::ItemConst,
         item_enum::ItemEnum,
         item_fn::ItemFn,
-        item_impl::ItemImpl,
+        item_impl::{ItemImpl, ItemImplItem},
         item_storage::{ItemStorage, StorageField},
         item_struct::ItemStruct,
-        item_trait::{ItemTrait, Traits},
+        item_trait::{ItemTrait, ItemTraitItem, TraitType, Traits},
+        item_type_alias::ItemTypeAlias,
         item_use::{ItemUse, UseTree},
         FnArg, FnArgs, FnSignature, Item, ItemKind, TypeField,
     },
@@ -1,5 +1,6 @@
 use crate::priv_prelude::*;
 
+#[derive(Clone, Debug)]
 pub struct Module {
     pub kind: ModuleKind,
     pub semicolon_token: SemicolonToken,
@@ -30,6 +31,7 @@ impl Spanned for Module {
     }
 }
 
+#[derive(Clone, Debug)]
 pub enum ModuleKind {
     Script {
         script_token: ScriptToken,
@@ -21,6 +21,11 @@ pub enum Pattern {
         fields: Braces<Punctuated<PatternStructField, CommaToken>>,
     },
     Tuple(Parens<Punctuated<Pattern, CommaToken>>),
+    Or {
+        lhs: Box<Pattern>,
+        pipe_token: PipeToken,
+        rhs: Box<Pattern>,
+    },
 }
 
 impl Spanned for Pattern {
@@ -44,6 +49,7 @@ impl Spanned for Pattern {
             Pattern::Constructor { path, args } => Span::join(path.span(), args.span()),
             Pattern::Struct { path, fields } => Span::join(path.span(), fields.span()),
             Pattern::Tuple(pat_tuple) => pat_tuple.span(),
+            Pattern::Or { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
         }
     }
 }
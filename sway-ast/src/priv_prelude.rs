@@ -16,10 +16,11 @@ pub use {
             item_const::ItemConst,
             item_enum::ItemEnum,
             item_fn::ItemFn,
-            item_impl::ItemImpl,
+            item_impl::{ItemImpl, ItemImplItem},
             item_storage::ItemStorage,
             item_struct::ItemStruct,
-            item_trait::{ItemTrait, Traits},
+            item_trait::{ItemTrait, ItemTraitItem, TraitType, Traits},
+            item_type_alias::ItemTypeAlias,
             item_use::ItemUse,
             FnSignature, Item, ItemKind, TypeField,
         },
@@ -26,6 +26,7 @@ pub enum PunctKind {
     Pipe,
     Underscore,
     Sharp,
+    QuestionMark,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
@@ -62,6 +63,7 @@ impl PunctKind {
             PunctKind::Pipe => '|',
             PunctKind::Underscore => '_',
             PunctKind::Sharp => '#',
+            PunctKind::QuestionMark => '?',
         }
     }
 }
@@ -280,6 +282,7 @@ impl CharExt for char {
             '|' => Some(PunctKind::Pipe),
             '_' => Some(PunctKind::Underscore),
             '#' => Some(PunctKind::Sharp),
+            '?' => Some(PunctKind::QuestionMark),
             _ => None,
         }
     }
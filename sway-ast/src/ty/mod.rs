@@ -13,6 +13,15 @@ pub enum Ty {
     Infer {
         underscore_token: UnderscoreToken,
     },
+    Ref {
+        ampersand_token: AmpersandToken,
+        ty: Box<Ty>,
+    },
+    Fn {
+        fn_token: FnToken,
+        arguments: Parens<Punctuated<Ty, CommaToken>>,
+        return_type_opt: Option<(RightArrowToken, Box<Ty>)>,
+    },
 }
 
 impl Spanned for Ty {
@@ -23,6 +32,21 @@ impl Spanned for Ty {
             Ty::Array(array_type) => array_type.span(),
             Ty::Str { str_token, length } => Span::join(str_token.span(), length.span()),
             Ty::Infer { underscore_token } => underscore_token.span(),
+            Ty::Ref {
+                ampersand_token,
+                ty,
+            } => Span::join(ampersand_token.span(), ty.span()),
+            Ty::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            } => {
+                let end = match return_type_opt {
+                    Some((_right_arrow_token, ty)) => ty.span(),
+                    None => arguments.span(),
+                };
+                Span::join(fn_token.span(), end)
+            }
         }
     }
 }
@@ -557,17 +557,30 @@ impl<'ir> AsmBuilder<'ir> {
                 )],
             );
         }
-        self.compile_branch_to_phi_value(true_block);
-        self.compile_branch_to_phi_value(false_block);
-
         let cond_reg = self.value_to_register(cond_value);
 
+        // The arguments of each destination may only be moved into place on the edge that leads
+        // to it, since the other destination may still need the values held by those registers.
+        // E.g., the exit edge of a nested loop sets the arguments of the outer loop, which the
+        // inner loop is still using. So if the true destination has arguments, branch to a
+        // separate sequence which moves them before jumping to the destination.
         let true_label = self.block_to_label(&true_block.block);
-        self.cur_bytecode
-            .push(Op::jump_if_not_zero(cond_reg, true_label));
+        let true_args_label = (!true_block.args.is_empty()).then(|| self.reg_seqr.get_label());
+        self.cur_bytecode.push(Op::jump_if_not_zero(
+            cond_reg,
+            true_args_label.unwrap_or(true_label),
+        ));
 
+        self.compile_branch_to_phi_value(false_block);
         let false_label = self.block_to_label(&false_block.block);
         self.cur_bytecode.push(Op::jump_to_label(false_label));
+
+        if let Some(true_args_label) = true_args_label {
+            self.cur_bytecode
+                .push(Op::unowned_jump_label(true_args_label));
+            self.compile_branch_to_phi_value(true_block);
+            self.cur_bytecode.push(Op::jump_to_label(true_label));
+        }
         ok((), vec![], vec![])
     }
 
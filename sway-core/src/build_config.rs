@@ -1,4 +1,9 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    sync::Arc,
+};
+use sway_error::warning::LintLevel;
 
 /// Configuration for the overall build and compilation process.
 #[derive(Clone)]
@@ -11,6 +16,9 @@ pub struct BuildConfig {
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
     pub(crate) include_tests: bool,
+    pub(crate) features: BTreeSet<String>,
+    pub(crate) lints: BTreeMap<String, LintLevel>,
+    pub(crate) deny_warnings: bool,
 }
 
 impl BuildConfig {
@@ -34,7 +42,7 @@ impl BuildConfig {
             true => root_module,
             false => {
                 assert!(
-                    root_module.starts_with(canonical_manifest_dir.file_stem().unwrap()),
+                    root_module.starts_with(canonical_manifest_dir.file_name().unwrap()),
                     "file_name must be either absolute or relative to manifest directory",
                 );
                 canonical_manifest_dir
@@ -50,6 +58,9 @@ impl BuildConfig {
             print_finalized_asm: false,
             print_ir: false,
             include_tests: false,
+            features: BTreeSet::new(),
+            lints: BTreeMap::new(),
+            deny_warnings: false,
         }
     }
 
@@ -85,6 +96,8 @@ impl BuildConfig {
     ///
     /// This should be set to `true` by invocations like `forc test` or `forc check --tests`.
     ///
+    /// Items annotated with `#[cfg(test)]` are only compiled when this is `true`.
+    ///
     /// Default: `false`
     pub fn include_tests(self, include_tests: bool) -> Self {
         Self {
@@ -93,6 +106,34 @@ impl BuildConfig {
         }
     }
 
+    /// The enabled features of the package.
+    ///
+    /// Items annotated with `#[cfg(feature = "name")]` are only compiled when `name` is enabled.
+    ///
+    /// Default: empty
+    pub fn features(self, features: BTreeSet<String>) -> Self {
+        Self { features, ..self }
+    }
+
+    /// The package-wide lint levels, keyed by lint name.
+    ///
+    /// These apply wherever no `allow`, `warn` or `deny` attribute sets the level of a lint.
+    ///
+    /// Default: empty
+    pub fn lints(self, lints: BTreeMap<String, LintLevel>) -> Self {
+        Self { lints, ..self }
+    }
+
+    /// Whether or not warnings that are not explicitly allowed should be reported as errors.
+    ///
+    /// Default: `false`
+    pub fn deny_warnings(self, deny_warnings: bool) -> Self {
+        Self {
+            deny_warnings,
+            ..self
+        }
+    }
+
     pub fn canonical_root_module(&self) -> Arc<PathBuf> {
         self.canonical_root_module.clone()
     }
@@ -187,6 +187,7 @@ fn connect_declaration<'eng: 'cfg, 'cfg>(
         | StructDeclaration(_)
         | EnumDeclaration(_)
         | StorageDeclaration(_)
+        | TypeAliasDeclaration(_)
         | GenericTypeForFunctionScope { .. } => Ok(leaves.to_vec()),
         VariableDeclaration(_) | ConstantDeclaration(_) => {
             let entry_node = graph.add_node(engines, node.into());
@@ -9,7 +9,7 @@ use petgraph::{prelude::NodeIndex, visit::Dfs};
 use std::collections::BTreeSet;
 use sway_error::warning::{CompileWarning, Warning};
 use sway_error::{error::CompileError, type_error::TypeError};
-use sway_types::{span::Span, Ident, Spanned};
+use sway_types::{constants::CLOSURE_FN_NAME, span::Span, Ident, Spanned};
 
 impl<'cfg> ControlFlowGraph<'cfg> {
     pub(crate) fn find_dead_code(
@@ -363,17 +363,20 @@ fn connect_declaration<'eng: 'cfg, 'cfg>(
             let ty::TyConstantDeclaration { name, value, .. } =
                 declaration_engine.get_constant(decl_id.clone(), &span)?;
             graph.namespace.insert_constant(name, entry_node);
-            connect_expression(
-                engines,
-                &value.expression,
-                graph,
-                &[entry_node],
-                exit_node,
-                "constant declaration expression",
-                tree_type,
-                value.span.clone(),
-                options,
-            )
+            match value {
+                Some(value) => connect_expression(
+                    engines,
+                    &value.expression,
+                    graph,
+                    &[entry_node],
+                    exit_node,
+                    "constant declaration expression",
+                    tree_type,
+                    value.span.clone(),
+                    options,
+                ),
+                None => Ok(leaves.to_vec()),
+            }
         }
         FunctionDeclaration(decl_id) => {
             let fn_decl = declaration_engine.get_function(decl_id.clone(), &decl.span())?;
@@ -392,6 +395,7 @@ fn connect_declaration<'eng: 'cfg, 'cfg>(
             connect_abi_declaration(engines, &abi_decl, graph, entry_node)?;
             Ok(leaves.to_vec())
         }
+        TypeAliasDeclaration(_) => Ok(leaves.to_vec()),
         StructDeclaration(decl_id) => {
             let struct_decl = declaration_engine.get_struct(decl_id.clone(), &span)?;
             connect_struct_declaration(engines, &struct_decl, graph, entry_node, tree_type);
@@ -651,7 +655,7 @@ fn get_struct_type_info_from_type_id(
             Ok(None)
         }
         TypeInfo::Struct { .. } => Ok(Some(type_info)),
-        TypeInfo::Array(type_arg, _) => {
+        TypeInfo::Array(type_arg, _) | TypeInfo::Ref(type_arg) => {
             get_struct_type_info_from_type_id(type_engine, type_arg.type_id)
         }
         _ => Ok(None),
@@ -1000,6 +1004,7 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
                 })
                 .unwrap_or_else(|| leaves.to_vec()))
         }
+        AssociatedConstant { .. } => Ok(leaves.to_vec()),
         EnumInstantiation {
             enum_decl,
             variant_name,
@@ -1071,6 +1076,7 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
         StructExpression {
             struct_name,
             fields,
+            base,
             ..
         } => {
             let decl = match graph.namespace.find_struct_decl(struct_name.as_str()) {
@@ -1113,6 +1119,20 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
                     options,
                 )?;
             }
+            // the remaining fields are copied from the base, which is evaluated last
+            if let Some(base) = base {
+                current_leaf = connect_expression(
+                    engines,
+                    &base.expression,
+                    graph,
+                    &current_leaf,
+                    exit_node,
+                    "struct update base",
+                    tree_type,
+                    base.span.clone(),
+                    options,
+                )?;
+            }
 
             // connect the final field to the exit
             for leaf in current_leaf {
@@ -1234,6 +1254,76 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             }
             Ok(vec![exit])
         }
+        Closure { decl_id, captures } => {
+            let fn_decl = declaration_engine.get_function(decl_id.clone(), &expression_span)?;
+            let entry = graph.add_node(engines, "closure entry".into());
+            let exit = graph.add_node(engines, "closure exit".into());
+            for leaf in leaves {
+                graph.add_edge(*leaf, entry, label.into());
+            }
+            if fn_decl.name.as_str() == CLOSURE_FN_NAME {
+                // the body of a closure is only reachable through the closure
+                let closure_exit = graph.add_node(engines, "closure body exit".into());
+                depth_first_insertion_code_block(
+                    engines,
+                    &fn_decl.body,
+                    graph,
+                    &[entry],
+                    Some(closure_exit),
+                    tree_type,
+                    options,
+                )?;
+            } else if let Some(function) = graph.namespace.get_function(&fn_decl.name).cloned() {
+                // a function used as a value
+                graph.add_edge(entry, function.entry_point, "".into());
+            }
+
+            let mut current_leaf = vec![entry];
+            for capture in captures {
+                current_leaf = connect_expression(
+                    engines,
+                    &capture.expression,
+                    graph,
+                    &current_leaf,
+                    exit_node,
+                    "closure capture",
+                    tree_type,
+                    capture.clone().span,
+                    options,
+                )?;
+            }
+            for leaf in current_leaf {
+                graph.add_edge(leaf, exit, "".into());
+            }
+            Ok(vec![exit])
+        }
+        ClosureCall { closure, arguments } => {
+            let mut current_leaf = connect_expression(
+                engines,
+                &closure.expression,
+                graph,
+                leaves,
+                exit_node,
+                "closure call",
+                tree_type,
+                closure.span.clone(),
+                options,
+            )?;
+            for (_name, arg) in arguments {
+                current_leaf = connect_expression(
+                    engines,
+                    &arg.expression,
+                    graph,
+                    &current_leaf,
+                    exit_node,
+                    "arg eval",
+                    tree_type,
+                    arg.clone().span,
+                    options,
+                )?;
+            }
+            Ok(current_leaf)
+        }
         AbiCast { address, .. } => connect_expression(
             engines,
             &address.expression,
@@ -1359,6 +1449,28 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             exp.span.clone(),
             options,
         ),
+        Ref(exp) => connect_expression(
+            engines,
+            &exp.expression,
+            graph,
+            leaves,
+            exit_node,
+            "ref exp",
+            tree_type,
+            exp.span.clone(),
+            options,
+        ),
+        Deref(exp) => connect_expression(
+            engines,
+            &exp.expression,
+            graph,
+            leaves,
+            exit_node,
+            "deref exp",
+            tree_type,
+            exp.span.clone(),
+            options,
+        ),
         WhileLoop {
             body, condition, ..
         } => {
@@ -1664,6 +1776,12 @@ fn construct_dead_code_warning_from_node(
             content: ty::TyAstNodeContent::Declaration(ty::TyDeclaration::StorageDeclaration { .. }),
             ..
         } => return None,
+        // Type aliases are resolved to the types they name during type checking, so uses of the
+        // alias are not visible in the graph.
+        ty::TyAstNode {
+            content: ty::TyAstNodeContent::Declaration(ty::TyDeclaration::TypeAliasDeclaration(_)),
+            ..
+        } => return None,
         ty::TyAstNode {
             content: ty::TyAstNodeContent::Declaration(..),
             span,
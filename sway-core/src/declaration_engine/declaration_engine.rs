@@ -218,4 +218,20 @@ impl DeclarationEngine {
     ) -> Result<ty::TyEnumDeclaration, CompileError> {
         self.slab.get(*index).expect_enum(span)
     }
+
+    pub(crate) fn insert_type_alias(
+        &self,
+        type_alias: ty::TyTypeAliasDeclaration,
+    ) -> DeclarationId {
+        let span = type_alias.name.span();
+        self.insert(DeclarationWrapper::TypeAlias(type_alias), span)
+    }
+
+    pub fn get_type_alias(
+        &self,
+        index: DeclarationId,
+        span: &Span,
+    ) -> Result<ty::TyTypeAliasDeclaration, CompileError> {
+        self.slab.get(*index).expect_type_alias(span)
+    }
 }
@@ -27,6 +27,7 @@ pub enum DeclarationWrapper {
     Abi(ty::TyAbiDeclaration),
     Constant(Box<ty::TyConstantDeclaration>),
     Enum(ty::TyEnumDeclaration),
+    TypeAlias(ty::TyTypeAliasDeclaration),
 }
 
 impl Default for DeclarationWrapper {
@@ -53,6 +54,9 @@ impl PartialEqWithEngines for DeclarationWrapper {
             (DeclarationWrapper::Abi(l), DeclarationWrapper::Abi(r)) => l.eq(r, engines),
             (DeclarationWrapper::Constant(l), DeclarationWrapper::Constant(r)) => l.eq(r, engines),
             (DeclarationWrapper::Enum(l), DeclarationWrapper::Enum(r)) => l.eq(r, engines),
+            (DeclarationWrapper::TypeAlias(l), DeclarationWrapper::TypeAlias(r)) => {
+                l.eq(r, engines)
+            }
             _ => false,
         }
     }
@@ -75,8 +79,9 @@ impl CopyTypes for DeclarationWrapper {
             DeclarationWrapper::Struct(decl) => decl.copy_types(type_mapping, engines),
             DeclarationWrapper::Storage(_) => {}
             DeclarationWrapper::Abi(_) => {}
-            DeclarationWrapper::Constant(_) => {}
+            DeclarationWrapper::Constant(decl) => decl.copy_types(type_mapping, engines),
             DeclarationWrapper::Enum(decl) => decl.copy_types(type_mapping, engines),
+            DeclarationWrapper::TypeAlias(decl) => decl.copy_types(type_mapping, engines),
         }
     }
 }
@@ -92,16 +97,19 @@ impl ReplaceSelfType for DeclarationWrapper {
             DeclarationWrapper::Struct(decl) => decl.replace_self_type(engines, self_type),
             DeclarationWrapper::Storage(_) => {}
             DeclarationWrapper::Abi(_) => {}
-            DeclarationWrapper::Constant(_) => {}
+            DeclarationWrapper::Constant(decl) => decl.replace_self_type(engines, self_type),
             DeclarationWrapper::Enum(decl) => decl.replace_self_type(engines, self_type),
+            DeclarationWrapper::TypeAlias(decl) => decl.replace_self_type(engines, self_type),
         }
     }
 }
 
 impl ReplaceDecls for DeclarationWrapper {
     fn replace_decls_inner(&mut self, decl_mapping: &DeclMapping, engines: Engines<'_>) {
-        if let DeclarationWrapper::Function(decl) = self {
-            decl.replace_decls(decl_mapping, engines);
+        match self {
+            DeclarationWrapper::Function(decl) => decl.replace_decls(decl_mapping, engines),
+            DeclarationWrapper::Constant(decl) => decl.replace_decls(decl_mapping, engines),
+            _ => {}
         }
     }
 }
@@ -122,7 +130,8 @@ impl ReplaceFunctionImplementingType for DeclarationWrapper {
             | DeclarationWrapper::Storage(_)
             | DeclarationWrapper::Abi(_)
             | DeclarationWrapper::Constant(_)
-            | DeclarationWrapper::Enum(_) => {}
+            | DeclarationWrapper::Enum(_)
+            | DeclarationWrapper::TypeAlias(_) => {}
         }
     }
 }
@@ -141,6 +150,7 @@ impl DeclarationWrapper {
             DeclarationWrapper::Abi(_) => "abi",
             DeclarationWrapper::Constant(_) => "constant",
             DeclarationWrapper::Enum(_) => "enum",
+            DeclarationWrapper::TypeAlias(_) => "type alias",
         }
     }
 
@@ -281,4 +291,21 @@ impl DeclarationWrapper {
             }),
         }
     }
+
+    pub(super) fn expect_type_alias(
+        self,
+        span: &Span,
+    ) -> Result<ty::TyTypeAliasDeclaration, CompileError> {
+        match self {
+            DeclarationWrapper::TypeAlias(decl) => Ok(decl),
+            DeclarationWrapper::Unknown => Err(CompileError::Internal(
+                "did not expect to find unknown declaration",
+                span.clone(),
+            )),
+            _ => Err(CompileError::Internal(
+                "expected to find type alias definition",
+                span.clone(),
+            )),
+        }
+    }
 }
@@ -285,6 +285,7 @@ fn compile_declarations(
             | ty::TyDeclaration::AbiDeclaration(_)
             | ty::TyDeclaration::GenericTypeForFunctionScope { .. }
             | ty::TyDeclaration::StorageDeclaration(_)
+            | ty::TyDeclaration::TypeAliasDeclaration(_)
             | ty::TyDeclaration::ErrorRecovery(_) => (),
         }
     }
@@ -92,7 +92,7 @@ pub(crate) fn compile_const_decl(
                     let ty::TyConstantDeclaration { name, value, .. } = env
                         .declaration_engine
                         .get_constant(decl_id.clone(), &name.span())?;
-                    Some((name, value))
+                    value.map(|value| (name, value))
                 }
                 _otherwise => None,
             };
@@ -241,7 +241,55 @@ fn const_eval_typed_expr(
                     .and_then(|v| v.get_constant(lookup.context).cloned())
             }
         },
-        ty::TyExpressionVariant::StructExpression { fields, .. } => {
+        ty::TyExpressionVariant::AssociatedConstant { const_decl_id, .. } => {
+            let const_decl = lookup
+                .declaration_engine
+                .get_constant(const_decl_id.clone(), &expr.span)?;
+            match const_decl.value {
+                Some(value) => const_eval_typed_expr(lookup, known_consts, &value)?,
+                None => None,
+            }
+        }
+        ty::TyExpressionVariant::StructExpression {
+            fields,
+            base: Some(base),
+            ..
+        } => match const_eval_typed_expr(lookup, known_consts, base)? {
+            Some(Constant {
+                ty,
+                value: ConstantValue::Struct(mut field_vals),
+            }) => {
+                // Start from the base and replace the fields which are listed explicitly.
+                for field in fields {
+                    let field_kind = ty::ProjectionKind::StructField {
+                        name: field.name.clone(),
+                    };
+                    let field_idx = get_struct_name_field_index_and_type(
+                        lookup.type_engine,
+                        expr.return_type,
+                        field_kind,
+                    )
+                    .and_then(|(_struct_name, field_idx_and_type_opt)| {
+                        field_idx_and_type_opt.map(|(field_idx, _field_type)| field_idx as usize)
+                    });
+                    let eval_expr_opt = const_eval_typed_expr(lookup, known_consts, &field.value)?;
+                    match (field_idx, eval_expr_opt) {
+                        (Some(field_idx), Some(cv)) if field_idx < field_vals.len() => {
+                            field_vals[field_idx] = cv;
+                        }
+                        _ => return Ok(None),
+                    }
+                }
+                Some(Constant {
+                    ty,
+                    value: ConstantValue::Struct(field_vals),
+                })
+            }
+            _ => None,
+        },
+        ty::TyExpressionVariant::StructExpression {
+            fields, base: None, ..
+        } => {
             let (mut field_typs, mut field_vals): (Vec<_>, Vec<_>) = (vec![], vec![]);
             for field in fields {
                 let ty::TyStructExpressionField { name: _, value, .. } = field;
@@ -389,6 +437,10 @@ fn const_eval_typed_expr(
         | ty::TyExpressionVariant::AbiName(_)
         | ty::TyExpressionVariant::EnumTag { .. }
         | ty::TyExpressionVariant::UnsafeDowncast { .. }
+        | ty::TyExpressionVariant::Ref(_)
+        | ty::TyExpressionVariant::Deref(_)
+        | ty::TyExpressionVariant::Closure { .. }
+        | ty::TyExpressionVariant::ClosureCall { .. }
         | ty::TyExpressionVariant::Break
         | ty::TyExpressionVariant::Continue
         | ty::TyExpressionVariant::WhileLoop { .. } => None,
@@ -129,6 +129,17 @@ fn convert_resolved_type(
         }
         TypeInfo::RawUntypedPtr => Type::Uint(64),
         TypeInfo::RawUntypedSlice => Type::Slice,
+        // References are the address of the referenced value.
+        TypeInfo::Ref(_) => Type::Uint(64),
+        // Closures are the values of their captured variables.
+        TypeInfo::Closure { captures, .. } => {
+            if captures.is_empty() {
+                Type::Unit
+            } else {
+                let new_fields = captures.iter().map(|x| x.type_id).collect();
+                create_tuple_aggregate(type_engine, context, new_fields).map(Type::Struct)?
+            }
+        }
 
         // Unsupported types which shouldn't exist in the AST after type checking and
         // monomorphisation.
@@ -138,7 +149,9 @@ fn convert_resolved_type(
         TypeInfo::ContractCaller { .. } => reject_type!("ContractCaller"),
         TypeInfo::Unknown => reject_type!("Unknown"),
         TypeInfo::UnknownGeneric { .. } => reject_type!("Generic"),
+        TypeInfo::TraitType { .. } => reject_type!("Associated"),
         TypeInfo::ErrorRecovery => reject_type!("Error recovery"),
         TypeInfo::Storage { .. } => reject_type!("Storage"),
+        TypeInfo::Function { .. } => reject_type!("Function"),
     })
 }
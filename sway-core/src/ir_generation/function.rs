@@ -18,7 +18,7 @@ use crate::{
         *,
     },
     metadata::MetadataManager,
-    type_system::{LogId, MessageId, TypeId, TypeInfo},
+    type_system::{CopyTypes, LogId, MessageId, TypeId, TypeInfo, TypeMapping},
     types::DeterministicallyAborts,
     TypeEngine,
 };
@@ -205,6 +205,8 @@ impl<'eng> FnCompiler<'eng> {
                         span: ast_node.span.clone(),
                     })
                 }
+                // Aliases have already been resolved to the types they name.
+                ty::TyDeclaration::TypeAliasDeclaration(_) => Ok(None),
             },
             ty::TyAstNodeContent::Expression(te) => {
                 // An expression with an ignored return value... I assume.
@@ -274,15 +276,33 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::VariableExpression { name, .. } => {
                 self.compile_var_expr(context, name.as_str(), span_md_idx)
             }
+            ty::TyExpressionVariant::AssociatedConstant { const_decl_id, .. } => {
+                let const_decl = self
+                    .declaration_engine
+                    .get_constant(const_decl_id.clone(), &ast_expr.span)?;
+                let value = const_decl.value.ok_or(CompileError::Internal(
+                    "associated constant has no value",
+                    const_decl.span,
+                ))?;
+                self.compile_expression(context, md_mgr, &value)
+            }
             ty::TyExpressionVariant::Array { contents } => {
                 self.compile_array_expr(context, md_mgr, contents, span_md_idx)
             }
             ty::TyExpressionVariant::ArrayIndex { prefix, index } => {
                 self.compile_array_index(context, md_mgr, prefix, index, span_md_idx)
             }
-            ty::TyExpressionVariant::StructExpression { fields, .. } => {
-                self.compile_struct_expr(context, md_mgr, fields, span_md_idx)
-            }
+            ty::TyExpressionVariant::StructExpression { fields, base, .. } => match base {
+                Some(base) => self.compile_struct_update_expr(
+                    context,
+                    md_mgr,
+                    fields,
+                    base,
+                    ast_expr.return_type,
+                    span_md_idx,
+                ),
+                None => self.compile_struct_expr(context, md_mgr, fields, span_md_idx),
+            },
             ty::TyExpressionVariant::CodeBlock(cb) => self.compile_code_block(context, md_mgr, cb),
             ty::TyExpressionVariant::FunctionParameter => Err(CompileError::Internal(
                 "Unexpected function parameter declaration.",
@@ -335,6 +355,12 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::Tuple { fields } => {
                 self.compile_tuple_expr(context, md_mgr, fields, span_md_idx)
             }
+            ty::TyExpressionVariant::Closure { captures, .. } => {
+                self.compile_tuple_expr(context, md_mgr, captures, span_md_idx)
+            }
+            ty::TyExpressionVariant::ClosureCall { closure, arguments } => {
+                self.compile_closure_call(context, md_mgr, closure, arguments, span_md_idx)
+            }
             ty::TyExpressionVariant::TupleElemAccess {
                 prefix,
                 elem_to_access_num: idx,
@@ -374,6 +400,12 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::EnumTag { exp } => {
                 self.compile_enum_tag(context, md_mgr, exp.to_owned())
             }
+            ty::TyExpressionVariant::Ref(exp) => {
+                self.compile_ref(context, md_mgr, exp, span_md_idx)
+            }
+            ty::TyExpressionVariant::Deref(exp) => {
+                self.compile_deref(context, md_mgr, exp, ast_expr.return_type, span_md_idx)
+            }
             ty::TyExpressionVariant::WhileLoop { body, condition } => self.compile_while_loop(
                 context,
                 md_mgr,
@@ -1225,6 +1257,39 @@ impl<'eng> FnCompiler<'eng> {
         // Eventually we need to Do It Properly and inline into the AST only when necessary, and
         // compile the standard library to an actual module.
 
+        // Parameters of function types take on the types of the closures passed to them, which
+        // makes an instance of the callee for every closure.
+        let (fn_type_params, closure_types): (Vec<_>, Vec<_>) = callee
+            .parameters
+            .iter()
+            .zip(ast_args.iter())
+            .filter(|(param, (_, arg))| {
+                matches!(
+                    (
+                        self.type_engine.look_up_type_id(param.type_id),
+                        self.type_engine.look_up_type_id(arg.return_type),
+                    ),
+                    (TypeInfo::Function { .. }, TypeInfo::Closure { .. })
+                )
+            })
+            .map(|(param, (_, arg))| (param.type_id, arg.return_type))
+            .unzip();
+        let instantiated_callee;
+        let callee = if fn_type_params.is_empty() {
+            callee
+        } else {
+            let mut callee = callee.clone();
+            callee.copy_types(
+                &TypeMapping::from_type_parameters_and_type_arguments(
+                    fn_type_params,
+                    closure_types,
+                ),
+                Engines::new(self.type_engine, self.declaration_engine),
+            );
+            instantiated_callee = callee;
+            &instantiated_callee
+        };
+
         // Get the callee from the cache if we've already compiled it.  We can't insert it with
         // .entry() since `compile_function()` returns a Result we need to handle.  The key to our
         // cache, to uniquely identify a function instance, is the span and the type IDs of any
@@ -1319,6 +1384,54 @@ impl<'eng> FnCompiler<'eng> {
             .add_metadatum(context, state_idx_md_idx))
     }
 
+    fn compile_closure_call(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        closure: &ty::TyExpression,
+        arguments: &[(Ident, ty::TyExpression)],
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let (decl_id, captures) = match self.type_engine.look_up_type_id(closure.return_type) {
+            TypeInfo::Closure {
+                decl_id, captures, ..
+            } => (decl_id, captures),
+            _ => {
+                return Err(CompileError::Internal(
+                    "Called value is not a closure.",
+                    closure.span.clone(),
+                ))
+            }
+        };
+        let callee = self
+            .declaration_engine
+            .get_function(decl_id, &closure.span)?;
+
+        // The captured variables are the fields of the closure, and are passed before the
+        // arguments of the call.
+        let ast_args = callee
+            .parameters
+            .iter()
+            .zip(captures.iter())
+            .enumerate()
+            .map(|(idx, (param, capture))| {
+                let capture_expr = ty::TyExpression {
+                    expression: ty::TyExpressionVariant::TupleElemAccess {
+                        prefix: Box::new(closure.clone()),
+                        elem_to_access_num: idx,
+                        resolved_type_of_parent: closure.return_type,
+                        elem_to_access_span: closure.span.clone(),
+                    },
+                    return_type: capture.type_id,
+                    span: closure.span.clone(),
+                };
+                (param.name.clone(), capture_expr)
+            })
+            .chain(arguments.iter().cloned())
+            .collect::<Vec<_>>();
+        self.compile_fn_call(context, md_mgr, &ast_args, &callee, None, span_md_idx)
+    }
+
     fn compile_if(
         &mut self,
         context: &mut Context,
@@ -1428,6 +1541,59 @@ impl<'eng> FnCompiler<'eng> {
         ))
     }
 
+    fn compile_ref(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        exp: &ty::TyExpression,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // Copy types are rejected during type checking, but a generic type may only turn out to
+        // be one after monomorphization.
+        let ir_type =
+            convert_resolved_typeid(self.type_engine, context, &exp.return_type, &exp.span)?;
+        if ir_type.is_copy_type() {
+            return Err(CompileError::RefOfCopyType {
+                ty: Engines::new(self.type_engine, self.declaration_engine)
+                    .help_out(exp.return_type)
+                    .to_string(),
+                span: exp.span.clone(),
+            });
+        }
+        let value = self.compile_expression(context, md_mgr, exp)?;
+        Ok(self
+            .current_block
+            .ins(context)
+            .addr_of(value)
+            .add_metadatum(context, span_md_idx))
+    }
+
+    fn compile_deref(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        exp: &ty::TyExpression,
+        return_type: TypeId,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let ir_type = convert_resolved_typeid(self.type_engine, context, &return_type, &exp.span)?;
+        if ir_type.is_copy_type() {
+            return Err(CompileError::RefOfCopyType {
+                ty: Engines::new(self.type_engine, self.declaration_engine)
+                    .help_out(return_type)
+                    .to_string(),
+                span: exp.span.clone(),
+            });
+        }
+        // The reference is the address of the value, reinterpret it as a pointer to it.
+        let address = self.compile_expression(context, md_mgr, exp)?;
+        Ok(self
+            .current_block
+            .ins(context)
+            .int_to_ptr(address, ir_type)
+            .add_metadatum(context, span_md_idx))
+    }
+
     fn compile_enum_tag(
         &mut self,
         context: &mut Context,
@@ -1681,7 +1847,13 @@ impl<'eng> FnCompiler<'eng> {
     ) -> Result<(), CompileError> {
         // This is local to the function, so we add it to the locals, rather than the module
         // globals like other const decls.
-        let ty::TyConstantDeclaration { name, value, .. } = ast_const_decl;
+        let ty::TyConstantDeclaration {
+            name, value, span, ..
+        } = ast_const_decl;
+        let value = value.ok_or(CompileError::Internal(
+            "constant declaration has no value",
+            span,
+        ))?;
         let const_expr_val = compile_constant_expression(
             Engines::new(self.type_engine, self.declaration_engine),
             context,
@@ -2064,6 +2236,89 @@ impl<'eng> FnCompiler<'eng> {
         ))
     }
 
+    fn compile_struct_update_expr(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        fields: &[ty::TyStructExpressionField],
+        base: &ty::TyExpression,
+        struct_type_id: TypeId,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // The listed fields are evaluated first and then the base.  The base is copied into a
+        // temporary, which has the listed fields inserted into it, so the base itself is left
+        // untouched.
+        let mut inserted_values_indices = Vec::with_capacity(fields.len());
+        for struct_field in fields {
+            let insert_val = self.compile_expression(context, md_mgr, &struct_field.value)?;
+            if insert_val.is_diverging(context) {
+                return Ok(insert_val);
+            }
+            let field_kind = ty::ProjectionKind::StructField {
+                name: struct_field.name.clone(),
+            };
+            let field_idx =
+                get_struct_name_field_index_and_type(self.type_engine, struct_type_id, field_kind)
+                    .and_then(|(_struct_name, field_idx_and_type_opt)| {
+                        field_idx_and_type_opt.map(|(field_idx, _field_type)| field_idx)
+                    })
+                    .ok_or_else(|| {
+                        CompileError::Internal(
+                            "Unknown field in struct update expression.",
+                            struct_field.value.span.clone(),
+                        )
+                    })?;
+            inserted_values_indices.push((insert_val, field_idx));
+        }
+
+        let base_val = self.compile_expression(context, md_mgr, base)?;
+        if base_val.is_diverging(context) {
+            return Ok(base_val);
+        }
+
+        let aggregate = match convert_resolved_typeid(
+            self.type_engine,
+            context,
+            &struct_type_id,
+            &base.span,
+        )? {
+            Type::Struct(aggregate) => aggregate,
+            _ => {
+                return Err(CompileError::Internal(
+                    "Struct update expression does not have a struct type.",
+                    base.span.clone(),
+                ))
+            }
+        };
+        let temp_name = self.lexical_map.insert_anon();
+        let struct_ptr = self
+            .function
+            .new_local_ptr(context, temp_name, Type::Struct(aggregate), false, None)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+        let struct_ptr_ty = *struct_ptr.get_type(context);
+        let agg_value = self
+            .current_block
+            .ins(context)
+            .get_ptr(struct_ptr, struct_ptr_ty, 0)
+            .add_metadatum(context, span_md_idx);
+        if ir_type_size_in_bytes(context, &struct_ptr_ty) > 0 {
+            self.current_block
+                .ins(context)
+                .store(agg_value, base_val)
+                .add_metadatum(context, span_md_idx);
+        }
+
+        Ok(inserted_values_indices.into_iter().fold(
+            agg_value,
+            |agg_value, (insert_val, insert_idx)| {
+                self.current_block
+                    .ins(context)
+                    .insert_value(agg_value, aggregate, insert_val, vec![insert_idx])
+                    .add_metadatum(context, span_md_idx)
+            },
+        ))
+    }
+
     fn compile_struct_field_expr(
         &mut self,
         context: &mut Context,
@@ -2089,6 +2344,16 @@ impl<'eng> FnCompiler<'eng> {
         }) = struct_val.get_constant(context)
         {
             Ok(*agg)
+        } else if let Type::Struct(agg) = convert_resolved_typeid(
+            self.type_engine,
+            context,
+            &struct_type_id,
+            &ast_struct_expr_span,
+        )? {
+            // The value of a block, like that of an `if` or `match` expression, is passed as a
+            // pointer to the block following it, so go by the type of the expression instead, as
+            // tuple element accesses do.
+            Ok(agg)
         } else {
             Err(CompileError::InternalOwned(
                 "Unsupported struct value for field expression.".to_owned(),
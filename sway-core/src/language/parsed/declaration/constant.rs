@@ -11,7 +11,7 @@ pub struct ConstantDeclaration {
     pub attributes: transform::AttributesMap,
     pub type_ascription: TypeInfo,
     pub type_ascription_span: Option<Span>,
-    pub value: Expression,
+    pub value: Option<Expression>,
     pub visibility: Visibility,
     pub span: Span,
 }
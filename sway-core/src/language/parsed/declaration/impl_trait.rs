@@ -1,4 +1,4 @@
-use super::FunctionDeclaration;
+use super::{ConstantDeclaration, FunctionDeclaration, TraitTypeDeclaration};
 use crate::{
     language::CallPath,
     type_system::{TypeInfo, TypeParameter},
@@ -15,6 +15,8 @@ pub struct ImplTrait {
     pub type_implementing_for: TypeInfo,
     pub type_implementing_for_span: Span,
     pub functions: Vec<FunctionDeclaration>,
+    pub constants: Vec<ConstantDeclaration>,
+    pub types: Vec<TraitTypeDeclaration>,
     // the span of the whole impl trait and block
     pub(crate) block_span: Span,
 }
@@ -27,6 +29,7 @@ pub struct ImplSelf {
     pub type_implementing_for: TypeInfo,
     pub(crate) type_implementing_for_span: Span,
     pub functions: Vec<FunctionDeclaration>,
+    pub constants: Vec<ConstantDeclaration>,
     // the span of the whole impl trait and block
     pub(crate) block_span: Span,
 }
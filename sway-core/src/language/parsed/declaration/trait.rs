@@ -1,4 +1,4 @@
-use super::{FunctionDeclaration, FunctionParameter};
+use super::{ConstantDeclaration, FunctionDeclaration, FunctionParameter};
 
 use crate::{language::*, transform, type_system::*};
 use sway_types::{ident::Ident, span::Span, Spanned};
@@ -9,6 +9,8 @@ pub struct TraitDeclaration {
     pub(crate) type_parameters: Vec<TypeParameter>,
     pub attributes: transform::AttributesMap,
     pub interface_surface: Vec<TraitFn>,
+    pub constants: Vec<ConstantDeclaration>,
+    pub types: Vec<TraitTypeDeclaration>,
     pub methods: Vec<FunctionDeclaration>,
     pub(crate) supertraits: Vec<Supertrait>,
     pub visibility: Visibility,
@@ -35,3 +37,13 @@ pub struct TraitFn {
     pub return_type: TypeInfo,
     pub return_type_span: Span,
 }
+
+/// An associated type, either declared in a trait (`type Item;`, optionally
+/// with a default) or given a value in an impl block (`type Item = u64;`).
+#[derive(Debug, Clone)]
+pub struct TraitTypeDeclaration {
+    pub name: Ident,
+    pub attributes: transform::AttributesMap,
+    pub ty_opt: Option<TypeArgument>,
+    pub span: Span,
+}
@@ -0,0 +1,17 @@
+use crate::{
+    language::Visibility,
+    transform,
+    type_system::{TypeInfo, TypeParameter},
+};
+use sway_types::{ident::Ident, span::Span};
+
+#[derive(Debug, Clone)]
+pub struct TypeAliasDeclaration {
+    pub name: Ident,
+    pub attributes: transform::AttributesMap,
+    pub type_parameters: Vec<TypeParameter>,
+    pub ty: TypeInfo,
+    pub ty_span: Span,
+    pub visibility: Visibility,
+    pub(crate) span: Span,
+}
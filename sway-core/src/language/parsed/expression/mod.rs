@@ -1,6 +1,6 @@
 use crate::{
     language::{parsed::CodeBlock, *},
-    type_system::TypeBinding,
+    type_system::{TypeBinding, TypeInfo},
 };
 use sway_types::{ident::Ident, Span, Spanned};
 
@@ -45,6 +45,8 @@ pub struct TupleIndexExpression {
 pub struct StructExpression {
     pub call_path_binding: TypeBinding<CallPath>,
     pub fields: Vec<StructExpressionField>,
+    /// The struct that the fields which are not listed are taken from, as in `..base`.
+    pub base: Option<Box<Expression>>,
 }
 
 #[derive(Debug, Clone)]
@@ -134,6 +136,51 @@ pub struct WhileLoopExpression {
     pub body: CodeBlock,
 }
 
+#[derive(Debug, Clone)]
+pub struct ForLoopExpression {
+    /// Makes the names of the variables that the loop is desugared into unique, so that nested
+    /// loops don't share any state.
+    pub id: usize,
+    pub iterable: ForLoopIterable,
+    /// The body of the loop, which starts by binding the loop's pattern to the current element.
+    pub body: CodeBlock,
+}
+
+impl ForLoopExpression {
+    /// The name of the variable starting with `prefix` that the loop with the given `id` is
+    /// desugared into.
+    pub(crate) fn var_name(id: usize, prefix: &str, span: Span) -> Ident {
+        let name = format!("{}{}", prefix, id);
+        Ident::new_with_override(Box::leak(name.into_boxed_str()), span)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ForLoopIterable {
+    /// A half-open range of integers, `start..end`.
+    Range {
+        start: Box<Expression>,
+        end: Box<Expression>,
+    },
+    /// An array, or a value with a `next` method such as an implementation of `std::iterator::Iterator`.
+    Expression(Box<Expression>),
+}
+
+#[derive(Debug, Clone)]
+pub struct ClosureExpression {
+    pub parameters: Vec<ClosureParameter>,
+    pub body: Box<Expression>,
+}
+
+/// A parameter of a closure. Its type is [TypeInfo::Unknown] when it has no type annotation, in
+/// which case it has to be inferred from the type the closure is expected to have.
+#[derive(Debug, Clone)]
+pub struct ClosureParameter {
+    pub name: Ident,
+    pub type_info: TypeInfo,
+    pub type_span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub struct ReassignmentExpression {
     pub lhs: ReassignmentTarget,
@@ -201,10 +248,28 @@ pub enum ExpressionKind {
     /// A control flow element which loops continually until some boolean expression evaluates as
     /// `false`.
     WhileLoop(WhileLoopExpression),
+    /// A loop over the elements of a range, an array or an iterator.
+    ///
+    /// Which of those it is can only be known once the type of the iterable has been inferred,
+    /// so the loop is desugared into a `WhileLoop` during type checking.
+    ForLoop(ForLoopExpression),
     Break,
     Continue,
     Reassignment(ReassignmentExpression),
     Return(Box<Expression>),
+    /// Unwraps an `Option` or a `Result`, returning early from the enclosing function on `None`
+    /// or `Err`, i.e. `expr?`.
+    ///
+    /// Which of those it is can only be known once the type of the inner expression has been
+    /// inferred, so it is desugared into a `Match` during type checking.
+    Try(Box<Expression>),
+    /// Takes a reference to the value of the inner expression, i.e. `ref expr`.
+    Ref(Box<Expression>),
+    /// Reads the value behind a reference, i.e. `deref expr`.
+    Deref(Box<Expression>),
+    /// An anonymous function that captures the values of the variables it uses, i.e.
+    /// `|a, b| a + b`.
+    Closure(Box<ClosureExpression>),
 }
 
 /// Represents the left hand side of a reassignment, which could either be a regular variable
@@ -220,6 +285,8 @@ pub enum ReassignmentTarget {
 pub struct StructExpressionField {
     pub name: Ident,
     pub value: Expression,
+    /// Whether the field is initialized with the variable of the same name, as in `Foo { a }`.
+    pub(crate) is_shorthand: bool,
     pub(crate) span: Span,
 }
 
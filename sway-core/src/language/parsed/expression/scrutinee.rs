@@ -36,6 +36,11 @@ pub enum Scrutinee {
         elems: Vec<Scrutinee>,
         span: Span,
     },
+    /// An or-pattern, `a | b | ..`, which matches if any of its alternatives match.
+    Or {
+        elems: Vec<Scrutinee>,
+        span: Span,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -60,6 +65,7 @@ impl Spanned for Scrutinee {
             Scrutinee::StructScrutinee { span, .. } => span.clone(),
             Scrutinee::EnumScrutinee { span, .. } => span.clone(),
             Scrutinee::Tuple { span, .. } => span.clone(),
+            Scrutinee::Or { span, .. } => span.clone(),
         }
     }
 }
@@ -152,7 +158,7 @@ impl Scrutinee {
                 let value = value.gather_approximate_typeinfo_dependencies();
                 vec![name, value].concat()
             }
-            Scrutinee::Tuple { elems, .. } => elems
+            Scrutinee::Tuple { elems, .. } | Scrutinee::Or { elems, .. } => elems
                 .iter()
                 .flat_map(|scrutinee| scrutinee.gather_approximate_typeinfo_dependencies())
                 .collect::<Vec<TypeInfo>>(),
@@ -1,4 +1,5 @@
 use super::ParseModule;
+use crate::transform::LintScopes;
 use sway_types::Ident;
 
 /// A parsed, but not yet type-checked, Sway program.
@@ -8,6 +9,8 @@ use sway_types::Ident;
 pub struct ParseProgram {
     pub kind: TreeType,
     pub root: ParseModule,
+    /// The lint levels set by attributes within all modules of the program.
+    pub lint_scopes: LintScopes,
 }
 
 /// A Sway program can be either a contract, script, predicate, or a library.
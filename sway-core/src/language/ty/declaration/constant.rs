@@ -1,6 +1,7 @@
 use sway_types::{Ident, Span};
 
 use crate::{
+    declaration_engine::{DeclMapping, ReplaceDecls},
     engine_threading::*,
     language::{ty::*, Visibility},
     transform,
@@ -10,7 +11,7 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct TyConstantDeclaration {
     pub name: Ident,
-    pub value: TyExpression,
+    pub value: Option<TyExpression>,
     pub visibility: Visibility,
     pub return_type: TypeId,
     pub attributes: transform::AttributesMap,
@@ -31,3 +32,29 @@ impl PartialEqWithEngines for TyConstantDeclaration {
             && self.span == other.span
     }
 }
+
+impl CopyTypes for TyConstantDeclaration {
+    fn copy_types_inner(&mut self, type_mapping: &TypeMapping, engines: Engines<'_>) {
+        self.return_type.copy_types(type_mapping, engines);
+        if let Some(value) = &mut self.value {
+            value.copy_types(type_mapping, engines);
+        }
+    }
+}
+
+impl ReplaceSelfType for TyConstantDeclaration {
+    fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
+        self.return_type.replace_self_type(engines, self_type);
+        if let Some(value) = &mut self.value {
+            value.replace_self_type(engines, self_type);
+        }
+    }
+}
+
+impl ReplaceDecls for TyConstantDeclaration {
+    fn replace_decls_inner(&mut self, decl_mapping: &DeclMapping, engines: Engines<'_>) {
+        if let Some(value) = &mut self.value {
+            value.replace_decls(decl_mapping, engines);
+        }
+    }
+}
@@ -26,6 +26,7 @@ pub enum TyDeclaration {
     GenericTypeForFunctionScope { name: Ident, type_id: TypeId },
     ErrorRecovery(Span),
     StorageDeclaration(DeclarationId),
+    TypeAliasDeclaration(DeclarationId),
 }
 
 impl EqWithEngines for TyDeclaration {}
@@ -41,6 +42,7 @@ impl PartialEqWithEngines for TyDeclaration {
             (Self::ImplTrait(x), Self::ImplTrait(y)) => x.eq(y, engines),
             (Self::AbiDeclaration(x), Self::AbiDeclaration(y)) => x.eq(y, engines),
             (Self::StorageDeclaration(x), Self::StorageDeclaration(y)) => x.eq(y, engines),
+            (Self::TypeAliasDeclaration(x), Self::TypeAliasDeclaration(y)) => x.eq(y, engines),
             (
                 Self::GenericTypeForFunctionScope {
                     name: xn,
@@ -66,6 +68,7 @@ impl CopyTypes for TyDeclaration {
             TraitDeclaration(ref mut decl_id) => decl_id.copy_types(type_mapping, engines),
             StructDeclaration(ref mut decl_id) => decl_id.copy_types(type_mapping, engines),
             EnumDeclaration(ref mut decl_id) => decl_id.copy_types(type_mapping, engines),
+            TypeAliasDeclaration(ref mut decl_id) => decl_id.copy_types(type_mapping, engines),
             ImplTrait(decl_id) => decl_id.copy_types(type_mapping, engines),
             // generics in an ABI is unsupported by design
             AbiDeclaration(..)
@@ -86,6 +89,7 @@ impl ReplaceSelfType for TyDeclaration {
             TraitDeclaration(ref mut decl_id) => decl_id.replace_self_type(engines, self_type),
             StructDeclaration(ref mut decl_id) => decl_id.replace_self_type(engines, self_type),
             EnumDeclaration(ref mut decl_id) => decl_id.replace_self_type(engines, self_type),
+            TypeAliasDeclaration(ref mut decl_id) => decl_id.replace_self_type(engines, self_type),
             ImplTrait(decl_id) => decl_id.replace_self_type(engines, self_type),
             // generics in an ABI is unsupported by design
             AbiDeclaration(..)
@@ -107,6 +111,7 @@ impl Spanned for TyDeclaration {
             TraitDeclaration(decl_id) => decl_id.span(),
             StructDeclaration(decl_id) => decl_id.span(),
             EnumDeclaration(decl_id) => decl_id.span(),
+            TypeAliasDeclaration(decl_id) => decl_id.span(),
             AbiDeclaration(decl_id) => decl_id.span(),
             ImplTrait(decl_id) => decl_id.span(),
             StorageDeclaration(decl) => decl.span(),
@@ -175,6 +180,12 @@ impl DisplayWithEngines for TyDeclaration {
                         Err(_) => "unknown enum".into(),
                     }
                 }
+                TyDeclaration::TypeAliasDeclaration(decl_id) => {
+                    match declaration_engine.get_type_alias(decl_id.clone(), &decl_id.span()) {
+                        Ok(TyTypeAliasDeclaration { name, .. }) => name.as_str().into(),
+                        Err(_) => "unknown type alias".into(),
+                    }
+                }
                 _ => String::new(),
             }
         )
@@ -225,7 +236,9 @@ impl CollectTypesMetadata for TyDeclaration {
             }
             ConstantDeclaration(decl_id) => {
                 match declaration_engine.get_constant(decl_id.clone(), &decl_id.span()) {
-                    Ok(TyConstantDeclaration { value, .. }) => {
+                    Ok(TyConstantDeclaration {
+                        value: Some(value), ..
+                    }) => {
                         check!(
                             value.collect_types_metadata(ctx),
                             return err(warnings, errors),
@@ -233,6 +246,7 @@ impl CollectTypesMetadata for TyDeclaration {
                             errors
                         )
                     }
+                    Ok(TyConstantDeclaration { value: None, .. }) => vec![],
                     Err(e) => {
                         errors.push(e);
                         return err(warnings, errors);
@@ -244,6 +258,7 @@ impl CollectTypesMetadata for TyDeclaration {
             | TraitDeclaration(_)
             | StructDeclaration(_)
             | EnumDeclaration(_)
+            | TypeAliasDeclaration(_)
             | ImplTrait { .. }
             | AbiDeclaration(_)
             | GenericTypeForFunctionScope { .. } => vec![],
@@ -290,6 +305,12 @@ impl GetDeclIdent for TyDeclaration {
                     .unwrap()
                     .name,
             ),
+            TyDeclaration::TypeAliasDeclaration(decl) => Some(
+                declaration_engine
+                    .get_type_alias(decl.clone(), &decl.span())
+                    .unwrap()
+                    .name,
+            ),
             TyDeclaration::ImplTrait(decl) => Some(
                 declaration_engine
                     .get_impl_trait(decl.clone(), &decl.span())
@@ -477,6 +498,7 @@ impl TyDeclaration {
             TraitDeclaration(_) => "trait",
             StructDeclaration(_) => "struct",
             EnumDeclaration(_) => "enum",
+            TypeAliasDeclaration(_) => "type alias",
             ImplTrait { .. } => "impl trait",
             AbiDeclaration(..) => "abi",
             GenericTypeForFunctionScope { .. } => "generic type parameter",
@@ -497,6 +519,7 @@ impl TyDeclaration {
             ImplTrait(_) => "impl_trait",
             FunctionDeclaration(_) => "fn",
             ConstantDeclaration(_) => "constant",
+            TypeAliasDeclaration(_) => "type",
             _ => unreachable!("these items are non-documentable"),
         }
     }
@@ -543,6 +566,17 @@ impl TyDeclaration {
                 );
                 decl.create_type_id(engines)
             }
+            TyDeclaration::TypeAliasDeclaration(decl_id) => {
+                let decl = check!(
+                    CompileResult::from(
+                        declaration_engine.get_type_alias(decl_id.clone(), access_span)
+                    ),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                decl.create_type_id(engines)
+            }
             TyDeclaration::StorageDeclaration(decl_id) => {
                 let storage_decl = check!(
                     CompileResult::from(
@@ -624,6 +658,17 @@ impl TyDeclaration {
                 );
                 visibility
             }
+            TypeAliasDeclaration(decl_id) => {
+                let TyTypeAliasDeclaration { visibility, .. } = check!(
+                    CompileResult::from(
+                        declaration_engine.get_type_alias(decl_id.clone(), &decl_id.span())
+                    ),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                visibility
+            }
             FunctionDeclaration(decl_id) => {
                 let TyFunctionDeclaration { visibility, .. } = check!(
                     CompileResult::from(
@@ -10,6 +10,9 @@ pub struct TyImplTrait {
     pub trait_name: CallPath,
     pub trait_type_arguments: Vec<TypeArgument>,
     pub methods: Vec<DeclarationId>,
+    pub constants: Vec<DeclarationId>,
+    /// The associated types, as type aliases of the types the impl block provides.
+    pub types: Vec<DeclarationId>,
     pub implementing_for_type_id: TypeId,
     pub type_implementing_for_span: Span,
     pub span: Span,
@@ -25,6 +28,8 @@ impl PartialEqWithEngines for TyImplTrait {
                 .trait_type_arguments
                 .eq(&other.trait_type_arguments, engines)
             && self.methods.eq(&other.methods, engines)
+            && self.constants.eq(&other.constants, engines)
+            && self.types.eq(&other.types, engines)
             && self.implementing_for_type_id == other.implementing_for_type_id
             && self.type_implementing_for_span == other.type_implementing_for_span
             && self.span == other.span
@@ -41,6 +46,12 @@ impl CopyTypes for TyImplTrait {
         self.methods
             .iter_mut()
             .for_each(|x| x.copy_types(type_mapping, engines));
+        self.constants
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping, engines));
+        self.types
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping, engines));
     }
 }
 
@@ -54,5 +65,11 @@ impl ReplaceSelfType for TyImplTrait {
         self.methods
             .iter_mut()
             .for_each(|x| x.replace_self_type(engines, self_type));
+        self.constants
+            .iter_mut()
+            .for_each(|x| x.replace_self_type(engines, self_type));
+        self.types
+            .iter_mut()
+            .for_each(|x| x.replace_self_type(engines, self_type));
     }
 }
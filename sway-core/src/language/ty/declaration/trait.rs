@@ -13,6 +13,8 @@ pub struct TyTraitDeclaration {
     pub name: Ident,
     pub type_parameters: Vec<TypeParameter>,
     pub interface_surface: Vec<DeclarationId>,
+    pub constants: Vec<DeclarationId>,
+    pub types: Vec<TyTraitType>,
     pub methods: Vec<DeclarationId>,
     pub supertraits: Vec<parsed::Supertrait>,
     pub visibility: Visibility,
@@ -26,6 +28,8 @@ impl PartialEqWithEngines for TyTraitDeclaration {
         self.name == other.name
             && self.type_parameters.eq(&other.type_parameters, engines)
             && self.interface_surface.eq(&other.interface_surface, engines)
+            && self.constants.eq(&other.constants, engines)
+            && self.types.eq(&other.types, engines)
             && self.methods.eq(&other.methods, engines)
             && self.supertraits == other.supertraits
             && self.visibility == other.visibility
@@ -47,6 +51,15 @@ impl CopyTypes for TyTraitDeclaration {
                     .copy_types_and_insert_new(type_mapping, engines);
                 function_decl_id.replace_id(*new_decl_id);
             });
+        self.constants.iter_mut().for_each(|const_decl_id| {
+            let new_decl_id = const_decl_id
+                .clone()
+                .copy_types_and_insert_new(type_mapping, engines);
+            const_decl_id.replace_id(*new_decl_id);
+        });
+        self.types
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping, engines));
         // we don't have to type check the methods because it hasn't been type checked yet
     }
 }
@@ -64,6 +77,15 @@ impl ReplaceSelfType for TyTraitDeclaration {
                     .replace_self_type_and_insert_new(engines, self_type);
                 function_decl_id.replace_id(*new_decl_id);
             });
+        self.constants.iter_mut().for_each(|const_decl_id| {
+            let new_decl_id = const_decl_id
+                .clone()
+                .replace_self_type_and_insert_new(engines, self_type);
+            const_decl_id.replace_id(*new_decl_id);
+        });
+        self.types
+            .iter_mut()
+            .for_each(|x| x.replace_self_type(engines, self_type));
         // we don't have to type check the methods because it hasn't been type checked yet
     }
 }
@@ -77,3 +99,47 @@ impl MonomorphizeHelper for TyTraitDeclaration {
         &self.type_parameters
     }
 }
+
+/// An associated type declared in a trait.
+///
+/// Inside the trait the associated type is represented by the generic
+/// `type_id`, which implementations of the trait replace with the type they
+/// provide (or with the default in `ty_opt`).
+#[derive(Clone, Debug)]
+pub struct TyTraitType {
+    pub name: Ident,
+    pub type_id: TypeId,
+    pub ty_opt: Option<TypeArgument>,
+    pub attributes: transform::AttributesMap,
+    pub span: Span,
+}
+
+impl EqWithEngines for TyTraitType {}
+impl PartialEqWithEngines for TyTraitType {
+    fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
+        let type_engine = engines.te();
+        self.name == other.name
+            && type_engine
+                .look_up_type_id(self.type_id)
+                .eq(&type_engine.look_up_type_id(other.type_id), engines)
+            && self.ty_opt.eq(&other.ty_opt, engines)
+            && self.attributes == other.attributes
+            && self.span == other.span
+    }
+}
+
+impl CopyTypes for TyTraitType {
+    fn copy_types_inner(&mut self, type_mapping: &TypeMapping, engines: Engines<'_>) {
+        if let Some(ty) = &mut self.ty_opt {
+            ty.copy_types(type_mapping, engines);
+        }
+    }
+}
+
+impl ReplaceSelfType for TyTraitType {
+    fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
+        if let Some(ty) = &mut self.ty_opt {
+            ty.replace_self_type(engines, self_type);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use sway_types::{Ident, Span, Spanned};
+
+use crate::{engine_threading::*, language::Visibility, transform, type_system::*};
+
+#[derive(Clone, Debug)]
+pub struct TyTypeAliasDeclaration {
+    pub name: Ident,
+    pub ty: TypeArgument,
+    pub type_parameters: Vec<TypeParameter>,
+    pub visibility: Visibility,
+    pub span: Span,
+    pub attributes: transform::AttributesMap,
+}
+
+impl EqWithEngines for TyTypeAliasDeclaration {}
+impl PartialEqWithEngines for TyTypeAliasDeclaration {
+    fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
+        self.name == other.name
+            && self.ty.eq(&other.ty, engines)
+            && self.type_parameters.eq(&other.type_parameters, engines)
+            && self.visibility == other.visibility
+    }
+}
+
+impl CopyTypes for TyTypeAliasDeclaration {
+    fn copy_types_inner(&mut self, type_mapping: &TypeMapping, engines: Engines<'_>) {
+        self.ty.copy_types(type_mapping, engines);
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping, engines));
+    }
+}
+
+impl ReplaceSelfType for TyTypeAliasDeclaration {
+    fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
+        self.ty.replace_self_type(engines, self_type);
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.replace_self_type(engines, self_type));
+    }
+}
+
+impl CreateTypeId for TyTypeAliasDeclaration {
+    /// Aliases are transparent, so this creates a fresh copy of the aliased type.
+    fn create_type_id(&self, engines: Engines<'_>) -> TypeId {
+        let type_engine = engines.te();
+        let declaration_engine = engines.de();
+        type_engine.insert_type(
+            declaration_engine,
+            type_engine.look_up_type_id(self.ty.type_id),
+        )
+    }
+}
+
+impl Spanned for TyTypeAliasDeclaration {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl MonomorphizeHelper for TyTypeAliasDeclaration {
+    fn type_parameters(&self) -> &[TypeParameter] {
+        &self.type_parameters
+    }
+
+    fn name(&self) -> &Ident {
+        &self.name
+    }
+}
+
+impl TyTypeAliasDeclaration {
+    /// Returns the unresolved type this alias stands for, with the alias' type parameters
+    /// replaced by the given `type_arguments`.
+    ///
+    /// This is used to keep the initial types of declarations free of aliases, so that the JSON
+    /// ABI shows the underlying types.
+    pub(crate) fn expand(&self, engines: Engines<'_>, type_arguments: &[TypeArgument]) -> TypeId {
+        let substitutions = self
+            .type_parameters
+            .iter()
+            .map(|type_param| type_param.name_ident.clone())
+            .zip(
+                type_arguments
+                    .iter()
+                    .map(|type_arg| type_arg.initial_type_id),
+            )
+            .collect::<Vec<_>>();
+        substitute_type_parameters(engines, self.ty.initial_type_id, &substitutions)
+    }
+}
+
+fn substitute_type_parameters(
+    engines: Engines<'_>,
+    type_id: TypeId,
+    substitutions: &[(Ident, TypeId)],
+) -> TypeId {
+    let type_engine = engines.te();
+    let substitute_arg = |type_arg: &TypeArgument| {
+        let type_id = substitute_type_parameters(engines, type_arg.initial_type_id, substitutions);
+        TypeArgument {
+            type_id,
+            initial_type_id: type_id,
+            span: type_arg.span.clone(),
+        }
+    };
+    let type_info = match type_engine.look_up_type_id(type_id) {
+        TypeInfo::Custom {
+            name,
+            type_arguments,
+        } => match type_arguments {
+            Some(type_arguments) if !type_arguments.is_empty() => TypeInfo::Custom {
+                name,
+                type_arguments: Some(type_arguments.iter().map(substitute_arg).collect()),
+            },
+            _ => match substitutions.iter().find(|(param, _)| *param == name) {
+                Some((_, type_id)) => return *type_id,
+                None => return type_id,
+            },
+        },
+        TypeInfo::Tuple(fields) => TypeInfo::Tuple(fields.iter().map(substitute_arg).collect()),
+        TypeInfo::Array(elem_ty, length) => TypeInfo::Array(substitute_arg(&elem_ty), length),
+        TypeInfo::Ref(ty) => TypeInfo::Ref(substitute_arg(&ty)),
+        _ => return type_id,
+    };
+    type_engine.insert_type_always(type_info)
+}
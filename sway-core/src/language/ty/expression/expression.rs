@@ -136,7 +136,9 @@ impl CollectTypesMetadata for TyExpression {
                     }
                 }
             }
-            StructExpression { fields, span, .. } => {
+            StructExpression {
+                fields, base, span, ..
+            } => {
                 if let TypeInfo::Struct {
                     type_parameters, ..
                 } = ctx.type_engine.look_up_type_id(self.return_type)
@@ -153,6 +155,14 @@ impl CollectTypesMetadata for TyExpression {
                         errors
                     ));
                 }
+                if let Some(base) = base {
+                    res.append(&mut check!(
+                        base.collect_types_metadata(ctx),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
             }
             LazyOperator { lhs, rhs, .. } => {
                 res.append(&mut check!(
@@ -352,16 +362,56 @@ impl CollectTypesMetadata for TyExpression {
                     ));
                 }
             }
-            Return(exp) => res.append(&mut check!(
+            Return(exp) | Ref(exp) | Deref(exp) => res.append(&mut check!(
                 exp.collect_types_metadata(ctx),
                 return err(warnings, errors),
                 warnings,
                 errors
             )),
+            Closure { decl_id, captures } => {
+                for capture in captures.iter() {
+                    res.append(&mut check!(
+                        capture.collect_types_metadata(ctx),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+                let function_decl =
+                    match declaration_engine.get_function(decl_id.clone(), &self.span) {
+                        Ok(decl) => decl,
+                        Err(e) => return err(vec![], vec![e]),
+                    };
+                for content in function_decl.body.contents.iter() {
+                    res.append(&mut check!(
+                        content.collect_types_metadata(ctx),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+            }
+            ClosureCall { closure, arguments } => {
+                res.append(&mut check!(
+                    closure.collect_types_metadata(ctx),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ));
+                for arg in arguments.iter() {
+                    res.append(&mut check!(
+                        arg.1.collect_types_metadata(ctx),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+            }
             // storage access can never be generic
             // variable expressions don't ever have return types themselves, they're stored in
             // `TyExpression::return_type`. Variable expressions are just names of variables.
             VariableExpression { .. }
+            | AssociatedConstant { .. }
             | StorageAccess { .. }
             | Literal(_)
             | AbiName(_)
@@ -425,9 +475,18 @@ impl DeterministicallyAborts for TyExpression {
                         x.deterministically_aborts(declaration_engine, check_call_body)
                     })
             }
-            Tuple { fields, .. } => fields
+            Tuple { fields, .. }
+            | Closure {
+                captures: fields, ..
+            } => fields
                 .iter()
                 .any(|x| x.deterministically_aborts(declaration_engine, check_call_body)),
+            ClosureCall { closure, arguments } => {
+                closure.deterministically_aborts(declaration_engine, check_call_body)
+                    || arguments.iter().any(|(_, x)| {
+                        x.deterministically_aborts(declaration_engine, check_call_body)
+                    })
+            }
             Array { contents, .. } => contents
                 .iter()
                 .any(|x| x.deterministically_aborts(declaration_engine, check_call_body)),
@@ -437,10 +496,14 @@ impl DeterministicallyAborts for TyExpression {
             LazyOperator { lhs, .. } => {
                 lhs.deterministically_aborts(declaration_engine, check_call_body)
             }
-            StructExpression { fields, .. } => fields.iter().any(|x| {
-                x.value
-                    .deterministically_aborts(declaration_engine, check_call_body)
-            }),
+            StructExpression { fields, base, .. } => {
+                fields.iter().any(|x| {
+                    x.value
+                        .deterministically_aborts(declaration_engine, check_call_body)
+                }) || base
+                    .iter()
+                    .any(|base| base.deterministically_aborts(declaration_engine, check_call_body))
+            }
             EnumInstantiation { contents, .. } => contents
                 .as_ref()
                 .map(|x| x.deterministically_aborts(declaration_engine, check_call_body))
@@ -452,6 +515,7 @@ impl DeterministicallyAborts for TyExpression {
             | Literal(_)
             | StorageAccess { .. }
             | VariableExpression { .. }
+            | AssociatedConstant { .. }
             | FunctionParameter
             | TupleElemAccess { .. } => false,
             IntrinsicFunction(kind) => {
@@ -484,7 +548,7 @@ impl DeterministicallyAborts for TyExpression {
             }
             AbiName(_) => false,
             EnumTag { exp } => exp.deterministically_aborts(declaration_engine, check_call_body),
-            UnsafeDowncast { exp, .. } => {
+            UnsafeDowncast { exp, .. } | Ref(exp) | Deref(exp) => {
                 exp.deterministically_aborts(declaration_engine, check_call_body)
             }
             WhileLoop { condition, body } => {
@@ -1,9 +1,10 @@
 use std::{
     collections::HashMap,
     fmt::{self, Write},
+    iter,
 };
 
-use sway_types::{state::StateIndex, Ident, Span};
+use sway_types::{state::StateIndex, Ident, Span, Spanned};
 
 use crate::{
     declaration_engine::{DeclMapping, DeclarationId, ReplaceDecls},
@@ -35,6 +36,11 @@ pub enum TyExpressionVariant {
         span: Span,
         mutability: VariableMutability,
     },
+    /// An associated constant of a type, such as `T::NAME` or `Self::NAME`.
+    AssociatedConstant {
+        call_path: CallPath,
+        const_decl_id: DeclarationId,
+    },
     Tuple {
         fields: Vec<TyExpression>,
     },
@@ -47,7 +53,11 @@ pub enum TyExpressionVariant {
     },
     StructExpression {
         struct_name: Ident,
+        /// The fields listed explicitly. When there is no `base`, these are all the fields of the
+        /// struct in declaration order.
         fields: Vec<TyStructExpressionField>,
+        /// The struct that the remaining fields are copied from, as in `..base`.
+        base: Option<Box<TyExpression>>,
         span: Span,
     },
     CodeBlock(TyCodeBlock),
@@ -120,6 +130,22 @@ pub enum TyExpressionVariant {
     Reassignment(Box<TyReassignment>),
     StorageReassignment(Box<TyStorageReassignment>),
     Return(Box<TyExpression>),
+    /// Takes the address of the value of `exp`, which is never of a copy type.
+    Ref(Box<TyExpression>),
+    /// Reads the value behind the reference `exp`.
+    Deref(Box<TyExpression>),
+    /// A closure, or a function used as a value. The value of a closure is the tuple of its
+    /// `captures`, which are passed before the other arguments to the function `decl_id` when
+    /// the closure is called.
+    Closure {
+        decl_id: DeclarationId,
+        captures: Vec<TyExpression>,
+    },
+    /// Calls the closure or function value `closure`.
+    ClosureCall {
+        closure: Box<TyExpression>,
+        arguments: Vec<(Ident, TyExpression)>,
+    },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -188,6 +214,16 @@ impl PartialEqWithEngines for TyExpressionVariant {
                     mutability: r_mutability,
                 },
             ) => l_name == r_name && l_span == r_span && l_mutability == r_mutability,
+            (
+                Self::AssociatedConstant {
+                    call_path: l_call_path,
+                    const_decl_id: l_const_decl_id,
+                },
+                Self::AssociatedConstant {
+                    call_path: r_call_path,
+                    const_decl_id: r_const_decl_id,
+                },
+            ) => l_call_path == r_call_path && **l_const_decl_id == **r_const_decl_id,
             (Self::Tuple { fields: l_fields }, Self::Tuple { fields: r_fields }) => {
                 l_fields.eq(r_fields, engines)
             }
@@ -213,15 +249,24 @@ impl PartialEqWithEngines for TyExpressionVariant {
                 Self::StructExpression {
                     struct_name: l_struct_name,
                     fields: l_fields,
+                    base: l_base,
                     span: l_span,
                 },
                 Self::StructExpression {
                     struct_name: r_struct_name,
                     fields: r_fields,
+                    base: r_base,
                     span: r_span,
                 },
             ) => {
-                l_struct_name == r_struct_name && l_fields.eq(r_fields, engines) && l_span == r_span
+                l_struct_name == r_struct_name
+                    && l_fields.eq(r_fields, engines)
+                    && if let (Some(l_base), Some(r_base)) = (l_base, r_base) {
+                        (**l_base).eq(&**r_base, engines)
+                    } else {
+                        true
+                    }
+                    && l_span == r_span
             }
             (Self::CodeBlock(l0), Self::CodeBlock(r0)) => l0.eq(r0, engines),
             (
@@ -368,6 +413,43 @@ impl PartialEqWithEngines for TyExpressionVariant {
                     condition: r_condition,
                 },
             ) => l_body.eq(r_body, engines) && l_condition.eq(r_condition, engines),
+            (Self::Ref(l_exp), Self::Ref(r_exp)) | (Self::Deref(l_exp), Self::Deref(r_exp)) => {
+                l_exp.eq(r_exp, engines)
+            }
+            (
+                Self::Closure {
+                    decl_id: l_decl_id,
+                    captures: l_captures,
+                },
+                Self::Closure {
+                    decl_id: r_decl_id,
+                    captures: r_captures,
+                },
+            ) => {
+                l_decl_id.span() == r_decl_id.span()
+                    && l_captures.len() == r_captures.len()
+                    && l_captures
+                        .iter()
+                        .zip(r_captures.iter())
+                        .all(|(l, r)| l.eq(r, engines))
+            }
+            (
+                Self::ClosureCall {
+                    closure: l_closure,
+                    arguments: l_arguments,
+                },
+                Self::ClosureCall {
+                    closure: r_closure,
+                    arguments: r_arguments,
+                },
+            ) => {
+                l_closure.eq(r_closure, engines)
+                    && l_arguments.len() == r_arguments.len()
+                    && l_arguments
+                        .iter()
+                        .zip(r_arguments.iter())
+                        .all(|((l_name, l), (r_name, r))| l_name == r_name && l.eq(r, engines))
+            }
             _ => false,
         }
     }
@@ -396,6 +478,8 @@ impl CopyTypes for TyExpressionVariant {
                 (*rhs).copy_types(type_mapping, engines);
             }
             VariableExpression { .. } => (),
+            // the constant itself is monomorphized when its decl id is replaced
+            AssociatedConstant { .. } => (),
             Tuple { fields } => fields
                 .iter_mut()
                 .for_each(|x| x.copy_types(type_mapping, engines)),
@@ -406,9 +490,14 @@ impl CopyTypes for TyExpressionVariant {
                 (*prefix).copy_types(type_mapping, engines);
                 (*index).copy_types(type_mapping, engines);
             }
-            StructExpression { fields, .. } => fields
-                .iter_mut()
-                .for_each(|x| x.copy_types(type_mapping, engines)),
+            StructExpression { fields, base, .. } => {
+                fields
+                    .iter_mut()
+                    .for_each(|x| x.copy_types(type_mapping, engines));
+                if let Some(base) = base {
+                    base.copy_types(type_mapping, engines);
+                }
+            }
             CodeBlock(block) => {
                 block.copy_types(type_mapping, engines);
             }
@@ -488,6 +577,17 @@ impl CopyTypes for TyExpressionVariant {
             Reassignment(reassignment) => reassignment.copy_types(type_mapping, engines),
             StorageReassignment(..) => (),
             Return(stmt) => stmt.copy_types(type_mapping, engines),
+            Ref(exp) | Deref(exp) => exp.copy_types(type_mapping, engines),
+            // the function of the closure is copied along with the type of the closure
+            Closure { captures, .. } => captures
+                .iter_mut()
+                .for_each(|x| x.copy_types(type_mapping, engines)),
+            ClosureCall { closure, arguments } => {
+                closure.copy_types(type_mapping, engines);
+                arguments
+                    .iter_mut()
+                    .for_each(|(_ident, expr)| expr.copy_types(type_mapping, engines));
+            }
         }
     }
 }
@@ -515,6 +615,7 @@ impl ReplaceSelfType for TyExpressionVariant {
                 (*rhs).replace_self_type(engines, self_type);
             }
             VariableExpression { .. } => (),
+            AssociatedConstant { .. } => (),
             Tuple { fields } => fields
                 .iter_mut()
                 .for_each(|x| x.replace_self_type(engines, self_type)),
@@ -525,9 +626,14 @@ impl ReplaceSelfType for TyExpressionVariant {
                 (*prefix).replace_self_type(engines, self_type);
                 (*index).replace_self_type(engines, self_type);
             }
-            StructExpression { fields, .. } => fields
-                .iter_mut()
-                .for_each(|x| x.replace_self_type(engines, self_type)),
+            StructExpression { fields, base, .. } => {
+                fields
+                    .iter_mut()
+                    .for_each(|x| x.replace_self_type(engines, self_type));
+                if let Some(base) = base {
+                    base.replace_self_type(engines, self_type);
+                }
+            }
             CodeBlock(block) => {
                 block.replace_self_type(engines, self_type);
             }
@@ -601,6 +707,16 @@ impl ReplaceSelfType for TyExpressionVariant {
             Reassignment(reassignment) => reassignment.replace_self_type(engines, self_type),
             StorageReassignment(..) => (),
             Return(stmt) => stmt.replace_self_type(engines, self_type),
+            Ref(exp) | Deref(exp) => exp.replace_self_type(engines, self_type),
+            Closure { captures, .. } => captures
+                .iter_mut()
+                .for_each(|x| x.replace_self_type(engines, self_type)),
+            ClosureCall { closure, arguments } => {
+                closure.replace_self_type(engines, self_type);
+                arguments
+                    .iter_mut()
+                    .for_each(|(_ident, expr)| expr.replace_self_type(engines, self_type));
+            }
         }
     }
 }
@@ -629,6 +745,10 @@ impl ReplaceDecls for TyExpressionVariant {
                 (*rhs).replace_decls(decl_mapping, engines);
             }
             VariableExpression { .. } => (),
+            AssociatedConstant {
+                ref mut const_decl_id,
+                ..
+            } => const_decl_id.replace_decls(decl_mapping, engines),
             Tuple { fields } => fields
                 .iter_mut()
                 .for_each(|x| x.replace_decls(decl_mapping, engines)),
@@ -639,9 +759,14 @@ impl ReplaceDecls for TyExpressionVariant {
                 (*prefix).replace_decls(decl_mapping, engines);
                 (*index).replace_decls(decl_mapping, engines);
             }
-            StructExpression { fields, .. } => fields
-                .iter_mut()
-                .for_each(|x| x.replace_decls(decl_mapping, engines)),
+            StructExpression { fields, base, .. } => {
+                fields
+                    .iter_mut()
+                    .for_each(|x| x.replace_decls(decl_mapping, engines));
+                if let Some(base) = base {
+                    base.replace_decls(decl_mapping, engines);
+                }
+            }
             CodeBlock(block) => {
                 block.replace_decls(decl_mapping, engines);
             }
@@ -697,6 +822,16 @@ impl ReplaceDecls for TyExpressionVariant {
             Reassignment(reassignment) => reassignment.replace_decls(decl_mapping, engines),
             StorageReassignment(..) => (),
             Return(stmt) => stmt.replace_decls(decl_mapping, engines),
+            Ref(exp) | Deref(exp) => exp.replace_decls(decl_mapping, engines),
+            Closure { captures, .. } => captures
+                .iter_mut()
+                .for_each(|x| x.replace_decls(decl_mapping, engines)),
+            ClosureCall { closure, arguments } => {
+                closure.replace_decls(decl_mapping, engines);
+                for (_, arg) in arguments.iter_mut() {
+                    arg.replace_decls(decl_mapping, engines);
+                }
+            }
         }
     }
 }
@@ -777,6 +912,9 @@ impl DisplayWithEngines for TyExpressionVariant {
             }
             TyExpressionVariant::IntrinsicFunction(kind) => engines.help_out(kind).to_string(),
             TyExpressionVariant::AbiName(n) => format!("ABI name {}", n),
+            TyExpressionVariant::AssociatedConstant { call_path, .. } => {
+                format!("\"{}\" associated constant", call_path)
+            }
             TyExpressionVariant::EnumTag { exp } => {
                 format!("({} as tag)", engines.help_out(exp.return_type))
             }
@@ -821,6 +959,16 @@ impl DisplayWithEngines for TyExpressionVariant {
             TyExpressionVariant::Return(exp) => {
                 format!("return {}", engines.help_out(&**exp))
             }
+            TyExpressionVariant::Ref(exp) => {
+                format!("ref {}", engines.help_out(&**exp))
+            }
+            TyExpressionVariant::Deref(exp) => {
+                format!("deref {}", engines.help_out(&**exp))
+            }
+            TyExpressionVariant::Closure { .. } => "closure".into(),
+            TyExpressionVariant::ClosureCall { closure, .. } => {
+                format!("call of {}", engines.help_out(&**closure))
+            }
         };
         write!(f, "{}", s)
     }
@@ -907,9 +1055,10 @@ impl TyExpressionVariant {
                     .flat_map(|expr| expr.gather_return_statements())
                     .collect()
             }
-            TyExpressionVariant::StructExpression { fields, .. } => fields
+            TyExpressionVariant::StructExpression { fields, base, .. } => fields
                 .iter()
                 .flat_map(|field| field.value.gather_return_statements())
+                .chain(base.iter().flat_map(|base| base.gather_return_statements()))
                 .collect(),
             TyExpressionVariant::FunctionApplication {
                 contract_call_params,
@@ -928,6 +1077,18 @@ impl TyExpressionVariant {
                 .collect(),
             TyExpressionVariant::EnumTag { exp } => exp.gather_return_statements(),
             TyExpressionVariant::UnsafeDowncast { exp, .. } => exp.gather_return_statements(),
+            TyExpressionVariant::Ref(exp) | TyExpressionVariant::Deref(exp) => {
+                exp.gather_return_statements()
+            }
+            // return statements in the body of a closure return from the closure
+            TyExpressionVariant::Closure { captures, .. } => captures
+                .iter()
+                .flat_map(|expr| expr.gather_return_statements())
+                .collect(),
+            TyExpressionVariant::ClosureCall { closure, arguments } => iter::once(&**closure)
+                .chain(arguments.iter().map(|(_name, expr)| expr))
+                .flat_map(|expr| expr.gather_return_statements())
+                .collect(),
 
             TyExpressionVariant::Return(exp) => {
                 vec![exp]
@@ -938,6 +1099,7 @@ impl TyExpressionVariant {
             | TyExpressionVariant::FunctionParameter { .. }
             | TyExpressionVariant::AsmExpression { .. }
             | TyExpressionVariant::VariableExpression { .. }
+            | TyExpressionVariant::AssociatedConstant { .. }
             | TyExpressionVariant::AbiName(_)
             | TyExpressionVariant::StorageAccess { .. }
             | TyExpressionVariant::Break
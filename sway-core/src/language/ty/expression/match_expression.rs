@@ -12,6 +12,8 @@ pub(crate) struct TyMatchExpression {
 #[derive(Debug)]
 pub(crate) struct TyMatchBranch {
     pub(crate) conditions: MatchReqMap,
+    /// The guard of the branch, preceded by the variable declarations that it may refer to.
+    pub(crate) guard: Option<TyExpression>,
     pub(crate) result: TyExpression,
     #[allow(dead_code)]
     pub(crate) span: Span,
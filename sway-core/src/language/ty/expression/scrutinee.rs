@@ -26,6 +26,7 @@ pub(crate) enum TyScrutineeVariant {
         value: Box<TyScrutinee>,
     },
     Tuple(Vec<TyScrutinee>),
+    Or(Vec<TyScrutinee>),
 }
 
 #[derive(Debug, Clone)]
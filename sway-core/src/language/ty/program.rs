@@ -7,6 +7,7 @@ use crate::{
     fuel_prelude::fuel_tx::StorageSlot,
     language::{parsed, ty::*, Purity},
     semantic_analysis::storage_only_types,
+    transform::LintScopes,
     type_system::*,
     Engines,
 };
@@ -19,6 +20,8 @@ pub struct TyProgram {
     pub storage_slots: Vec<StorageSlot>,
     pub logged_types: Vec<(LogId, TypeId)>,
     pub messages_types: Vec<(MessageId, TypeId)>,
+    /// The lint levels set by attributes within the program.
+    pub lint_scopes: LintScopes,
 }
 
 impl TyProgram {
@@ -246,6 +249,29 @@ impl TyProgram {
             }
             _ => (),
         }
+        // Closures and functions only exist within a program, so they cannot be passed to its
+        // entry points.
+        let (entry_points, what) = match &typed_program_kind {
+            TyProgramKind::Script { main_function, .. }
+            | TyProgramKind::Predicate { main_function, .. } => {
+                (vec![main_function], "the main function")
+            }
+            TyProgramKind::Contract { abi_entries } => {
+                (abi_entries.iter().collect(), "contract methods")
+            }
+            TyProgramKind::Library { .. } => (vec![], ""),
+        };
+        for param in entry_points.iter().flat_map(|entry| &entry.parameters) {
+            if matches!(
+                ty_engine.look_up_type_id(param.type_id),
+                TypeInfo::Function { .. }
+            ) {
+                errors.push(CompileError::FunctionTypeParameterNotAllowed {
+                    what,
+                    span: param.type_span.clone(),
+                });
+            }
+        }
         ok((typed_program_kind, declarations), warnings, errors)
     }
 
@@ -42,6 +42,7 @@ pub use type_system::*;
 
 use language::{parsed, ty};
 use transform::to_parsed_lang::{self, convert_module_kind};
+use transform::LintScopes;
 
 pub mod fuel_prelude {
     pub use fuel_vm::{self, fuel_asm, fuel_crypto, fuel_tx, fuel_types};
@@ -67,13 +68,36 @@ pub fn parse(
     engines: Engines<'_>,
     config: Option<&BuildConfig>,
 ) -> CompileResult<parsed::ParseProgram> {
-    CompileResult::with_handler(|h| match config {
+    let res = CompileResult::with_handler(|h| match config {
         None => parse_in_memory(h, engines, input),
         // When a `BuildConfig` is given,
         // the module source may declare `dep`s that must be parsed from other files.
-        Some(config) => parse_module_tree(h, engines, input, config.canonical_root_module())
-            .map(|(kind, root)| parsed::ParseProgram { kind, root }),
-    })
+        Some(config) => {
+            let mut lint_scopes = LintScopes::default();
+            parse_module_tree(
+                h,
+                engines,
+                config,
+                input,
+                config.canonical_root_module(),
+                LintScopes::default(),
+                &mut lint_scopes,
+            )
+            .map(|(kind, root)| parsed::ParseProgram {
+                kind,
+                root,
+                lint_scopes,
+            })
+        }
+    });
+    match res
+        .value
+        .as_ref()
+        .map(|program| program.lint_scopes.clone())
+    {
+        Some(lint_scopes) => transform::apply_lints(&lint_scopes, config, res),
+        None => res,
+    }
 }
 
 /// Parses the tree kind in the input provided.
@@ -91,24 +115,39 @@ fn parse_in_memory(
     engines: Engines<'_>,
     src: Arc<str>,
 ) -> Result<parsed::ParseProgram, ErrorEmitted> {
-    let module = sway_parse::parse_file(handler, src, None)?;
+    let mut module = sway_parse::parse_file(handler, src, None)?;
+    to_parsed_lang::strip_cfg_items(handler, &mut module, None);
+    let lint_scopes = LintScopes::collect(handler, &module, LintScopes::default());
     let (kind, tree) = to_parsed_lang::convert_parse_tree(handler, engines, module)?;
     let submodules = Default::default();
     let root = parsed::ParseModule { tree, submodules };
-    Ok(parsed::ParseProgram { kind, root })
+    Ok(parsed::ParseProgram {
+        kind,
+        root,
+        lint_scopes,
+    })
 }
 
 /// Parse all dependencies `deps` as submodules.
+///
+/// Each submodule inherits the lint levels applying to its `dep` item from `module_lint_scopes`.
 fn parse_submodules(
     handler: &Handler,
     engines: Engines<'_>,
+    config: &BuildConfig,
     module: &sway_ast::Module,
     module_dir: &Path,
+    module_lint_scopes: &LintScopes,
+    lint_scopes: &mut LintScopes,
 ) -> Vec<(Ident, parsed::ParseSubmodule)> {
     // Assume the happy path, so there'll be as many submodules as dependencies, but no more.
     let mut submods = Vec::with_capacity(module.dependencies().count());
 
-    module.dependencies().for_each(|dep| {
+    let dep_items = module.items.iter().filter_map(|item| match &item.value {
+        sway_ast::ItemKind::Dependency(dep) => Some((item, dep)),
+        _ => None,
+    });
+    dep_items.for_each(|(dep_item, dep)| {
         // Read the source code from the dependency.
         // If we cannot, record as an error, but continue with other files.
         let dep_path = Arc::new(module_path(module_dir, dep));
@@ -124,9 +163,18 @@ fn parse_submodules(
             }
         };
 
-        if let Ok((kind, module)) =
-            parse_module_tree(handler, engines, dep_str.clone(), dep_path.clone())
-        {
+        let dep_span = span::Span::new(dep_str.clone(), 0, dep_str.len(), Some(dep_path.clone()))
+            .expect("the whole source is a valid span");
+        let inherited_lint_scopes = module_lint_scopes.for_submodule(dep_item, &dep_span);
+        if let Ok((kind, module)) = parse_module_tree(
+            handler,
+            engines,
+            config,
+            dep_str.clone(),
+            dep_path.clone(),
+            inherited_lint_scopes,
+            lint_scopes,
+        ) {
             let library_name = match kind {
                 parsed::TreeType::Library { name } => name,
                 _ => {
@@ -153,19 +201,38 @@ fn parse_submodules(
 
 /// Given the source of the module along with its path,
 /// parse this module including all of its submodules.
+///
+/// The lint levels set within the module tree, on top of the `inherited` ones, are added to
+/// `lint_scopes`.
 fn parse_module_tree(
     handler: &Handler,
     engines: Engines<'_>,
+    config: &BuildConfig,
     src: Arc<str>,
     path: Arc<PathBuf>,
+    inherited_lint_scopes: LintScopes,
+    lint_scopes: &mut LintScopes,
 ) -> Result<(parsed::TreeType, parsed::ParseModule), ErrorEmitted> {
     // Parse this module first.
     let module_dir = path.parent().expect("module file has no parent directory");
-    let module = sway_parse::parse_file(handler, src, Some(path.clone()))?;
+    let mut module = sway_parse::parse_file(handler, src, Some(path.clone()))?;
+
+    // Strip the items disabled by `cfg` attributes, including `dep`s, before parsing submodules.
+    to_parsed_lang::strip_cfg_items(handler, &mut module, Some(config));
+    let module_lint_scopes = LintScopes::collect(handler, &module, inherited_lint_scopes);
 
     // Parse all submodules before converting to the `ParseTree`.
     // This always recovers on parse errors for the file itself by skipping that file.
-    let submodules = parse_submodules(handler, engines, &module, module_dir);
+    let submodules = parse_submodules(
+        handler,
+        engines,
+        config,
+        &module,
+        module_dir,
+        &module_lint_scopes,
+        lint_scopes,
+    );
+    lint_scopes.extend(&module_lint_scopes);
 
     // Convert from the raw parsed module to the `ParseTree` ready for type-check.
     let (kind, tree) = to_parsed_lang::convert_parse_tree(handler, engines, module)?;
@@ -191,6 +258,17 @@ pub fn parsed_to_ast(
     parse_program: &parsed::ParseProgram,
     initial_namespace: namespace::Module,
     build_config: Option<&BuildConfig>,
+) -> CompileResult<ty::TyProgram> {
+    let res = type_check_and_analyze(engines, parse_program, initial_namespace, build_config);
+    transform::apply_lints(&parse_program.lint_scopes, build_config, res)
+}
+
+/// Type check the parsed program and perform the static analyses on the typed program.
+fn type_check_and_analyze(
+    engines: Engines<'_>,
+    parse_program: &parsed::ParseProgram,
+    initial_namespace: namespace::Module,
+    build_config: Option<&BuildConfig>,
 ) -> CompileResult<ty::TyProgram> {
     // Type check the program.
     let CompileResult {
@@ -355,8 +433,9 @@ pub fn ast_to_asm(
         Some(typed_program) => {
             let mut errors = ast_res.errors.clone();
             let mut warnings = ast_res.warnings.clone();
+            let asm_res = compile_ast_to_ir_to_asm(engines, typed_program, build_config);
             let asm = check!(
-                compile_ast_to_ir_to_asm(engines, typed_program, build_config),
+                transform::apply_lints(&typed_program.lint_scopes, Some(build_config), asm_res),
                 return deduped_err(warnings, errors),
                 warnings,
                 errors
@@ -514,8 +593,7 @@ pub fn inline_function_calls(
 
         match attributed_inline {
             Some(Inline::Always) => {
-                // TODO: check if inlining of function is possible
-                // return true;
+                return true;
             }
             Some(Inline::Never) => {
                 return false;
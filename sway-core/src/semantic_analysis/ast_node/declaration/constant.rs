@@ -0,0 +1,89 @@
+use sway_error::warning::{CompileWarning, Warning};
+use sway_types::{style::is_screaming_snake_case, Spanned};
+
+use crate::{
+    error::*,
+    language::{parsed::*, ty},
+    semantic_analysis::*,
+    type_system::*,
+};
+
+impl ty::TyConstantDeclaration {
+    pub(crate) fn type_check(
+        mut ctx: TypeCheckContext,
+        decl: ConstantDeclaration,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let type_engine = ctx.type_engine;
+        let declaration_engine = ctx.declaration_engine;
+        let engines = ctx.engines();
+
+        let ConstantDeclaration {
+            name,
+            type_ascription,
+            value,
+            visibility,
+            attributes,
+            span,
+            ..
+        } = decl;
+
+        let type_ascription = check!(
+            ctx.resolve_type_with_self(
+                type_engine.insert_type(declaration_engine, type_ascription),
+                &span,
+                EnforceTypeArguments::No,
+                None
+            ),
+            type_engine.insert_type(declaration_engine, TypeInfo::ErrorRecovery),
+            warnings,
+            errors,
+        );
+
+        if !is_screaming_snake_case(name.as_str()) {
+            warnings.push(CompileWarning {
+                span: name.span(),
+                warning_content: Warning::NonScreamingSnakeCaseConstName { name: name.clone() },
+            })
+        }
+
+        // Constants declared in a trait may omit their value, in which case
+        // every implementation of the trait has to provide one.
+        let value = value.map(|value| {
+            let mut ctx = ctx
+                .by_ref()
+                .with_type_annotation(type_ascription)
+                .with_help_text(
+                    "This declaration's type annotation does not match up with the assigned \
+                    expression's type.",
+                );
+            check!(
+                ty::TyExpression::type_check(ctx.by_ref(), value),
+                ty::TyExpression::error(name.span(), engines),
+                warnings,
+                errors
+            )
+        });
+
+        // Integers are special in the sense that we can't only rely on the type of `body`
+        // to get the type of the variable. The type of the variable *has* to follow
+        // `type_ascription` if `type_ascription` is a concrete integer type that does not
+        // conflict with the type of `body` (i.e. passes the type checking above).
+        let return_type = match (type_engine.look_up_type_id(type_ascription), &value) {
+            (TypeInfo::UnsignedInteger(_), _) | (_, None) => type_ascription,
+            (_, Some(value)) => value.return_type,
+        };
+
+        let decl = ty::TyConstantDeclaration {
+            name,
+            value,
+            visibility,
+            return_type,
+            attributes,
+            span,
+        };
+        ok(decl, warnings, errors)
+    }
+}
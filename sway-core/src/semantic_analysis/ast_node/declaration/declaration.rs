@@ -1,5 +1,4 @@
-use sway_error::warning::{CompileWarning, Warning};
-use sway_types::{style::is_screaming_snake_case, Spanned};
+use sway_types::Spanned;
 
 use crate::{
     declaration_engine::ReplaceFunctionImplementingType,
@@ -73,67 +72,14 @@ impl ty::TyDeclaration {
                 ctx.namespace.insert_symbol(name, typed_var_decl.clone());
                 typed_var_decl
             }
-            parsed::Declaration::ConstantDeclaration(parsed::ConstantDeclaration {
-                name,
-                type_ascription,
-                value,
-                visibility,
-                attributes,
-                span,
-                ..
-            }) => {
-                let type_ascription = check!(
-                    ctx.resolve_type_with_self(
-                        type_engine.insert_type(declaration_engine, type_ascription),
-                        &span,
-                        EnforceTypeArguments::No,
-                        None
-                    ),
-                    type_engine.insert_type(declaration_engine, TypeInfo::ErrorRecovery),
-                    warnings,
-                    errors,
-                );
-
-                let mut ctx = ctx
-                    .by_ref()
-                    .with_type_annotation(type_ascription)
-                    .with_help_text(
-                        "This declaration's type annotation does not match up with the assigned \
-                        expression's type.",
-                    );
-                let result = ty::TyExpression::type_check(ctx.by_ref(), value);
-
-                if !is_screaming_snake_case(name.as_str()) {
-                    warnings.push(CompileWarning {
-                        span: name.span(),
-                        warning_content: Warning::NonScreamingSnakeCaseConstName {
-                            name: name.clone(),
-                        },
-                    })
-                }
-
-                let value = check!(
-                    result,
-                    ty::TyExpression::error(name.span(), engines),
+            parsed::Declaration::ConstantDeclaration(decl) => {
+                let name = decl.name.clone();
+                let decl = check!(
+                    ty::TyConstantDeclaration::type_check(ctx.by_ref(), decl),
+                    return err(warnings, errors),
                     warnings,
                     errors
                 );
-                // Integers are special in the sense that we can't only rely on the type of `body`
-                // to get the type of the variable. The type of the variable *has* to follow
-                // `type_ascription` if `type_ascription` is a concrete integer type that does not
-                // conflict with the type of `body` (i.e. passes the type checking above).
-                let return_type = match type_engine.look_up_type_id(type_ascription) {
-                    TypeInfo::UnsignedInteger(_) => type_ascription,
-                    _ => value.return_type,
-                };
-                let decl = ty::TyConstantDeclaration {
-                    name: name.clone(),
-                    value,
-                    visibility,
-                    return_type,
-                    attributes,
-                    span,
-                };
                 let typed_const_decl = ty::TyDeclaration::ConstantDeclaration(
                     declaration_engine.insert_constant(decl),
                 );
@@ -208,7 +154,12 @@ impl ty::TyDeclaration {
                         impl_trait.trait_name.clone(),
                         impl_trait.trait_type_arguments.clone(),
                         impl_trait.implementing_for_type_id,
-                        &impl_trait.methods,
+                        &[
+                            impl_trait.methods.clone(),
+                            impl_trait.constants.clone(),
+                            impl_trait.types.clone(),
+                        ]
+                        .concat(),
                         &impl_trait.span,
                         false,
                         engines,
@@ -238,7 +189,12 @@ impl ty::TyDeclaration {
                         impl_trait.trait_name.clone(),
                         impl_trait.trait_type_arguments.clone(),
                         impl_trait.implementing_for_type_id,
-                        &impl_trait.methods,
+                        &[
+                            impl_trait.methods.clone(),
+                            impl_trait.constants.clone(),
+                            impl_trait.types.clone(),
+                        ]
+                        .concat(),
                         &impl_trait.span,
                         true,
                         engines,
@@ -275,6 +231,26 @@ impl ty::TyDeclaration {
                 );
                 decl
             }
+            parsed::Declaration::TypeAliasDeclaration(decl) => {
+                let span = decl.span.clone();
+                let decl = check!(
+                    ty::TyTypeAliasDeclaration::type_check(ctx.by_ref(), decl),
+                    return ok(ty::TyDeclaration::ErrorRecovery(span), warnings, errors),
+                    warnings,
+                    errors
+                );
+                let name = decl.name.clone();
+                let decl_id = declaration_engine.insert_type_alias(decl);
+                let decl = ty::TyDeclaration::TypeAliasDeclaration(decl_id);
+                // insert the type alias decl into namespace
+                check!(
+                    ctx.namespace.insert_symbol(name, decl.clone()),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                decl
+            }
             parsed::Declaration::AbiDeclaration(abi_decl) => {
                 let span = abi_decl.span.clone();
                 let mut abi_decl = check!(
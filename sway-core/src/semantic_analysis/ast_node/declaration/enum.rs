@@ -89,6 +89,7 @@ impl ty::TyEnumVariant {
             warnings,
             errors,
         );
+        let initial_type_id = ctx.expand_type_aliases(initial_type_id);
         ok(
             ty::TyEnumVariant {
                 name: variant.name.clone(),
@@ -1,15 +1,19 @@
 mod function_parameter;
 
 pub use function_parameter::*;
-use sway_error::warning::{CompileWarning, Warning};
+use sway_error::{
+    error::CompileError,
+    warning::{CompileWarning, Warning},
+};
 
 use crate::{
+    engine_threading::*,
     error::*,
     language::{parsed::*, ty, Visibility},
     semantic_analysis::*,
     type_system::*,
 };
-use sway_types::{style::is_snake_case, Spanned};
+use sway_types::{style::is_snake_case, Span, Spanned};
 
 impl ty::TyFunctionDeclaration {
     pub fn type_check(
@@ -91,6 +95,26 @@ impl ty::TyFunctionDeclaration {
             warnings,
             errors,
         );
+        let initial_return_type = fn_ctx.expand_type_aliases(initial_return_type);
+
+        // References point into the call frame of the function that created them, so they can't
+        // be handed back to the caller, neither as the return value nor through a `ref mut`
+        // parameter.
+        for parameter in new_parameters
+            .iter()
+            .filter(|parameter| parameter.is_reference && parameter.is_mutable)
+        {
+            errors.extend(check_reference_does_not_escape(
+                engines,
+                parameter.type_id,
+                &parameter.type_span,
+            ));
+        }
+        errors.extend(check_reference_does_not_escape(
+            engines,
+            return_type,
+            &return_type_span,
+        ));
 
         // type check the function body
         //
@@ -101,7 +125,8 @@ impl ty::TyFunctionDeclaration {
                 .by_ref()
                 .with_purity(purity)
                 .with_help_text("Function body's return type does not match up with its return type annotation.")
-                .with_type_annotation(return_type);
+                .with_type_annotation(return_type)
+                .with_function_return_type(return_type);
             check!(
                 ty::TyCodeBlock::type_check(fn_ctx, body),
                 (
@@ -179,6 +204,24 @@ impl ty::TyFunctionDeclaration {
     }
 }
 
+fn check_reference_does_not_escape(
+    engines: Engines<'_>,
+    type_id: TypeId,
+    span: &Span,
+) -> Vec<CompileError> {
+    let type_engine = engines.te();
+    let nested_types = type_engine
+        .look_up_type_id(type_id)
+        .extract_nested_types(type_engine, span)
+        .value
+        .unwrap_or_default();
+    if nested_types.iter().any(|ty| matches!(ty, TypeInfo::Ref(_))) {
+        vec![CompileError::ReferenceEscapesFunction { span: span.clone() }]
+    } else {
+        vec![]
+    }
+}
+
 #[test]
 fn test_function_selector_behavior() {
     use crate::{declaration_engine::DeclarationEngine, language::Visibility};
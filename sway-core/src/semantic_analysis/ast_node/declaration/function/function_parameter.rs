@@ -43,6 +43,7 @@ impl ty::TyFunctionParameter {
             warnings,
             errors,
         );
+        let initial_type_id = ctx.expand_type_aliases(initial_type_id);
 
         if !is_from_method {
             let mutability = ty::VariableMutability::new_from_ref_mut(is_reference, is_mutable);
@@ -62,7 +63,7 @@ impl ty::TyFunctionParameter {
             type_span,
         };
 
-        insert_into_namespace(ctx, &typed_parameter);
+        typed_parameter.insert_into_namespace(ctx);
 
         ok(typed_parameter, warnings, errors)
     }
@@ -101,6 +102,7 @@ impl ty::TyFunctionParameter {
             warnings,
             errors,
         );
+        let initial_type_id = ctx.expand_type_aliases(initial_type_id);
 
         let typed_parameter = ty::TyFunctionParameter {
             name,
@@ -114,25 +116,26 @@ impl ty::TyFunctionParameter {
 
         ok(typed_parameter, warnings, errors)
     }
-}
 
-fn insert_into_namespace(ctx: TypeCheckContext, typed_parameter: &ty::TyFunctionParameter) {
-    ctx.namespace.insert_symbol(
-        typed_parameter.name.clone(),
-        ty::TyDeclaration::VariableDeclaration(Box::new(ty::TyVariableDeclaration {
-            name: typed_parameter.name.clone(),
-            body: ty::TyExpression {
-                expression: ty::TyExpressionVariant::FunctionParameter,
-                return_type: typed_parameter.type_id,
-                span: typed_parameter.name.span(),
-            },
-            mutability: ty::VariableMutability::new_from_ref_mut(
-                typed_parameter.is_reference,
-                typed_parameter.is_mutable,
-            ),
-            return_type: typed_parameter.type_id,
-            type_ascription: typed_parameter.type_id,
-            type_ascription_span: Some(typed_parameter.type_span.clone()),
-        })),
-    );
+    /// Insert the parameter into the namespace of a function body, as a variable.
+    pub(crate) fn insert_into_namespace(&self, ctx: TypeCheckContext) {
+        ctx.namespace.insert_symbol(
+            self.name.clone(),
+            ty::TyDeclaration::VariableDeclaration(Box::new(ty::TyVariableDeclaration {
+                name: self.name.clone(),
+                body: ty::TyExpression {
+                    expression: ty::TyExpressionVariant::FunctionParameter,
+                    return_type: self.type_id,
+                    span: self.name.span(),
+                },
+                mutability: ty::VariableMutability::new_from_ref_mut(
+                    self.is_reference,
+                    self.is_mutable,
+                ),
+                return_type: self.type_id,
+                type_ascription: self.type_id,
+                type_ascription_span: Some(self.type_span.clone()),
+            })),
+        );
+    }
 }
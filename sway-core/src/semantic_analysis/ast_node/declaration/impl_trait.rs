@@ -27,6 +27,8 @@ impl ty::TyImplTrait {
             type_implementing_for,
             type_implementing_for_span,
             functions,
+            constants,
+            types,
             block_span,
         } = impl_trait;
 
@@ -134,7 +136,7 @@ impl ty::TyImplTrait {
                     errors
                 );
 
-                let new_methods = check!(
+                let (new_methods, new_constants, new_types) = check!(
                     type_check_trait_implementation(
                         ctx.by_ref(),
                         &new_impl_type_parameters,
@@ -142,9 +144,14 @@ impl ty::TyImplTrait {
                         &trait_type_arguments,
                         &trait_decl.supertraits,
                         &trait_decl.interface_surface,
+                        &trait_decl.constants,
+                        &trait_decl.types,
                         &trait_decl.methods,
                         &functions,
+                        &constants,
+                        &types,
                         &trait_name,
+                        &trait_decl.name,
                         &block_span,
                         false,
                     ),
@@ -158,6 +165,8 @@ impl ty::TyImplTrait {
                     trait_type_arguments,
                     span: block_span,
                     methods: new_methods,
+                    constants: new_constants,
+                    types: new_types,
                     implementing_for_type_id,
                     type_implementing_for_span: type_implementing_for_span.clone(),
                 }
@@ -187,7 +196,7 @@ impl ty::TyImplTrait {
 
                 let mut ctx = ctx.with_mode(Mode::ImplAbiFn);
 
-                let new_methods = check!(
+                let (new_methods, _, _) = check!(
                     type_check_trait_implementation(
                         ctx.by_ref(),
                         &[], // this is empty because abi definitions don't support generics,
@@ -195,9 +204,14 @@ impl ty::TyImplTrait {
                         &[], // this is empty because abi definitions don't support generics,
                         &[], // this is empty because abi definitions don't support supertraits,
                         &abi.interface_surface,
+                        &[], // this is empty because abi definitions don't support constants,
+                        &[], // this is empty because abi definitions don't support types,
                         &abi.methods,
                         &functions,
+                        &constants,
+                        &types,
                         &trait_name,
+                        &abi.name,
                         &block_span,
                         true
                     ),
@@ -211,6 +225,8 @@ impl ty::TyImplTrait {
                     trait_type_arguments: vec![], // this is empty because abi definitions don't support generics
                     span: block_span,
                     methods: new_methods,
+                    constants: vec![], // this is empty because abi definitions don't support constants
+                    types: vec![],     // this is empty because abi definitions don't support types
                     implementing_for_type_id,
                     type_implementing_for_span,
                 }
@@ -260,6 +276,7 @@ impl ty::TyImplTrait {
             let res = match &expr.expression {
                 ty::TyExpressionVariant::Literal(_)
                 | ty::TyExpressionVariant::VariableExpression { .. }
+                | ty::TyExpressionVariant::AssociatedConstant { .. }
                 | ty::TyExpressionVariant::FunctionParameter
                 | ty::TyExpressionVariant::AsmExpression { .. }
                 | ty::TyExpressionVariant::Break
@@ -300,7 +317,7 @@ impl ty::TyImplTrait {
                     false
                 }
 
-                ty::TyExpressionVariant::StructExpression { fields, .. } => {
+                ty::TyExpressionVariant::StructExpression { fields, base, .. } => {
                     for f in fields.iter() {
                         let b = expr_contains_get_storage_index(
                             declaration_engine,
@@ -311,7 +328,9 @@ impl ty::TyImplTrait {
                             return Ok(true);
                         }
                     }
-                    false
+                    base.as_ref().map_or(Ok(false), |base| {
+                        expr_contains_get_storage_index(declaration_engine, base, access_span)
+                    })?
                 }
                 ty::TyExpressionVariant::CodeBlock(cb) => {
                     codeblock_contains_get_storage_index(declaration_engine, cb, access_span)?
@@ -331,7 +350,9 @@ impl ty::TyImplTrait {
                 | ty::TyExpressionVariant::TupleElemAccess { prefix: exp, .. }
                 | ty::TyExpressionVariant::AbiCast { address: exp, .. }
                 | ty::TyExpressionVariant::EnumTag { exp }
-                | ty::TyExpressionVariant::UnsafeDowncast { exp, .. } => {
+                | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+                | ty::TyExpressionVariant::Ref(exp)
+                | ty::TyExpressionVariant::Deref(exp) => {
                     expr_contains_get_storage_index(declaration_engine, exp, access_span)?
                 }
                 ty::TyExpressionVariant::EnumInstantiation { contents, .. } => {
@@ -369,6 +390,31 @@ impl ty::TyImplTrait {
                 ty::TyExpressionVariant::Return(exp) => {
                     expr_contains_get_storage_index(declaration_engine, exp, access_span)?
                 }
+                ty::TyExpressionVariant::Closure { decl_id, captures } => {
+                    for f in captures.iter() {
+                        let b =
+                            expr_contains_get_storage_index(declaration_engine, f, access_span)?;
+                        if b {
+                            return Ok(true);
+                        }
+                    }
+                    let fn_decl = declaration_engine.get_function(decl_id.clone(), access_span)?;
+                    codeblock_contains_get_storage_index(
+                        declaration_engine,
+                        &fn_decl.body,
+                        access_span,
+                    )?
+                }
+                ty::TyExpressionVariant::ClosureCall { closure, arguments } => {
+                    for f in arguments.iter() {
+                        let b =
+                            expr_contains_get_storage_index(declaration_engine, &f.1, access_span)?;
+                        if b {
+                            return Ok(true);
+                        }
+                    }
+                    expr_contains_get_storage_index(declaration_engine, closure, access_span)?
+                }
             };
             Ok(res)
         }
@@ -385,7 +431,9 @@ impl ty::TyImplTrait {
                 ty::TyDeclaration::ConstantDeclaration(decl_id) => {
                     let ty::TyConstantDeclaration { value: expr, .. } =
                         declaration_engine.get_constant(decl_id.clone(), access_span)?;
-                    expr_contains_get_storage_index(declaration_engine, &expr, access_span)
+                    expr.map_or(Ok(false), |expr| {
+                        expr_contains_get_storage_index(declaration_engine, &expr, access_span)
+                    })
                 }
                 // We're already inside a type's impl. So we can't have these
                 // nested functions etc. We just ignore them.
@@ -397,7 +445,8 @@ impl ty::TyImplTrait {
                 | ty::TyDeclaration::AbiDeclaration(_)
                 | ty::TyDeclaration::GenericTypeForFunctionScope { .. }
                 | ty::TyDeclaration::ErrorRecovery(_)
-                | ty::TyDeclaration::StorageDeclaration(_) => Ok(false),
+                | ty::TyDeclaration::StorageDeclaration(_)
+                | ty::TyDeclaration::TypeAliasDeclaration(_) => Ok(false),
             }
         }
 
@@ -449,6 +498,7 @@ impl ty::TyImplTrait {
             type_implementing_for,
             type_implementing_for_span,
             functions,
+            constants,
             block_span,
         } = impl_self;
 
@@ -531,6 +581,32 @@ impl ty::TyImplTrait {
             .with_help_text("")
             .with_type_annotation(type_engine.insert_type(declaration_engine, TypeInfo::Unknown));
 
+        // type check the constants inside of the impl block and make them
+        // available to the methods
+        let mut constants_ids = vec![];
+        for const_decl in constants.into_iter() {
+            let const_decl = check!(
+                ty::TyConstantDeclaration::type_check(ctx.by_ref(), const_decl),
+                continue,
+                warnings,
+                errors
+            );
+            constants_ids.push(declaration_engine.insert_constant(const_decl));
+        }
+        //
+        // We purposefully do not check for errors here because this is a
+        // temporary namespace and the impl block is inserted for real once it
+        // has been type checked.
+        ctx.namespace.insert_trait_implementation(
+            trait_name.clone(),
+            vec![],
+            implementing_for_type_id,
+            &constants_ids,
+            &block_span,
+            true,
+            engines,
+        );
+
         // type check the methods inside of the impl block
         let mut methods = vec![];
         for fn_decl in functions.into_iter() {
@@ -568,6 +644,8 @@ impl ty::TyImplTrait {
             trait_type_arguments: vec![], // this is empty because impl selfs don't support generics on the "Self" trait,
             span: block_span,
             methods: methods_ids,
+            constants: constants_ids,
+            types: vec![], // this is empty because impl selfs don't support associated types
             implementing_for_type_id,
             type_implementing_for_span,
         };
@@ -583,12 +661,17 @@ fn type_check_trait_implementation(
     trait_type_arguments: &[TypeArgument],
     trait_supertraits: &[Supertrait],
     trait_interface_surface: &[DeclarationId],
+    trait_constants: &[DeclarationId],
+    trait_types: &[ty::TyTraitType],
     trait_methods: &[DeclarationId],
     impl_methods: &[FunctionDeclaration],
+    impl_constants: &[ConstantDeclaration],
+    impl_types: &[TraitTypeDeclaration],
     trait_name: &CallPath,
+    interface_decl_name: &Ident,
     block_span: &Span,
     is_contract: bool,
-) -> CompileResult<Vec<DeclarationId>> {
+) -> CompileResult<(Vec<DeclarationId>, Vec<DeclarationId>, Vec<DeclarationId>)> {
     use sway_error::error::InterfaceName;
 
     let mut errors = vec![];
@@ -599,11 +682,12 @@ fn type_check_trait_implementation(
     let engines = ctx.engines();
     let self_type = ctx.self_type();
 
+    // The name of the trait or ABI as it is declared, rather than as it is referred to here.
     let interface_name = || -> InterfaceName {
         if is_contract {
-            InterfaceName::Abi(trait_name.suffix.clone())
+            InterfaceName::Abi(interface_decl_name.clone())
         } else {
-            InterfaceName::Trait(trait_name.suffix.clone())
+            InterfaceName::Trait(interface_decl_name.clone())
         }
     };
 
@@ -652,18 +736,214 @@ fn type_check_trait_implementation(
         engines,
     );
 
-    // This map keeps track of the remaining functions in the interface surface
-    // that still need to be implemented for the trait to be fully implemented.
-    let mut method_checklist: BTreeMap<Ident, ty::TyTraitFn> = BTreeMap::new();
+    // Resolve the associated types given by the impl block, falling back to
+    // the defaults of the trait, and insert them into the namespace as items
+    // of the trait for this type so that `Self::Item` refers to them. The
+    // associated types of the trait are then replaced with these types in the
+    // signatures and methods of the trait.
+    let mut type_checklist: BTreeMap<Ident, ty::TyTraitType> = trait_types
+        .iter()
+        .map(|trait_type| (trait_type.name.clone(), trait_type.clone()))
+        .collect();
+    let mut impld_types: Vec<(ty::TyTraitType, TypeArgument)> = vec![];
+    for impl_type in impl_types.iter() {
+        let trait_type = match type_checklist.remove(&impl_type.name) {
+            Some(trait_type) => trait_type,
+            None => {
+                errors.push(CompileError::TypeNotAPartOfInterfaceSurface {
+                    name: impl_type.name.clone(),
+                    interface_name: interface_name(),
+                    span: impl_type.name.span(),
+                });
+                continue;
+            }
+        };
+        let mut ty = match impl_type.ty_opt.clone() {
+            Some(ty) => ty,
+            None => continue,
+        };
+        ty.type_id = check!(
+            ctx.resolve_type_with_self(ty.type_id, &ty.span, EnforceTypeArguments::Yes, None),
+            continue,
+            warnings,
+            errors
+        );
+        impld_types.push((trait_type, ty));
+    }
+    let mut missing_types = vec![];
+    for (name, trait_type) in type_checklist.into_iter() {
+        match trait_type.ty_opt.clone() {
+            Some(mut ty) => {
+                ty.replace_self_type(engines, self_type);
+                impld_types.push((trait_type, ty));
+            }
+            None => missing_types.push(name),
+        }
+    }
+    if !missing_types.is_empty() {
+        errors.push(CompileError::MissingInterfaceSurfaceTypes {
+            span: block_span.clone(),
+            missing_types: missing_types
+                .into_iter()
+                .map(|ident| ident.as_str().to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        });
+    }
+    let impld_type_ids = impld_types
+        .iter()
+        .map(|(trait_type, ty)| {
+            declaration_engine.insert_type_alias(ty::TyTypeAliasDeclaration {
+                name: trait_type.name.clone(),
+                ty: ty.clone(),
+                type_parameters: vec![],
+                visibility: Visibility::Public,
+                span: trait_type.span.clone(),
+                attributes: trait_type.attributes.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    //
+    // We purposefully do not check for errors here because this is a temporary
+    // namespace and the impl block is inserted for real once it has been type
+    // checked.
+    ctx.namespace.insert_trait_implementation(
+        trait_name.clone(),
+        trait_type_arguments.to_vec(),
+        self_type,
+        &impld_type_ids,
+        &trait_name.span(),
+        false,
+        engines,
+    );
+
+    // This mapping transforms the interface surface and the methods of the
+    // trait into the correct typing for this impl block by using the type
+    // parameters and associated types from the original trait declaration and
+    // the type arguments of the trait name and the associated types of the
+    // impl block that we are type checking.
+    let type_mapping = TypeMapping::from_type_parameters_and_type_arguments(
+        trait_type_parameters
+            .iter()
+            .map(|type_param| type_param.type_id)
+            .chain(impld_types.iter().map(|(trait_type, _)| trait_type.type_id))
+            .collect(),
+        trait_type_arguments
+            .iter()
+            .map(|type_arg| type_arg.type_id)
+            .chain(impld_types.iter().map(|(_, ty)| ty.type_id))
+            .collect(),
+    );
 
     // This map keeps track of the original declaration id's of the original
-    // interface surface.
+    // interface surface and associated constants.
     let mut original_method_ids: BTreeMap<Ident, DeclarationId> = BTreeMap::new();
 
     // This map keeps track of the new declaration ids of the implemented
     // interface surface.
     let mut impld_method_ids: BTreeMap<Ident, DeclarationId> = BTreeMap::new();
 
+    // Type check the associated constants given by the impl block and fill in
+    // the remaining ones from the defaults of the trait.
+    let mut constant_checklist: BTreeMap<Ident, (DeclarationId, ty::TyConstantDeclaration)> =
+        BTreeMap::new();
+    for decl_id in trait_constants.iter() {
+        let const_decl = check!(
+            CompileResult::from(declaration_engine.get_constant(decl_id.clone(), block_span)),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        original_method_ids.insert(const_decl.name.clone(), decl_id.clone());
+        constant_checklist.insert(const_decl.name.clone(), (decl_id.clone(), const_decl));
+    }
+    let mut impld_constant_ids: BTreeMap<Ident, DeclarationId> = BTreeMap::new();
+    for impl_constant in impl_constants.iter() {
+        let impl_constant = check!(
+            ty::TyConstantDeclaration::type_check(ctx.by_ref(), impl_constant.clone()),
+            continue,
+            warnings,
+            errors
+        );
+        if impld_constant_ids.contains_key(&impl_constant.name) {
+            errors.push(CompileError::MultipleDefinitionsOfConstant {
+                name: impl_constant.name.clone(),
+                span: impl_constant.name.span(),
+            });
+            continue;
+        }
+        let (trait_decl_id, mut trait_constant) =
+            match constant_checklist.remove(&impl_constant.name) {
+                Some(trait_constant) => trait_constant,
+                None => {
+                    errors.push(CompileError::ConstantNotAPartOfInterfaceSurface {
+                        name: impl_constant.name.clone(),
+                        interface_name: interface_name(),
+                        span: impl_constant.name.span(),
+                    });
+                    continue;
+                }
+            };
+        trait_constant.replace_self_type(engines, self_type);
+        trait_constant.copy_types(&type_mapping, engines);
+        if !type_engine.look_up_type_id(impl_constant.return_type).eq(
+            &type_engine.look_up_type_id(trait_constant.return_type),
+            engines,
+        ) {
+            errors.push(CompileError::MismatchedTypeInInterfaceSurface {
+                interface_name: interface_name(),
+                span: impl_constant.span.clone(),
+                expected: engines.help_out(trait_constant.return_type).to_string(),
+                given: engines.help_out(impl_constant.return_type).to_string(),
+            });
+            continue;
+        }
+        let name = impl_constant.name.clone();
+        let decl_id = declaration_engine
+            .insert_constant(impl_constant)
+            .with_parent(declaration_engine, trait_decl_id);
+        impld_constant_ids.insert(name, decl_id);
+    }
+    let mut missing_constants = vec![];
+    for (name, (trait_decl_id, mut trait_constant)) in constant_checklist.into_iter() {
+        if trait_constant.value.is_none() {
+            missing_constants.push(name);
+            continue;
+        }
+        trait_constant.replace_self_type(engines, self_type);
+        trait_constant.copy_types(&type_mapping, engines);
+        let decl_id = declaration_engine
+            .insert_constant(trait_constant)
+            .with_parent(declaration_engine, trait_decl_id);
+        impld_constant_ids.insert(name, decl_id);
+    }
+    if !missing_constants.is_empty() {
+        errors.push(CompileError::MissingInterfaceSurfaceConstants {
+            span: block_span.clone(),
+            missing_constants: missing_constants
+                .into_iter()
+                .map(|ident| ident.as_str().to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        });
+    }
+
+    // Insert the associated constants into this namespace so that the
+    // methods defined in the impl block can use them.
+    ctx.namespace.insert_trait_implementation(
+        trait_name.clone(),
+        trait_type_arguments.to_vec(),
+        self_type,
+        &impld_constant_ids.values().cloned().collect::<Vec<_>>(),
+        &trait_name.span(),
+        false,
+        engines,
+    );
+
+    // This map keeps track of the remaining functions in the interface surface
+    // that still need to be implemented for the trait to be fully implemented.
+    let mut method_checklist: BTreeMap<Ident, ty::TyTraitFn> = BTreeMap::new();
+
     for decl_id in trait_interface_surface.iter() {
         let method = check!(
             CompileResult::from(declaration_engine.get_trait_fn(decl_id.clone(), block_span)),
@@ -715,6 +995,7 @@ fn type_check_trait_implementation(
         // `TypeInfo::SelfType` to avoid replacing types in the original trait
         // declaration
         impl_method_signature.replace_self_type(engines, self_type);
+        impl_method_signature.copy_types(&type_mapping, engines);
 
         // ensure this fn decl's parameters and signature lines up with the one
         // in the trait
@@ -891,22 +1172,11 @@ fn type_check_trait_implementation(
 
     // Retrieve the methods defined on the trait declaration and transform
     // them into the correct typing for this impl block by using the type
-    // parameters from the original trait declaration and the type arguments of
-    // the trait name in the current impl block that we are type checking and
-    // using the original decl ids from the interface surface and the new
-    // decl ids from the newly implemented methods.
-    let type_mapping = TypeMapping::from_type_parameters_and_type_arguments(
-        trait_type_parameters
-            .iter()
-            .map(|type_param| type_param.type_id)
-            .collect(),
-        trait_type_arguments
-            .iter()
-            .map(|type_arg| type_arg.type_id)
-            .collect(),
-    );
+    // mapping from above and using the original decl ids from the interface
+    // surface and the new decl ids from the newly implemented methods.
     original_method_ids.extend(supertrait_original_method_ids);
     impld_method_ids.extend(supertrait_impld_method_ids);
+    impld_method_ids.extend(impld_constant_ids.clone());
     let decl_mapping =
         DeclMapping::from_original_and_new_decl_ids(original_method_ids, impld_method_ids);
     for decl_id in trait_methods.iter() {
@@ -930,6 +1200,7 @@ fn type_check_trait_implementation(
     if !method_checklist.is_empty() {
         errors.push(CompileError::MissingInterfaceSurfaceMethods {
             span: block_span.clone(),
+            interface_name: interface_name(),
             missing_functions: method_checklist
                 .into_keys()
                 .map(|ident| ident.as_str().to_string())
@@ -939,7 +1210,15 @@ fn type_check_trait_implementation(
     }
 
     if errors.is_empty() {
-        ok(all_method_ids, warnings, errors)
+        ok(
+            (
+                all_method_ids,
+                impld_constant_ids.into_values().collect(),
+                impld_type_ids,
+            ),
+            warnings,
+            errors,
+        )
     } else {
         err(warnings, errors)
     }
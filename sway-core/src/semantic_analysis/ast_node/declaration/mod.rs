@@ -1,4 +1,5 @@
 mod abi;
+mod constant;
 #[allow(clippy::module_inception)]
 mod declaration;
 mod r#enum;
@@ -9,6 +10,7 @@ mod r#struct;
 mod supertrait;
 mod r#trait;
 mod trait_fn;
+mod type_alias;
 
 pub use abi::*;
 pub use function::*;
@@ -19,3 +21,4 @@ pub use r#trait::*;
 pub use storage::*;
 pub(crate) use supertrait::*;
 pub use trait_fn::*;
+pub use type_alias::*;
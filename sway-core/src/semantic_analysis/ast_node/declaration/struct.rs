@@ -90,6 +90,7 @@ impl ty::TyStructField {
             warnings,
             errors,
         );
+        let initial_type_id = ctx.expand_type_aliases(initial_type_id);
         let field = ty::TyStructField {
             name: field.name,
             type_id: r#type,
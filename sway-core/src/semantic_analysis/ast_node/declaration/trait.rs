@@ -4,13 +4,14 @@ use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{style::is_upper_camel_case, Ident, Spanned};
 
 use crate::{
-    declaration_engine::*,
+    declaration_engine::{declaration_wrapper::DeclarationWrapper, *},
     error::*,
-    language::{parsed::*, ty, CallPath},
+    language::{parsed::*, ty, CallPath, Visibility},
     semantic_analysis::{declaration::insert_supertraits_into_namespace, Mode, TypeCheckContext},
     type_system::*,
 };
 
+/// Map of method and associated constant names to their declarations.
 type MethodMap = BTreeMap<Ident, DeclarationId>;
 
 impl ty::TyTraitDeclaration {
@@ -26,6 +27,8 @@ impl ty::TyTraitDeclaration {
             type_parameters,
             attributes,
             interface_surface,
+            constants,
+            types,
             methods,
             supertraits,
             visibility,
@@ -68,12 +71,93 @@ impl ty::TyTraitDeclaration {
             errors
         );
 
+        // Represent the associated types with generic types, which
+        // implementations of the trait replace with the types they provide.
+        let mut new_types = vec![];
+        let mut type_alias_ids = vec![];
+        for TraitTypeDeclaration {
+            name,
+            attributes,
+            ty_opt,
+            span,
+        } in types.into_iter()
+        {
+            let ty_opt = match ty_opt {
+                Some(mut ty) => {
+                    ty.type_id = check!(
+                        ctx.resolve_type_with_self(
+                            ty.type_id,
+                            &ty.span,
+                            EnforceTypeArguments::Yes,
+                            None
+                        ),
+                        continue,
+                        warnings,
+                        errors
+                    );
+                    Some(ty)
+                }
+                None => None,
+            };
+            let type_id = type_engine.insert_type(
+                declaration_engine,
+                TypeInfo::UnknownGeneric {
+                    name: name.clone(),
+                    trait_constraints: VecSet(vec![]),
+                },
+            );
+            type_alias_ids.push(
+                declaration_engine.insert_type_alias(ty::TyTypeAliasDeclaration {
+                    name: name.clone(),
+                    ty: TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: name.span(),
+                    },
+                    type_parameters: vec![],
+                    visibility: Visibility::Private,
+                    span: span.clone(),
+                    attributes: attributes.clone(),
+                }),
+            );
+            new_types.push(ty::TyTraitType {
+                name,
+                type_id,
+                ty_opt,
+                attributes,
+                span,
+            });
+        }
+
+        // A temporary namespace in which the associated types are items of the
+        // trait for `Self`, so that `Self::Item` refers to them in the
+        // interface surface and the associated constants.
+        let mut surface_namespace = ctx.namespace.clone();
+        let mut surface_ctx = ctx.by_ref().scoped(&mut surface_namespace);
+        //
+        // We purposefully do not check for errors here because this is a
+        // temporary namespace, and the associated types are inserted again
+        // along with the interface surface below.
+        surface_ctx.namespace.insert_trait_implementation(
+            CallPath {
+                prefixes: vec![],
+                suffix: name.clone(),
+                is_absolute: false,
+            },
+            new_type_parameters.iter().map(|x| x.into()).collect(),
+            self_type,
+            &type_alias_ids,
+            &span,
+            false,
+            engines,
+        );
+
         // type check the interface surface
         let mut new_interface_surface = vec![];
-        let mut dummy_interface_surface = vec![];
+        let mut dummy_interface_surface = type_alias_ids;
         for method in interface_surface.into_iter() {
             let method = check!(
-                ty::TyTraitFn::type_check(ctx.by_ref(), method),
+                ty::TyTraitFn::type_check(surface_ctx.by_ref(), method),
                 return err(warnings, errors),
                 warnings,
                 errors
@@ -87,8 +171,22 @@ impl ty::TyTraitDeclaration {
             );
         }
 
-        // insert placeholder functions representing the interface surface
-        // to allow methods to use those functions
+        // type check the associated constants
+        let mut new_constants = vec![];
+        for const_decl in constants.into_iter() {
+            let const_decl = check!(
+                ty::TyConstantDeclaration::type_check(surface_ctx.by_ref(), const_decl),
+                continue,
+                warnings,
+                errors
+            );
+            new_constants.push(declaration_engine.insert_constant(const_decl));
+        }
+        dummy_interface_surface.extend(new_constants.iter().cloned());
+
+        // insert placeholder functions, the associated constants and the
+        // associated types representing the interface surface to allow
+        // methods to use them
         check!(
             ctx.namespace.insert_trait_implementation(
                 CallPath {
@@ -124,6 +222,8 @@ impl ty::TyTraitDeclaration {
             name,
             type_parameters: new_type_parameters,
             interface_surface: new_interface_surface,
+            constants: new_constants,
+            types: new_types,
             methods: new_methods,
             supertraits,
             visibility,
@@ -148,6 +248,7 @@ impl ty::TyTraitDeclaration {
 
         let ty::TyTraitDeclaration {
             interface_surface,
+            constants,
             name,
             ..
         } = self;
@@ -167,20 +268,40 @@ impl ty::TyTraitDeclaration {
             );
             interface_surface_method_ids.insert(method.name, decl_id.clone());
         }
+        for decl_id in constants.iter() {
+            let const_decl = check!(
+                CompileResult::from(
+                    declaration_engine.get_constant(decl_id.clone(), &call_path.span())
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            interface_surface_method_ids.insert(const_decl.name, decl_id.clone());
+        }
 
-        // Retrieve the implemented methods for this type.
+        // Retrieve the implemented methods and constants for this type.
         for decl_id in ctx
             .namespace
-            .get_methods_for_type_and_trait_name(engines, type_id, call_path)
+            .get_items_for_type_and_trait_name(engines, type_id, call_path)
             .into_iter()
         {
-            let method = check!(
-                CompileResult::from(declaration_engine.get_function(decl_id.clone(), &name.span())),
-                return err(warnings, errors),
-                warnings,
-                errors
-            );
-            impld_method_ids.insert(method.name, decl_id);
+            let name = match declaration_engine.look_up_decl_id(decl_id.clone()) {
+                DeclarationWrapper::Constant(const_decl) => const_decl.name,
+                DeclarationWrapper::TypeAlias(_) => continue,
+                _ => {
+                    check!(
+                        CompileResult::from(
+                            declaration_engine.get_function(decl_id.clone(), &name.span())
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    )
+                    .name
+                }
+            };
+            impld_method_ids.insert(name, decl_id);
         }
 
         ok(
@@ -208,6 +329,7 @@ impl ty::TyTraitDeclaration {
 
         let ty::TyTraitDeclaration {
             interface_surface,
+            constants,
             methods,
             type_parameters,
             ..
@@ -228,6 +350,17 @@ impl ty::TyTraitDeclaration {
             );
             interface_surface_method_ids.insert(method.name, decl_id.clone());
         }
+        for decl_id in constants.iter() {
+            let const_decl = check!(
+                CompileResult::from(
+                    declaration_engine.get_constant(decl_id.clone(), &call_path.span())
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            interface_surface_method_ids.insert(const_decl.name, decl_id.clone());
+        }
 
         // Retrieve the trait methods for this trait.
         for decl_id in methods.iter() {
@@ -255,22 +388,31 @@ impl ty::TyTraitDeclaration {
         );
         for decl_id in ctx
             .namespace
-            .get_methods_for_type_and_trait_name(engines, type_id, call_path)
+            .get_items_for_type_and_trait_name(engines, type_id, call_path)
             .into_iter()
         {
-            let mut method = check!(
-                CompileResult::from(
-                    declaration_engine.get_function(decl_id.clone(), &call_path.span())
-                ),
-                return err(warnings, errors),
-                warnings,
-                errors
-            );
-            method.copy_types(&type_mapping, engines);
+            let mut decl = declaration_engine.look_up_decl_id(decl_id.clone());
+            let name = match &decl {
+                DeclarationWrapper::Function(method) => method.name.clone(),
+                DeclarationWrapper::Constant(const_decl) => const_decl.name.clone(),
+                DeclarationWrapper::TypeAlias(_) => continue,
+                _ => {
+                    check!(
+                        CompileResult::from(
+                            declaration_engine.get_function(decl_id.clone(), &call_path.span())
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    continue;
+                }
+            };
+            decl.copy_types(&type_mapping, engines);
             impld_method_ids.insert(
-                method.name.clone(),
+                name,
                 declaration_engine
-                    .insert_function(method)
+                    .insert(decl, decl_id.span())
                     .with_parent(declaration_engine, decl_id),
             );
         }
@@ -297,6 +439,8 @@ impl ty::TyTraitDeclaration {
 
         let ty::TyTraitDeclaration {
             interface_surface,
+            constants,
+            types,
             methods,
             type_parameters,
             ..
@@ -304,17 +448,52 @@ impl ty::TyTraitDeclaration {
 
         let mut all_methods = vec![];
 
+        // The associated types of the trait are those of `type_id`, which are
+        // replaced with the types given by an implementation once `type_id`
+        // is known.
+        let associated_type_ids = types
+            .iter()
+            .map(|trait_type| {
+                engines.te().insert_type(
+                    declaration_engine,
+                    TypeInfo::TraitType {
+                        name: trait_type.name.clone(),
+                        qualifier: type_id,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        for (trait_type, associated_type_id) in types.iter().zip(associated_type_ids.iter()) {
+            all_methods.push(
+                declaration_engine.insert_type_alias(ty::TyTypeAliasDeclaration {
+                    name: trait_type.name.clone(),
+                    ty: TypeArgument {
+                        type_id: *associated_type_id,
+                        initial_type_id: *associated_type_id,
+                        span: trait_type.name.span(),
+                    },
+                    type_parameters: vec![],
+                    visibility: Visibility::Private,
+                    span: trait_type.span.clone(),
+                    attributes: trait_type.attributes.clone(),
+                }),
+            );
+        }
+
         // Retrieve the trait methods for this trait. Transform them into the
-        // correct typing for this impl block by using the type parameters from
-        // the original trait declaration and the given type arguments.
+        // correct typing for this impl block by using the type parameters and
+        // associated types from the original trait declaration and the given
+        // type arguments and the associated types of `type_id`.
         let type_mapping = TypeMapping::from_type_parameters_and_type_arguments(
             type_parameters
                 .iter()
                 .map(|type_param| type_param.type_id)
+                .chain(types.iter().map(|trait_type| trait_type.type_id))
                 .collect(),
             type_arguments
                 .iter()
                 .map(|type_arg| type_arg.type_id)
+                .chain(associated_type_ids)
                 .collect(),
         );
         for decl_id in interface_surface.iter() {
@@ -334,6 +513,23 @@ impl ty::TyTraitDeclaration {
                     .with_parent(ctx.declaration_engine, decl_id.clone()),
             );
         }
+        for decl_id in constants.iter() {
+            let mut const_decl = check!(
+                CompileResult::from(
+                    declaration_engine.get_constant(decl_id.clone(), &trait_name.span())
+                ),
+                continue,
+                warnings,
+                errors
+            );
+            const_decl.replace_self_type(engines, type_id);
+            const_decl.copy_types(&type_mapping, engines);
+            all_methods.push(
+                ctx.declaration_engine
+                    .insert_constant(const_decl)
+                    .with_parent(ctx.declaration_engine, decl_id.clone()),
+            );
+        }
         for decl_id in methods.iter() {
             let mut method = check!(
                 CompileResult::from(
@@ -0,0 +1,77 @@
+use sway_error::error::CompileError;
+
+use crate::{
+    error::*,
+    language::{parsed::*, ty},
+    semantic_analysis::*,
+    type_system::*,
+};
+
+impl ty::TyTypeAliasDeclaration {
+    pub(crate) fn type_check(
+        ctx: TypeCheckContext,
+        decl: TypeAliasDeclaration,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let TypeAliasDeclaration {
+            name,
+            ty,
+            ty_span,
+            type_parameters,
+            visibility,
+            span,
+            attributes,
+        } = decl;
+
+        // create a namespace for the decl, used to create a scope for generics
+        let mut decl_namespace = ctx.namespace.clone();
+        let mut ctx = ctx.scoped(&mut decl_namespace);
+
+        // type check the type parameters
+        // insert them into the namespace
+        let mut new_type_parameters = vec![];
+        for type_parameter in type_parameters.into_iter() {
+            if !type_parameter.trait_constraints.is_empty() {
+                errors.push(CompileError::WhereClauseNotYetSupported {
+                    span: type_parameter.trait_constraints_span,
+                });
+                return err(warnings, errors);
+            }
+            new_type_parameters.push(check!(
+                TypeParameter::type_check(ctx.by_ref(), type_parameter),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+        }
+
+        // resolve the aliased type
+        let type_engine = ctx.type_engine;
+        let declaration_engine = ctx.declaration_engine;
+        let initial_type_id = type_engine.insert_type(declaration_engine, ty);
+        let type_id = check!(
+            ctx.resolve_type_with_self(initial_type_id, &ty_span, EnforceTypeArguments::Yes, None),
+            return err(warnings, errors),
+            warnings,
+            errors,
+        );
+        let initial_type_id = ctx.expand_type_aliases(initial_type_id);
+
+        let decl = ty::TyTypeAliasDeclaration {
+            name,
+            ty: TypeArgument {
+                type_id,
+                initial_type_id,
+                span: ty_span,
+            },
+            type_parameters: new_type_parameters,
+            visibility,
+            span,
+            attributes,
+        };
+
+        ok(decl, warnings, errors)
+    }
+}
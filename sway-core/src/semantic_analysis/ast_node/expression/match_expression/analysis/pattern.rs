@@ -151,6 +151,19 @@ impl Pattern {
                 }
                 Pattern::Tuple(new_elems)
             }
+            ty::TyScrutineeVariant::Or(elems) => {
+                let mut new_elems = PatStack::empty();
+                for elem in elems.into_iter() {
+                    let pat = check!(
+                        Pattern::from_scrutinee(elem),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    new_elems.append(&mut pat.flatten());
+                }
+                Pattern::Or(new_elems)
+            }
             ty::TyScrutineeVariant::EnumScrutinee {
                 call_path, value, ..
             } => {
@@ -638,6 +651,45 @@ impl Pattern {
         }
     }
 
+    /// Converts a witness `Pattern` into the source code of a Sway pattern that matches it.
+    ///
+    /// Returns `None` if the witness can't be written as a pattern, e.g. a range that holds
+    /// more than one number.
+    pub(crate) fn to_source(&self) -> Option<String> {
+        let source = match self {
+            Pattern::Wildcard => "_".to_string(),
+            Pattern::U8(range) => range.single_value()?.to_string(),
+            Pattern::U16(range) => range.single_value()?.to_string(),
+            Pattern::U32(range) => range.single_value()?.to_string(),
+            Pattern::U64(range) => range.single_value()?.to_string(),
+            Pattern::Numeric(range) => range.single_value()?.to_string(),
+            Pattern::B256(bytes) => bytes.iter().fold("0x".to_string(), |mut s, byte| {
+                let _ = write!(s, "{:02x}", byte);
+                s
+            }),
+            Pattern::Boolean(b) => b.to_string(),
+            Pattern::String(s) => format!("\"{}\"", s),
+            Pattern::Struct(struct_pattern) => struct_pattern.to_source()?,
+            Pattern::Enum(enum_pattern) => enum_pattern.to_source()?,
+            Pattern::Tuple(elems) => {
+                let elems = elems
+                    .iter()
+                    .map(Pattern::to_source)
+                    .collect::<Option<Vec<_>>>()?;
+                match elems.as_slice() {
+                    [elem] => format!("({},)", elem),
+                    elems => format!("({})", elems.join(", ")),
+                }
+            }
+            Pattern::Or(elems) => elems
+                .iter()
+                .map(Pattern::to_source)
+                .collect::<Option<Vec<_>>>()?
+                .join(" | "),
+        };
+        Some(source)
+    }
+
     pub(crate) fn matches_type_info(&self, type_info: &TypeInfo) -> bool {
         match (self, type_info) {
             (
@@ -702,7 +754,11 @@ impl fmt::Display for Pattern {
                 builder.push(')');
                 builder
             }
-            Pattern::Or(_) => unreachable!(),
+            Pattern::Or(elems) => elems
+                .iter()
+                .map(|x| format!("{}", x))
+                .collect::<Vec<_>>()
+                .join(" | "),
         };
         write!(f, "{}", s)
     }
@@ -760,6 +816,35 @@ impl StructPattern {
     }
 }
 
+impl StructPattern {
+    /// Converts the `StructPattern` into the source code of a Sway pattern. Trailing wildcard
+    /// fields are replaced by `..`.
+    fn to_source(&self) -> Option<String> {
+        let fields = match self
+            .fields
+            .iter()
+            .rposition(|(_, pat)| *pat != Pattern::Wildcard)
+        {
+            Some(last) => {
+                let mut fields = self.fields[..=last]
+                    .iter()
+                    .map(|(name, pat)| Some(format!("{}: {}", name, pat.to_source()?)))
+                    .collect::<Option<Vec<_>>>()?;
+                if last + 1 < self.fields.len() {
+                    fields.push("..".to_string());
+                }
+                fields
+            }
+            None => self
+                .fields
+                .iter()
+                .map(|(name, _)| format!("{}: _", name))
+                .collect(),
+        };
+        Some(format!("{} {{ {} }}", self.struct_name, fields.join(", ")))
+    }
+}
+
 impl fmt::Display for StructPattern {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut builder = String::new();
@@ -866,6 +951,21 @@ impl std::cmp::PartialOrd for EnumPattern {
     }
 }
 
+impl EnumPattern {
+    /// Converts the `EnumPattern` into the source code of a Sway pattern. Variants of the unit
+    /// type are written without a value.
+    fn to_source(&self) -> Option<String> {
+        let value = match &*self.value {
+            Pattern::Tuple(elems) if elems.is_empty() => String::new(),
+            value => format!("({})", value.to_source()?),
+        };
+        Some(format!(
+            "{}::{}{}",
+            self.enum_name, self.variant_name, value
+        ))
+    }
+}
+
 impl fmt::Display for EnumPattern {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut builder = String::new();
@@ -878,3 +978,74 @@ impl fmt::Display for EnumPattern {
         write!(f, "{}", builder)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_variant(enum_name: &str, variant_name: &str) -> Pattern {
+        Pattern::Enum(EnumPattern {
+            enum_name: enum_name.to_string(),
+            variant_name: variant_name.to_string(),
+            value: Box::new(Pattern::Tuple(PatStack::empty())),
+        })
+    }
+
+    #[test]
+    fn witnesses_to_source() {
+        assert_eq!(
+            unit_variant("Color", "Red").to_source().as_deref(),
+            Some("Color::Red")
+        );
+        let green = Pattern::Enum(EnumPattern {
+            enum_name: "Color".to_string(),
+            variant_name: "Green".to_string(),
+            value: Box::new(Pattern::Wildcard),
+        });
+        assert_eq!(green.to_source().as_deref(), Some("Color::Green(_)"));
+
+        let point = |x, y| {
+            Pattern::Struct(StructPattern::new(
+                "Point".to_string(),
+                vec![("x".to_string(), x), ("y".to_string(), y)],
+            ))
+        };
+        let zero = Pattern::U64(Range::from_single(0));
+        assert_eq!(
+            point(zero.clone(), Pattern::Wildcard)
+                .to_source()
+                .as_deref(),
+            Some("Point { x: 0, .. }")
+        );
+        assert_eq!(
+            point(Pattern::Wildcard, zero).to_source().as_deref(),
+            Some("Point { x: _, y: 0 }")
+        );
+        assert_eq!(
+            point(Pattern::Wildcard, Pattern::Wildcard)
+                .to_source()
+                .as_deref(),
+            Some("Point { x: _, y: _ }")
+        );
+
+        let tuple = Pattern::Tuple(PatStack::from(vec![
+            Pattern::Boolean(true),
+            Pattern::U8(Range::from_single(u8::MAX)),
+        ]));
+        assert_eq!(tuple.to_source().as_deref(), Some("(true, 255)"));
+        let single = Pattern::Tuple(PatStack::from(vec![Pattern::Boolean(false)]));
+        assert_eq!(single.to_source().as_deref(), Some("(false,)"));
+        let or = Pattern::Or(PatStack::from(vec![
+            unit_variant("Color", "Red"),
+            unit_variant("Color", "Blue"),
+        ]));
+        assert_eq!(or.to_source().as_deref(), Some("Color::Red | Color::Blue"));
+
+        // Ranges of more than one number can't be written as a pattern.
+        let range = Pattern::Tuple(PatStack::from(vec![
+            Pattern::Boolean(true),
+            Pattern::U64(Range::u64()),
+        ]));
+        assert_eq!(range.to_source(), None);
+    }
+}
@@ -176,6 +176,15 @@ where
         }
     }
 
+    /// Returns the value held by a `Range<T>` whose lower and upper bounds are equal.
+    pub(crate) fn single_value(&self) -> Option<&T> {
+        if self.first == self.last {
+            Some(&self.first)
+        } else {
+            None
+        }
+    }
+
     /// Creates a `Range<T>` and ensures that it is a "valid `Range<T>`"
     /// (i.e.) that `first` is <= to `last`
     fn from_double(first: T, last: T, span: &Span) -> CompileResult<Range<T>> {
@@ -197,10 +197,20 @@ use super::{
 /// is an imaginary additional wildcard pattern. The match expression is
 /// exhaustive if the imaginary additional wildcard pattern has an empty
 /// `WitnessReport`.
+///
+/// # Guards
+///
+/// A match arm with a guard only catches the values that its pattern catches
+/// *and* for which the guard holds, which can not be known statically. So a
+/// guarded arm is reachable when its pattern is useful, but it is never added
+/// to *P*, and it does not help the match expression be exhaustive.
+///
+/// Each arm is given as its scrutinee paired with whether or not it has a
+/// guard.
 pub(crate) fn check_match_expression_usefulness(
     engines: Engines<'_>,
     type_id: TypeId,
-    scrutinees: Vec<ty::TyScrutinee>,
+    arms: Vec<(ty::TyScrutinee, bool)>,
     span: Span,
 ) -> CompileResult<(WitnessReport, Vec<ReachableReport>)> {
     let mut warnings = vec![];
@@ -213,7 +223,7 @@ pub(crate) fn check_match_expression_usefulness(
         warnings,
         errors
     );
-    for scrutinee in scrutinees.into_iter() {
+    for (scrutinee, is_guarded) in arms.into_iter() {
         let pat = check!(
             Pattern::from_scrutinee(scrutinee.clone()),
             return err(warnings, errors),
@@ -227,7 +237,9 @@ pub(crate) fn check_match_expression_usefulness(
             warnings,
             errors
         );
-        matrix.push(v);
+        if !is_guarded {
+            matrix.push(v);
+        }
         // if an arm has witnesses to its usefulness then it is reachable
         arms_reachability.push(ReachableReport::new(
             witness_report.has_witnesses(),
@@ -585,13 +597,16 @@ fn is_useful_constructed(
 /// *(r₁ | ... | rₐ)*.
 ///
 /// Compute the witness report for each element of q and aggregate them
-/// together.
+/// together. Every element is checked against *P* extended with the elements
+/// before it, so that an element is only useful if it catches something that
+/// neither the previous rows nor the previous elements catch.
 ///
 /// ---
 ///
 /// 1. For each *k* 0..*a* compute *q'* as \[*rₖ q₂ ... qₙ*\].
 /// 2. Compute the witnesses from *U(P, q')*
-/// 3. Aggregate the witnesses from every *U(P, q')*
+/// 3. Aggregate the witnesses from every *U(P, q')*, column by column, into a
+///    single row of witnesses
 fn is_useful_or(
     engines: Engines<'_>,
     factory: &ConstructorFactory,
@@ -609,7 +624,7 @@ fn is_useful_or(
         errors
     );
     let mut p = p.clone();
-    let mut witness_report = WitnessReport::Witnesses(PatStack::empty());
+    let mut witnesses: Vec<PatStack> = vec![];
     for pat in pats.into_iter() {
         // 1. For each *k* 0..*a* compute *q'* as \[*rₖ q₂ ... qₙ*\].
         let mut v = PatStack::from_pattern(pat);
@@ -624,10 +639,36 @@ fn is_useful_or(
         );
         p.push(v);
 
-        // 3. Aggregate the witnesses from every *U(P, q')*
-        witness_report = WitnessReport::join_witness_reports(witness_report, wr);
+        if let WitnessReport::Witnesses(wits) = wr {
+            witnesses.push(wits);
+        }
+    }
+
+    // 3. Aggregate the witnesses from every *U(P, q')*, column by column, into a
+    //    single row of witnesses
+    if witnesses.is_empty() {
+        return ok(WitnessReport::NoWitnesses, warnings, errors);
+    }
+    let mut columns = vec![PatStack::empty(); q.len()];
+    for wits in witnesses.into_iter() {
+        for (column, pat) in columns.iter_mut().zip(wits.into_iter()) {
+            for pat in pat.flatten().into_iter() {
+                if !column.contains(&pat) {
+                    column.push(pat);
+                }
+            }
+        }
+    }
+    let mut witness_report = PatStack::empty();
+    for column in columns.into_iter() {
+        witness_report.push(check!(
+            Pattern::from_pat_stack(column, span),
+            return err(warnings, errors),
+            warnings,
+            errors
+        ));
     }
-    ok(witness_report, warnings, errors)
+    ok(WitnessReport::Witnesses(witness_report), warnings, errors)
 }
 
 /// Given a `Matrix` *P*, constructs the default `Matrix` *D(P). This is done by
@@ -739,19 +780,8 @@ fn compute_specialized_matrix(c: &Pattern, p: &Matrix, span: &Span) -> CompileRe
             errors
         ));
     }
-    let (m, _) = check!(
-        s_c_p.m_n(span),
-        return err(warnings, errors),
-        warnings,
-        errors
-    );
-    if p.is_a_vector() && m > 1 {
-        errors.push(CompileError::Internal(
-            "S(c,p) must be a vector",
-            span.clone(),
-        ));
-        return err(warnings, errors);
-    }
+    // a row that starts with an or-pattern is specialized into one row per alternative, so
+    // S(c, P) may have more rows than P
     ok(s_c_p, warnings, errors)
 }
 
@@ -19,24 +19,6 @@ pub(crate) enum WitnessReport {
 }
 
 impl WitnessReport {
-    /// Joins two `WitnessReport`s together.
-    pub(crate) fn join_witness_reports(a: WitnessReport, b: WitnessReport) -> Self {
-        match (a, b) {
-            (WitnessReport::NoWitnesses, WitnessReport::NoWitnesses) => WitnessReport::NoWitnesses,
-            (WitnessReport::NoWitnesses, WitnessReport::Witnesses(wits)) => {
-                WitnessReport::Witnesses(wits)
-            }
-            (WitnessReport::Witnesses(wits), WitnessReport::NoWitnesses) => {
-                WitnessReport::Witnesses(wits)
-            }
-            (WitnessReport::Witnesses(wits1), WitnessReport::Witnesses(mut wits2)) => {
-                let mut wits = wits1;
-                wits.append(&mut wits2);
-                WitnessReport::Witnesses(wits)
-            }
-        }
-    }
-
     /// Given a `WitnessReport` *wr* and a constructor *c* with *a* number of
     /// sub-patterns, creates a new `Pattern` *p* and a new `WitnessReport`
     /// *wr'*. *p* is created by applying *c* to the first *a* elements of *wr*.
@@ -101,6 +83,18 @@ impl WitnessReport {
             WitnessReport::Witnesses(_) => true, // !witnesses.is_empty()
         }
     }
+
+    /// Converts each witness into the source code of a pattern that matches it, in the same
+    /// order as they are displayed. Returns `None` if any of the witnesses can't be written as a
+    /// pattern.
+    pub(crate) fn to_pattern_sources(&self) -> Option<Vec<String>> {
+        match self {
+            WitnessReport::NoWitnesses => Some(vec![]),
+            WitnessReport::Witnesses(witnesses) => {
+                witnesses.flatten().iter().map(Pattern::to_source).collect()
+            }
+        }
+    }
 }
 
 impl fmt::Display for WitnessReport {
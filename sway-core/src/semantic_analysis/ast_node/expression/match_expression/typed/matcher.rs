@@ -1,20 +1,31 @@
 use crate::{
     error::{err, ok},
-    language::{ty, Literal},
+    language::{ty, LazyOp, Literal},
     semantic_analysis::{
         ast_node::expression::typed_expression::{
-            instantiate_struct_field_access, instantiate_tuple_index_access,
-            instantiate_unsafe_downcast,
+            instantiate_if_expression, instantiate_lazy_operator, instantiate_struct_field_access,
+            instantiate_tuple_index_access, instantiate_unsafe_downcast,
         },
         TypeCheckContext,
     },
-    CompileResult, Ident, TypeId,
+    CompileError, CompileResult, Ident, TypeId, TypeInfo,
 };
 
-use sway_types::span::Span;
+use sway_types::{span::Span, Spanned};
+
+/// A requirement that a desugared if expression must include in the conditional.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub(crate) enum MatchReq {
+    /// The two expressions must be equal.
+    Eq(ty::TyExpression, ty::TyExpression),
+    /// At least one of the lists of requirements must hold, one list for every
+    /// alternative of an or-pattern.
+    Or(Vec<MatchReqMap>),
+}
 
 /// List of requirements that a desugared if expression must include in the conditional.
-pub(crate) type MatchReqMap = Vec<(ty::TyExpression, ty::TyExpression)>;
+pub(crate) type MatchReqMap = Vec<MatchReq>;
 /// List of variable declarations that must be placed inside of the body of the if expression.
 pub(crate) type MatchDeclMap = Vec<(Ident, ty::TyExpression)>;
 /// This is the result type given back by the matcher.
@@ -99,7 +110,86 @@ pub(crate) fn matcher(
             match_enum(ctx, exp, variant, *value, span)
         }
         ty::TyScrutineeVariant::Tuple(elems) => match_tuple(ctx, exp, elems, span),
+        ty::TyScrutineeVariant::Or(elems) => match_or(ctx, exp, elems, span),
+    }
+}
+
+/// Builds the boolean expression that checks all of the requirements in `match_req_map`, or
+/// `None` if there are no requirements, in which case the match always succeeds.
+pub(crate) fn match_req_map_to_conditional(
+    mut ctx: TypeCheckContext,
+    match_req_map: MatchReqMap,
+) -> CompileResult<Option<ty::TyExpression>> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let declaration_engine = ctx.declaration_engine;
+
+    // create the conditional, in reverse
+    let mut conditional: Option<ty::TyExpression> = None;
+    for req in match_req_map.into_iter().rev() {
+        let new_condition = match req {
+            MatchReq::Eq(left_req, right_req) => {
+                let joined_span = Span::join(left_req.span.clone(), right_req.span.clone());
+                let args = vec![left_req, right_req];
+                check!(
+                    ty::TyExpression::core_ops_eq(ctx.by_ref(), args, joined_span),
+                    continue,
+                    warnings,
+                    errors
+                )
+            }
+            MatchReq::Or(alternatives) => {
+                let mut alternative_conditionals = vec![];
+                for alternative in alternatives.into_iter() {
+                    alternative_conditionals.push(check!(
+                        match_req_map_to_conditional(ctx.by_ref(), alternative),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+                // if any of the alternatives always matches then so does the or-pattern
+                let alternative_conditionals = match alternative_conditionals
+                    .into_iter()
+                    .collect::<Option<Vec<_>>>()
+                {
+                    Some(alternative_conditionals) => alternative_conditionals,
+                    None => continue,
+                };
+                match alternative_conditionals.into_iter().reduce(|lhs, rhs| {
+                    let joined_span = Span::join(lhs.span.clone(), rhs.span.clone());
+                    instantiate_lazy_operator(
+                        LazyOp::Or,
+                        lhs,
+                        rhs,
+                        type_engine.insert_type(declaration_engine, TypeInfo::Boolean),
+                        joined_span,
+                    )
+                }) {
+                    Some(new_condition) => new_condition,
+                    None => continue,
+                }
+            }
+        };
+        conditional = Some(match conditional {
+            Some(inner_condition) => {
+                let joined_span =
+                    Span::join(inner_condition.span.clone(), new_condition.span.clone());
+                instantiate_lazy_operator(
+                    LazyOp::And,
+                    new_condition,
+                    inner_condition,
+                    type_engine.insert_type(declaration_engine, TypeInfo::Boolean),
+                    joined_span,
+                )
+            }
+            None => new_condition,
+        });
     }
+
+    ok(conditional, warnings, errors)
 }
 
 fn match_literal(
@@ -107,7 +197,7 @@ fn match_literal(
     scrutinee: Literal,
     span: Span,
 ) -> CompileResult<MatcherResult> {
-    let match_req_map = vec![(
+    let match_req_map = vec![MatchReq::Eq(
         exp.to_owned(),
         ty::TyExpression {
             expression: ty::TyExpressionVariant::Literal(scrutinee),
@@ -132,7 +222,7 @@ fn match_constant(
     scrutinee_type_id: TypeId,
     span: Span,
 ) -> CompileResult<MatcherResult> {
-    let match_req_map = vec![(
+    let match_req_map = vec![MatchReq::Eq(
         exp.to_owned(),
         ty::TyExpression {
             expression: ty::TyExpressionVariant::VariableExpression {
@@ -247,3 +337,133 @@ fn match_tuple(
     }
     ok((match_req_map, match_decl_map), warnings, errors)
 }
+
+/// An or-pattern matches if any of its alternatives match. Every alternative must bind the
+/// same variables, and each variable is bound to the value from the first alternative that
+/// matches, i.e., for `A(x) | B(x)`:
+///
+/// ```ignore
+/// let x = if <A(x) matches> { <x from A(x)> } else { <x from B(x)> };
+/// ```
+fn match_or(
+    mut ctx: TypeCheckContext,
+    exp: &ty::TyExpression,
+    elems: Vec<ty::TyScrutinee>,
+    span: Span,
+) -> CompileResult<MatcherResult> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let declaration_engine = ctx.declaration_engine;
+
+    let mut alternatives = vec![];
+    for elem in elems.into_iter() {
+        let elem_span = elem.span.clone();
+        let (match_req_map, match_decl_map) = check!(
+            matcher(ctx.by_ref(), exp, elem),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        alternatives.push((match_req_map, match_decl_map, elem_span));
+    }
+
+    // make sure that every alternative binds the same variables, with the same types
+    let (_, first_match_decl_map, _) = match alternatives.first() {
+        Some(first) => first,
+        None => {
+            errors.push(CompileError::Internal("found empty or-pattern", span));
+            return err(warnings, errors);
+        }
+    };
+    let first_match_decl_map = first_match_decl_map.clone();
+    for (_, match_decl_map, elem_span) in alternatives.iter() {
+        for (name, _) in first_match_decl_map.iter() {
+            if !match_decl_map.iter().any(|(other, _)| other == name) {
+                errors.push(
+                    CompileError::MatchOrPatternVariableNotBoundInAllAlternatives {
+                        variable: name.clone(),
+                        span: elem_span.clone(),
+                    },
+                );
+            }
+        }
+        for (name, decl) in match_decl_map.iter() {
+            match first_match_decl_map.iter().find(|(first, _)| first == name) {
+                Some((_, first_decl)) => append!(
+                    type_engine.unify(
+                        declaration_engine,
+                        decl.return_type,
+                        first_decl.return_type,
+                        &name.span(),
+                        "a variable must have the same type in all alternatives of an or-pattern"
+                    ),
+                    warnings,
+                    errors
+                ),
+                None => errors.push(
+                    CompileError::MatchOrPatternVariableNotBoundInAllAlternatives {
+                        variable: name.clone(),
+                        span: alternatives[0].2.clone(),
+                    },
+                ),
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return err(warnings, errors);
+    }
+
+    // bind every variable to the value from the first alternative that matches, building the
+    // if expressions in reverse
+    let mut match_decl_map: MatchDeclMap = vec![];
+    for (name, first_decl) in first_match_decl_map.into_iter() {
+        let mut decl: Option<ty::TyExpression> = None;
+        for (match_req_map, alternative_match_decl_map, _) in alternatives.iter().rev() {
+            let alternative_decl = alternative_match_decl_map
+                .iter()
+                .find(|(other, _)| *other == name)
+                .map(|(_, decl)| decl.clone())
+                .unwrap_or_else(|| first_decl.clone());
+            let conditional = check!(
+                match_req_map_to_conditional(ctx.by_ref(), match_req_map.clone()),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            decl = Some(match (decl, conditional) {
+                (Some(else_decl), Some(conditional)) => {
+                    let decl_span = alternative_decl.span.clone();
+                    let ctx = ctx.by_ref().with_type_annotation(first_decl.return_type);
+                    check!(
+                        instantiate_if_expression(
+                            ctx,
+                            conditional,
+                            alternative_decl,
+                            Some(else_decl),
+                            decl_span
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    )
+                }
+                // the last alternative that is tried does not need to be checked, and an
+                // alternative that always matches shadows the ones after it
+                (_, _) => alternative_decl,
+            });
+        }
+        if let Some(decl) = decl {
+            match_decl_map.push((name, decl));
+        }
+    }
+
+    let match_req_map = vec![MatchReq::Or(
+        alternatives
+            .into_iter()
+            .map(|(match_req_map, _, _)| match_req_map)
+            .collect(),
+    )];
+    ok((match_req_map, match_decl_map), warnings, errors)
+}
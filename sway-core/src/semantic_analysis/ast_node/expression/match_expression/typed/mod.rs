@@ -40,4 +40,4 @@ mod typed_match_branch;
 mod typed_match_expression;
 mod typed_scrutinee;
 
-pub(crate) use matcher::MatchReqMap;
+pub(crate) use matcher::{MatchReq, MatchReqMap};
@@ -21,6 +21,7 @@ impl ty::TyMatchBranch {
 
         let MatchBranch {
             scrutinee,
+            guard,
             result,
             span: branch_span,
         } = branch;
@@ -71,6 +72,43 @@ impl ty::TyMatchBranch {
             });
         }
 
+        // type check the guard, which is evaluated in its own code block that starts with the
+        // same variable declarations as the branch result
+        let typed_guard = match guard {
+            Some(guard) => {
+                let ctx = ctx
+                    .by_ref()
+                    .with_help_text("match guards must be boolean expressions")
+                    .with_type_annotation(
+                        type_engine.insert_type(declaration_engine, TypeInfo::Boolean),
+                    );
+                let typed_guard = check!(
+                    ty::TyExpression::type_check(ctx, guard),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                let ty::TyExpression {
+                    return_type: guard_return_type,
+                    span: guard_span,
+                    ..
+                } = typed_guard.clone();
+                let mut guard_contents = code_block_contents.clone();
+                guard_contents.push(ty::TyAstNode {
+                    content: ty::TyAstNodeContent::ImplicitReturnExpression(typed_guard),
+                    span: guard_span.clone(),
+                });
+                Some(ty::TyExpression {
+                    expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock {
+                        contents: guard_contents,
+                    }),
+                    return_type: guard_return_type,
+                    span: guard_span,
+                })
+            }
+            None => None,
+        };
+
         // type check the branch result
         let typed_result = {
             let ctx = ctx.by_ref().with_type_annotation(
@@ -131,6 +169,7 @@ impl ty::TyMatchBranch {
         // return!
         let typed_branch = ty::TyMatchBranch {
             conditions: match_req_map,
+            guard: typed_guard,
             result: new_result,
             span: branch_span,
         };
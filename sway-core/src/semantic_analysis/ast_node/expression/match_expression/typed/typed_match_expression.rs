@@ -12,6 +12,8 @@ use crate::{
     CompileError, CompileResult, TypeInfo,
 };
 
+use super::matcher::match_req_map_to_conditional;
+
 impl ty::TyMatchExpression {
     pub(crate) fn type_check(
         ctx: TypeCheckContext,
@@ -67,35 +69,34 @@ impl ty::TyMatchExpression {
 
         // for every branch of the match expression, in reverse
         for ty::TyMatchBranch {
-            conditions, result, ..
+            conditions,
+            guard,
+            result,
+            ..
         } in branches.into_iter().rev()
         {
-            // create the conditional that will act as the conditional for the if statement, in reverse
-            let mut conditional: Option<ty::TyExpression> = None;
-            for (left_req, right_req) in conditions.into_iter().rev() {
-                let joined_span = Span::join(left_req.span.clone(), right_req.span.clone());
-                let args = vec![left_req, right_req];
-                let new_condition = check!(
-                    ty::TyExpression::core_ops_eq(ctx.by_ref(), args, joined_span),
-                    continue,
-                    warnings,
-                    errors
-                );
-                conditional = Some(match conditional {
-                    Some(inner_condition) => {
-                        let joined_span =
-                            Span::join(inner_condition.span.clone(), new_condition.span.clone());
-                        instantiate_lazy_operator(
-                            LazyOp::And,
-                            new_condition,
-                            inner_condition,
-                            type_engine.insert_type(declaration_engine, TypeInfo::Boolean),
-                            joined_span,
-                        )
-                    }
-                    None => new_condition,
-                });
-            }
+            // create the conditional that will act as the conditional for the if statement
+            let conditional = check!(
+                match_req_map_to_conditional(ctx.by_ref(), conditions),
+                continue,
+                warnings,
+                errors
+            );
+
+            // the guard is only checked once the pattern is known to match
+            let conditional = match (conditional, guard) {
+                (Some(conditional), Some(guard)) => {
+                    let joined_span = Span::join(conditional.span.clone(), guard.span.clone());
+                    Some(instantiate_lazy_operator(
+                        LazyOp::And,
+                        conditional,
+                        guard,
+                        type_engine.insert_type(declaration_engine, TypeInfo::Boolean),
+                        joined_span,
+                    ))
+                }
+                (conditional, guard) => conditional.or(guard),
+            };
 
             // add to the if expression that we are building using the result component
             // of the match branch and using the conditional that we just built
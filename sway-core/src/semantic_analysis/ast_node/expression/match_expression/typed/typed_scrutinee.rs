@@ -43,6 +43,7 @@ impl ty::TyScrutinee {
                 span,
             } => type_check_enum(ctx, call_path, *value, span),
             Scrutinee::Tuple { elems, span } => type_check_tuple(ctx, elems, span),
+            Scrutinee::Or { elems, span } => type_check_or(ctx, elems, span),
         }
     }
 }
@@ -67,7 +68,11 @@ fn type_check_variable(
                 warnings,
                 errors
             );
-            let value = match constant_decl.value.extract_literal_value() {
+            let (value, value_type) = match constant_decl
+                .value
+                .as_ref()
+                .and_then(|value| Some((value.extract_literal_value()?, value.return_type)))
+            {
                 Some(value) => value,
                 None => {
                     errors.push(CompileError::Unimplemented(
@@ -78,12 +83,8 @@ fn type_check_variable(
                 }
             };
             ty::TyScrutinee {
-                variant: ty::TyScrutineeVariant::Constant(
-                    name,
-                    value,
-                    constant_decl.value.return_type,
-                ),
-                type_id: constant_decl.value.return_type,
+                variant: ty::TyScrutineeVariant::Constant(name, value, value_type),
+                type_id: value_type,
                 span,
             }
         }
@@ -320,3 +321,49 @@ fn type_check_tuple(
 
     ok(typed_scrutinee, warnings, errors)
 }
+
+fn type_check_or(
+    mut ctx: TypeCheckContext,
+    elems: Vec<Scrutinee>,
+    span: Span,
+) -> CompileResult<ty::TyScrutinee> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let declaration_engine = ctx.declaration_engine;
+
+    // every alternative is matched against the same value, so they must all have the same type
+    let type_id = type_engine.insert_type(declaration_engine, TypeInfo::Unknown);
+    let mut typed_elems = vec![];
+    for elem in elems.into_iter() {
+        let typed_elem = check!(
+            ty::TyScrutinee::type_check(ctx.by_ref(), elem),
+            continue,
+            warnings,
+            errors
+        );
+        append!(
+            type_engine.unify(
+                declaration_engine,
+                typed_elem.type_id,
+                type_id,
+                &typed_elem.span,
+                "all alternatives of an or-pattern must have the same type"
+            ),
+            warnings,
+            errors
+        );
+        typed_elems.push(typed_elem);
+    }
+    if !errors.is_empty() {
+        return err(warnings, errors);
+    }
+    let typed_scrutinee = ty::TyScrutinee {
+        variant: ty::TyScrutineeVariant::Or(typed_elems),
+        type_id,
+        span,
+    };
+
+    ok(typed_scrutinee, warnings, errors)
+}
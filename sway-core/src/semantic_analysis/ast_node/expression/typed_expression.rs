@@ -1,17 +1,21 @@
+mod closure;
 mod constant_declaration;
 mod enum_instantiation;
+mod for_loop;
 mod function_application;
 mod if_expression;
 mod lazy_operator;
 mod method_application;
 mod struct_field_access;
+mod try_operator;
 mod tuple_index_access;
 mod unsafe_downcast;
 
 use self::constant_declaration::instantiate_constant_decl;
 pub(crate) use self::{
-    enum_instantiation::*, function_application::*, if_expression::*, lazy_operator::*,
-    method_application::*, struct_field_access::*, tuple_index_access::*, unsafe_downcast::*,
+    closure::*, enum_instantiation::*, for_loop::*, function_application::*, if_expression::*,
+    lazy_operator::*, method_application::*, struct_field_access::*, try_operator::*,
+    tuple_index_access::*, unsafe_downcast::*,
 };
 
 use crate::{
@@ -171,8 +175,15 @@ impl ty::TyExpression {
                 let StructExpression {
                     call_path_binding,
                     fields,
+                    base,
                 } = *struct_expression;
-                Self::type_check_struct_expression(ctx.by_ref(), call_path_binding, fields, span)
+                Self::type_check_struct_expression(
+                    ctx.by_ref(),
+                    call_path_binding,
+                    fields,
+                    base,
+                    span,
+                )
             }
             ExpressionKind::Subfield(SubfieldExpression {
                 prefix,
@@ -244,6 +255,7 @@ impl ty::TyExpression {
             ExpressionKind::WhileLoop(WhileLoopExpression { condition, body }) => {
                 Self::type_check_while_loop(ctx.by_ref(), *condition, body, span)
             }
+            ExpressionKind::ForLoop(for_loop) => type_check_for_loop(ctx.by_ref(), for_loop, span),
             ExpressionKind::Break => {
                 let expr = ty::TyExpression {
                     expression: ty::TyExpressionVariant::Break,
@@ -298,6 +310,10 @@ impl ty::TyExpression {
                 };
                 ok(typed_expr, warnings, errors)
             }
+            ExpressionKind::Try(expr) => type_check_try(ctx.by_ref(), *expr, span),
+            ExpressionKind::Ref(expr) => Self::type_check_ref(ctx.by_ref(), *expr, span),
+            ExpressionKind::Deref(expr) => Self::type_check_deref(ctx.by_ref(), *expr, span),
+            ExpressionKind::Closure(closure) => type_check_closure(ctx.by_ref(), *closure, span),
         };
         let mut typed_expression = match res.value {
             Some(r) => r,
@@ -430,6 +446,10 @@ impl ty::TyExpression {
                     span,
                 }
             }
+            Some(ty::TyDeclaration::FunctionDeclaration(decl_id)) => {
+                let decl_id = decl_id.clone();
+                return type_check_function_value(ctx, name, decl_id, span);
+            }
             Some(ty::TyDeclaration::AbiDeclaration(decl_id)) => {
                 let decl = check!(
                     CompileResult::from(declaration_engine.get_abi(decl_id.clone(), &span)),
@@ -471,6 +491,21 @@ impl ty::TyExpression {
 
         let declaration_engine = ctx.declaration_engine;
 
+        // a variable holding a closure or a function is called like a function
+        let call_path = &call_path_binding.inner;
+        if call_path.prefixes.is_empty()
+            && !call_path.is_absolute
+            && call_path_binding.type_arguments.is_empty()
+        {
+            if let Some(ty::TyDeclaration::VariableDeclaration(decl)) =
+                ctx.namespace.resolve_symbol(&call_path.suffix).value
+            {
+                let name = call_path.suffix.clone();
+                let decl = (**decl).clone();
+                return type_check_closure_call(ctx, name, decl, arguments, span);
+            }
+        }
+
         // type check the declaration
         let unknown_decl = check!(
             TypeBinding::type_check_with_ident(&mut call_path_binding, ctx.by_ref()),
@@ -667,8 +702,17 @@ impl ty::TyExpression {
         };
 
         // check to see if the match expression is exhaustive and if all match arms are reachable
+        let arms = typed_scrutinees
+            .into_iter()
+            .zip(
+                typed_match_expression
+                    .branches
+                    .iter()
+                    .map(|branch| branch.guard.is_some()),
+            )
+            .collect();
         let (witness_report, arms_reachability) = check!(
-            check_match_expression_usefulness(engines, type_id, typed_scrutinees, span.clone()),
+            check_match_expression_usefulness(engines, type_id, arms, span.clone()),
             return err(warnings, errors),
             warnings,
             errors
@@ -684,6 +728,7 @@ impl ty::TyExpression {
         if witness_report.has_witnesses() {
             errors.push(CompileError::MatchExpressionNonExhaustive {
                 missing_patterns: format!("{}", witness_report),
+                missing_pattern_sources: witness_report.to_pattern_sources(),
                 span,
             });
             return err(warnings, errors);
@@ -782,6 +827,7 @@ impl ty::TyExpression {
         mut ctx: TypeCheckContext,
         call_path_binding: TypeBinding<CallPath>,
         fields: Vec<StructExpressionField>,
+        base: Option<Box<Expression>>,
         span: Span,
     ) -> CompileResult<ty::TyExpression> {
         let mut warnings = vec![];
@@ -848,12 +894,45 @@ impl ty::TyExpression {
         );
         let mut struct_fields = struct_fields.clone();
 
+        // the fields which are not listed are taken from the base, which must be of the same type
+        let typed_base = match base {
+            Some(base) => {
+                let ctx = ctx.by_ref().with_help_text("").with_type_annotation(
+                    type_engine.insert_type(declaration_engine, TypeInfo::Unknown),
+                );
+                let typed_base = check!(
+                    ty::TyExpression::type_check(ctx, *base),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                let (unify_warnings, unify_errors) = type_engine.unify(
+                    declaration_engine,
+                    typed_base.return_type,
+                    type_id,
+                    &typed_base.span,
+                    "",
+                );
+                warnings.extend(unify_warnings);
+                if !unify_errors.is_empty() {
+                    errors.push(CompileError::StructUpdateBaseTypeMismatch {
+                        expected: engines.help_out(type_id).to_string(),
+                        received: engines.help_out(typed_base.return_type).to_string(),
+                        span: typed_base.span.clone(),
+                    });
+                }
+                Some(Box::new(typed_base))
+            }
+            None => None,
+        };
+
         // match up the names with their type annotations from the declaration
         let mut typed_fields_buf = vec![];
         for def_field in struct_fields.iter_mut() {
             let expr_field: StructExpressionField =
                 match fields.iter().find(|x| x.name == def_field.name) {
                     Some(val) => val.clone(),
+                    None if typed_base.is_some() => continue,
                     None => {
                         errors.push(CompileError::StructMissingField {
                             field_name: def_field.name.clone(),
@@ -873,6 +952,19 @@ impl ty::TyExpression {
                     }
                 };
 
+            if expr_field.is_shorthand
+                && ctx
+                    .namespace
+                    .resolve_symbol(&expr_field.name)
+                    .value
+                    .is_none()
+            {
+                errors.push(CompileError::StructFieldShorthandUnknownVariable {
+                    field_name: expr_field.name.clone(),
+                });
+                continue;
+            }
+
             let ctx = ctx
                 .by_ref()
                 .with_help_text(
@@ -920,6 +1012,7 @@ impl ty::TyExpression {
             expression: ty::TyExpressionVariant::StructExpression {
                 struct_name: struct_name.clone(),
                 fields: typed_fields_buf,
+                base: typed_base,
                 span: inner_span,
             },
             return_type: type_id,
@@ -1328,10 +1421,55 @@ impl ty::TyExpression {
                 )
             }
             (false, None, None, None) => {
-                errors.push(CompileError::SymbolNotFound {
-                    name: call_path_binding.inner.suffix,
-                });
-                return err(warnings, errors);
+                // Check if this could be an associated constant of a type,
+                // such as `T::NAME` or `Self::NAME`.
+                match call_path_binding.inner.prefixes.as_slice() {
+                    [type_name] if !call_path_binding.inner.is_absolute => {
+                        let type_info = match type_name.as_str() {
+                            "Self" => TypeInfo::SelfType,
+                            _ => TypeInfo::Custom {
+                                name: type_name.clone(),
+                                type_arguments: None,
+                            },
+                        };
+                        let type_id = ctx.type_engine.insert_type(declaration_engine, type_info);
+                        let const_name = call_path_binding.inner.suffix.clone();
+                        let self_type = ctx.self_type();
+                        let const_decl_id = check!(
+                            ctx.namespace.find_constant_for_type(
+                                type_id,
+                                &const_name,
+                                self_type,
+                                ctx.engines()
+                            ),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+                        let const_decl = check!(
+                            CompileResult::from(
+                                declaration_engine.get_constant(const_decl_id.clone(), &span)
+                            ),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+                        ty::TyExpression {
+                            expression: ty::TyExpressionVariant::AssociatedConstant {
+                                call_path: call_path_binding.inner,
+                                const_decl_id,
+                            },
+                            return_type: const_decl.return_type,
+                            span,
+                        }
+                    }
+                    _ => {
+                        errors.push(CompileError::SymbolNotFound {
+                            name: call_path_binding.inner.suffix,
+                        });
+                        return err(warnings, errors);
+                    }
+                }
             }
             _ => {
                 errors.push(CompileError::AmbiguousPath { span });
@@ -1668,6 +1806,105 @@ impl ty::TyExpression {
         }
     }
 
+    fn type_check_ref(
+        mut ctx: TypeCheckContext,
+        expr: Expression,
+        span: Span,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let type_engine = ctx.type_engine;
+        let declaration_engine = ctx.declaration_engine;
+        let engines = ctx.engines();
+
+        let ctx = ctx
+            .by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.insert_type(declaration_engine, TypeInfo::Unknown));
+        let expr_span = expr.span();
+        let expr = check!(
+            ty::TyExpression::type_check(ctx, expr),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        // References are lowered to the address of the referenced value, and values of copy
+        // types live in registers, so they have no address to take.
+        let type_info = type_engine.look_up_type_id(expr.return_type);
+        if type_info.is_copy_type()
+            || matches!(
+                type_info,
+                TypeInfo::Numeric | TypeInfo::RawUntypedPtr | TypeInfo::Ref(_)
+            )
+        {
+            errors.push(CompileError::RefOfCopyType {
+                ty: engines.help_out(expr.return_type).to_string(),
+                span: expr_span,
+            });
+            return err(warnings, errors);
+        }
+
+        let return_type = type_engine.insert_type(
+            declaration_engine,
+            TypeInfo::Ref(TypeArgument {
+                type_id: expr.return_type,
+                initial_type_id: expr.return_type,
+                span: expr.span.clone(),
+            }),
+        );
+        let exp = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Ref(Box::new(expr)),
+            return_type,
+            span,
+        };
+        ok(exp, warnings, errors)
+    }
+
+    fn type_check_deref(
+        mut ctx: TypeCheckContext,
+        expr: Expression,
+        span: Span,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let type_engine = ctx.type_engine;
+        let declaration_engine = ctx.declaration_engine;
+        let engines = ctx.engines();
+
+        let ctx = ctx
+            .by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.insert_type(declaration_engine, TypeInfo::Unknown));
+        let expr_span = expr.span();
+        let expr = check!(
+            ty::TyExpression::type_check(ctx, expr),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        let return_type = match type_engine.look_up_type_id(expr.return_type) {
+            TypeInfo::Ref(ty) => ty.type_id,
+            TypeInfo::ErrorRecovery => return err(warnings, errors),
+            _ => {
+                errors.push(CompileError::DerefOfNonReference {
+                    ty: engines.help_out(expr.return_type).to_string(),
+                    span: expr_span,
+                });
+                return err(warnings, errors);
+            }
+        };
+        let exp = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Deref(Box::new(expr)),
+            return_type,
+            span,
+        };
+        ok(exp, warnings, errors)
+    }
+
     fn type_check_intrinsic_function(
         ctx: TypeCheckContext,
         kind_binding: TypeBinding<Intrinsic>,
@@ -0,0 +1,454 @@
+use std::sync::Arc;
+
+use sway_error::error::CompileError;
+use sway_types::{
+    constants::{CLOSURE_FN_NAME, INLINE_ALWAYS_NAME, INLINE_ATTRIBUTE_NAME},
+    Ident, Span, Spanned,
+};
+
+use crate::{
+    declaration_engine::DeclarationId,
+    engine_threading::*,
+    error::*,
+    language::{parsed::*, ty, Visibility},
+    semantic_analysis::{node_dependencies::referenced_names, *},
+    transform::{Attribute, AttributeArg, AttributeKind},
+    type_system::*,
+};
+
+/// Type check a closure by lifting it into a function of its own, whose parameters are the
+/// variables captured by the closure followed by the parameters of the closure itself. The
+/// lifted function is always inlined, so that calling a closure costs no more than evaluating
+/// its body.
+///
+/// Closures capture the variables they use by value, at the point where they are created. The
+/// types of the parameters of a closure may be left out when the closure is passed where a
+/// function type is expected, in which case they are taken from that function type.
+pub(crate) fn type_check_closure(
+    mut ctx: TypeCheckContext,
+    closure: ClosureExpression,
+    span: Span,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let declaration_engine = ctx.declaration_engine;
+    let engines = ctx.engines();
+
+    let ClosureExpression { parameters, body } = closure;
+
+    // The closure may be expected to have a function type, which gives the types of its
+    // parameters and of its return value.
+    let expected_signature = match type_engine.look_up_type_id(ctx.type_annotation()) {
+        TypeInfo::Function {
+            parameters: expected_parameters,
+            return_type,
+            ..
+        } => {
+            if expected_parameters.len() != parameters.len() {
+                errors.push(CompileError::ClosureParameterCountMismatch {
+                    expected: parameters.len(),
+                    received: expected_parameters.len(),
+                    span,
+                });
+                return err(warnings, errors);
+            }
+            Some((expected_parameters, return_type))
+        }
+        _ => None,
+    };
+
+    // Gather the variables the closure captures. The body refers to them by name, so they are
+    // the variables in scope named like something the body refers to, which are not shadowed by
+    // the parameters of the closure.
+    let captures = referenced_names(type_engine, &body)
+        .into_iter()
+        .filter(|name| parameters.iter().all(|param| param.name != *name))
+        .filter_map(|name| match ctx.namespace.resolve_symbol(&name).value {
+            Some(ty::TyDeclaration::VariableDeclaration(decl)) => Some((name, (**decl).clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Create a namespace for the lifted function, in which only the captured variables are
+    // visible, along with the items of the enclosing module.
+    let mut closure_namespace = ctx.namespace.clone();
+    closure_namespace.symbols = closure_namespace
+        .symbols
+        .clone()
+        .into_iter()
+        .filter(|(_, decl)| !matches!(decl, ty::TyDeclaration::VariableDeclaration(_)))
+        .collect();
+    let mut closure_ctx = ctx.by_ref().scoped(&mut closure_namespace);
+
+    let mut lifted_parameters = vec![];
+    let mut capture_exprs = vec![];
+    for (name, decl) in captures {
+        let capture = ty::TyFunctionParameter {
+            name: name.clone(),
+            is_reference: false,
+            is_mutable: false,
+            mutability_span: name.span(),
+            type_id: decl.return_type,
+            initial_type_id: decl.return_type,
+            type_span: name.span(),
+        };
+        capture.insert_into_namespace(closure_ctx.by_ref());
+        lifted_parameters.push(capture);
+        capture_exprs.push(ty::TyExpression {
+            expression: ty::TyExpressionVariant::VariableExpression {
+                name: decl.name,
+                span: name.span(),
+                mutability: decl.mutability,
+            },
+            return_type: decl.return_type,
+            span: name.span(),
+        });
+    }
+    let captures_count = lifted_parameters.len();
+
+    for (index, parameter) in parameters.into_iter().enumerate() {
+        let ClosureParameter {
+            name,
+            type_info,
+            type_span,
+        } = parameter;
+        let (type_id, initial_type_id) = match (&type_info, &expected_signature) {
+            (TypeInfo::Unknown, Some((expected_parameters, _))) => {
+                let expected = &expected_parameters[index];
+                (expected.type_id, expected.initial_type_id)
+            }
+            (TypeInfo::Unknown, None) => {
+                errors.push(CompileError::ClosureParameterTypeUnknown {
+                    name: name.clone(),
+                    span: name.span(),
+                });
+                let type_id = type_engine.insert_type(declaration_engine, TypeInfo::ErrorRecovery);
+                (type_id, type_id)
+            }
+            _ => {
+                let initial_type_id = type_engine.insert_type(declaration_engine, type_info);
+                let type_id = check!(
+                    closure_ctx.resolve_type_with_self(
+                        initial_type_id,
+                        &type_span,
+                        EnforceTypeArguments::Yes,
+                        None
+                    ),
+                    type_engine.insert_type(declaration_engine, TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors,
+                );
+                (type_id, closure_ctx.expand_type_aliases(initial_type_id))
+            }
+        };
+        let parameter = ty::TyFunctionParameter {
+            name: name.clone(),
+            is_reference: false,
+            is_mutable: false,
+            mutability_span: name.span(),
+            type_id,
+            initial_type_id,
+            type_span,
+        };
+        parameter.insert_into_namespace(closure_ctx.by_ref());
+        lifted_parameters.push(parameter);
+    }
+    if !errors.is_empty() {
+        return err(warnings, errors);
+    }
+
+    let return_type = match &expected_signature {
+        Some((_, return_type)) => return_type.type_id,
+        None => type_engine.insert_type(declaration_engine, TypeInfo::Unknown),
+    };
+
+    // type check the body of the closure, which returns from the lifted function
+    let body_span = body.span();
+    let typed_body = {
+        let body_ctx = closure_ctx
+            .by_ref()
+            .with_help_text(
+                "Closure body's return type does not match up with its expected return type.",
+            )
+            .with_type_annotation(return_type)
+            .with_function_return_type(return_type);
+        check!(
+            ty::TyExpression::type_check(body_ctx, *body),
+            ty::TyExpression::error(body_span.clone(), engines),
+            warnings,
+            errors
+        )
+    };
+    for stmt in typed_body.gather_return_statements() {
+        append!(
+            closure_ctx
+                .by_ref()
+                .with_type_annotation(return_type)
+                .with_help_text("Return statement must return the closure's return type.")
+                .unify_with_self(stmt.return_type, &stmt.span),
+            warnings,
+            errors
+        );
+    }
+    errors.extend(check_closure_does_not_escape(
+        engines,
+        return_type,
+        &body_span,
+    ));
+    if !errors.is_empty() {
+        return err(warnings, errors);
+    }
+
+    let inline_always = Attribute {
+        name: Ident::new_with_override(INLINE_ATTRIBUTE_NAME, span.clone()),
+        args: vec![AttributeArg {
+            name: Ident::new_with_override(INLINE_ALWAYS_NAME, span.clone()),
+            value: None,
+            span: span.clone(),
+        }],
+        span: span.clone(),
+    };
+    let closure_decl = ty::TyFunctionDeclaration {
+        name: Ident::new_with_override(CLOSURE_FN_NAME, span.clone()),
+        body: ty::TyCodeBlock {
+            contents: vec![ty::TyAstNode {
+                content: ty::TyAstNodeContent::ImplicitReturnExpression(typed_body),
+                span: body_span.clone(),
+            }],
+        },
+        parameters: lifted_parameters,
+        implementing_type: None,
+        span: span.clone(),
+        attributes: Arc::new([(AttributeKind::Inline, vec![inline_always])].into()),
+        return_type,
+        initial_return_type: return_type,
+        type_parameters: vec![],
+        return_type_span: body_span,
+        visibility: Visibility::Private,
+        is_contract_call: false,
+        purity: ctx.purity(),
+    };
+    let decl_id = declaration_engine.insert_function(closure_decl.clone());
+    let closure_type = closure_type(decl_id.clone(), &closure_decl, captures_count);
+
+    let exp = ty::TyExpression {
+        expression: ty::TyExpressionVariant::Closure {
+            decl_id: decl_id.clone(),
+            captures: capture_exprs,
+        },
+        return_type: type_engine.insert_type(declaration_engine, closure_type),
+        span,
+    };
+    ok(exp, warnings, errors)
+}
+
+/// Type check the use of the function `decl_id` as a value, which is a closure that captures
+/// nothing.
+pub(crate) fn type_check_function_value(
+    ctx: TypeCheckContext,
+    name: Ident,
+    decl_id: DeclarationId,
+    span: Span,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let declaration_engine = ctx.declaration_engine;
+
+    let function_decl = check!(
+        CompileResult::from(declaration_engine.get_function(decl_id.clone(), &span)),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    // A generic function has no single signature to give to the value.
+    if !function_decl.type_parameters.is_empty() {
+        errors.push(CompileError::GenericFunctionAsValue { name, span });
+        return err(warnings, errors);
+    }
+
+    let function_type = closure_type(decl_id.clone(), &function_decl, 0);
+    let exp = ty::TyExpression {
+        expression: ty::TyExpressionVariant::Closure {
+            decl_id,
+            captures: vec![],
+        },
+        return_type: type_engine.insert_type(declaration_engine, function_type),
+        span,
+    };
+    ok(exp, warnings, errors)
+}
+
+/// Type check a call of the closure or function stored in the variable `decl`, as in `f(a, b)`.
+pub(crate) fn type_check_closure_call(
+    mut ctx: TypeCheckContext,
+    name: Ident,
+    decl: ty::TyVariableDeclaration,
+    arguments: Vec<Expression>,
+    span: Span,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let engines = ctx.engines();
+
+    let (parameters, return_type) = match type_engine.look_up_type_id(decl.return_type) {
+        TypeInfo::Function {
+            parameters,
+            return_type,
+            ..
+        }
+        | TypeInfo::Closure {
+            parameters,
+            return_type,
+            ..
+        } => (parameters, return_type),
+        TypeInfo::ErrorRecovery => return err(warnings, errors),
+        ty => {
+            errors.push(CompileError::CallOfNonFunctionValue {
+                name,
+                ty: engines.help_out(ty).to_string(),
+                span,
+            });
+            return err(warnings, errors);
+        }
+    };
+
+    if parameters.len() != arguments.len() {
+        let (span, method_name, expected, received) =
+            (name.span(), name, parameters.len(), arguments.len());
+        errors.push(if expected < received {
+            CompileError::TooFewArgumentsForFunction {
+                span,
+                method_name,
+                dot_syntax_used: false,
+                expected,
+                received,
+            }
+        } else {
+            CompileError::TooManyArgumentsForFunction {
+                span,
+                method_name,
+                dot_syntax_used: false,
+                expected,
+                received,
+            }
+        });
+        return err(warnings, errors);
+    }
+
+    let mut typed_arguments = vec![];
+    for (arg, param) in arguments.into_iter().zip(parameters.iter()) {
+        let ctx = ctx
+            .by_ref()
+            .with_help_text(
+                "The argument that has been provided to this closure's type does not match the \
+                declared type of the parameter.",
+            )
+            .with_type_annotation(param.type_id);
+        let arg_span = arg.span();
+        let exp = check!(
+            ty::TyExpression::type_check(ctx, arg),
+            ty::TyExpression::error(arg_span, engines),
+            warnings,
+            errors
+        );
+        typed_arguments.push((Ident::new_with_override("_", param.span.clone()), exp));
+    }
+
+    let closure = ty::TyExpression {
+        expression: ty::TyExpressionVariant::VariableExpression {
+            name: decl.name,
+            span: name.span(),
+            mutability: decl.mutability,
+        },
+        return_type: decl.return_type,
+        span: name.span(),
+    };
+    let exp = ty::TyExpression {
+        expression: ty::TyExpressionVariant::ClosureCall {
+            closure: Box::new(closure),
+            arguments: typed_arguments,
+        },
+        return_type: return_type.type_id,
+        span,
+    };
+    ok(exp, warnings, errors)
+}
+
+/// The type annotation an argument passed for a parameter of type `param_type_id` is type
+/// checked with. Closures need their expected function type to infer the types of their
+/// parameters, whereas other arguments are unified with the type of the parameter afterwards.
+pub(crate) fn argument_type_annotation(ctx: &TypeCheckContext, param_type_id: TypeId) -> TypeId {
+    match ctx.type_engine.look_up_type_id(param_type_id) {
+        TypeInfo::Function { .. } => param_type_id,
+        _ => ctx
+            .type_engine
+            .insert_type(ctx.declaration_engine, TypeInfo::Unknown),
+    }
+}
+
+/// Closures are only ever called within the function they are created in, or within the
+/// functions they are passed to. They are therefore not allowed to be returned, which could
+/// otherwise only happen through generic functions, or closures returning closures.
+pub(crate) fn check_closure_does_not_escape(
+    engines: Engines<'_>,
+    return_type: TypeId,
+    span: &Span,
+) -> Vec<CompileError> {
+    let type_engine = engines.te();
+    let nested_types = type_engine
+        .look_up_type_id(return_type)
+        .extract_nested_types(type_engine, span)
+        .value
+        .unwrap_or_default();
+    if nested_types
+        .iter()
+        .any(|ty| matches!(ty, TypeInfo::Closure { .. }))
+    {
+        vec![CompileError::ClosureEscapesFunction { span: span.clone() }]
+    } else {
+        vec![]
+    }
+}
+
+/// The type of a closure lifted into `decl`, whose first `captures_count` parameters are the
+/// captured variables.
+fn closure_type(
+    decl_id: DeclarationId,
+    decl: &ty::TyFunctionDeclaration,
+    captures_count: usize,
+) -> TypeInfo {
+    let type_argument = |type_id, initial_type_id, span: Span| TypeArgument {
+        type_id,
+        initial_type_id,
+        span,
+    };
+    let (captures, parameters) = decl.parameters.split_at(captures_count);
+    let to_type_arguments = |params: &[ty::TyFunctionParameter]| {
+        params
+            .iter()
+            .map(|param| {
+                type_argument(
+                    param.type_id,
+                    param.initial_type_id,
+                    param.type_span.clone(),
+                )
+            })
+            .collect()
+    };
+    TypeInfo::Closure {
+        decl_id,
+        parameters: to_type_arguments(parameters),
+        return_type: type_argument(
+            decl.return_type,
+            decl.initial_return_type,
+            decl.return_type_span.clone(),
+        ),
+        captures: to_type_arguments(captures),
+    }
+}
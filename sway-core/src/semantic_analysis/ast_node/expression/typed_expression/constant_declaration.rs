@@ -17,7 +17,10 @@ pub(crate) fn instantiate_constant_decl(
                 span: const_decl.name.span(),
                 mutability: ty::VariableMutability::Immutable,
             },
-            return_type: const_decl.value.return_type,
+            return_type: const_decl
+                .value
+                .as_ref()
+                .map_or(const_decl.return_type, |value| value.return_type),
             span,
         },
         vec![],
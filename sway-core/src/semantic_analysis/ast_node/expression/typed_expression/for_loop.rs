@@ -0,0 +1,412 @@
+use sway_error::error::CompileError;
+use sway_types::{
+    constants::{
+        FOR_LOOP_ELEMENT_VAR_NAME_PREFIX, FOR_LOOP_INDEX_VAR_NAME_PREFIX,
+        FOR_LOOP_ITERABLE_VAR_NAME_PREFIX, FOR_LOOP_NEXT_VAR_NAME_PREFIX,
+    },
+    Ident, Span, Spanned,
+};
+
+use crate::{
+    error::*,
+    language::{parsed::*, ty, CallPath, Literal},
+    semantic_analysis::TypeCheckContext,
+    type_system::*,
+};
+
+/// Type check a `for` loop as a code block that declares the state of the loop and then runs it
+/// as a `while` loop. How the loop steps through its iterable depends on what the iterable is:
+///
+/// - a range `start..end` counts up from `start` to, but not including, `end`,
+/// - an array is indexed from `0` up to its length,
+/// - anything else must implement `std::iterator::Iterator`, and is stepped through with its
+///   `next` method until it returns `None`.
+///
+/// Each step binds the current element to `__for_element_<id>`, which the body of the loop starts
+/// by destructuring into the loop's pattern.
+///
+/// Unless it is a range, the iterable is type checked only once, to find out which kind of loop
+/// it needs, and its typed expression is what `__for_iterable_<id>` is bound to.
+pub(crate) fn type_check_for_loop(
+    mut ctx: TypeCheckContext,
+    for_loop: ForLoopExpression,
+    span: Span,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let declaration_engine = ctx.declaration_engine;
+    let engines = ctx.engines();
+
+    let ForLoopExpression { id, iterable, body } = for_loop;
+    let vars = LoopVars::new(id, &span);
+    let iterable = match iterable {
+        ForLoopIterable::Range { start, end } => {
+            let bound = variable(&vars.iterable, &span);
+            let element = variable(&vars.index, &span);
+            let desugared = counting_loop(&vars, Some(*end), *start, bound, element, body, span);
+            let span = desugared.whole_block_span.clone();
+            return ty::TyExpression::type_check_code_block(ctx, desugared, span);
+        }
+        ForLoopIterable::Expression(iterable) => *iterable,
+    };
+
+    let iterable_span = iterable.span();
+    let type_ascription = type_engine.insert_type(declaration_engine, TypeInfo::Unknown);
+    let typed_iterable = {
+        let ctx = ctx
+            .by_ref()
+            .with_type_annotation(type_ascription)
+            .with_help_text("");
+        check!(
+            ty::TyExpression::type_check(ctx, iterable),
+            return err(warnings, errors),
+            warnings,
+            errors
+        )
+    };
+    let iterable_type = typed_iterable.return_type;
+    let (desugared, mutability) = match type_engine.look_up_type_id(iterable_type) {
+        TypeInfo::ErrorRecovery => return err(warnings, errors),
+        TypeInfo::Array(_, length) => {
+            let start = literal(Literal::U64(0), &span);
+            let bound = literal(Literal::U64(length.val() as u64), &span);
+            let element = Expression {
+                kind: ExpressionKind::ArrayIndex(ArrayIndexExpression {
+                    prefix: Box::new(variable(&vars.iterable, &iterable_span)),
+                    index: Box::new(variable(&vars.index, &iterable_span)),
+                }),
+                span: iterable_span,
+            };
+            (
+                counting_loop(&vars, None, start, bound, element, body, span.clone()),
+                ty::VariableMutability::Immutable,
+            )
+        }
+        _ if implements_iterator(&ctx, iterable_type) => (
+            iterator_loop(&vars, &iterable_span, body, span.clone()),
+            ty::VariableMutability::Mutable,
+        ),
+        _ => {
+            errors.push(CompileError::ForLoopNotIterable {
+                ty: engines.help_out(iterable_type).to_string(),
+                span: iterable_span,
+            });
+            return err(warnings, errors);
+        }
+    };
+
+    // Bind the typed iterable in the scope of the desugared block, and type check only the
+    // loop over it.
+    let iterable_decl =
+        ty::TyDeclaration::VariableDeclaration(Box::new(ty::TyVariableDeclaration {
+            name: vars.iterable.clone(),
+            body: typed_iterable,
+            mutability,
+            return_type: iterable_type,
+            type_ascription,
+            type_ascription_span: None,
+        }));
+    let mut block_namespace = ctx.namespace.clone();
+    let mut block_ctx = ctx.by_ref().scoped(&mut block_namespace);
+    block_ctx
+        .namespace
+        .insert_symbol(vars.iterable.clone(), iterable_decl.clone());
+    let (typed_block, block_return_type) = check!(
+        ty::TyCodeBlock::type_check(block_ctx.by_ref(), desugared),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    append!(
+        ctx.unify_with_self(block_return_type, &span),
+        warnings,
+        errors
+    );
+
+    let contents = std::iter::once(ty::TyAstNode {
+        content: ty::TyAstNodeContent::Declaration(iterable_decl),
+        span: span.clone(),
+    })
+    .chain(typed_block.contents)
+    .collect();
+    let typed_loop = ty::TyExpression {
+        expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock { contents }),
+        return_type: block_return_type,
+        span,
+    };
+    ok(typed_loop, warnings, errors)
+}
+
+/// The names of the variables that a loop is desugared into.
+struct LoopVars {
+    iterable: Ident,
+    index: Ident,
+    next: Ident,
+    element: Ident,
+}
+
+impl LoopVars {
+    fn new(id: usize, span: &Span) -> Self {
+        let var_name = |prefix| ForLoopExpression::var_name(id, prefix, span.clone());
+        LoopVars {
+            iterable: var_name(FOR_LOOP_ITERABLE_VAR_NAME_PREFIX),
+            index: var_name(FOR_LOOP_INDEX_VAR_NAME_PREFIX),
+            next: var_name(FOR_LOOP_NEXT_VAR_NAME_PREFIX),
+            element: var_name(FOR_LOOP_ELEMENT_VAR_NAME_PREFIX),
+        }
+    }
+}
+
+/// Whether `type_id` implements `std::iterator::Iterator`.
+///
+/// Implementations are keyed by the path their trait was imported through, which is
+/// `std::iterator::Iterator` outside of the standard library, but `iterator::Iterator` within it,
+/// as for the iterator of `Vec`. The latter is only accepted for implementations that are declared
+/// in the standard library itself, so that a trait of the same name in a module `iterator` of
+/// another package is not mistaken for it.
+fn implements_iterator(ctx: &TypeCheckContext, type_id: TypeId) -> bool {
+    let engines = ctx.engines();
+    let trait_path = |prefixes: &[&'static str]| CallPath {
+        prefixes: prefixes
+            .iter()
+            .map(|name| Ident::new_no_span(name))
+            .collect(),
+        suffix: Ident::new_no_span("Iterator"),
+        is_absolute: false,
+    };
+    let std_iterator = trait_path(&["std", "iterator"]);
+    let std_dir = match ctx
+        .namespace
+        .root()
+        .resolve_call_path(&[], &std_iterator)
+        .value
+    {
+        Some(ty::TyDeclaration::TraitDeclaration(decl_id)) => {
+            match decl_id.span().path().and_then(|path| path.parent()) {
+                Some(dir) => dir.to_path_buf(),
+                None => return false,
+            }
+        }
+        _ => return false,
+    };
+    let has_next = |trait_name: &CallPath, within_std: bool| {
+        ctx.namespace
+            .get_items_for_type_and_trait_name(engines, type_id, trait_name)
+            .into_iter()
+            .any(|decl_id| {
+                let declared_in_std = decl_id
+                    .span()
+                    .path()
+                    .is_some_and(|path| path.starts_with(&std_dir));
+                (!within_std || declared_in_std)
+                    && matches!(
+                        engines.de().get_function(decl_id.clone(), &decl_id.span()),
+                        Ok(function) if function.name.as_str() == "next"
+                    )
+            })
+    };
+    has_next(&std_iterator, false) || has_next(&trait_path(&["iterator"]), true)
+}
+
+/// ```ignore
+/// {
+///     let mut __for_index_<id> = <start>;
+///     let __for_iterable_<id> = <iterable>;
+///     while __for_index_<id> < <bound> {
+///         let __for_element_<id> = <element>;
+///         __for_index_<id> = __for_index_<id> + 1;
+///         <body>
+///     }
+/// }
+/// ```
+///
+/// `__for_iterable_<id>` is only declared here if `iterable` is given, otherwise it is bound to
+/// the typed iterable by the caller.
+fn counting_loop(
+    vars: &LoopVars,
+    iterable: Option<Expression>,
+    start: Expression,
+    bound: Expression,
+    element: Expression,
+    body: CodeBlock,
+    span: Span,
+) -> CodeBlock {
+    let index = variable(&vars.index, &span);
+    let condition = op_call("lt", vec![index.clone(), bound], &span);
+    let increment = op_call(
+        "add",
+        vec![index, literal(Literal::Numeric(1), &span)],
+        &span,
+    );
+    let steps = vec![
+        let_node(&vars.element, false, element, &span),
+        reassignment_node(&vars.index, increment, &span),
+    ];
+    // `start` comes first, as for a range the iterable is the end of the range.
+    let contents = std::iter::once(let_node(&vars.index, true, start, &span))
+        .chain(iterable.map(|iterable| let_node(&vars.iterable, false, iterable, &span)))
+        .chain(std::iter::once(while_node(condition, steps, body, &span)))
+        .collect();
+    CodeBlock {
+        contents,
+        whole_block_span: span,
+    }
+}
+
+/// ```ignore
+/// {
+///     let mut __for_next_<id> = std::iterator::next(__for_iterable_<id>);
+///     while __for_next_<id>.is_some() {
+///         let __for_element_<id> = __for_next_<id>.unwrap();
+///         __for_next_<id> = std::iterator::next(__for_iterable_<id>);
+///         <body>
+///     }
+/// }
+/// ```
+///
+/// `__for_iterable_<id>` is mutable, and bound to the typed iterable by the caller.
+fn iterator_loop(vars: &LoopVars, iterable_span: &Span, body: CodeBlock, span: Span) -> CodeBlock {
+    let next = || iterator_next_call(variable(&vars.iterable, iterable_span), iterable_span);
+    let next_element = variable(&vars.next, iterable_span);
+    let condition = method_call("is_some", next_element.clone(), iterable_span);
+    let steps = vec![
+        let_node(
+            &vars.element,
+            false,
+            method_call("unwrap", next_element, iterable_span),
+            &span,
+        ),
+        reassignment_node(&vars.next, next(), &span),
+    ];
+    CodeBlock {
+        contents: vec![
+            let_node(&vars.next, true, next(), &span),
+            while_node(condition, steps, body, &span),
+        ],
+        whole_block_span: span,
+    }
+}
+
+fn while_node(condition: Expression, steps: Vec<AstNode>, body: CodeBlock, span: &Span) -> AstNode {
+    let CodeBlock {
+        contents,
+        whole_block_span,
+    } = body;
+    let body = CodeBlock {
+        contents: steps.into_iter().chain(contents).collect(),
+        whole_block_span,
+    };
+    AstNode {
+        content: AstNodeContent::Expression(Expression {
+            kind: ExpressionKind::WhileLoop(WhileLoopExpression {
+                condition: Box::new(condition),
+                body,
+            }),
+            span: span.clone(),
+        }),
+        span: span.clone(),
+    }
+}
+
+fn let_node(name: &Ident, is_mutable: bool, body: Expression, span: &Span) -> AstNode {
+    AstNode {
+        content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+            VariableDeclaration {
+                name: name.clone(),
+                type_ascription: TypeInfo::Unknown,
+                type_ascription_span: None,
+                body,
+                is_mutable,
+            },
+        )),
+        span: span.clone(),
+    }
+}
+
+fn reassignment_node(name: &Ident, rhs: Expression, span: &Span) -> AstNode {
+    AstNode {
+        content: AstNodeContent::Expression(Expression {
+            kind: ExpressionKind::Reassignment(ReassignmentExpression {
+                lhs: ReassignmentTarget::VariableExpression(Box::new(variable(name, span))),
+                rhs: Box::new(rhs),
+            }),
+            span: span.clone(),
+        }),
+        span: span.clone(),
+    }
+}
+
+fn variable(name: &Ident, span: &Span) -> Expression {
+    Expression {
+        kind: ExpressionKind::Variable(name.clone()),
+        span: span.clone(),
+    }
+}
+
+fn literal(literal: Literal, span: &Span) -> Expression {
+    Expression {
+        kind: ExpressionKind::Literal(literal),
+        span: span.clone(),
+    }
+}
+
+fn method_call(name: &'static str, target: Expression, span: &Span) -> Expression {
+    let method_name_binding = TypeBinding {
+        inner: MethodName::FromModule {
+            method_name: Ident::new_with_override(name, span.clone()),
+        },
+        type_arguments: vec![],
+        span: span.clone(),
+    };
+    Expression {
+        kind: ExpressionKind::MethodApplication(Box::new(MethodApplicationExpression {
+            method_name_binding,
+            contract_call_params: vec![],
+            arguments: vec![target],
+        })),
+        span: span.clone(),
+    }
+}
+
+/// Call `next` on `iterable` through `std::iterator::Iterator`.
+fn iterator_next_call(iterable: Expression, span: &Span) -> Expression {
+    trait_method_call(&["std", "iterator"], "next", vec![iterable], span)
+}
+
+/// Call one of the operators in `core::ops`, the same way binary operators are desugared.
+fn op_call(name: &'static str, arguments: Vec<Expression>, span: &Span) -> Expression {
+    trait_method_call(&["core", "ops"], name, arguments, span)
+}
+
+/// Call the method `name` of a trait declared in the module at `prefixes`.
+fn trait_method_call(
+    prefixes: &[&'static str],
+    name: &'static str,
+    arguments: Vec<Expression>,
+    span: &Span,
+) -> Expression {
+    let method_name_binding = TypeBinding {
+        inner: MethodName::FromTrait {
+            call_path: CallPath {
+                prefixes: prefixes
+                    .iter()
+                    .map(|prefix| Ident::new_with_override(prefix, span.clone()))
+                    .collect(),
+                suffix: Ident::new_with_override(name, span.clone()),
+                is_absolute: true,
+            },
+        },
+        type_arguments: vec![],
+        span: span.clone(),
+    };
+    Expression {
+        kind: ExpressionKind::MethodApplication(Box::new(MethodApplicationExpression {
+            method_name_binding,
+            contract_call_params: vec![],
+            arguments,
+        })),
+        span: span.clone(),
+    }
+}
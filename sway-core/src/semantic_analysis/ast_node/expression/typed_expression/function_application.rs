@@ -5,6 +5,8 @@ use crate::{
     semantic_analysis::{ast_node::*, TypeCheckContext},
 };
 use std::collections::HashMap;
+
+use super::{argument_type_annotation, check_closure_does_not_escape};
 use sway_error::error::CompileError;
 use sway_types::Spanned;
 
@@ -44,6 +46,7 @@ pub(crate) fn instantiate_function_application(
         .into_iter()
         .zip(function_decl.parameters.iter())
         .map(|(arg, param)| {
+            let annotation = argument_type_annotation(&ctx, param.type_id);
             let ctx = ctx
                 .by_ref()
                 .with_help_text(
@@ -51,9 +54,7 @@ pub(crate) fn instantiate_function_application(
                     not match the declared type of the parameter in the function \
                     declaration.",
                 )
-                .with_type_annotation(
-                    type_engine.insert_type(declaration_engine, TypeInfo::Unknown),
-                );
+                .with_type_annotation(annotation);
             let exp = check!(
                 ty::TyExpression::type_check(ctx, arg.clone()),
                 ty::TyExpression::error(arg.span(), engines),
@@ -99,7 +100,20 @@ pub(crate) fn instantiate_function_application(
         errors
     );
     function_decl.replace_decls(&decl_mapping, engines);
+
+    // Replace the associated types of the type parameters with the types
+    // given by the implementations for the types of the arguments.
+    let type_mapping = TypeParameter::gather_type_mapping_from_trait_constraints(
+        ctx.by_ref(),
+        &function_decl.type_parameters,
+    );
+    function_decl.copy_types(&type_mapping, engines);
     let return_type = function_decl.return_type;
+    errors.extend(check_closure_does_not_escape(
+        engines,
+        return_type,
+        &call_path.span(),
+    ));
     let span = function_decl.span.clone();
     let new_decl_id = declaration_engine.insert_function(function_decl);
 
@@ -5,7 +5,9 @@ use crate::{
     semantic_analysis::*,
     type_system::*,
 };
-use ast_node::typed_expression::check_function_arguments_arity;
+use ast_node::typed_expression::{
+    argument_type_annotation, check_closure_does_not_escape, check_function_arguments_arity,
+};
 use std::collections::{HashMap, VecDeque};
 use sway_error::error::CompileError;
 use sway_types::{constants, integer_bits::IntegerBits};
@@ -28,8 +30,18 @@ pub(crate) fn type_check_method_application(
     let engines = ctx.engines();
 
     // type check the function arguments
+    //
+    // Closures passed after the first argument are type checked once the method is known, as the
+    // types of their parameters may have to be inferred from the types of the parameters of the
+    // method.
     let mut args_buf = VecDeque::new();
-    for arg in &arguments {
+    let mut closure_args = vec![];
+    for (index, arg) in arguments.iter().enumerate() {
+        if index > 0 && matches!(arg.kind, ExpressionKind::Closure(_)) {
+            closure_args.push((index, arg.clone()));
+            args_buf.push_back(ty::TyExpression::error(arg.span(), engines));
+            continue;
+        }
         let ctx = ctx
             .by_ref()
             .with_help_text("")
@@ -356,6 +368,47 @@ pub(crate) fn type_check_method_application(
         }
     }
 
+    // type check the closures that have been left out above
+    let has_closure_args = !closure_args.is_empty();
+    for (index, arg) in closure_args {
+        let param_type_id = match method.parameters.get(index) {
+            Some(param) => param.type_id,
+            None => continue,
+        };
+        let annotation = argument_type_annotation(&ctx, param_type_id);
+        let ctx = ctx
+            .by_ref()
+            .with_help_text("This argument's type is not castable to the declared parameter type.")
+            .with_type_annotation(annotation);
+        args_buf[index] = check!(
+            ty::TyExpression::type_check(ctx, arg.clone()),
+            ty::TyExpression::error(arg.span(), engines),
+            warnings,
+            errors
+        );
+        append!(
+            type_engine.unify_right(
+                declaration_engine,
+                args_buf[index].return_type,
+                param_type_id,
+                &arg.span(),
+                "This argument's type is not castable to the declared parameter type."
+            ),
+            warnings,
+            errors
+        );
+    }
+    // the return type may only be known now that the closures have been type checked
+    if has_closure_args {
+        ctx.namespace
+            .insert_trait_implementation_for_type(engines, method.return_type);
+    }
+    errors.extend(check_closure_does_not_escape(
+        engines,
+        method.return_type,
+        &span,
+    ));
+
     // Map the names of the parameters to the typed arguments.
     let args_and_names = method
         .parameters
@@ -0,0 +1,332 @@
+use sway_error::error::CompileError;
+use sway_types::{
+    constants::{TRY_ERROR_VAR_NAME, TRY_SCRUTINEE_VAR_NAME, TRY_VALUE_VAR_NAME},
+    Ident, Span, Spanned,
+};
+
+use crate::{
+    engine_threading::*,
+    error::*,
+    language::{parsed::*, ty, CallPath},
+    semantic_analysis::TypeCheckContext,
+    type_system::*,
+};
+
+/// The kinds of values that the `?` operator can be applied to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TryKind {
+    Option,
+    Result,
+}
+
+impl TryKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TryKind::Option => "Option",
+            TryKind::Result => "Result",
+        }
+    }
+
+    /// The names of the variant the value is unwrapped from and of the one returned early.
+    fn variant_names(&self) -> (&'static str, &'static str) {
+        match self {
+            TryKind::Option => ("Some", "None"),
+            TryKind::Result => ("Ok", "Err"),
+        }
+    }
+
+    /// The path of the module in the standard library that declares the enum.
+    fn module_path(&self) -> [&'static str; 2] {
+        match self {
+            TryKind::Option => ["std", "option"],
+            TryKind::Result => ["std", "result"],
+        }
+    }
+
+    /// The path `std::option::Option` or `std::result::Result`, optionally followed by
+    /// `variant`.
+    fn path(&self, variant: Option<&'static str>, span: &Span) -> CallPath {
+        let ident = |name| Ident::new_with_override(name, span.clone());
+        let mut prefixes: Vec<_> = self.module_path().into_iter().map(ident).collect();
+        let suffix = match variant {
+            Some(variant) => {
+                prefixes.push(ident(self.as_str()));
+                ident(variant)
+            }
+            None => ident(self.as_str()),
+        };
+        CallPath {
+            prefixes,
+            suffix,
+            is_absolute: true,
+        }
+    }
+
+    /// The kind of `type_info`, along with its type parameters, if it is an instance of the
+    /// `Option` or the `Result` declared in the standard library.
+    ///
+    /// The enum is identified by its declaration, as found through its path in the standard
+    /// library, rather than by its name, so that other enums that happen to be named `Option`
+    /// or `Result` are not mistaken for it.
+    fn of(ctx: &TypeCheckContext, type_info: &TypeInfo) -> Option<(Self, Vec<TypeId>)> {
+        let (name, type_parameters) = match type_info {
+            TypeInfo::Enum {
+                name,
+                type_parameters,
+                ..
+            } => (name, type_parameters),
+            _ => return None,
+        };
+        let kind = [TryKind::Option, TryKind::Result]
+            .into_iter()
+            .find(|kind| kind.is_declared_as(ctx, name))?;
+        let type_ids = type_parameters.iter().map(|param| param.type_id).collect();
+        Some((kind, type_ids))
+    }
+
+    /// Whether the enum declared with the name `name` is the one of this kind.
+    fn is_declared_as(&self, ctx: &TypeCheckContext, name: &Ident) -> bool {
+        let name_span = name.span();
+        match ctx
+            .namespace
+            .resolve_call_path(&self.path(None, &name_span))
+            .value
+        {
+            Some(ty::TyDeclaration::EnumDeclaration(decl_id)) => ctx
+                .declaration_engine
+                .get_enum(decl_id.clone(), &name_span)
+                .is_ok_and(|decl| decl.name.span() == name_span),
+            _ => false,
+        }
+    }
+}
+
+/// Type check `expr?` as a `match` that unwraps the value of an `Option` or a `Result`, and
+/// that otherwise returns early from the enclosing function:
+///
+/// ```ignore
+/// {
+///     let __try_scrutinee = <expr>;
+///     match __try_scrutinee {
+///         std::option::Option::Some(__try_value) => __try_value,
+///         std::option::Option::None => return std::option::Option::None,
+///     }
+/// }
+///
+/// {
+///     let __try_scrutinee = <expr>;
+///     match __try_scrutinee {
+///         std::result::Result::Ok(__try_value) => __try_value,
+///         std::result::Result::Err(__try_error) => return std::result::Result::Err(__try_error),
+///     }
+/// }
+/// ```
+///
+/// Like in any other `match` expression, the scrutinee is bound to a variable first so that it
+/// is only evaluated once. `expr` is type checked only once, to find out whether it is an
+/// `Option` or a `Result`, and its typed expression is what the variable is bound to, so that
+/// nested `?`s are not type checked again by the desugared `match`.
+///
+/// An `Option` can only be unwrapped in a function returning an `Option`, and a `Result` in a
+/// function returning a `Result` with the same error type.
+pub(crate) fn type_check_try(
+    mut ctx: TypeCheckContext,
+    expr: Expression,
+    span: Span,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let declaration_engine = ctx.declaration_engine;
+    let engines = ctx.engines();
+    let expr_span = expr.span();
+
+    let type_ascription = type_engine.insert_type(declaration_engine, TypeInfo::Unknown);
+    let typed_expr = {
+        let ctx = ctx
+            .by_ref()
+            .with_type_annotation(type_ascription)
+            .with_help_text("");
+        check!(
+            ty::TyExpression::type_check(ctx, expr),
+            return err(warnings, errors),
+            warnings,
+            errors
+        )
+    };
+    let expr_type = typed_expr.return_type;
+    let expr_type_info = type_engine.look_up_type_id(expr_type);
+    if matches!(expr_type_info, TypeInfo::ErrorRecovery) {
+        return err(warnings, errors);
+    }
+    let (kind, type_params) = match TryKind::of(&ctx, &expr_type_info) {
+        Some(kind) => kind,
+        None => {
+            errors.push(CompileError::TryOnNonOptionOrResult {
+                ty: engines.help_out(expr_type).to_string(),
+                span: expr_span,
+            });
+            return err(warnings, errors);
+        }
+    };
+
+    let fn_return_type = match ctx.function_return_type() {
+        Some(fn_return_type) => fn_return_type,
+        None => {
+            errors.push(CompileError::TryOutsideOfFunction { span });
+            return err(warnings, errors);
+        }
+    };
+    let fn_return_type_info = type_engine.look_up_type_id(fn_return_type);
+    match TryKind::of(&ctx, &fn_return_type_info) {
+        _ if matches!(fn_return_type_info, TypeInfo::ErrorRecovery) => (),
+        Some((fn_kind, fn_type_params)) if fn_kind == kind => {
+            if let (TryKind::Result, Some(error_type), Some(fn_error_type)) =
+                (kind, type_params.get(1), fn_type_params.get(1))
+            {
+                if !same_type(engines, *error_type, *fn_error_type) {
+                    errors.push(CompileError::TryErrorTypeMismatch {
+                        error_type: engines.help_out(*error_type).to_string(),
+                        fn_error_type: engines.help_out(*fn_error_type).to_string(),
+                        span: span.clone(),
+                    });
+                }
+            }
+        }
+        _ => errors.push(CompileError::TryIncompatibleReturnType {
+            kind: kind.as_str(),
+            fn_return_type: engines.help_out(fn_return_type).to_string(),
+            span: span.clone(),
+        }),
+    }
+    if !errors.is_empty() {
+        return err(warnings, errors);
+    }
+
+    // Bind the typed scrutinee in the scope of the desugared block, and type check only the
+    // `match` on it.
+    let scrutinee = Ident::new_with_override(TRY_SCRUTINEE_VAR_NAME, span.clone());
+    let scrutinee_decl =
+        ty::TyDeclaration::VariableDeclaration(Box::new(ty::TyVariableDeclaration {
+            name: scrutinee.clone(),
+            body: typed_expr,
+            mutability: ty::VariableMutability::Immutable,
+            return_type: expr_type,
+            type_ascription,
+            type_ascription_span: None,
+        }));
+    let mut block_namespace = ctx.namespace.clone();
+    let block_ctx = ctx.by_ref().scoped(&mut block_namespace);
+    block_ctx
+        .namespace
+        .insert_symbol(scrutinee.clone(), scrutinee_decl.clone());
+    let typed_match = check!(
+        ty::TyExpression::type_check(block_ctx, try_match(kind, &scrutinee, &span)),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
+    let return_type = typed_match.return_type;
+    let contents = vec![
+        ty::TyAstNode {
+            content: ty::TyAstNodeContent::Declaration(scrutinee_decl),
+            span: span.clone(),
+        },
+        ty::TyAstNode {
+            content: ty::TyAstNodeContent::ImplicitReturnExpression(typed_match),
+            span: span.clone(),
+        },
+    ];
+    let typed_expr = ty::TyExpression {
+        expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock { contents }),
+        return_type,
+        span,
+    };
+    ok(typed_expr, warnings, errors)
+}
+
+/// Whether the error types `a` and `b` are the same, giving the benefit of the doubt to types
+/// that are yet to be inferred.
+fn same_type(engines: Engines<'_>, a: TypeId, b: TypeId) -> bool {
+    let type_engine = engines.te();
+    match (
+        type_engine.look_up_type_id(a),
+        type_engine.look_up_type_id(b),
+    ) {
+        (TypeInfo::Unknown | TypeInfo::ErrorRecovery, _)
+        | (_, TypeInfo::Unknown | TypeInfo::ErrorRecovery) => true,
+        (TypeInfo::Numeric, TypeInfo::Numeric | TypeInfo::UnsignedInteger(_))
+        | (TypeInfo::UnsignedInteger(_), TypeInfo::Numeric) => true,
+        (a, b) => a.eq(&b, engines),
+    }
+}
+
+fn try_match(kind: TryKind, scrutinee: &Ident, span: &Span) -> Expression {
+    let (unwrapped, returned) = kind.variant_names();
+    let value = Ident::new_with_override(TRY_VALUE_VAR_NAME, span.clone());
+    let unwrap_branch = MatchBranch {
+        scrutinee: Scrutinee::EnumScrutinee {
+            call_path: kind.path(Some(unwrapped), span),
+            value: Box::new(Scrutinee::Variable {
+                name: value.clone(),
+                span: span.clone(),
+            }),
+            span: span.clone(),
+        },
+        guard: None,
+        result: variable(&value, span),
+        span: span.clone(),
+    };
+    let (returned_value, returned_args) = match kind {
+        TryKind::Option => (Scrutinee::CatchAll { span: span.clone() }, vec![]),
+        TryKind::Result => {
+            let error = Ident::new_with_override(TRY_ERROR_VAR_NAME, span.clone());
+            (
+                Scrutinee::Variable {
+                    name: error.clone(),
+                    span: span.clone(),
+                },
+                vec![variable(&error, span)],
+            )
+        }
+    };
+    let return_branch = MatchBranch {
+        scrutinee: Scrutinee::EnumScrutinee {
+            call_path: kind.path(Some(returned), span),
+            value: Box::new(returned_value),
+            span: span.clone(),
+        },
+        guard: None,
+        result: Expression {
+            kind: ExpressionKind::Return(Box::new(Expression {
+                kind: ExpressionKind::DelineatedPath(Box::new(DelineatedPathExpression {
+                    call_path_binding: TypeBinding {
+                        inner: kind.path(Some(returned), span),
+                        type_arguments: vec![],
+                        span: span.clone(),
+                    },
+                    args: returned_args,
+                })),
+                span: span.clone(),
+            })),
+            span: span.clone(),
+        },
+        span: span.clone(),
+    };
+    Expression {
+        kind: ExpressionKind::Match(MatchExpression {
+            value: Box::new(variable(scrutinee, span)),
+            branches: vec![unwrap_branch, return_branch],
+        }),
+        span: span.clone(),
+    }
+}
+
+fn variable(name: &Ident, span: &Span) -> Expression {
+    Expression {
+        kind: ExpressionKind::Variable(name.clone()),
+        span: span.clone(),
+    }
+}
@@ -2,7 +2,7 @@ use sway_types::{integer_bits::IntegerBits, Span};
 
 use crate::{
     language::{ty, Literal},
-    semantic_analysis::ast_node::expression::match_expression::MatchReqMap,
+    semantic_analysis::ast_node::expression::match_expression::{MatchReq, MatchReqMap},
     Engines, TypeInfo,
 };
 // currently the unsafe downcast expr is only used for enums, so this method is specialized for enums
@@ -14,7 +14,7 @@ pub(crate) fn instantiate_unsafe_downcast(
 ) -> (MatchReqMap, ty::TyExpression) {
     let type_engine = engines.te();
     let declaration_engine = engines.de();
-    let match_req_map = vec![(
+    let match_req_map = vec![MatchReq::Eq(
         ty::TyExpression {
             expression: ty::TyExpressionVariant::EnumTag {
                 exp: Box::new(exp.clone()),
@@ -180,6 +180,7 @@ fn analyze_expression(
         // base cases: no warnings can be emitted
         Literal(_)
         | VariableExpression { .. }
+        | AssociatedConstant { .. }
         | FunctionParameter
         | StorageAccess(_)
         | Break
@@ -267,15 +268,38 @@ fn analyze_expression(
             }
             set_union(intr_effs, args_effs)
         }
-        Tuple { fields: exprs } | Array { contents: exprs } => {
+        Tuple { fields: exprs }
+        | Array { contents: exprs }
+        | Closure {
+            captures: exprs, ..
+        } => {
             // assuming left-to-right fields/elements evaluation
             analyze_expressions(engines, exprs.iter().collect(), block_name, warnings)
         }
-        StructExpression { fields, .. } => {
-            // assuming left-to-right fields evaluation
+        ClosureCall { closure, arguments } => {
+            let closure_effs = effects_of_closure_call(engines, closure);
+            let args_effs = analyze_expressions(
+                engines,
+                std::iter::once(&**closure)
+                    .chain(arguments.iter().map(|(_, e)| e))
+                    .collect(),
+                block_name,
+                warnings,
+            );
+            if args_effs.contains(&Effect::Interaction) {
+                warn_after_interaction(&closure_effs, &expr.span, &expr.span, block_name, warnings)
+            }
+            set_union(closure_effs, args_effs)
+        }
+        StructExpression { fields, base, .. } => {
+            // assuming left-to-right fields evaluation, followed by the base
             analyze_expressions(
                 engines,
-                fields.iter().map(|e| &e.value).collect(),
+                fields
+                    .iter()
+                    .map(|e| &e.value)
+                    .chain(base.as_deref())
+                    .collect(),
                 block_name,
                 warnings,
             )
@@ -285,6 +309,8 @@ fn analyze_expression(
         | Return(expr)
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
+        | Ref(expr)
+        | Deref(expr)
         | AbiCast { address: expr, .. } => analyze_expression(engines, expr, block_name, warnings),
         EnumInstantiation { contents, .. } => match contents {
             Some(expr) => analyze_expression(engines, expr, block_name, warnings),
@@ -480,6 +506,23 @@ fn effects_of_codeblock_decl(engines: Engines<'_>, decl: &ty::TyDeclaration) ->
     }
 }
 
+/// The effects of the body of the closure called by a closure call. The closure passed to a
+/// parameter of a function type is only known once the function is instantiated, so such calls
+/// are assumed to have no effects.
+fn effects_of_closure_call(engines: Engines<'_>, closure: &ty::TyExpression) -> HashSet<Effect> {
+    match engines.te().look_up_type_id(closure.return_type) {
+        crate::TypeInfo::Closure { decl_id, .. } => {
+            let fn_body = engines
+                .de()
+                .get_function(decl_id, &closure.span)
+                .unwrap()
+                .body;
+            effects_of_codeblock(engines, &fn_body)
+        }
+        _ => HashSet::new(),
+    }
+}
+
 fn effects_of_expression(engines: Engines<'_>, expr: &ty::TyExpression) -> HashSet<Effect> {
     use crate::ty::TyExpressionVariant::*;
     let type_engine = engines.te();
@@ -487,6 +530,7 @@ fn effects_of_expression(engines: Engines<'_>, expr: &ty::TyExpression) -> HashS
     match &expr.expression {
         Literal(_)
         | VariableExpression { .. }
+        | AssociatedConstant { .. }
         | FunctionParameter
         | Break
         | Continue
@@ -520,10 +564,26 @@ fn effects_of_expression(engines: Engines<'_>, expr: &ty::TyExpression) -> HashS
             effs.extend(rhs_effs);
             effs
         }
-        Tuple { fields: exprs } | Array { contents: exprs } => {
-            effects_of_expressions(engines, exprs)
+        Tuple { fields: exprs }
+        | Array { contents: exprs }
+        | Closure {
+            captures: exprs, ..
+        } => effects_of_expressions(engines, exprs),
+        ClosureCall { closure, arguments } => {
+            let mut effs = effects_of_closure_call(engines, closure);
+            effs.extend(effects_of_expression(engines, closure));
+            effs.extend(map_hashsets_union(arguments, |e| {
+                effects_of_expression(engines, &e.1)
+            }));
+            effs
+        }
+        StructExpression { fields, base, .. } => {
+            let mut effs = effects_of_struct_expressions(engines, fields);
+            if let Some(base) = base {
+                effs.extend(effects_of_expression(engines, base));
+            }
+            effs
         }
-        StructExpression { fields, .. } => effects_of_struct_expressions(engines, fields),
         CodeBlock(codeblock) => effects_of_codeblock(engines, codeblock),
         IfExp {
             condition,
@@ -543,6 +603,8 @@ fn effects_of_expression(engines: Engines<'_>, expr: &ty::TyExpression) -> HashS
         | TupleElemAccess { prefix: expr, .. }
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
+        | Ref(expr)
+        | Deref(expr)
         | Return(expr) => effects_of_expression(engines, expr),
         EnumInstantiation { contents, .. } => match contents {
             Some(expr) => effects_of_expression(engines, expr),
@@ -27,12 +27,14 @@ pub fn possibly_nonzero_u64_expression(
                         }
                         ty::TyDeclaration::ConstantDeclaration(decl_id) => {
                             match declaration_engine.get_constant(decl_id.clone(), &expr.span) {
-                                Ok(const_decl) => possibly_nonzero_u64_expression(
+                                Ok(ty::TyConstantDeclaration {
+                                    value: Some(value), ..
+                                }) => possibly_nonzero_u64_expression(
                                     namespace,
                                     declaration_engine,
-                                    &const_decl.value,
+                                    &value,
                                 ),
-                                Err(_) => true,
+                                _ => true,
                             }
                         }
                         _ => true, // impossible cases, true is a safer option here
@@ -51,6 +53,8 @@ pub fn possibly_nonzero_u64_expression(
         // Since we don't analyze the following types of expressions, we just assume
         // those result in non-zero amount of coins
         FunctionApplication { .. }
+        | ClosureCall { .. }
+        | AssociatedConstant { .. }
         | ArrayIndex { .. }
         | CodeBlock(_)
         | IfExp { .. }
@@ -63,6 +67,7 @@ pub fn possibly_nonzero_u64_expression(
         // but we still consider these as non-zero to be on the safe side
         LazyOperator { .. }
         | Tuple { .. }
+        | Closure { .. }
         | Array { .. }
         | StructExpression { .. }
         | FunctionParameter
@@ -72,6 +77,8 @@ pub fn possibly_nonzero_u64_expression(
         | AbiName(_)
         | UnsafeDowncast { .. }
         | EnumTag { .. }
+        | Ref(_)
+        | Deref(_)
         | Break
         | Continue
         | Reassignment(_)
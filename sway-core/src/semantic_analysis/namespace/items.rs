@@ -115,7 +115,8 @@ impl Items {
         if self.symbols.get(&name).is_some() {
             match item {
                 ty::TyDeclaration::EnumDeclaration { .. }
-                | ty::TyDeclaration::StructDeclaration { .. } => {
+                | ty::TyDeclaration::StructDeclaration { .. }
+                | ty::TyDeclaration::TypeAliasDeclaration { .. } => {
                     errors.push(CompileError::ShadowsOtherSymbol { name: name.clone() });
                 }
                 ty::TyDeclaration::GenericTypeForFunctionScope { .. } => {
@@ -192,14 +193,32 @@ impl Items {
             .get_methods_for_type(engines, type_id)
     }
 
-    pub(crate) fn get_methods_for_type_and_trait_name(
+    pub(crate) fn get_constants_for_type(
+        &self,
+        engines: Engines<'_>,
+        type_id: TypeId,
+    ) -> Vec<DeclarationId> {
+        self.implemented_traits
+            .get_constants_for_type(engines, type_id)
+    }
+
+    pub(crate) fn get_associated_types_for_type(
+        &self,
+        engines: Engines<'_>,
+        type_id: TypeId,
+    ) -> Vec<DeclarationId> {
+        self.implemented_traits
+            .get_associated_types_for_type(engines, type_id)
+    }
+
+    pub(crate) fn get_items_for_type_and_trait_name(
         &self,
         engines: Engines<'_>,
         type_id: TypeId,
         trait_name: &CallPath,
     ) -> Vec<DeclarationId> {
         self.implemented_traits
-            .get_methods_for_type_and_trait_name(engines, type_id, trait_name)
+            .get_items_for_type_and_trait_name(engines, type_id, trait_name)
     }
 
     pub(crate) fn has_storage_declared(&self) -> bool {
@@ -17,7 +17,7 @@ use super::{
 use std::collections::BTreeMap;
 use sway_ast::ItemConst;
 use sway_error::handler::Handler;
-use sway_error::{error::CompileError, handler::ErrorEmitted};
+use sway_error::{error::CompileError, handler::ErrorEmitted, type_error::TypeError};
 use sway_parse::{lex, Parser};
 use sway_types::{span::Span, ConfigTimeConstant, Spanned};
 
@@ -99,7 +99,10 @@ impl Module {
             )?;
 
             // Temporarily disallow non-literals. See https://github.com/FuelLabs/sway/issues/2647.
-            if !matches!(const_decl.value.kind, ExpressionKind::Literal(_)) {
+            if !matches!(
+                const_decl.value.as_ref().map(|value| &value.kind),
+                Some(ExpressionKind::Literal(_))
+            ) {
                 return Err(
                     handler.emit_err(CompileError::ConfigTimeConstantNotALiteral {
                         span: const_item_span,
@@ -113,8 +116,29 @@ impl Module {
             };
             let mut ns = Namespace::init_root(Default::default());
             let type_check_ctx = TypeCheckContext::from_root(&mut ns, engines);
+            let mut errors = vec![];
             let typed_node = ty::TyAstNode::type_check(type_check_ctx, ast_node)
-                .unwrap(&mut vec![], &mut vec![]);
+                .unwrap(&mut vec![], &mut errors);
+            // report values that don't match the declared type in terms of the manifest, as the
+            // generated const decl is never seen by the user.
+            if !errors.is_empty() {
+                let mut error_emitted = None;
+                for error in errors {
+                    let error = match error {
+                        CompileError::TypeError(TypeError::MismatchedType { received, .. }) => {
+                            CompileError::ConfigTimeConstantTypeMismatch {
+                                name: name.as_str().to_string(),
+                                expected: r#type.clone(),
+                                received,
+                                span: const_item_span.clone(),
+                            }
+                        }
+                        error => error,
+                    };
+                    error_emitted = Some(handler.emit_err(error));
+                }
+                return Err(error_emitted.unwrap());
+            }
             // get the decl out of the typed node:
             // we know as an invariant this must be a const decl, as we hardcoded a const decl in
             // the above `format!`.  if it isn't we report an
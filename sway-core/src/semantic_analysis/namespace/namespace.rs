@@ -125,6 +125,51 @@ impl Namespace {
         )
     }
 
+    /// Replaces any type aliases named within `type_id` with the unresolved types they stand for.
+    ///
+    /// Unlike [Namespace::resolve_type_with_self], the result is not resolved. It is used for the
+    /// initial types of declarations, from which the JSON ABI is generated.
+    pub(crate) fn expand_type_aliases(&self, engines: Engines<'_>, type_id: TypeId) -> TypeId {
+        let type_engine = engines.te();
+        let declaration_engine = engines.de();
+        let expand_arg = |type_arg: &TypeArgument| {
+            let type_id = self.expand_type_aliases(engines, type_arg.initial_type_id);
+            TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: type_arg.span.clone(),
+            }
+        };
+        let type_info = match type_engine.look_up_type_id(type_id) {
+            TypeInfo::Custom {
+                name,
+                type_arguments,
+            } => {
+                let type_arguments = type_arguments.map(|type_arguments| {
+                    type_arguments.iter().map(expand_arg).collect::<Vec<_>>()
+                });
+                if let Some(ty::TyDeclaration::TypeAliasDeclaration(decl_id)) =
+                    self.root.resolve_symbol(&self.mod_path, &name).value
+                {
+                    if let Ok(decl) =
+                        declaration_engine.get_type_alias(decl_id.clone(), &name.span())
+                    {
+                        return decl.expand(engines, &type_arguments.unwrap_or_default());
+                    }
+                }
+                TypeInfo::Custom {
+                    name,
+                    type_arguments,
+                }
+            }
+            TypeInfo::Tuple(fields) => TypeInfo::Tuple(fields.iter().map(expand_arg).collect()),
+            TypeInfo::Array(elem_ty, length) => TypeInfo::Array(expand_arg(&elem_ty), length),
+            TypeInfo::Ref(ty) => TypeInfo::Ref(expand_arg(&ty)),
+            _ => return type_id,
+        };
+        type_engine.insert_type_always(type_info)
+    }
+
     /// Short-hand for calling [Root::resolve_type_without_self] on `root` and with the `mod_path`.
     pub(crate) fn resolve_type_without_self(
         &mut self,
@@ -242,6 +287,129 @@ impl Namespace {
         err(warnings, errors)
     }
 
+    /// Given a `type_id` and the name of an associated constant `const_name`,
+    /// find the declaration of that constant for the type, either from an
+    /// `impl` block of the type or from a trait constraint on it.
+    ///
+    /// This function will generate a symbol not found error if the constant
+    /// is not found.
+    pub(crate) fn find_constant_for_type(
+        &mut self,
+        type_id: TypeId,
+        const_name: &Ident,
+        self_type: TypeId,
+        engines: Engines<'_>,
+    ) -> CompileResult<DeclarationId> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let declaration_engine = engines.de();
+
+        // resolve the type
+        let type_id = check!(
+            self.resolve_type_with_self(
+                engines,
+                type_id,
+                self_type,
+                &const_name.span(),
+                EnforceTypeArguments::No,
+                None
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        // grab the local module
+        let local_module = check!(
+            self.root().check_submodule(&self.mod_path),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        for decl_id in local_module
+            .get_constants_for_type(engines, type_id)
+            .into_iter()
+        {
+            let const_decl = check!(
+                CompileResult::from(
+                    declaration_engine.get_constant(decl_id.clone(), &decl_id.span())
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            if &const_decl.name == const_name {
+                return ok(decl_id, warnings, errors);
+            }
+        }
+
+        errors.push(CompileError::SymbolNotFound {
+            name: const_name.clone(),
+        });
+        err(warnings, errors)
+    }
+
+    /// Given a resolved `type_id` and the name of an associated type
+    /// `type_name`, find the type that `type_id` gives to it, either from the
+    /// `impl` block of a trait for the type or from a trait constraint on it.
+    ///
+    /// This function will generate an associated type not found error if the
+    /// associated type is not found.
+    pub(crate) fn find_associated_type_for_type(
+        &self,
+        type_id: TypeId,
+        type_name: &Ident,
+        mod_path: &Path,
+        engines: Engines<'_>,
+    ) -> CompileResult<TypeId> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let type_engine = engines.te();
+        let declaration_engine = engines.de();
+
+        if type_engine
+            .look_up_type_id(type_id)
+            .eq(&TypeInfo::ErrorRecovery, engines)
+        {
+            return err(warnings, errors);
+        }
+
+        // grab the local module
+        let local_module = check!(
+            self.root().check_submodule(mod_path),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        for decl_id in local_module
+            .get_associated_types_for_type(engines, type_id)
+            .into_iter()
+        {
+            let type_alias = check!(
+                CompileResult::from(
+                    declaration_engine.get_type_alias(decl_id.clone(), &decl_id.span())
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            if &type_alias.name == type_name {
+                return ok(type_alias.ty.type_id, warnings, errors);
+            }
+        }
+
+        errors.push(CompileError::AssociatedTypeNotFound {
+            name: type_name.clone(),
+            type_name: engines.help_out(type_id).to_string(),
+            span: type_name.span(),
+        });
+        err(warnings, errors)
+    }
+
     /// Short-hand for performing a [Module::star_import] with `mod_path` as the destination.
     pub(crate) fn star_import(&mut self, src: &Path, engines: Engines<'_>) -> CompileResult<()> {
         self.root.star_import(src, &self.mod_path, engines)
@@ -4,7 +4,7 @@ use sway_error::error::CompileError;
 use sway_types::{Ident, Span, Spanned};
 
 use crate::{
-    declaration_engine::DeclarationId,
+    declaration_engine::{declaration_wrapper::DeclarationWrapper, DeclarationId},
     engine_threading::*,
     error::*,
     language::CallPath,
@@ -62,7 +62,10 @@ impl OrdWithEngines for TraitKey {
     }
 }
 
-/// Map of function name to [TyFunctionDeclaration](ty::TyFunctionDeclaration)
+/// Map of function, associated constant and associated type names to their
+/// [TyFunctionDeclaration](ty::TyFunctionDeclaration)s,
+/// [TyConstantDeclaration](ty::TyConstantDeclaration)s and
+/// [TyTypeAliasDeclaration](ty::TyTypeAliasDeclaration)s.
 type TraitMethods = im::HashMap<String, DeclarationId>;
 
 #[derive(Clone, Debug)]
@@ -85,7 +88,8 @@ pub(crate) struct TraitMap {
 
 impl TraitMap {
     /// Given a [TraitName] `trait_name`, [TypeId] `type_id`, and list of
-    /// [TyFunctionDeclaration](ty::TyFunctionDeclaration) `methods`, inserts
+    /// [TyFunctionDeclaration](ty::TyFunctionDeclaration) and
+    /// [TyConstantDeclaration](ty::TyConstantDeclaration) `methods`, inserts
     /// `methods` into the [TraitMap] with the key `(trait_name, type_id)`.
     ///
     /// This method is as conscious as possible of existing entries in the
@@ -111,13 +115,22 @@ impl TraitMap {
 
         let mut trait_methods: TraitMethods = im::HashMap::new();
         for decl_id in methods.iter() {
-            let method = check!(
-                CompileResult::from(declaration_engine.get_function(decl_id.clone(), impl_span)),
-                return err(warnings, errors),
-                warnings,
-                errors
-            );
-            trait_methods.insert(method.name.to_string(), decl_id.clone());
+            let name = match declaration_engine.look_up_decl_id(decl_id.clone()) {
+                DeclarationWrapper::Constant(const_decl) => const_decl.name,
+                DeclarationWrapper::TypeAlias(type_alias) => type_alias.name,
+                _ => {
+                    check!(
+                        CompileResult::from(
+                            declaration_engine.get_function(decl_id.clone(), impl_span)
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    )
+                    .name
+                }
+            };
+            trait_methods.insert(name.to_string(), decl_id.clone());
         }
 
         // check to see if adding this trait will produce a conflicting definition
@@ -193,14 +206,10 @@ impl TraitMap {
             } else if types_are_subset {
                 for (name, decl_id) in trait_methods.iter() {
                     if map_trait_methods.get(name).is_some() {
-                        let method = check!(
-                            CompileResult::from(
-                                declaration_engine.get_function(decl_id.clone(), impl_span)
-                            ),
-                            return err(warnings, errors),
-                            warnings,
-                            errors
-                        );
+                        let method = match declaration_engine.look_up_decl_id(decl_id.clone()) {
+                            DeclarationWrapper::Function(method) => method,
+                            _ => continue,
+                        };
                         errors.push(CompileError::DuplicateMethodsDefinedForType {
                             func_name: method.name.to_string(),
                             type_implementing_for: engines.help_out(type_id).to_string(),
@@ -649,31 +658,83 @@ impl TraitMap {
         engines: Engines<'_>,
         type_id: TypeId,
     ) -> Vec<DeclarationId> {
+        self.get_items_for_type(engines, type_id)
+            .into_iter()
+            .filter(|decl_id| {
+                matches!(
+                    engines.de().look_up_decl_id(decl_id.clone()),
+                    DeclarationWrapper::Function(_)
+                )
+            })
+            .collect()
+    }
+
+    /// Find the associated constants of the entries in `self` that are
+    /// equivalent to `type_id`.
+    ///
+    /// Equivalency is defined as in [TraitMap::get_methods_for_type].
+    pub(crate) fn get_constants_for_type(
+        &self,
+        engines: Engines<'_>,
+        type_id: TypeId,
+    ) -> Vec<DeclarationId> {
+        self.get_items_for_type(engines, type_id)
+            .into_iter()
+            .filter(|decl_id| {
+                matches!(
+                    engines.de().look_up_decl_id(decl_id.clone()),
+                    DeclarationWrapper::Constant(_)
+                )
+            })
+            .collect()
+    }
+
+    /// Find the associated types of the entries in `self` that are equivalent
+    /// to `type_id`.
+    ///
+    /// Equivalency is defined as in [TraitMap::get_methods_for_type].
+    pub(crate) fn get_associated_types_for_type(
+        &self,
+        engines: Engines<'_>,
+        type_id: TypeId,
+    ) -> Vec<DeclarationId> {
+        self.get_items_for_type(engines, type_id)
+            .into_iter()
+            .filter(|decl_id| {
+                matches!(
+                    engines.de().look_up_decl_id(decl_id.clone()),
+                    DeclarationWrapper::TypeAlias(_)
+                )
+            })
+            .collect()
+    }
+
+    fn get_items_for_type(&self, engines: Engines<'_>, type_id: TypeId) -> Vec<DeclarationId> {
         let type_engine = engines.te();
-        let mut methods = vec![];
+        let mut items = vec![];
         // small performance gain in bad case
         if type_engine
             .look_up_type_id(type_id)
             .eq(&TypeInfo::ErrorRecovery, engines)
         {
-            return methods;
+            return items;
         }
         for entry in self.trait_impls.iter() {
             if are_equal_minus_dynamic_types(type_engine, type_id, entry.key.type_id) {
-                let mut trait_methods = entry
+                let mut trait_items = entry
                     .value
                     .values()
                     .cloned()
                     .into_iter()
                     .collect::<Vec<_>>();
-                methods.append(&mut trait_methods);
+                items.append(&mut trait_items);
             }
         }
-        methods
+        items
     }
 
-    /// Find the entries in `self` that are equivalent to `type_id` with trait
-    /// name `trait_name`.
+    /// Find the methods and associated constants of the entries in `self`
+    /// that are equivalent to `type_id` with trait name `trait_name`.
     ///
     /// Notes:
     /// - equivalency is defined (1) based on whether the types contains types
@@ -682,20 +743,20 @@ impl TraitMap {
     /// - this method does not translate types from the found entries to the
     ///     `type_id` (like in `filter_by_type()`). This is because the only
     ///     entries that qualify as hits are equivalents of `type_id`
-    pub(crate) fn get_methods_for_type_and_trait_name(
+    pub(crate) fn get_items_for_type_and_trait_name(
         &self,
         engines: Engines<'_>,
         type_id: TypeId,
         trait_name: &CallPath,
     ) -> Vec<DeclarationId> {
         let type_engine = engines.te();
-        let mut methods = vec![];
+        let mut items = vec![];
         // small performance gain in bad case
         if type_engine
             .look_up_type_id(type_id)
             .eq(&TypeInfo::ErrorRecovery, engines)
         {
-            return methods;
+            return items;
         }
         for e in self.trait_impls.iter() {
             let map_trait_name = CallPath {
@@ -706,11 +767,11 @@ impl TraitMap {
             if &map_trait_name == trait_name
                 && are_equal_minus_dynamic_types(type_engine, type_id, e.key.type_id)
             {
-                let mut trait_methods = e.value.values().cloned().into_iter().collect::<Vec<_>>();
-                methods.append(&mut trait_methods);
+                let mut trait_items = e.value.values().cloned().into_iter().collect::<Vec<_>>();
+                items.append(&mut trait_items);
             }
         }
-        methods
+        items
     }
 
     /// Checks to see if the trait constraints are satisfied for a given type.
@@ -953,6 +1014,9 @@ fn are_equal_minus_dynamic_types(type_engine: &TypeEngine, left: TypeId, right:
             l1.val() == r1.val()
                 && are_equal_minus_dynamic_types(type_engine, l0.type_id, r0.type_id)
         }
+        (TypeInfo::Ref(l), TypeInfo::Ref(r)) => {
+            are_equal_minus_dynamic_types(type_engine, l.type_id, r.type_id)
+        }
         _ => false,
     }
 }
@@ -52,6 +52,28 @@ pub(crate) fn order_ast_nodes_by_dependency(
     }
 }
 
+/// The names of the variables and functions that `expr` refers to, sorted by name. Names declared
+/// within `expr` itself are included too.
+pub(crate) fn referenced_names(type_engine: &TypeEngine, expr: &Expression) -> Vec<Ident> {
+    let deps = Dependencies {
+        deps: HashSet::new(),
+        fn_values: HashSet::new(),
+    }
+    .gather_from_expr(type_engine, expr);
+    let mut names = deps
+        .deps
+        .into_iter()
+        .chain(deps.fn_values)
+        .filter_map(|dep| match dep {
+            DependentSymbol::Symbol(name) | DependentSymbol::Fn(name, _) => Some(name),
+            DependentSymbol::Impl(..) => None,
+        })
+        .collect::<Vec<_>>();
+    names.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    names.dedup_by(|a, b| a.as_str() == b.as_str());
+    names
+}
+
 // -------------------------------------------------------------------------------------------------
 // Recursion detection.
 
@@ -269,6 +291,8 @@ fn depends_on(
 #[derive(Debug)]
 struct Dependencies {
     deps: HashSet<DependentSymbol>,
+    /// Functions possibly used as values, i.e. variables which might refer to a function.
+    fn_values: HashSet<DependentSymbol>,
 }
 
 impl Dependencies {
@@ -278,13 +302,16 @@ impl Dependencies {
     ) -> Option<(DependentSymbol, Dependencies)> {
         match &node.content {
             AstNodeContent::Declaration(decl) => decl_name(decl).map(|name| {
-                (
-                    name,
-                    Dependencies {
-                        deps: HashSet::new(),
-                    }
-                    .gather_from_decl(type_engine, decl),
-                )
+                let mut deps = Dependencies {
+                    deps: HashSet::new(),
+                    fn_values: HashSet::new(),
+                }
+                .gather_from_decl(type_engine, decl);
+                // A function using a variable named after itself is not recursive.
+                let fn_values = std::mem::take(&mut deps.fn_values);
+                deps.deps
+                    .extend(fn_values.into_iter().filter(|fn_value| fn_value != &name));
+                (name, deps)
             }),
             _ => None,
         }
@@ -299,13 +326,9 @@ impl Dependencies {
             }) => self
                 .gather_from_typeinfo(type_engine, type_ascription)
                 .gather_from_expr(type_engine, body),
-            Declaration::ConstantDeclaration(ConstantDeclaration {
-                type_ascription,
-                value,
-                ..
-            }) => self
-                .gather_from_typeinfo(type_engine, type_ascription)
-                .gather_from_expr(type_engine, value),
+            Declaration::ConstantDeclaration(const_decl) => {
+                self.gather_from_const_decl(type_engine, const_decl)
+            }
             Declaration::FunctionDeclaration(fn_decl) => {
                 self.gather_from_fn_decl(type_engine, fn_decl)
             }
@@ -318,6 +341,13 @@ impl Dependencies {
                     deps.gather_from_typeinfo(type_engine, &field.type_info)
                 })
                 .gather_from_type_parameters(type_parameters),
+            Declaration::TypeAliasDeclaration(TypeAliasDeclaration {
+                ty,
+                type_parameters,
+                ..
+            }) => self
+                .gather_from_typeinfo(type_engine, ty)
+                .gather_from_type_parameters(type_parameters),
             Declaration::EnumDeclaration(EnumDeclaration {
                 variants,
                 type_parameters,
@@ -331,6 +361,8 @@ impl Dependencies {
                 interface_surface,
                 methods,
                 supertraits,
+                constants,
+                types,
                 ..
             }) => self
                 .gather_from_iter(supertraits.iter(), |deps, sup| {
@@ -344,12 +376,20 @@ impl Dependencies {
                 })
                 .gather_from_iter(methods.iter(), |deps, fn_decl| {
                     deps.gather_from_fn_decl(type_engine, fn_decl)
+                })
+                .gather_from_iter(constants.iter(), |deps, const_decl| {
+                    deps.gather_from_const_decl(type_engine, const_decl)
+                })
+                .gather_from_iter(types.iter(), |deps, type_decl| {
+                    deps.gather_from_type_arguments(type_engine, type_decl.ty_opt.as_slice())
                 }),
             Declaration::ImplTrait(ImplTrait {
                 impl_type_parameters,
                 trait_name,
                 type_implementing_for,
                 functions,
+                constants,
+                types,
                 ..
             }) => self
                 .gather_from_call_path(trait_name, false, false)
@@ -357,15 +397,25 @@ impl Dependencies {
                 .gather_from_type_parameters(impl_type_parameters)
                 .gather_from_iter(functions.iter(), |deps, fn_decl| {
                     deps.gather_from_fn_decl(type_engine, fn_decl)
+                })
+                .gather_from_iter(constants.iter(), |deps, const_decl| {
+                    deps.gather_from_const_decl(type_engine, const_decl)
+                })
+                .gather_from_iter(types.iter(), |deps, type_decl| {
+                    deps.gather_from_type_arguments(type_engine, type_decl.ty_opt.as_slice())
                 }),
             Declaration::ImplSelf(ImplSelf {
                 type_implementing_for,
                 functions,
+                constants,
                 ..
             }) => self
                 .gather_from_typeinfo(type_engine, type_implementing_for)
                 .gather_from_iter(functions.iter(), |deps, fn_decl| {
                     deps.gather_from_fn_decl(type_engine, fn_decl)
+                })
+                .gather_from_iter(constants.iter(), |deps, const_decl| {
+                    deps.gather_from_const_decl(type_engine, const_decl)
                 }),
             Declaration::AbiDeclaration(AbiDeclaration {
                 interface_surface,
@@ -388,6 +438,17 @@ impl Dependencies {
         }
     }
 
+    fn gather_from_const_decl(
+        self,
+        type_engine: &TypeEngine,
+        const_decl: &ConstantDeclaration,
+    ) -> Self {
+        self.gather_from_typeinfo(type_engine, &const_decl.type_ascription)
+            .gather_from_iter(const_decl.value.iter(), |deps, value| {
+                deps.gather_from_expr(type_engine, value)
+            })
+    }
+
     fn gather_from_fn_decl(self, type_engine: &TypeEngine, fn_decl: &FunctionDeclaration) -> Self {
         let FunctionDeclaration {
             parameters,
@@ -409,7 +470,10 @@ impl Dependencies {
             ExpressionKind::Variable(name) => {
                 // in the case of ABI variables, we actually want to check if the ABI needs to be
                 // ordered
-                self.gather_from_call_path(&(name.clone()).into(), false, false)
+                let mut this = self.gather_from_call_path(&(name.clone()).into(), false, false);
+                this.fn_values
+                    .insert(DependentSymbol::Fn(name.clone(), None));
+                this
             }
             ExpressionKind::FunctionApplication(function_application_expression) => {
                 let FunctionApplicationExpression {
@@ -456,12 +520,14 @@ impl Dependencies {
                 let StructExpression {
                     call_path_binding,
                     fields,
+                    base,
                 } = &**struct_expression;
                 self.gather_from_call_path(&call_path_binding.inner, false, false)
                     .gather_from_type_arguments(type_engine, &call_path_binding.type_arguments)
                     .gather_from_iter(fields.iter(), |deps, field| {
                         deps.gather_from_expr(type_engine, &field.value)
                     })
+                    .gather_from_opt_expr(type_engine, base.as_deref())
             }
             ExpressionKind::Subfield(SubfieldExpression { prefix, .. }) => {
                 self.gather_from_expr(type_engine, prefix)
@@ -538,21 +604,44 @@ impl Dependencies {
             }) => self
                 .gather_from_expr(type_engine, condition)
                 .gather_from_block(type_engine, body),
-            ExpressionKind::Reassignment(reassignment) => {
-                self.gather_from_expr(type_engine, &reassignment.rhs)
+            ExpressionKind::ForLoop(ForLoopExpression { iterable, body, .. }) => match iterable {
+                ForLoopIterable::Range { start, end } => self
+                    .gather_from_expr(type_engine, start)
+                    .gather_from_expr(type_engine, end),
+                ForLoopIterable::Expression(expr) => self.gather_from_expr(type_engine, expr),
+            }
+            .gather_from_block(type_engine, body),
+            ExpressionKind::Reassignment(reassignment) => match &reassignment.lhs {
+                ReassignmentTarget::VariableExpression(lhs) => {
+                    self.gather_from_expr(type_engine, lhs)
+                }
+                ReassignmentTarget::StorageField(_) => self,
             }
-            ExpressionKind::Return(expr) => self.gather_from_expr(type_engine, expr),
+            .gather_from_expr(type_engine, &reassignment.rhs),
+            ExpressionKind::Closure(closure) => self
+                .gather_from_iter(closure.parameters.iter(), |deps, param| {
+                    deps.gather_from_typeinfo(type_engine, &param.type_info)
+                })
+                .gather_from_expr(type_engine, &closure.body),
+            ExpressionKind::Return(expr)
+            | ExpressionKind::Try(expr)
+            | ExpressionKind::Ref(expr)
+            | ExpressionKind::Deref(expr) => self.gather_from_expr(type_engine, expr),
         }
     }
 
     fn gather_from_match_branch(self, type_engine: &TypeEngine, branch: &MatchBranch) -> Self {
         let MatchBranch {
-            scrutinee, result, ..
+            scrutinee,
+            guard,
+            result,
+            ..
         } = branch;
         self.gather_from_iter(
             scrutinee.gather_approximate_typeinfo_dependencies().iter(),
             |deps, type_info| deps.gather_from_typeinfo(type_engine, type_info),
         )
+        .gather_from_opt_expr(type_engine, guard.as_ref())
         .gather_from_expr(type_engine, result)
     }
 
@@ -648,8 +737,14 @@ impl Dependencies {
             TypeInfo::Tuple(elems) => self.gather_from_iter(elems.iter(), |deps, elem| {
                 deps.gather_from_typeinfo(type_engine, &type_engine.look_up_type_id(elem.type_id))
             }),
-            TypeInfo::Array(elem_type, _) => self
+            TypeInfo::Array(elem_type, _) | TypeInfo::Ref(elem_type) => self
                 .gather_from_typeinfo(type_engine, &type_engine.look_up_type_id(elem_type.type_id)),
+            TypeInfo::TraitType { qualifier, .. } => {
+                self.gather_from_typeinfo(type_engine, &type_engine.look_up_type_id(*qualifier))
+            }
+            TypeInfo::Function { .. } => {
+                self.gather_from_type_arguments(type_engine, &type_info.signature_type_arguments())
+            }
             TypeInfo::Struct { fields, .. } => {
                 self.gather_from_iter(fields.iter(), |deps, field| {
                     deps.gather_from_typeinfo(
@@ -746,6 +841,7 @@ fn decl_name(decl: &Declaration) -> Option<DependentSymbol> {
         Declaration::EnumDeclaration(decl) => dep_sym(decl.name.clone()),
         Declaration::TraitDeclaration(decl) => dep_sym(decl.name.clone()),
         Declaration::AbiDeclaration(decl) => dep_sym(decl.name.clone()),
+        Declaration::TypeAliasDeclaration(decl) => dep_sym(decl.name.clone()),
 
         // These have the added complexity of converting CallPath and/or TypeInfo into a name.
         Declaration::ImplSelf(decl) => {
@@ -815,6 +911,10 @@ fn type_info_name(type_info: &TypeInfo) -> String {
         TypeInfo::Storage { .. } => "contract storage",
         TypeInfo::RawUntypedPtr => "raw untyped ptr",
         TypeInfo::RawUntypedSlice => "raw untyped slice",
+        TypeInfo::Ref(..) => "ref",
+        TypeInfo::Function { .. } => "function",
+        TypeInfo::Closure { .. } => "closure",
+        TypeInfo::TraitType { name, .. } => return format!("trait type {}", name),
     }
     .to_string()
 }
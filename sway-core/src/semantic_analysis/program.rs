@@ -24,7 +24,11 @@ impl ty::TyProgram {
         let mut namespace = Namespace::init_root(initial_namespace);
         let ctx =
             TypeCheckContext::from_root(&mut namespace, engines).with_kind(parsed.kind.clone());
-        let ParseProgram { root, kind } = parsed;
+        let ParseProgram {
+            root,
+            kind,
+            lint_scopes,
+        } = parsed;
         let mod_span = root.tree.span.clone();
         let mod_res = ty::TyModule::type_check(ctx, root);
         mod_res.flat_map(|root| {
@@ -36,6 +40,7 @@ impl ty::TyProgram {
                 storage_slots: vec![],
                 logged_types: vec![],
                 messages_types: vec![],
+                lint_scopes: lint_scopes.clone(),
             })
         })
     }
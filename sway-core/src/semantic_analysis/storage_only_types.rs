@@ -21,6 +21,7 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
     match &expr.expression {
         ty::TyExpressionVariant::Literal(_)
         | ty::TyExpressionVariant::VariableExpression { .. }
+        | ty::TyExpressionVariant::AssociatedConstant { .. }
         | ty::TyExpressionVariant::FunctionParameter
         | ty::TyExpressionVariant::AsmExpression { .. }
         | ty::TyExpressionVariant::StorageAccess(_)
@@ -52,10 +53,13 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
                 check!(expr_validate(engines, f), continue, warnings, errors)
             }
         }
-        ty::TyExpressionVariant::StructExpression { fields, .. } => {
+        ty::TyExpressionVariant::StructExpression { fields, base, .. } => {
             for f in fields {
                 check!(expr_validate(engines, &f.value), continue, warnings, errors);
             }
+            if let Some(base) = base {
+                check!(expr_validate(engines, base), (), warnings, errors);
+            }
         }
         ty::TyExpressionVariant::CodeBlock(cb) => {
             check!(
@@ -80,7 +84,9 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
         | ty::TyExpressionVariant::TupleElemAccess { prefix: exp, .. }
         | ty::TyExpressionVariant::AbiCast { address: exp, .. }
         | ty::TyExpressionVariant::EnumTag { exp }
-        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. } => {
+        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+        | ty::TyExpressionVariant::Ref(exp)
+        | ty::TyExpressionVariant::Deref(exp) => {
             check!(expr_validate(engines, exp), (), warnings, errors)
         }
         ty::TyExpressionVariant::EnumInstantiation { contents, .. } => {
@@ -125,6 +131,29 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
         ty::TyExpressionVariant::Return(exp) => {
             check!(expr_validate(engines, exp), (), warnings, errors)
         }
+        ty::TyExpressionVariant::Closure { decl_id, captures } => {
+            for f in captures {
+                check!(expr_validate(engines, f), continue, warnings, errors)
+            }
+            let fn_decl = check!(
+                CompileResult::from(engines.de().get_function(decl_id.clone(), &expr.span)),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            check!(
+                validate_decls_for_storage_only_types_in_codeblock(engines, &fn_decl.body),
+                (),
+                warnings,
+                errors
+            );
+        }
+        ty::TyExpressionVariant::ClosureCall { closure, arguments } => {
+            check!(expr_validate(engines, closure), (), warnings, errors);
+            for f in arguments {
+                check!(expr_validate(engines, &f.1), continue, warnings, errors);
+            }
+        }
     }
     ok((), warnings, errors)
 }
@@ -192,13 +221,15 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDeclaration) -> CompileResul
                 warnings,
                 errors
             );
-            check!(
-                check_type(engines, expr.return_type, name.span(), false),
-                (),
-                warnings,
-                errors
-            );
-            check!(expr_validate(engines, &expr), (), warnings, errors)
+            if let Some(expr) = expr {
+                check!(
+                    check_type(engines, expr.return_type, name.span(), false),
+                    (),
+                    warnings,
+                    errors
+                );
+                check!(expr_validate(engines, &expr), (), warnings, errors)
+            }
         }
         ty::TyDeclaration::FunctionDeclaration(decl_id) => {
             let ty::TyFunctionDeclaration {
@@ -298,7 +329,9 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDeclaration) -> CompileResul
                 );
             }
         }
-        ty::TyDeclaration::GenericTypeForFunctionScope { .. }
+        // Aliases may name storage-only types, which are checked wherever the alias is used.
+        ty::TyDeclaration::TypeAliasDeclaration(_)
+        | ty::TyDeclaration::GenericTypeForFunctionScope { .. }
         | ty::TyDeclaration::ErrorRecovery(_) => {}
     }
     ok((), warnings, errors)
@@ -42,6 +42,11 @@ pub struct TypeCheckContext<'a> {
     ///
     /// Assists type inference.
     type_annotation: TypeId,
+    /// While type-checking the body of a function, this is the function's return type.
+    ///
+    /// Used to check early returns that are implied rather than written out, like those of the
+    /// `?` operator.
+    function_return_type: Option<TypeId>,
     /// Whether or not we're within an `abi` implementation.
     ///
     /// This is `ImplAbiFn` while checking `abi` implementations whether at their original impl
@@ -79,6 +84,7 @@ impl<'a> TypeCheckContext<'a> {
             type_engine,
             declaration_engine,
             type_annotation: type_engine.insert_type(declaration_engine, TypeInfo::Unknown),
+            function_return_type: None,
             help_text: "",
             // TODO: Contract? Should this be passed in based on program kind (aka TreeType)?
             self_type: type_engine.insert_type(declaration_engine, TypeInfo::Contract),
@@ -100,6 +106,7 @@ impl<'a> TypeCheckContext<'a> {
         TypeCheckContext {
             namespace: self.namespace,
             type_annotation: self.type_annotation,
+            function_return_type: self.function_return_type,
             self_type: self.self_type,
             mode: self.mode,
             help_text: self.help_text,
@@ -115,6 +122,7 @@ impl<'a> TypeCheckContext<'a> {
         TypeCheckContext {
             namespace,
             type_annotation: self.type_annotation,
+            function_return_type: self.function_return_type,
             self_type: self.self_type,
             mode: self.mode,
             help_text: self.help_text,
@@ -159,6 +167,14 @@ impl<'a> TypeCheckContext<'a> {
         }
     }
 
+    /// Map this `TypeCheckContext` instance to a new one with the given function return type.
+    pub(crate) fn with_function_return_type(self, function_return_type: TypeId) -> Self {
+        Self {
+            function_return_type: Some(function_return_type),
+            ..self
+        }
+    }
+
     /// Map this `TypeCheckContext` instance to a new one with the given ABI `mode`.
     pub(crate) fn with_mode(self, mode: Mode) -> Self {
         Self { mode, ..self }
@@ -190,6 +206,10 @@ impl<'a> TypeCheckContext<'a> {
         self.type_annotation
     }
 
+    pub(crate) fn function_return_type(&self) -> Option<TypeId> {
+        self.function_return_type
+    }
+
     pub(crate) fn mode(&self) -> Mode {
         self.mode
     }
@@ -250,6 +270,11 @@ impl<'a> TypeCheckContext<'a> {
         )
     }
 
+    /// Short-hand for calling [Namespace::expand_type_aliases]
+    pub(crate) fn expand_type_aliases(&self, type_id: TypeId) -> TypeId {
+        self.namespace.expand_type_aliases(self.engines(), type_id)
+    }
+
     /// Short-hand for calling [Namespace::resolve_type_without_self]
     pub(crate) fn resolve_type_without_self(
         &mut self,
@@ -20,6 +20,7 @@
 //!
 //!   #[foo(bar, bar)]
 
+use crate::language::Literal;
 use std::{collections::HashMap, sync::Arc};
 use sway_types::{Ident, Span};
 
@@ -29,7 +30,16 @@ use sway_types::{Ident, Span};
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Attribute {
     pub name: Ident,
-    pub args: Vec<Ident>,
+    pub args: Vec<AttributeArg>,
+    pub span: Span,
+}
+
+/// An argument of an attribute, i.e. `read` in `#[storage(read)]` or `feature = "foo"` in
+/// `#[cfg(feature = "foo")]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttributeArg {
+    pub name: Ident,
+    pub value: Option<Literal>,
     pub span: Span,
 }
 
@@ -42,6 +52,10 @@ pub enum AttributeKind {
     Inline,
     Test,
     Payable,
+    Allow,
+    Warn,
+    Deny,
+    Cfg,
 }
 
 /// Stores the attributes associated with the type.
@@ -59,7 +73,7 @@ pub(crate) fn generate_json_abi_attributes_map(
                 .flat_map(|(_attr_kind, attrs)| {
                     attrs.iter().map(|attr| fuels_types::Attribute {
                         name: attr.name.to_string(),
-                        arguments: attr.args.iter().map(|arg| arg.to_string()).collect(),
+                        arguments: attr.args.iter().map(|arg| arg.name.to_string()).collect(),
                     })
                 })
                 .collect(),
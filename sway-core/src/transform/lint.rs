@@ -0,0 +1,265 @@
+//! Lint levels set through the `allow`, `warn` and `deny` attributes.
+//!
+//! Each lint attribute applies to the span of the item it annotates, including any nested items.
+//! When placed on a `dep` item, it applies to the whole submodule. Where several attributes
+//! apply, the innermost one wins:
+//!
+//!   #[allow(dead_code)]
+//!   impl Foo {
+//!       #[warn(dead_code)]
+//!       fn bar() {} // Still reported.
+//!       fn baz() {} // Silenced.
+//!   }
+//!
+//! Lints that no attribute applies to fall back to the package-wide levels in the
+//! `BuildConfig` and are otherwise reported as warnings.
+
+use crate::{error::*, BuildConfig};
+
+use sway_ast::{attribute::Annotated, AttributeDecl, Item, ItemKind, Module};
+use sway_error::{
+    error::CompileError,
+    handler::Handler,
+    warning::{CompileWarning, LintLevel, Warning, LINT_NAMES, WARNINGS_LINT_GROUP},
+};
+use sway_types::{
+    constants::{ALLOW_ATTRIBUTE_NAME, DENY_ATTRIBUTE_NAME, WARN_ATTRIBUTE_NAME},
+    Ident, Span, Spanned,
+};
+
+use std::sync::Arc;
+
+/// A lint level set by an attribute, along with the span it applies to.
+#[derive(Debug, Clone)]
+struct LintScope {
+    span: Span,
+    lint: Ident,
+    level: LintLevel,
+}
+
+/// The lint levels set by attributes within a module.
+#[derive(Debug, Clone, Default)]
+pub struct LintScopes {
+    scopes: Vec<LintScope>,
+}
+
+impl LintScopes {
+    /// Collects the lint levels set by attributes on the items of `module`, on top of those
+    /// `inherited` from the `dep` item declaring it.
+    pub(crate) fn collect(handler: &Handler, module: &Module, inherited: LintScopes) -> Self {
+        let mut lint_scopes = inherited;
+        for item in &module.items {
+            lint_scopes.collect_item(handler, item);
+        }
+        lint_scopes
+    }
+
+    /// The lint levels inherited by the submodule declared by the `dep` item. Every lint
+    /// attribute applying to the `dep` item applies to the whole submodule, given by
+    /// `submodule_span`.
+    pub(crate) fn for_submodule(&self, dep: &Item, submodule_span: &Span) -> Self {
+        let dep_span = dep.span();
+        let mut inherited: Vec<&LintScope> = self
+            .scopes
+            .iter()
+            .filter(|scope| span_contains(&scope.span, &dep_span))
+            .collect();
+        // Outermost first, so that inner levels win over outer ones within the submodule.
+        inherited.sort_by_key(|scope| std::cmp::Reverse(span_len(&scope.span)));
+        let scopes = inherited
+            .into_iter()
+            .map(|scope| LintScope {
+                span: submodule_span.clone(),
+                ..scope.clone()
+            })
+            .collect();
+        Self { scopes }
+    }
+
+    /// Adds the lint levels of `other` to these.
+    pub(crate) fn extend(&mut self, other: &LintScopes) {
+        self.scopes.extend(other.scopes.iter().cloned());
+    }
+
+    /// Applies the lint levels to the given `warnings`, returning those still reported as
+    /// warnings. Allowed warnings are dropped and denied ones are pushed onto `errors`.
+    pub(crate) fn apply(
+        &self,
+        build_config: Option<&BuildConfig>,
+        warnings: Vec<CompileWarning>,
+        errors: &mut Vec<CompileError>,
+    ) -> Vec<CompileWarning> {
+        let deny_warnings = matches!(build_config, Some(config) if config.deny_warnings);
+        let mut reported = Vec::with_capacity(warnings.len());
+        for warning in warnings {
+            let lint = warning.warning_content.lint_name();
+            let (level, level_lint) =
+                self.level_at(lint, &warning.span)
+                    .or_else(|| {
+                        let lints = &build_config?.lints;
+                        lints.get(lint).map(|level| (*level, lint)).or_else(|| {
+                            Some((*lints.get(WARNINGS_LINT_GROUP)?, WARNINGS_LINT_GROUP))
+                        })
+                    })
+                    .unwrap_or((LintLevel::Warn, lint));
+            match level {
+                LintLevel::Allow => (),
+                LintLevel::Warn if !deny_warnings => reported.push(warning),
+                LintLevel::Warn => errors.push(CompileError::DeniedLint {
+                    lint: WARNINGS_LINT_GROUP,
+                    warning_content: warning.warning_content,
+                    span: warning.span,
+                }),
+                LintLevel::Deny => errors.push(CompileError::DeniedLint {
+                    lint: level_lint,
+                    warning_content: warning.warning_content,
+                    span: warning.span,
+                }),
+            }
+        }
+        reported
+    }
+
+    /// The level set for `lint` by the innermost attribute applying to `span`, along with the
+    /// name of the lint or lint group the attribute refers to.
+    fn level_at(&self, lint: &'static str, span: &Span) -> Option<(LintLevel, &'static str)> {
+        let mut innermost: Option<&LintScope> = None;
+        for scope in &self.scopes {
+            let name = scope.lint.as_str();
+            if (name != lint && name != WARNINGS_LINT_GROUP) || !span_contains(&scope.span, span) {
+                continue;
+            }
+            // Later attributes win over earlier ones with the same span.
+            match innermost {
+                Some(inner) if span_len(&scope.span) > span_len(&inner.span) => (),
+                _ => innermost = Some(scope),
+            }
+        }
+        innermost.map(|scope| {
+            let name = if scope.lint.as_str() == lint {
+                lint
+            } else {
+                WARNINGS_LINT_GROUP
+            };
+            (scope.level, name)
+        })
+    }
+
+    fn collect_item(&mut self, handler: &Handler, item: &Item) {
+        self.collect_attributes(handler, &item.attribute_list, item.span());
+        match &item.value {
+            ItemKind::Struct(item_struct) => {
+                for field in item_struct.fields.get() {
+                    self.collect_annotated(handler, field);
+                }
+            }
+            ItemKind::Enum(item_enum) => {
+                for field in item_enum.fields.get() {
+                    self.collect_annotated(handler, field);
+                }
+            }
+            ItemKind::Impl(item_impl) => {
+                for impl_item in item_impl.contents.get() {
+                    self.collect_annotated(handler, impl_item);
+                }
+            }
+            ItemKind::Trait(item_trait) => {
+                for trait_item in item_trait.trait_items.get() {
+                    self.collect_annotated(handler, trait_item);
+                }
+                if let Some(trait_defs) = &item_trait.trait_defs_opt {
+                    for trait_def in trait_defs.get() {
+                        self.collect_annotated(handler, trait_def);
+                    }
+                }
+            }
+            ItemKind::Abi(item_abi) => {
+                for (abi_item, _) in item_abi.abi_items.get() {
+                    self.collect_annotated(handler, abi_item);
+                }
+                if let Some(abi_defs) = &item_abi.abi_defs_opt {
+                    for abi_def in abi_defs.get() {
+                        self.collect_annotated(handler, abi_def);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn collect_annotated<T: Spanned>(&mut self, handler: &Handler, annotated: &Annotated<T>) {
+        let span = match annotated.attribute_list.first() {
+            Some(attr0) => Span::join(attr0.span(), annotated.value.span()),
+            None => annotated.value.span(),
+        };
+        self.collect_attributes(handler, &annotated.attribute_list, span);
+    }
+
+    fn collect_attributes(
+        &mut self,
+        handler: &Handler,
+        attribute_list: &[AttributeDecl],
+        span: Span,
+    ) {
+        for attr_decl in attribute_list {
+            for attr in attr_decl.attribute.get() {
+                let level = match attr.name.as_str() {
+                    ALLOW_ATTRIBUTE_NAME => LintLevel::Allow,
+                    WARN_ATTRIBUTE_NAME => LintLevel::Warn,
+                    DENY_ATTRIBUTE_NAME => LintLevel::Deny,
+                    _ => continue,
+                };
+                let lints = attr
+                    .args
+                    .iter()
+                    .flat_map(|args| args.get())
+                    .map(|arg| &arg.name);
+                for lint in lints {
+                    if !LINT_NAMES.contains(&lint.as_str()) {
+                        handler.emit_warn(CompileWarning {
+                            span: lint.span(),
+                            warning_content: Warning::UnknownLint {
+                                lint_name: lint.clone(),
+                            },
+                        });
+                        continue;
+                    }
+                    self.scopes.push(LintScope {
+                        span: span.clone(),
+                        lint: lint.clone(),
+                        level,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Applies the lint levels set by attributes within `scopes` and by the `build_config` to the
+/// warnings of the given result.
+pub(crate) fn apply_lints<T>(
+    scopes: &LintScopes,
+    build_config: Option<&BuildConfig>,
+    res: CompileResult<T>,
+) -> CompileResult<T> {
+    let CompileResult {
+        value,
+        warnings,
+        mut errors,
+    } = res;
+    let warnings = scopes.apply(build_config, warnings, &mut errors);
+    CompileResult::new(value, warnings, errors)
+}
+
+fn span_contains(outer: &Span, inner: &Span) -> bool {
+    let same_source = match (outer.path(), inner.path()) {
+        (Some(outer_path), Some(inner_path)) => outer_path == inner_path,
+        (None, None) => Arc::ptr_eq(outer.src(), inner.src()),
+        _ => false,
+    };
+    same_source && outer.start() <= inner.start() && inner.end() <= outer.end()
+}
+
+fn span_len(span: &Span) -> usize {
+    span.end() - span.start()
+}
@@ -1,4 +1,7 @@
 mod attribute;
+mod lint;
 pub(crate) mod to_parsed_lang;
 
 pub use attribute::*;
+pub(crate) use lint::apply_lints;
+pub use lint::LintScopes;
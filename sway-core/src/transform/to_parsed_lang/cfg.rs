@@ -0,0 +1,122 @@
+//! Conditional compilation of items annotated with the `cfg` attribute.
+//!
+//! An item annotated with `#[cfg(test)]` is only compiled when tests are included in the build,
+//! and an item annotated with `#[cfg(feature = "name")]` is only compiled when the feature `name`
+//! is enabled for the package. An item with more than one predicate, whether within the same
+//! attribute or across several, is only compiled when all of them hold:
+//!
+//! ```ignore
+//! #[cfg(test, feature = "logging")]
+//! fn log_test_value(value: u64) { ... }
+//! ```
+//!
+//! `#[test]` functions are only compiled when tests are included, as though they were annotated
+//! with `#[cfg(test)]`. This allows tests to use the `#[cfg(test)]` items of the module.
+//!
+//! Items are stripped from the module before it is converted to the parse tree, so stripped items
+//! are never type checked. Module level items and the items of `impl`, `trait` and `abi` blocks
+//! may be conditionally compiled.
+
+use crate::BuildConfig;
+use sway_ast::{AttributeDecl, ItemKind, Literal, Module};
+use sway_error::{convert_parse_tree_error::ConvertParseTreeError, handler::Handler};
+use sway_types::{
+    constants::{CFG_ATTRIBUTE_NAME, CFG_FEATURE_NAME, CFG_TEST_NAME, TEST_ATTRIBUTE_NAME},
+    Spanned,
+};
+
+/// Remove all items from the module whose `cfg` predicates do not hold for the given config.
+///
+/// When no config is given, tests are included and no features are enabled.
+pub(crate) fn strip_cfg_items(
+    handler: &Handler,
+    module: &mut Module,
+    config: Option<&BuildConfig>,
+) {
+    module.items.retain_mut(|item| {
+        let enabled = is_enabled(handler, &item.attribute_list, config);
+        if enabled {
+            strip_cfg_inner_items(handler, &mut item.value, config);
+        }
+        enabled
+    });
+}
+
+/// Remove the items of the given `impl`, `trait` or `abi` block whose `cfg` predicates do not
+/// hold for the given config.
+fn strip_cfg_inner_items(handler: &Handler, item: &mut ItemKind, config: Option<&BuildConfig>) {
+    let is_enabled = |attribute_list: &[AttributeDecl]| is_enabled(handler, attribute_list, config);
+    match item {
+        ItemKind::Impl(item_impl) => item_impl
+            .contents
+            .inner
+            .retain(|item| is_enabled(&item.attribute_list)),
+        ItemKind::Trait(item_trait) => {
+            item_trait
+                .trait_items
+                .inner
+                .retain(|item| is_enabled(&item.attribute_list));
+            if let Some(trait_defs) = &mut item_trait.trait_defs_opt {
+                trait_defs
+                    .inner
+                    .retain(|item| is_enabled(&item.attribute_list));
+            }
+        }
+        ItemKind::Abi(item_abi) => {
+            item_abi
+                .abi_items
+                .inner
+                .retain(|(item, _)| is_enabled(&item.attribute_list));
+            if let Some(abi_defs) = &mut item_abi.abi_defs_opt {
+                abi_defs
+                    .inner
+                    .retain(|item| is_enabled(&item.attribute_list));
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Whether or not all `cfg` predicates within the given attributes hold for the given config.
+///
+/// Invalid predicates are reported as errors and otherwise treated as though they hold, so that
+/// the annotated item is still checked.
+fn is_enabled(
+    handler: &Handler,
+    attribute_list: &[AttributeDecl],
+    config: Option<&BuildConfig>,
+) -> bool {
+    let include_tests = config.is_none_or(|config| config.include_tests);
+    let mut enabled = true;
+    for attr in attribute_list
+        .iter()
+        .flat_map(|attr_decl| attr_decl.attribute.get())
+    {
+        match attr.name.as_str() {
+            TEST_ATTRIBUTE_NAME => enabled &= include_tests,
+            CFG_ATTRIBUTE_NAME => {
+                let args: Vec<_> = attr.args.iter().flat_map(|args| args.get()).collect();
+                if args.is_empty() {
+                    let error = ConvertParseTreeError::InvalidCfgPredicate { span: attr.span() };
+                    handler.emit_err(error.into());
+                }
+                for arg in args {
+                    match (arg.name.as_str(), &arg.value) {
+                        (CFG_TEST_NAME, None) => enabled &= include_tests,
+                        (CFG_FEATURE_NAME, Some((_, Literal::String(feature)))) => {
+                            enabled &= config
+                                .is_some_and(|config| config.features.contains(&feature.parsed))
+                        }
+                        _ => {
+                            let error =
+                                ConvertParseTreeError::InvalidCfgPredicate { span: arg.span() };
+                            handler.emit_err(error.into());
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    enabled
+}
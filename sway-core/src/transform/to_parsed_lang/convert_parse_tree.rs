@@ -9,24 +9,26 @@ use sway_ast::{
     attribute::Annotated,
     expr::{ReassignmentOp, ReassignmentOpVariant},
     ty::TyTupleDescriptor,
-    AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
-    CommaToken, Dependency, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField,
-    ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition,
-    IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemKind,
-    ItemStorage, ItemStruct, ItemTrait, ItemUse, LitInt, LitIntType, MatchBranchKind, Module,
-    ModuleKind, Parens, PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern,
-    PatternStructField, PubToken, Punctuated, QualifiedPathRoot, Statement, StatementLet, Traits,
-    Ty, TypeField, UseTree, WhereClause,
+    AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, ClosureParam,
+    ClosureParams, CodeBlockContents, CommaToken, Dependency, DoubleColonToken, Expr,
+    ExprArrayDescriptor, ExprStructField, ExprStructFields, ExprTupleDescriptor, FnArg, FnArgs,
+    FnSignature, ForIterable, GenericArgs, GenericParams, IfCondition, IfExpr, Instruction,
+    Intrinsic, Item, ItemAbi, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemImplItem, ItemKind,
+    ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemTypeAlias, ItemUse, LitInt, LitIntType,
+    MatchBranchKind, Module, ModuleKind, Parens, PathExpr, PathExprSegment, PathType,
+    PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated, QualifiedPathRoot,
+    Statement, StatementLet, TraitType, Traits, Ty, TypeField, UseTree, WhereClause,
 };
 use sway_error::convert_parse_tree_error::ConvertParseTreeError;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{
     constants::{
-        DESTRUCTURE_PREFIX, DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME, INLINE_ATTRIBUTE_NAME,
-        MATCH_RETURN_VAR_NAME_PREFIX, PAYABLE_ATTRIBUTE_NAME, STORAGE_PURITY_ATTRIBUTE_NAME,
-        STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME, TEST_ATTRIBUTE_NAME,
-        TUPLE_NAME_PREFIX, VALID_ATTRIBUTE_NAMES,
+        ALLOW_ATTRIBUTE_NAME, CFG_ATTRIBUTE_NAME, DENY_ATTRIBUTE_NAME, DESTRUCTURE_PREFIX,
+        DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME, FOR_LOOP_ELEMENT_VAR_NAME_PREFIX,
+        INLINE_ATTRIBUTE_NAME, MATCH_RETURN_VAR_NAME_PREFIX, PAYABLE_ATTRIBUTE_NAME,
+        STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
+        TEST_ATTRIBUTE_NAME, TUPLE_NAME_PREFIX, VALID_ATTRIBUTE_NAMES, WARN_ATTRIBUTE_NAME,
     },
     integer_bits::IntegerBits,
 };
@@ -119,8 +121,15 @@ fn item_to_ast_nodes(
                 };
                 handler.emit_err(error.into());
             }
+            // Lint attributes on a dependency apply to the whole submodule.
             for (attribute_kind, attributes) in attributes.iter() {
-                if attribute_kind != &AttributeKind::DocComment {
+                if !matches!(
+                    attribute_kind,
+                    AttributeKind::DocComment
+                        | AttributeKind::Allow
+                        | AttributeKind::Warn
+                        | AttributeKind::Deny
+                ) {
                     for attribute in attributes {
                         let error = ConvertParseTreeError::CannotAnnotateDependency {
                             span: attribute.span.clone(),
@@ -181,11 +190,21 @@ fn item_to_ast_nodes(
             handler, engines, item_abi, attributes,
         )?)),
         ItemKind::Const(item_const) => decl(Declaration::ConstantDeclaration(
-            item_const_to_constant_declaration(handler, engines, item_const, attributes)?,
+            item_const_to_constant_declaration_with_value(
+                handler, engines, item_const, attributes,
+            )?,
         )),
         ItemKind::Storage(item_storage) => decl(Declaration::StorageDeclaration(
             item_storage_to_storage_declaration(handler, engines, item_storage, attributes)?,
         )),
+        ItemKind::TypeAlias(item_type_alias) => decl(Declaration::TypeAliasDeclaration(
+            item_type_alias_to_type_alias_declaration(
+                handler,
+                engines,
+                item_type_alias,
+                attributes,
+            )?,
+        )),
     };
 
     Ok(contents
@@ -293,6 +312,7 @@ fn item_struct_to_struct_declaration(
         .into_inner()
         .into_iter()
         .map(|type_field| {
+            error_if_cfg_attribute(handler, &type_field.attribute_list)?;
             let attributes = item_attrs_to_map(handler, &type_field.attribute_list)?;
             type_field_to_struct_field(handler, engines, type_field.value, attributes)
         })
@@ -349,6 +369,7 @@ fn item_enum_to_enum_declaration(
         .into_iter()
         .enumerate()
         .map(|(tag, type_field)| {
+            error_if_cfg_attribute(handler, &type_field.attribute_list)?;
             let attributes = item_attrs_to_map(handler, &type_field.attribute_list)?;
             type_field_to_enum_variant(handler, engines, type_field.value, attributes, tag)
         })
@@ -443,13 +464,13 @@ fn get_attributed_purity(
     match attributes.get(&AttributeKind::Storage) {
         Some(attrs) if !attrs.is_empty() => {
             for arg in attrs.iter().flat_map(|attr| &attr.args) {
-                match arg.as_str() {
+                match arg.name.as_str() {
                     STORAGE_PURITY_READ_NAME => add_impurity(Purity::Reads, Purity::Writes),
                     STORAGE_PURITY_WRITE_NAME => add_impurity(Purity::Writes, Purity::Reads),
                     _otherwise => {
                         let error = ConvertParseTreeError::InvalidAttributeArgument {
                             attribute: "storage".to_owned(),
-                            span: arg.span(),
+                            span: arg.span.clone(),
                         };
                         return Err(handler.emit_err(error.into()));
                     }
@@ -474,17 +495,26 @@ fn item_trait_to_trait_declaration(
         item_trait.generics,
         item_trait.where_clause_opt,
     )?;
-    let interface_surface = {
-        item_trait
-            .trait_items
-            .into_inner()
-            .into_iter()
-            .map(|(fn_signature, _)| {
-                let attributes = item_attrs_to_map(handler, &fn_signature.attribute_list)?;
-                fn_signature_to_trait_fn(handler, engines, fn_signature.value, attributes)
-            })
-            .collect::<Result<_, _>>()?
-    };
+    let mut interface_surface = Vec::new();
+    let mut constants = Vec::new();
+    let mut types = Vec::new();
+    for item in item_trait.trait_items.into_inner() {
+        let attributes = item_attrs_to_map(handler, &item.attribute_list)?;
+        match item.value {
+            ItemTraitItem::Fn(fn_signature, _) => interface_surface.push(fn_signature_to_trait_fn(
+                handler,
+                engines,
+                fn_signature,
+                attributes,
+            )?),
+            ItemTraitItem::Const(item_const) => constants.push(item_const_to_constant_declaration(
+                handler, engines, item_const, attributes,
+            )?),
+            ItemTraitItem::Type(trait_type) => types.push(trait_type_to_trait_type_declaration(
+                handler, engines, trait_type, attributes,
+            )?),
+        }
+    }
     let methods = match item_trait.trait_defs_opt {
         None => Vec::new(),
         Some(trait_defs) => trait_defs
@@ -505,6 +535,8 @@ fn item_trait_to_trait_declaration(
         name: item_trait.name,
         type_parameters,
         interface_surface,
+        constants,
+        types,
         methods,
         supertraits,
         visibility,
@@ -513,6 +545,25 @@ fn item_trait_to_trait_declaration(
     })
 }
 
+fn trait_type_to_trait_type_declaration(
+    handler: &Handler,
+    engines: Engines<'_>,
+    trait_type: TraitType,
+    attributes: AttributesMap,
+) -> Result<TraitTypeDeclaration, ErrorEmitted> {
+    let span = trait_type.span();
+    let ty_opt = match trait_type.ty_opt {
+        Some((_eq_token, ty)) => Some(ty_to_type_argument(handler, engines, ty)?),
+        None => None,
+    };
+    Ok(TraitTypeDeclaration {
+        name: trait_type.name,
+        attributes,
+        ty_opt,
+        span,
+    })
+}
+
 fn item_impl_to_declaration(
     handler: &Handler,
     engines: Engines<'_>,
@@ -521,15 +572,30 @@ fn item_impl_to_declaration(
     let block_span = item_impl.span();
     let type_implementing_for_span = item_impl.ty.span();
     let type_implementing_for = ty_to_type_info(handler, engines, item_impl.ty)?;
-    let functions = item_impl
-        .contents
-        .into_inner()
-        .into_iter()
-        .map(|item| {
-            let attributes = item_attrs_to_map(handler, &item.attribute_list)?;
-            item_fn_to_function_declaration(handler, engines, item.value, attributes)
-        })
-        .collect::<Result<_, _>>()?;
+    let mut functions = Vec::new();
+    let mut constants = Vec::new();
+    let mut types = Vec::new();
+    for item in item_impl.contents.into_inner() {
+        let attributes = item_attrs_to_map(handler, &item.attribute_list)?;
+        match item.value {
+            ItemImplItem::Fn(item_fn) => functions.push(item_fn_to_function_declaration(
+                handler, engines, item_fn, attributes,
+            )?),
+            ItemImplItem::Const(item_const) => {
+                constants.push(item_const_to_constant_declaration_with_value(
+                    handler, engines, item_const, attributes,
+                )?)
+            }
+            ItemImplItem::Type(item_type_alias) => {
+                types.push(item_type_alias_to_trait_type_declaration(
+                    handler,
+                    engines,
+                    item_type_alias,
+                    attributes,
+                )?)
+            }
+        }
+    }
 
     let impl_type_parameters = generic_params_opt_to_type_parameters(
         handler,
@@ -549,6 +615,8 @@ fn item_impl_to_declaration(
                 type_implementing_for,
                 type_implementing_for_span,
                 functions,
+                constants,
+                types,
                 block_span,
             };
             Ok(Declaration::ImplTrait(impl_trait))
@@ -557,11 +625,18 @@ fn item_impl_to_declaration(
             TypeInfo::Contract => Err(handler
                 .emit_err(ConvertParseTreeError::SelfImplForContract { span: block_span }.into())),
             _ => {
+                if let Some(trait_type) = types.first() {
+                    let error = ConvertParseTreeError::AssociatedTypeInImplSelf {
+                        span: trait_type.span.clone(),
+                    };
+                    return Err(handler.emit_err(error.into()));
+                }
                 let impl_self = ImplSelf {
                     type_implementing_for,
                     type_implementing_for_span,
                     impl_type_parameters,
                     functions,
+                    constants,
                     block_span,
                 };
                 Ok(Declaration::ImplSelf(impl_self))
@@ -666,13 +741,87 @@ pub(crate) fn item_const_to_constant_declaration(
         name: item_const.name,
         type_ascription,
         type_ascription_span,
-        value: expr_to_expression(handler, engines, item_const.expr)?,
+        value: match item_const.expr_opt {
+            Some((_eq_token, expr)) => Some(expr_to_expression(handler, engines, expr)?),
+            None => None,
+        },
         visibility: pub_token_opt_to_visibility(item_const.visibility),
         attributes,
         span,
     })
 }
 
+/// Converts a constant that must be given a value, i.e. any constant that is
+/// not declared in a trait.
+fn item_const_to_constant_declaration_with_value(
+    handler: &Handler,
+    engines: Engines<'_>,
+    item_const: ItemConst,
+    attributes: AttributesMap,
+) -> Result<ConstantDeclaration, ErrorEmitted> {
+    let const_decl = item_const_to_constant_declaration(handler, engines, item_const, attributes)?;
+    if const_decl.value.is_none() {
+        let error = ConvertParseTreeError::ConstantRequiresExpression {
+            name: const_decl.name.clone(),
+            span: const_decl.span.clone(),
+        };
+        return Err(handler.emit_err(error.into()));
+    }
+    Ok(const_decl)
+}
+
+fn item_type_alias_to_trait_type_declaration(
+    handler: &Handler,
+    engines: Engines<'_>,
+    item_type_alias: ItemTypeAlias,
+    attributes: AttributesMap,
+) -> Result<TraitTypeDeclaration, ErrorEmitted> {
+    let span = item_type_alias.span();
+    if let Some(generics) = item_type_alias.generics {
+        let error = ConvertParseTreeError::GenericsNotSupportedHere {
+            span: generics.parameters.span(),
+        };
+        return Err(handler.emit_err(error.into()));
+    }
+    Ok(TraitTypeDeclaration {
+        name: item_type_alias.name,
+        attributes,
+        ty_opt: Some(ty_to_type_argument(handler, engines, item_type_alias.ty)?),
+        span,
+    })
+}
+
+fn item_type_alias_to_type_alias_declaration(
+    handler: &Handler,
+    engines: Engines<'_>,
+    item_type_alias: ItemTypeAlias,
+    attributes: AttributesMap,
+) -> Result<TypeAliasDeclaration, ErrorEmitted> {
+    let span = item_type_alias.span();
+    let ty_span = item_type_alias.ty.span();
+    let ty = ty_to_type_info(handler, engines, item_type_alias.ty)?;
+
+    if matches!(&ty, TypeInfo::Custom { name, .. } if name == &item_type_alias.name) {
+        let error = ConvertParseTreeError::RecursiveType { span };
+        return Err(handler.emit_err(error.into()));
+    }
+
+    Ok(TypeAliasDeclaration {
+        name: item_type_alias.name,
+        attributes,
+        type_parameters: generic_params_opt_to_type_parameters(
+            handler,
+            engines,
+            item_type_alias.generics,
+            None,
+        )?,
+        ty,
+        ty_span,
+        visibility: pub_token_opt_to_visibility(item_type_alias.visibility),
+        span,
+    })
+}
+
 fn item_storage_to_storage_declaration(
     handler: &Handler,
     engines: Engines<'_>,
@@ -686,6 +835,7 @@ fn item_storage_to_storage_declaration(
         .into_inner()
         .into_iter()
         .map(|storage_field| {
+            error_if_cfg_attribute(handler, &storage_field.attribute_list)?;
             let attributes = item_attrs_to_map(handler, &storage_field.attribute_list)?;
             storage_field_to_storage_field(handler, engines, storage_field.value, attributes)
         })
@@ -961,10 +1111,58 @@ fn ty_to_type_info(
         }
         Ty::Str { length, .. } => TypeInfo::Str(expr_to_length(handler, *length.into_inner())?),
         Ty::Infer { .. } => TypeInfo::Unknown,
+        Ty::Ref { ty, .. } => TypeInfo::Ref(ty_to_type_argument(handler, engines, *ty)?),
+        ty @ Ty::Fn { .. } => {
+            let error = ConvertParseTreeError::FunctionTypeNotAllowedHere { span: ty.span() };
+            return Err(handler.emit_err(error.into()));
+        }
     };
     Ok(type_info)
 }
 
+/// Like [ty_to_type_info], but also allows function types, which can only be the types of
+/// function parameters.
+fn fn_param_ty_to_type_info(
+    handler: &Handler,
+    engines: Engines<'_>,
+    ty: Ty,
+) -> Result<TypeInfo, ErrorEmitted> {
+    let type_engine = engines.te();
+    let declaration_engine = engines.de();
+    let span = ty.span();
+    match ty {
+        Ty::Fn {
+            arguments,
+            return_type_opt,
+            ..
+        } => {
+            let parameters = arguments
+                .into_inner()
+                .into_iter()
+                .map(|ty| ty_to_type_argument(handler, engines, ty))
+                .collect::<Result<_, _>>()?;
+            let return_type = match return_type_opt {
+                Some((_right_arrow_token, ty)) => ty_to_type_argument(handler, engines, *ty)?,
+                None => {
+                    let unit_type =
+                        type_engine.insert_type(declaration_engine, TypeInfo::Tuple(Vec::new()));
+                    TypeArgument {
+                        type_id: unit_type,
+                        initial_type_id: unit_type,
+                        span: span.clone(),
+                    }
+                }
+            };
+            Ok(TypeInfo::Function {
+                parameters,
+                return_type,
+                span,
+            })
+        }
+        ty => ty_to_type_info(handler, engines, ty),
+    }
+}
+
 fn ty_to_type_argument(
     handler: &Handler,
     engines: Engines<'_>,
@@ -1111,21 +1309,26 @@ fn struct_path_and_fields_to_struct_expression(
     handler: &Handler,
     engines: Engines<'_>,
     path: PathExpr,
-    fields: Braces<Punctuated<ExprStructField, CommaToken>>,
+    fields: Braces<ExprStructFields>,
 ) -> Result<Box<StructExpression>, ErrorEmitted> {
     let call_path_binding = path_expr_to_call_path_binding(handler, engines, path)?;
-    let fields = {
-        fields
-            .into_inner()
-            .into_iter()
-            .map(|expr_struct_field| {
-                expr_struct_field_to_struct_expression_field(handler, engines, expr_struct_field)
-            })
-            .collect::<Result<_, _>>()?
+    let ExprStructFields { fields, base_opt } = fields.into_inner();
+    let fields = fields
+        .into_iter()
+        .map(|expr_struct_field| {
+            expr_struct_field_to_struct_expression_field(handler, engines, expr_struct_field)
+        })
+        .collect::<Result<_, _>>()?;
+    let base = match base_opt {
+        Some((_double_dot_token, base)) => {
+            Some(Box::new(expr_to_expression(handler, engines, *base)?))
+        }
+        None => None,
     };
     Ok(Box::new(StructExpression {
         call_path_binding,
         fields,
+        base,
     }))
 }
 
@@ -1471,6 +1674,44 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::For {
+            for_token,
+            value_pattern,
+            iterable,
+            block,
+            ..
+        } => {
+            // Generate a deterministic id for the variables of the loop.
+            // Because the parser is single threaded, the id generated below will be stable.
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let id = COUNTER.fetch_add(1, Ordering::SeqCst);
+            let element = Expression {
+                kind: ExpressionKind::Variable(ForLoopExpression::var_name(
+                    id,
+                    FOR_LOOP_ELEMENT_VAR_NAME_PREFIX,
+                    for_token.span(),
+                )),
+                span: for_token.span(),
+            };
+            let pattern_span = value_pattern.span();
+            let mut body = braced_code_block_contents_to_code_block(handler, engines, block)?;
+            let bindings =
+                unfold_pattern(handler, engines, value_pattern, None, element, pattern_span)?;
+            body.contents.splice(0..0, bindings);
+            let iterable = match iterable {
+                ForIterable::Range { start, end, .. } => ForLoopIterable::Range {
+                    start: Box::new(expr_to_expression(handler, engines, *start)?),
+                    end: Box::new(expr_to_expression(handler, engines, *end)?),
+                },
+                ForIterable::Expr(expr) => ForLoopIterable::Expression(Box::new(
+                    expr_to_expression(handler, engines, *expr)?,
+                )),
+            };
+            Expression {
+                kind: ExpressionKind::ForLoop(ForLoopExpression { id, iterable, body }),
+                span,
+            }
+        }
         Expr::FuncApp { func, args } => {
             let kind = expr_func_app_to_expression_kind(handler, engines, func, args)?;
             Expression { kind, span }
@@ -1560,8 +1801,42 @@ fn expr_to_expression(
             }),
             span,
         },
-        Expr::Ref { .. } => unimplemented!(),
-        Expr::Deref { .. } => unimplemented!(),
+        Expr::Closure { params, body } => {
+            let parameters = match params {
+                ClosureParams::Empty { .. } => Vec::new(),
+                ClosureParams::List { params, .. } => params
+                    .into_iter()
+                    .map(|param| closure_param_to_closure_parameter(handler, engines, param))
+                    .collect::<Result<_, _>>()?,
+            };
+            let mut unique_params = HashSet::<Ident>::default();
+            for param in &parameters {
+                if !unique_params.insert(param.name.clone()) {
+                    let error = ConvertParseTreeError::DuplicateParameterIdentifier {
+                        name: param.name.clone(),
+                        span: param.name.span(),
+                    };
+                    return Err(handler.emit_err(error.into()));
+                }
+            }
+            let body = Box::new(expr_to_expression(handler, engines, *body)?);
+            Expression {
+                kind: ExpressionKind::Closure(Box::new(ClosureExpression { parameters, body })),
+                span,
+            }
+        }
+        Expr::Try { expr, .. } => Expression {
+            kind: ExpressionKind::Try(Box::new(expr_to_expression(handler, engines, *expr)?)),
+            span,
+        },
+        Expr::Ref { expr, .. } => Expression {
+            kind: ExpressionKind::Ref(Box::new(expr_to_expression(handler, engines, *expr)?)),
+            span,
+        },
+        Expr::Deref { expr, .. } => Expression {
+            kind: ExpressionKind::Deref(Box::new(expr_to_expression(handler, engines, *expr)?)),
+            span,
+        },
         Expr::Not { bang_token, expr } => {
             let expr = expr_to_expression(handler, engines, *expr)?;
             op_call("not", bang_token.span(), span, &[expr])?
@@ -1841,6 +2116,7 @@ fn statement_to_ast_nodes(
             statement_let_to_ast_nodes(handler, engines, statement_let)?
         }
         Statement::Item(item) => {
+            error_if_cfg_attribute(handler, &item.attribute_list)?;
             let nodes = item_to_ast_nodes(handler, engines, item, false, None)?;
             nodes.iter().fold(Ok(()), |res, node| {
                 if ast_node_is_test_fn(node) {
@@ -1896,6 +2172,10 @@ fn fn_arg_to_function_parameter(
             let error = ConvertParseTreeError::TuplePatternsNotSupportedHere { span: pat_span };
             return Err(handler.emit_err(error.into()));
         }
+        Pattern::Or { .. } => {
+            let error = ConvertParseTreeError::OrPatternsNotSupportedHere { span: pat_span };
+            return Err(handler.emit_err(error.into()));
+        }
     };
     let mutability_span = match (&reference, &mutable) {
         (None, None) => Span::dummy(),
@@ -1908,12 +2188,31 @@ fn fn_arg_to_function_parameter(
         is_reference: reference.is_some(),
         is_mutable: mutable.is_some(),
         mutability_span,
-        type_info: ty_to_type_info(handler, engines, fn_arg.ty)?,
+        type_info: fn_param_ty_to_type_info(handler, engines, fn_arg.ty)?,
         type_span,
     };
     Ok(function_parameter)
 }
 
+fn closure_param_to_closure_parameter(
+    handler: &Handler,
+    engines: Engines<'_>,
+    param: ClosureParam,
+) -> Result<ClosureParameter, ErrorEmitted> {
+    let (type_info, type_span) = match param.ty_opt {
+        Some((_colon_token, ty)) => {
+            let type_span = ty.span();
+            (ty_to_type_info(handler, engines, ty)?, type_span)
+        }
+        None => (TypeInfo::Unknown, param.name.span()),
+    };
+    Ok(ClosureParameter {
+        name: param.name,
+        type_info,
+        type_span,
+    })
+}
+
 fn expr_to_length(handler: &Handler, expr: Expr) -> Result<Length, ErrorEmitted> {
     let span = expr.span();
     Ok(Length::new(expr_to_usize(handler, expr)?, span))
@@ -2125,6 +2424,7 @@ fn if_expr_to_expression(
             let scrutinee_span = scrutinee.span();
             let mut branches = vec![MatchBranch {
                 scrutinee,
+                guard: None,
                 result: then_block.clone(),
                 span: Span::join(scrutinee_span, then_block_span),
             }];
@@ -2135,6 +2435,7 @@ fn if_expr_to_expression(
                         scrutinee: Scrutinee::CatchAll {
                             span: else_block_span.clone(),
                         },
+                        guard: None,
                         result: else_block,
                         span: else_block_span,
                     }
@@ -2145,6 +2446,7 @@ fn if_expr_to_expression(
                         scrutinee: Scrutinee::CatchAll {
                             span: else_block_span.clone(),
                         },
+                        guard: None,
                         // If there's no else in an `if-let` expression,
                         // then the else is equivalent to an empty block.
                         result: Expression {
@@ -2397,6 +2699,7 @@ fn expr_struct_field_to_struct_expression_field(
     expr_struct_field: ExprStructField,
 ) -> Result<StructExpressionField, ErrorEmitted> {
     let span = expr_struct_field.span();
+    let is_shorthand = expr_struct_field.expr_opt.is_none();
     let value = match expr_struct_field.expr_opt {
         Some((_colon_token, expr)) => expr_to_expression(handler, engines, *expr)?,
         None => Expression {
@@ -2407,6 +2710,7 @@ fn expr_struct_field_to_struct_expression_field(
     Ok(StructExpressionField {
         name: expr_struct_field.field_name,
         value,
+        is_shorthand,
         span,
     })
 }
@@ -2488,6 +2792,10 @@ fn match_branch_to_match_branch(
     let span = match_branch.span();
     Ok(MatchBranch {
         scrutinee: pattern_to_scrutinee(handler, match_branch.pattern)?,
+        guard: match match_branch.guard_opt {
+            Some((_if_token, guard)) => Some(expr_to_expression(handler, engines, guard)?),
+            None => None,
+        },
         result: match match_branch.kind {
             MatchBranchKind::Block { block, .. } => {
                 let span = block.span();
@@ -2509,245 +2817,252 @@ fn statement_let_to_ast_nodes(
     engines: Engines<'_>,
     statement_let: StatementLet,
 ) -> Result<Vec<AstNode>, ErrorEmitted> {
-    fn unfold(
-        handler: &Handler,
-        engines: Engines<'_>,
-        pattern: Pattern,
-        ty_opt: Option<Ty>,
-        expression: Expression,
-        span: Span,
-    ) -> Result<Vec<AstNode>, ErrorEmitted> {
-        let ast_nodes = match pattern {
-            Pattern::Wildcard { .. } | Pattern::Var { .. } => {
-                let (reference, mutable, name) = match pattern {
-                    Pattern::Var {
-                        reference,
-                        mutable,
-                        name,
-                    } => (reference, mutable, name),
-                    Pattern::Wildcard { .. } => (None, None, Ident::new_no_span("_")),
-                    _ => unreachable!(),
-                };
-                if reference.is_some() {
-                    let error = ConvertParseTreeError::RefVariablesNotSupported { span };
-                    return Err(handler.emit_err(error.into()));
-                }
-                let (type_ascription, type_ascription_span) = match ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(handler, engines, ty)?;
-                        (type_ascription, Some(type_ascription_span))
-                    }
-                    None => (TypeInfo::Unknown, None),
-                };
-                let ast_node = AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        VariableDeclaration {
-                            name,
-                            type_ascription,
-                            type_ascription_span,
-                            body: expression,
-                            is_mutable: mutable.is_some(),
-                        },
-                    )),
-                    span,
-                };
-                vec![ast_node]
-            }
-            Pattern::Literal(..) => {
-                let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
-                return Err(handler.emit_err(error.into()));
-            }
-            Pattern::Constant(..) => {
-                let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
-                return Err(handler.emit_err(error.into()));
-            }
-            Pattern::Constructor { .. } => {
-                let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
+    let span = statement_let.span();
+    let initial_expression = expr_to_expression(handler, engines, statement_let.expr)?;
+    unfold_pattern(
+        handler,
+        engines,
+        statement_let.pattern,
+        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
+        initial_expression,
+        span,
+    )
+}
+
+/// Bind the variables of `pattern` to the parts of `expression` that they match, producing a
+/// variable declaration for each of them.
+fn unfold_pattern(
+    handler: &Handler,
+    engines: Engines<'_>,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let ast_nodes = match pattern {
+        Pattern::Wildcard { .. } | Pattern::Var { .. } => {
+            let (reference, mutable, name) = match pattern {
+                Pattern::Var {
+                    reference,
+                    mutable,
+                    name,
+                } => (reference, mutable, name),
+                Pattern::Wildcard { .. } => (None, None, Ident::new_no_span("_")),
+                _ => unreachable!(),
+            };
+            if reference.is_some() {
+                let error = ConvertParseTreeError::RefVariablesNotSupported { span };
                 return Err(handler.emit_err(error.into()));
             }
-            Pattern::Struct { path, fields, .. } => {
-                let mut ast_nodes = Vec::new();
-
-                // Generate a deterministic name for the destructured struct
-                // Because the parser is single threaded, the name generated below will be stable.
-                static COUNTER: AtomicUsize = AtomicUsize::new(0);
-                let destructured_name =
-                    format!("{}{}", DESTRUCTURE_PREFIX, COUNTER.load(Ordering::SeqCst));
-                COUNTER.fetch_add(1, Ordering::SeqCst);
-                let destructure_name = Ident::new_with_override(
-                    Box::leak(destructured_name.into_boxed_str()),
-                    path.prefix.name.span(),
-                );
+            let (type_ascription, type_ascription_span) = match ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(handler, engines, ty)?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
+            let ast_node = AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    VariableDeclaration {
+                        name,
+                        type_ascription,
+                        type_ascription_span,
+                        body: expression,
+                        is_mutable: mutable.is_some(),
+                    },
+                )),
+                span,
+            };
+            vec![ast_node]
+        }
+        Pattern::Literal(..) => {
+            let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Constant(..) => {
+            let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Constructor { .. } => {
+            let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Or { .. } => {
+            let error = ConvertParseTreeError::OrPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Struct { path, fields, .. } => {
+            let mut ast_nodes = Vec::new();
 
-                // Parse the type ascription and the type ascription span.
-                // In the event that the user did not provide a type ascription,
-                // it is set to TypeInfo::Unknown and the span to None.
-                let (type_ascription, type_ascription_span) = match &ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(handler, engines, ty.clone())?;
-                        (type_ascription, Some(type_ascription_span))
-                    }
-                    None => (TypeInfo::Unknown, None),
-                };
+            // Generate a deterministic name for the destructured struct
+            // Because the parser is single threaded, the name generated below will be stable.
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let destructured_name =
+                format!("{}{}", DESTRUCTURE_PREFIX, COUNTER.load(Ordering::SeqCst));
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+            let destructure_name = Ident::new_with_override(
+                Box::leak(destructured_name.into_boxed_str()),
+                path.prefix.name.span(),
+            );
 
-                // Save the destructure to the new name as a new variable declaration
-                let save_body_first = VariableDeclaration {
-                    name: destructure_name.clone(),
-                    type_ascription,
-                    type_ascription_span,
-                    body: expression,
-                    is_mutable: false,
-                };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        save_body_first,
-                    )),
-                    span: span.clone(),
-                });
+            // Parse the type ascription and the type ascription span.
+            // In the event that the user did not provide a type ascription,
+            // it is set to TypeInfo::Unknown and the span to None.
+            let (type_ascription, type_ascription_span) = match &ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(handler, engines, ty.clone())?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
 
-                // create a new variable expression that points to the new destructured struct name that we just created
-                let new_expr = Expression {
-                    kind: ExpressionKind::Variable(destructure_name),
-                    span: span.clone(),
-                };
+            // Save the destructure to the new name as a new variable declaration
+            let save_body_first = VariableDeclaration {
+                name: destructure_name.clone(),
+                type_ascription,
+                type_ascription_span,
+                body: expression,
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
 
-                // for all of the fields of the struct destructuring on the LHS,
-                // recursively create variable declarations
-                for pattern_struct_field in fields.into_inner().into_iter() {
-                    let (field, recursive_pattern) = match pattern_struct_field {
-                        PatternStructField::Field {
-                            field_name,
-                            pattern_opt,
-                        } => {
-                            let recursive_pattern = match pattern_opt {
-                                Some((_colon_token, box_pattern)) => *box_pattern,
-                                None => Pattern::Var {
-                                    reference: None,
-                                    mutable: None,
-                                    name: field_name.clone(),
-                                },
-                            };
-                            (field_name, recursive_pattern)
-                        }
-                        PatternStructField::Rest { .. } => {
-                            continue;
-                        }
-                    };
+            // create a new variable expression that points to the new destructured struct name that we just created
+            let new_expr = Expression {
+                kind: ExpressionKind::Variable(destructure_name),
+                span: span.clone(),
+            };
 
-                    // recursively create variable declarations for the subpatterns on the LHS
-                    // and add them to the ast nodes
-                    ast_nodes.extend(unfold(
-                        handler,
-                        engines,
-                        recursive_pattern,
-                        None,
-                        Expression {
-                            kind: ExpressionKind::Subfield(SubfieldExpression {
-                                prefix: Box::new(new_expr.clone()),
-                                field_to_access: field,
-                            }),
-                            span: span.clone(),
-                        },
-                        span.clone(),
-                    )?);
-                }
-                ast_nodes
-            }
-            Pattern::Tuple(pat_tuple) => {
-                let mut ast_nodes = Vec::new();
-
-                // Generate a deterministic name for the tuple.
-                // Because the parser is single threaded, the name generated below will be stable.
-                static COUNTER: AtomicUsize = AtomicUsize::new(0);
-                let tuple_name = format!("{}{}", TUPLE_NAME_PREFIX, COUNTER.load(Ordering::SeqCst));
-                COUNTER.fetch_add(1, Ordering::SeqCst);
-                let tuple_name =
-                    Ident::new_with_override(Box::leak(tuple_name.into_boxed_str()), span.clone());
-
-                // Parse the type ascription and the type ascription span.
-                // In the event that the user did not provide a type ascription,
-                // it is set to TypeInfo::Unknown and the span to None.
-                let (type_ascription, type_ascription_span) = match &ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(handler, engines, ty.clone())?;
-                        (type_ascription, Some(type_ascription_span))
+            // for all of the fields of the struct destructuring on the LHS,
+            // recursively create variable declarations
+            for pattern_struct_field in fields.into_inner().into_iter() {
+                let (field, recursive_pattern) = match pattern_struct_field {
+                    PatternStructField::Field {
+                        field_name,
+                        pattern_opt,
+                    } => {
+                        let recursive_pattern = match pattern_opt {
+                            Some((_colon_token, box_pattern)) => *box_pattern,
+                            None => Pattern::Var {
+                                reference: None,
+                                mutable: None,
+                                name: field_name.clone(),
+                            },
+                        };
+                        (field_name, recursive_pattern)
+                    }
+                    PatternStructField::Rest { .. } => {
+                        continue;
                     }
-                    None => (TypeInfo::Unknown, None),
                 };
 
-                // Save the tuple to the new name as a new variable declaration.
-                let save_body_first = VariableDeclaration {
-                    name: tuple_name.clone(),
-                    type_ascription,
-                    type_ascription_span,
-                    body: expression,
-                    is_mutable: false,
-                };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        save_body_first,
-                    )),
-                    span: span.clone(),
-                });
+                // recursively create variable declarations for the subpatterns on the LHS
+                // and add them to the ast nodes
+                ast_nodes.extend(unfold_pattern(
+                    handler,
+                    engines,
+                    recursive_pattern,
+                    None,
+                    Expression {
+                        kind: ExpressionKind::Subfield(SubfieldExpression {
+                            prefix: Box::new(new_expr.clone()),
+                            field_to_access: field,
+                        }),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
+        Pattern::Tuple(pat_tuple) => {
+            let mut ast_nodes = Vec::new();
 
-                // create a variable expression that points to the new tuple name that we just created
-                let new_expr = Expression {
-                    kind: ExpressionKind::Variable(tuple_name),
-                    span: span.clone(),
-                };
+            // Generate a deterministic name for the tuple.
+            // Because the parser is single threaded, the name generated below will be stable.
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let tuple_name = format!("{}{}", TUPLE_NAME_PREFIX, COUNTER.load(Ordering::SeqCst));
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+            let tuple_name =
+                Ident::new_with_override(Box::leak(tuple_name.into_boxed_str()), span.clone());
+
+            // Parse the type ascription and the type ascription span.
+            // In the event that the user did not provide a type ascription,
+            // it is set to TypeInfo::Unknown and the span to None.
+            let (type_ascription, type_ascription_span) = match &ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(handler, engines, ty.clone())?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
 
-                // from the possible type annotation, if the annotation was a tuple annotation,
-                // extract the internal types of the annotation
-                let tuple_tys_opt = match ty_opt {
-                    Some(Ty::Tuple(tys)) => Some(tys.into_inner().to_tys()),
-                    _ => None,
-                };
+            // Save the tuple to the new name as a new variable declaration.
+            let save_body_first = VariableDeclaration {
+                name: tuple_name.clone(),
+                type_ascription,
+                type_ascription_span,
+                body: expression,
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
 
-                // for all of the elements in the tuple destructuring on the LHS,
-                // recursively create variable declarations
-                for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
-                    // from the possible type annotation, grab the type at the index of the current element
-                    // we are processing
-                    let ty_opt = tuple_tys_opt
-                        .as_ref()
-                        .and_then(|tys| tys.get(index).cloned());
-
-                    // recursively create variable declarations for the subpatterns on the LHS
-                    // and add them to the ast nodes
-                    ast_nodes.extend(unfold(
-                        handler,
-                        engines,
-                        pattern,
-                        ty_opt,
-                        Expression {
-                            kind: ExpressionKind::TupleIndex(TupleIndexExpression {
-                                prefix: Box::new(new_expr.clone()),
-                                index,
-                                index_span: span.clone(),
-                            }),
-                            span: span.clone(),
-                        },
-                        span.clone(),
-                    )?);
-                }
-                ast_nodes
+            // create a variable expression that points to the new tuple name that we just created
+            let new_expr = Expression {
+                kind: ExpressionKind::Variable(tuple_name),
+                span: span.clone(),
+            };
+
+            // from the possible type annotation, if the annotation was a tuple annotation,
+            // extract the internal types of the annotation
+            let tuple_tys_opt = match ty_opt {
+                Some(Ty::Tuple(tys)) => Some(tys.into_inner().to_tys()),
+                _ => None,
+            };
+
+            // for all of the elements in the tuple destructuring on the LHS,
+            // recursively create variable declarations
+            for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
+                // from the possible type annotation, grab the type at the index of the current element
+                // we are processing
+                let ty_opt = tuple_tys_opt
+                    .as_ref()
+                    .and_then(|tys| tys.get(index).cloned());
+
+                // recursively create variable declarations for the subpatterns on the LHS
+                // and add them to the ast nodes
+                ast_nodes.extend(unfold_pattern(
+                    handler,
+                    engines,
+                    pattern,
+                    ty_opt,
+                    Expression {
+                        kind: ExpressionKind::TupleIndex(TupleIndexExpression {
+                            prefix: Box::new(new_expr.clone()),
+                            index,
+                            index_span: span.clone(),
+                        }),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
             }
-        };
-        Ok(ast_nodes)
-    }
-    let span = statement_let.span();
-    let initial_expression = expr_to_expression(handler, engines, statement_let.expr)?;
-    unfold(
-        handler,
-        engines,
-        statement_let.pattern,
-        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
-        initial_expression,
-        span,
-    )
+            ast_nodes
+        }
+    };
+    Ok(ast_nodes)
 }
 
 fn dependency_to_include_statement(dependency: &Dependency) -> IncludeStatement {
@@ -2885,6 +3200,15 @@ fn pattern_to_scrutinee(handler: &Handler, pattern: Pattern) -> Result<Scrutinee
             },
             span,
         },
+        Pattern::Or { lhs, rhs, .. } => {
+            // `a | b | c` is parsed as `(a | b) | c`, so flatten the alternatives into one list.
+            let mut elems = match pattern_to_scrutinee(handler, *lhs)? {
+                Scrutinee::Or { elems, .. } => elems,
+                lhs => vec![lhs],
+            };
+            elems.push(pattern_to_scrutinee(handler, *rhs)?);
+            Scrutinee::Or { elems, span }
+        }
     };
     Ok(scrutinee)
 }
@@ -2913,6 +3237,8 @@ fn ty_to_type_parameter(
         Ty::Tuple(..) => panic!("tuple types are not allowed in this position"),
         Ty::Array(..) => panic!("array types are not allowed in this position"),
         Ty::Str { .. } => panic!("str types are not allowed in this position"),
+        Ty::Ref { .. } => panic!("reference types are not allowed in this position"),
+        Ty::Fn { .. } => panic!("function types are not allowed in this position"),
     };
     let custom_type = type_engine.insert_type(
         declaration_engine,
@@ -3147,6 +3473,28 @@ fn path_type_to_type_info(
         suffix,
     } = path_type;
 
+    // `Self::Item` and `T::Item` refer to the associated type `Item` of the
+    // type before it, which is resolved against the implementations of the
+    // traits of that type during type checking.
+    if let (None, [(_, assoc_type)]) = (&root_opt, suffix.as_slice()) {
+        if assoc_type.generics_opt.is_none() {
+            let assoc_name = assoc_type.name.clone();
+            let qualifier = path_type_to_type_info(
+                handler,
+                engines,
+                PathType {
+                    root_opt: None,
+                    prefix: PathTypeSegment { name, generics_opt },
+                    suffix: vec![],
+                },
+            )?;
+            return Ok(TypeInfo::TraitType {
+                name: assoc_name,
+                qualifier: engines.te().insert_type(engines.de(), qualifier),
+            });
+        }
+    }
+
     if root_opt.is_some() || !suffix.is_empty() {
         let error = ConvertParseTreeError::FullySpecifiedTypesNotSupported { span };
         return Err(handler.emit_err(error.into()));
@@ -3241,6 +3589,25 @@ where
     Some(ret)
 }
 
+/// `cfg` attributes are only evaluated for the items stripped by [super::strip_cfg_items], so
+/// produce an error for those found elsewhere.
+fn error_if_cfg_attribute(
+    handler: &Handler,
+    attribute_list: &[AttributeDecl],
+) -> Result<(), ErrorEmitted> {
+    let cfg_attr = attribute_list
+        .iter()
+        .flat_map(|attr_decl| attr_decl.attribute.get())
+        .find(|attr| attr.name.as_str() == CFG_ATTRIBUTE_NAME);
+    match cfg_attr {
+        Some(attr) => {
+            let error = ConvertParseTreeError::CfgNotAllowedHere { span: attr.span() };
+            Err(handler.emit_err(error.into()))
+        }
+        None => Ok(()),
+    }
+}
+
 fn item_attrs_to_map(
     handler: &Handler,
     attribute_list: &[AttributeDecl],
@@ -3259,11 +3626,18 @@ fn item_attrs_to_map(
                 })
             }
 
-            let args = attr
-                .args
-                .as_ref()
-                .map(|parens| parens.get().into_iter().cloned().collect())
-                .unwrap_or_else(Vec::new);
+            let mut args = vec![];
+            for arg in attr.args.iter().flat_map(|parens| parens.get()) {
+                let value = match &arg.value {
+                    Some((_, value)) => Some(literal_to_literal(handler, value.clone())?),
+                    None => None,
+                };
+                args.push(AttributeArg {
+                    name: arg.name.clone(),
+                    value,
+                    span: arg.span(),
+                });
+            }
 
             let attribute = Attribute {
                 name: attr.name.clone(),
@@ -3278,6 +3652,10 @@ fn item_attrs_to_map(
                 INLINE_ATTRIBUTE_NAME => Some(AttributeKind::Inline),
                 TEST_ATTRIBUTE_NAME => Some(AttributeKind::Test),
                 PAYABLE_ATTRIBUTE_NAME => Some(AttributeKind::Payable),
+                ALLOW_ATTRIBUTE_NAME => Some(AttributeKind::Allow),
+                WARN_ATTRIBUTE_NAME => Some(AttributeKind::Warn),
+                DENY_ATTRIBUTE_NAME => Some(AttributeKind::Deny),
+                CFG_ATTRIBUTE_NAME => Some(AttributeKind::Cfg),
                 _ => None,
             } {
                 match attrs_map.get_mut(&attr_kind) {
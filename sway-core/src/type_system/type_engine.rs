@@ -2,8 +2,8 @@ use core::fmt::Write;
 use core::hash::Hasher;
 use hashbrown::hash_map::RawEntryMut;
 use hashbrown::HashMap;
-use std::hash::BuildHasher;
 use std::sync::RwLock;
+use std::{hash::BuildHasher, iter};
 
 use crate::concurrent_slab::ListDisplay;
 use crate::{
@@ -97,14 +97,14 @@ impl TypeEngine {
         }
     }
 
-    /// Currently the [TypeEngine] is a lazy static object, so when we run
-    /// cargo tests, we can either choose to use a local [TypeEngine] and bypass
-    /// all of the global methods or we can use the lazy static [TypeEngine].
-    /// This method is for testing to be able to bypass the global methods for
-    /// the lazy static [TypeEngine] (contained within the call to hash in the
-    /// id_map).
-    #[cfg(test)]
-    #[allow(dead_code)]
+    /// Inserts `ty` into the [TypeEngine] without deduplicating it against
+    /// existing entries.
+    ///
+    /// Deduplication ignores the initial types of any nested
+    /// [TypeArgument]s, so this is used when those initial types matter, e.g.
+    /// when expanding type aliases for the JSON ABI. It is also used in tests
+    /// to bypass the global methods for the lazy static [TypeEngine]
+    /// (contained within the call to hash in the id_map).
     pub(crate) fn insert_type_always(&self, ty: TypeInfo) -> TypeId {
         TypeId::new(self.slab.insert(ty))
     }
@@ -539,6 +539,47 @@ impl TypeEngine {
                         // return the id
                         type_id
                     }
+                    Some(ty::TyDeclaration::TypeAliasDeclaration(original_id)) => {
+                        // get the copy from the declaration engine
+                        let mut new_copy = check!(
+                            CompileResult::from(
+                                declaration_engine.get_type_alias(original_id, &name.span())
+                            ),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+
+                        // monomorphize the copy, in place
+                        check!(
+                            self.monomorphize(
+                                declaration_engine,
+                                &mut new_copy,
+                                &mut type_arguments.unwrap_or_default(),
+                                enforce_type_arguments,
+                                span,
+                                namespace,
+                                mod_path
+                            ),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+
+                        // aliases are transparent, so the type id is that of the aliased type,
+                        // carrying the initial types of the expanded alias for the JSON ABI
+                        let initial_type_id = namespace.expand_type_aliases(engines, type_id);
+                        let type_id = self.with_initial_type_ids(
+                            new_copy.create_type_id(engines),
+                            initial_type_id,
+                        );
+
+                        // take any trait methods that apply to this type and copy them to the new type
+                        namespace.insert_trait_implementation_for_type(engines, type_id);
+
+                        // return the id
+                        type_id
+                    }
                     Some(ty::TyDeclaration::GenericTypeForFunctionScope { type_id, .. }) => type_id,
                     _ => {
                         errors.push(CompileError::UnknownTypeName {
@@ -566,6 +607,23 @@ impl TypeEngine {
                 );
                 self.insert_type(declaration_engine, TypeInfo::Array(elem_ty, n))
             }
+            TypeInfo::Ref(mut ty) => {
+                ty.type_id = check!(
+                    self.resolve_type(
+                        declaration_engine,
+                        ty.type_id,
+                        span,
+                        enforce_type_arguments,
+                        None,
+                        namespace,
+                        mod_path
+                    ),
+                    self.insert_type(declaration_engine, TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                self.insert_type(declaration_engine, TypeInfo::Ref(ty))
+            }
             TypeInfo::Tuple(mut type_arguments) => {
                 for type_argument in type_arguments.iter_mut() {
                     type_argument.type_id = check!(
@@ -585,11 +643,118 @@ impl TypeEngine {
                 }
                 self.insert_type(declaration_engine, TypeInfo::Tuple(type_arguments))
             }
+            TypeInfo::Function {
+                mut parameters,
+                mut return_type,
+                span: fn_span,
+            } => {
+                for type_argument in parameters.iter_mut().chain(iter::once(&mut return_type)) {
+                    type_argument.type_id = check!(
+                        self.resolve_type(
+                            declaration_engine,
+                            type_argument.type_id,
+                            span,
+                            enforce_type_arguments,
+                            None,
+                            namespace,
+                            mod_path
+                        ),
+                        self.insert_type(declaration_engine, TypeInfo::ErrorRecovery),
+                        warnings,
+                        errors
+                    );
+                }
+                self.insert_type(
+                    declaration_engine,
+                    TypeInfo::Function {
+                        parameters,
+                        return_type,
+                        span: fn_span,
+                    },
+                )
+            }
+            TypeInfo::TraitType { name, qualifier } => {
+                let qualifier = check!(
+                    self.resolve_type(
+                        declaration_engine,
+                        qualifier,
+                        span,
+                        enforce_type_arguments,
+                        type_info_prefix,
+                        namespace,
+                        mod_path
+                    ),
+                    self.insert_type(declaration_engine, TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                check!(
+                    namespace.find_associated_type_for_type(qualifier, &name, mod_path, engines),
+                    self.insert_type(declaration_engine, TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                )
+            }
             _ => type_id,
         };
         ok(type_id, warnings, errors)
     }
 
+    /// Rebuilds the resolved `type_id` so that the initial types of its
+    /// tuple fields, array elements and references are taken from
+    /// `initial_type_id`, which has the same shape but is unresolved.
+    fn with_initial_type_ids(&self, type_id: TypeId, initial_type_id: TypeId) -> TypeId {
+        let align = |type_arg: TypeArgument, initial_type_arg: &TypeArgument| TypeArgument {
+            type_id: self.with_initial_type_ids(type_arg.type_id, initial_type_arg.initial_type_id),
+            initial_type_id: initial_type_arg.initial_type_id,
+            span: type_arg.span,
+        };
+        let type_info = match (
+            self.look_up_type_id(type_id),
+            self.look_up_type_id(initial_type_id),
+        ) {
+            (TypeInfo::Tuple(fields), TypeInfo::Tuple(initial_fields))
+                if fields.len() == initial_fields.len() =>
+            {
+                TypeInfo::Tuple(
+                    fields
+                        .into_iter()
+                        .zip(initial_fields.iter())
+                        .map(|(field, initial_field)| align(field, initial_field))
+                        .collect(),
+                )
+            }
+            (TypeInfo::Array(elem_ty, length), TypeInfo::Array(initial_elem_ty, _)) => {
+                TypeInfo::Array(align(elem_ty, &initial_elem_ty), length)
+            }
+            (TypeInfo::Ref(ty), TypeInfo::Ref(initial_ty)) => TypeInfo::Ref(align(ty, &initial_ty)),
+            (
+                TypeInfo::Function {
+                    parameters,
+                    return_type,
+                    span,
+                },
+                TypeInfo::Function {
+                    parameters: initial_parameters,
+                    return_type: initial_return_type,
+                    ..
+                },
+            ) if parameters.len() == initial_parameters.len() => TypeInfo::Function {
+                parameters: parameters
+                    .into_iter()
+                    .zip(initial_parameters.iter())
+                    .map(|(param, initial_param)| align(param, initial_param))
+                    .collect(),
+                return_type: align(return_type, &initial_return_type),
+                span,
+            },
+            _ => return type_id,
+        };
+        // the rebuilt type differs from `type_id` only in its initial types, which
+        // deduplication ignores, so it must not be merged with an existing entry
+        self.insert_type_always(type_info)
+    }
+
     /// Replace any instances of the [TypeInfo::SelfType] variant with
     /// `self_type` in `type_id`, then resolve `type_id`.
     #[allow(clippy::too_many_arguments)]
@@ -95,7 +95,7 @@ impl ReplaceSelfType for TypeId {
                     }
                 }
             }
-            TypeInfo::Array(mut type_id, _) => {
+            TypeInfo::Array(mut type_id, _) | TypeInfo::Ref(mut type_id) => {
                 type_id.replace_self_type(engines, self_type);
             }
             TypeInfo::Storage { mut fields } => {
@@ -103,6 +103,29 @@ impl ReplaceSelfType for TypeId {
                     field.replace_self_type(engines, self_type);
                 }
             }
+            TypeInfo::Function {
+                mut parameters,
+                mut return_type,
+                ..
+            } => {
+                for parameter in parameters.iter_mut() {
+                    parameter.replace_self_type(engines, self_type);
+                }
+                return_type.replace_self_type(engines, self_type);
+            }
+            TypeInfo::Closure { .. } => {}
+            TypeInfo::TraitType {
+                name,
+                qualifier: original_qualifier,
+            } => {
+                let mut qualifier = original_qualifier;
+                qualifier.replace_self_type(engines, self_type);
+                if qualifier != original_qualifier {
+                    *self = engines
+                        .te()
+                        .insert_type(engines.de(), TypeInfo::TraitType { name, qualifier });
+                }
+            }
             TypeInfo::Unknown
             | TypeInfo::UnknownGeneric { .. }
             | TypeInfo::Str(_)
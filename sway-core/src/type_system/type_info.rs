@@ -1,16 +1,18 @@
 use super::*;
 use crate::{
+    declaration_engine::DeclarationId,
     engine_threading::*,
     language::{ty, CallPath},
     Ident,
 };
 use sway_error::error::CompileError;
-use sway_types::{integer_bits::IntegerBits, span::Span};
+use sway_types::{integer_bits::IntegerBits, span::Span, Spanned};
 
 use std::{
     collections::HashSet,
     fmt,
     hash::{Hash, Hasher},
+    iter,
 };
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -126,6 +128,37 @@ pub enum TypeInfo {
     /// gtf instruction, or manipulating u64s.
     RawUntypedPtr,
     RawUntypedSlice,
+    /// A reference to a value of the inner type, created with `ref` and read with `deref`.
+    /// References are represented in memory as the u64 address of the referenced value, so
+    /// passing one around never copies the value itself.
+    Ref(TypeArgument),
+    /// The type of a function parameter that takes a closure or a function, as in
+    /// `f: fn(u64) -> u64`. Function types are resolved to the [TypeInfo::Closure] of the
+    /// argument when the function is instantiated in the IR, and `span` tells apart the function
+    /// types of different parameters while doing so.
+    Function {
+        parameters: Vec<TypeArgument>,
+        return_type: TypeArgument,
+        span: Span,
+    },
+    /// The type of a closure, or of a function used as a value. Closures are lifted into the
+    /// function `decl_id`, whose parameters are the captured variables followed by the
+    /// parameters of the closure, and are represented in memory as a tuple of the values of
+    /// the captured variables.
+    Closure {
+        decl_id: DeclarationId,
+        parameters: Vec<TypeArgument>,
+        return_type: TypeArgument,
+        captures: Vec<TypeArgument>,
+    },
+    /// The associated type `name` of the type `qualifier`, as in `Self::Item` or `T::Item`.
+    /// It is resolved to the type given by the implementation of the trait for `qualifier`,
+    /// and stays unresolved while `qualifier` is a generic type, until monomorphization
+    /// replaces it.
+    TraitType {
+        name: Ident,
+        qualifier: TypeId,
+    },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -227,6 +260,38 @@ impl HashWithEngines for TypeInfo {
             TypeInfo::RawUntypedSlice => {
                 state.write_u8(19);
             }
+            TypeInfo::Ref(ty) => {
+                state.write_u8(20);
+                ty.hash(state, type_engine);
+            }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+                ..
+            } => {
+                state.write_u8(21);
+                parameters.hash(state, type_engine);
+                return_type.hash(state, type_engine);
+            }
+            TypeInfo::Closure {
+                decl_id,
+                parameters,
+                return_type,
+                captures,
+            } => {
+                state.write_u8(22);
+                decl_id.span().hash(state);
+                parameters.hash(state, type_engine);
+                return_type.hash(state, type_engine);
+                captures.hash(state, type_engine);
+            }
+            TypeInfo::TraitType { name, qualifier } => {
+                state.write_u8(23);
+                name.hash(state);
+                type_engine
+                    .look_up_type_id(*qualifier)
+                    .hash(state, type_engine);
+            }
         }
     }
 }
@@ -332,6 +397,61 @@ impl PartialEqWithEngines for TypeInfo {
             }
             (TypeInfo::RawUntypedPtr, TypeInfo::RawUntypedPtr) => true,
             (TypeInfo::RawUntypedSlice, TypeInfo::RawUntypedSlice) => true,
+            (TypeInfo::Ref(l), TypeInfo::Ref(r)) => type_engine
+                .look_up_type_id(l.type_id)
+                .eq(&type_engine.look_up_type_id(r.type_id), engines),
+            (
+                TypeInfo::Function {
+                    parameters: l_parameters,
+                    return_type: l_return_type,
+                    ..
+                },
+                TypeInfo::Function {
+                    parameters: r_parameters,
+                    return_type: r_return_type,
+                    ..
+                },
+            ) => {
+                l_parameters.len() == r_parameters.len()
+                    && l_parameters.eq(r_parameters, engines)
+                    && l_return_type.eq(r_return_type, engines)
+            }
+            (
+                TypeInfo::Closure {
+                    decl_id: l_decl_id,
+                    parameters: l_parameters,
+                    return_type: l_return_type,
+                    captures: l_captures,
+                },
+                TypeInfo::Closure {
+                    decl_id: r_decl_id,
+                    parameters: r_parameters,
+                    return_type: r_return_type,
+                    captures: r_captures,
+                },
+            ) => {
+                // Copies of a closure made while monomorphizing are lifted into different
+                // functions, so closures are told apart by where they are written instead.
+                l_decl_id.span() == r_decl_id.span()
+                    && l_parameters.eq(r_parameters, engines)
+                    && l_return_type.eq(r_return_type, engines)
+                    && l_captures.eq(r_captures, engines)
+            }
+            (
+                TypeInfo::TraitType {
+                    name: l_name,
+                    qualifier: l_qualifier,
+                },
+                TypeInfo::TraitType {
+                    name: r_name,
+                    qualifier: r_qualifier,
+                },
+            ) => {
+                l_name == r_name
+                    && type_engine
+                        .look_up_type_id(*l_qualifier)
+                        .eq(&type_engine.look_up_type_id(*r_qualifier), engines)
+            }
             _ => false,
         }
     }
@@ -405,6 +525,36 @@ impl DisplayWithEngines for TypeInfo {
             Storage { .. } => "contract storage".into(),
             RawUntypedPtr => "raw untyped ptr".into(),
             RawUntypedSlice => "raw untyped slice".into(),
+            Ref(ty) => format!("&{}", engines.help_out(ty)),
+            Function {
+                parameters,
+                return_type,
+                ..
+            } => format!(
+                "fn({}) -> {}",
+                parameters
+                    .iter()
+                    .map(|param| engines.help_out(param).to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                engines.help_out(return_type)
+            ),
+            Closure {
+                parameters,
+                return_type,
+                ..
+            } => format!(
+                "closure({}) -> {}",
+                parameters
+                    .iter()
+                    .map(|param| engines.help_out(param).to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                engines.help_out(return_type)
+            ),
+            TraitType { name, qualifier } => {
+                format!("{}::{}", engines.help_out(qualifier), name)
+            }
         };
         write!(f, "{}", s)
     }
@@ -497,9 +647,21 @@ impl UnconstrainedTypeParameters for TypeInfo {
                         .type_parameter_is_unconstrained(engines, type_parameter)
                 })
                 .any(|x| x),
-            TypeInfo::Array(elem, _) => elem
+            TypeInfo::Array(elem, _) | TypeInfo::Ref(elem) => elem
                 .type_id
                 .type_parameter_is_unconstrained(engines, type_parameter),
+            TypeInfo::Function {
+                parameters,
+                return_type,
+                ..
+            } => parameters
+                .iter()
+                .chain(iter::once(return_type))
+                .any(|type_arg| {
+                    type_arg
+                        .type_id
+                        .type_parameter_is_unconstrained(engines, type_parameter)
+                }),
             TypeInfo::Unknown
             | TypeInfo::Str(_)
             | TypeInfo::UnsignedInteger(_)
@@ -512,7 +674,9 @@ impl UnconstrainedTypeParameters for TypeInfo {
             | TypeInfo::ErrorRecovery
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
-            | TypeInfo::Storage { .. } => false,
+            | TypeInfo::Storage { .. }
+            | TypeInfo::Closure { .. }
+            | TypeInfo::TraitType { .. } => false,
         }
     }
 }
@@ -560,6 +724,28 @@ impl TypeInfo {
             Storage { .. } => "contract storage".into(),
             RawUntypedPtr => "raw untyped ptr".into(),
             RawUntypedSlice => "raw untyped slice".into(),
+            Ref(ty) => format!("&{}", ty.json_abi_str(type_engine)),
+            Function {
+                parameters,
+                return_type,
+                ..
+            } => format!(
+                "fn({}) -> {}",
+                parameters
+                    .iter()
+                    .map(|param| param.json_abi_str(type_engine))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                return_type.json_abi_str(type_engine)
+            ),
+            Closure { .. } => "closure".into(),
+            TraitType { name, qualifier } => format!(
+                "{}::{}",
+                type_engine
+                    .look_up_type_id(*qualifier)
+                    .json_abi_str(type_engine),
+                name
+            ),
         }
     }
 
@@ -845,6 +1031,34 @@ impl TypeInfo {
         matches!(self, TypeInfo::Boolean | TypeInfo::UnsignedInteger(_)) || self.is_unit()
     }
 
+    /// The parameter and return types of a [TypeInfo::Function], followed by the types of the
+    /// captured variables of a [TypeInfo::Closure].
+    pub(crate) fn signature_type_arguments(&self) -> Vec<TypeArgument> {
+        match self {
+            TypeInfo::Function {
+                parameters,
+                return_type,
+                ..
+            } => parameters
+                .iter()
+                .chain(iter::once(return_type))
+                .cloned()
+                .collect(),
+            TypeInfo::Closure {
+                parameters,
+                return_type,
+                captures,
+                ..
+            } => parameters
+                .iter()
+                .chain(iter::once(return_type))
+                .chain(captures.iter())
+                .cloned()
+                .collect(),
+            _ => vec![],
+        }
+    }
+
     pub(crate) fn apply_type_arguments(
         self,
         type_arguments: Vec<TypeArgument>,
@@ -893,6 +1107,10 @@ impl TypeInfo {
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery
             | TypeInfo::Array(_, _)
+            | TypeInfo::Ref(_)
+            | TypeInfo::Function { .. }
+            | TypeInfo::Closure { .. }
+            | TypeInfo::TraitType { .. }
             | TypeInfo::Storage { .. } => {
                 errors.push(CompileError::TypeArgumentsNotAllowed { span: span.clone() });
                 err(warnings, errors)
@@ -960,7 +1178,7 @@ impl TypeInfo {
                         }
                     }
                 }
-                TypeInfo::Array(elem_ty, _) => {
+                TypeInfo::Array(elem_ty, _) | TypeInfo::Ref(elem_ty) => {
                     inner_types.insert(elem_ty.type_id);
                     inner_types.extend(
                         type_engine
@@ -988,6 +1206,19 @@ impl TypeInfo {
                         );
                     }
                 }
+                TypeInfo::Function { .. } | TypeInfo::Closure { .. } => {
+                    inner_types.insert(type_id);
+                    for type_arg in type_engine
+                        .look_up_type_id(type_id)
+                        .signature_type_arguments()
+                    {
+                        inner_types.extend(
+                            type_engine
+                                .look_up_type_id(type_arg.type_id)
+                                .extract_inner_types(type_engine),
+                        );
+                    }
+                }
                 TypeInfo::Unknown
                 | TypeInfo::UnknownGeneric { .. }
                 | TypeInfo::Str(_)
@@ -999,7 +1230,8 @@ impl TypeInfo {
                 | TypeInfo::Numeric
                 | TypeInfo::RawUntypedPtr
                 | TypeInfo::RawUntypedSlice
-                | TypeInfo::Contract => {
+                | TypeInfo::Contract
+                | TypeInfo::TraitType { .. } => {
                     inner_types.insert(type_id);
                 }
                 TypeInfo::ErrorRecovery => {}
@@ -1040,7 +1272,7 @@ impl TypeInfo {
                     }
                 }
             }
-            TypeInfo::Array(elem_ty, _) => {
+            TypeInfo::Array(elem_ty, _) | TypeInfo::Ref(elem_ty) => {
                 inner_types.extend(helper(elem_ty.type_id));
             }
             TypeInfo::Tuple(elems) => {
@@ -1053,6 +1285,11 @@ impl TypeInfo {
                     inner_types.extend(helper(field.type_id));
                 }
             }
+            TypeInfo::Function { .. } | TypeInfo::Closure { .. } => {
+                for type_arg in self.signature_type_arguments() {
+                    inner_types.extend(helper(type_arg.type_id));
+                }
+            }
             TypeInfo::Unknown
             | TypeInfo::UnknownGeneric { .. }
             | TypeInfo::Str(_)
@@ -1065,7 +1302,8 @@ impl TypeInfo {
             | TypeInfo::Contract
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
-            | TypeInfo::ErrorRecovery => {}
+            | TypeInfo::ErrorRecovery
+            | TypeInfo::TraitType { .. } => {}
         }
         inner_types
     }
@@ -1086,6 +1324,7 @@ impl TypeInfo {
             | TypeInfo::Tuple(_)
             | TypeInfo::B256
             | TypeInfo::UnknownGeneric { .. }
+            | TypeInfo::TraitType { .. }
             | TypeInfo::Numeric => ok((), warnings, errors),
             TypeInfo::Unknown
             | TypeInfo::RawUntypedPtr
@@ -1097,6 +1336,9 @@ impl TypeInfo {
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery
             | TypeInfo::Array(_, _)
+            | TypeInfo::Ref(_)
+            | TypeInfo::Function { .. }
+            | TypeInfo::Closure { .. }
             | TypeInfo::Storage { .. } => {
                 errors.push(CompileError::Unimplemented(
                     "matching on this type is unsupported right now",
@@ -1131,6 +1373,10 @@ impl TypeInfo {
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::SelfType
             | TypeInfo::ErrorRecovery
+            | TypeInfo::Ref(_)
+            | TypeInfo::Function { .. }
+            | TypeInfo::Closure { .. }
+            | TypeInfo::TraitType { .. }
             | TypeInfo::Storage { .. } => {
                 errors.push(CompileError::Unimplemented(
                     "implementing traits on this type is unsupported right now",
@@ -1221,7 +1467,7 @@ impl TypeInfo {
                     all_nested_types.append(&mut nested_types);
                 }
             }
-            TypeInfo::Array(elem_ty, _) => {
+            TypeInfo::Array(elem_ty, _) | TypeInfo::Ref(elem_ty) => {
                 let mut nested_types = check!(
                     type_engine
                         .look_up_type_id(elem_ty.type_id)
@@ -1245,6 +1491,19 @@ impl TypeInfo {
                     all_nested_types.append(&mut nested_types);
                 }
             }
+            TypeInfo::Function { .. } | TypeInfo::Closure { .. } => {
+                for type_arg in self.signature_type_arguments() {
+                    let mut nested_types = check!(
+                        type_engine
+                            .look_up_type_id(type_arg.type_id)
+                            .extract_nested_types(type_engine, span),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    all_nested_types.append(&mut nested_types);
+                }
+            }
             TypeInfo::UnknownGeneric {
                 trait_constraints, ..
             } => {
@@ -1262,6 +1521,17 @@ impl TypeInfo {
                     }
                 }
             }
+            TypeInfo::TraitType { qualifier, .. } => {
+                let mut nested_types = check!(
+                    type_engine
+                        .look_up_type_id(qualifier)
+                        .extract_nested_types(type_engine, span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                all_nested_types.append(&mut nested_types);
+            }
             TypeInfo::Unknown
             | TypeInfo::Str(_)
             | TypeInfo::UnsignedInteger(_)
@@ -1615,6 +1885,10 @@ impl TypeInfo {
             | TypeInfo::SelfType
             | TypeInfo::Tuple(_)
             | TypeInfo::Array(_, _)
+            | TypeInfo::Ref(_)
+            | TypeInfo::Function { .. }
+            | TypeInfo::Closure { .. }
+            | TypeInfo::TraitType { .. }
             | TypeInfo::Contract
             | TypeInfo::Storage { .. }
             | TypeInfo::Numeric => true,
@@ -2,6 +2,7 @@ use std::fmt;
 
 use super::*;
 use crate::engine_threading::*;
+use sway_types::Spanned;
 
 type SourceType = TypeId;
 type DestinationType = TypeId;
@@ -208,7 +209,8 @@ impl TypeMapping {
                     type_arguments.iter().map(|x| x.type_id).collect::<Vec<_>>(),
                 )
             }
-            (TypeInfo::Array(type_parameter, _), TypeInfo::Array(type_argument, _)) => {
+            (TypeInfo::Array(type_parameter, _), TypeInfo::Array(type_argument, _))
+            | (TypeInfo::Ref(type_parameter), TypeInfo::Ref(type_argument)) => {
                 TypeMapping::from_type_parameters_and_type_arguments(
                     vec![type_parameter.type_id],
                     vec![type_argument.type_id],
@@ -270,14 +272,18 @@ impl TypeMapping {
     /// [TypeInfo::Struct], both `fields` and `type_parameters` will recursively
     /// call `find_match` (via calling [CopyTypes]).
     ///
-    /// A match can be found in two different circumstances:
+    /// A match can be found in three different circumstances:
     /// - `type_id` is a [TypeInfo::Custom] or [TypeInfo::UnknownGeneric]
+    /// - `type_id` is a [TypeInfo::TraitType] equal to a [SourceType]
+    /// - `type_id` is a [TypeInfo::Function] of the same parameter as a
+    ///   [SourceType]
     ///
     /// A match is potentially created (i.e. a new `TypeId` is created) in these
     /// circumstances:
     /// - `type_id` is a [TypeInfo::Struct], [TypeInfo::Enum],
-    ///     [TypeInfo::Array], or [TypeInfo::Tuple] and one of the sub-types
-    ///     finds a match in a recursive call to `find_match`
+    ///   [TypeInfo::Array], [TypeInfo::Tuple], [TypeInfo::Function],
+    ///   [TypeInfo::Closure] or [TypeInfo::TraitType] and one of the sub-types
+    ///   finds a match in a recursive call to `find_match`
     ///
     /// A match cannot be found in any other circumstance.
     pub(crate) fn find_match(&self, type_id: TypeId, engines: Engines<'_>) -> Option<TypeId> {
@@ -371,6 +377,10 @@ impl TypeMapping {
                     type_engine.insert_type(declaration_engine, TypeInfo::Array(elem_ty, count))
                 })
             }
+            TypeInfo::Ref(mut ty) => self.find_match(ty.type_id, engines).map(|type_id| {
+                ty.type_id = type_id;
+                type_engine.insert_type(declaration_engine, TypeInfo::Ref(ty))
+            }),
             TypeInfo::Tuple(fields) => {
                 let mut need_to_create_new = false;
                 let fields = fields
@@ -407,6 +417,100 @@ impl TypeMapping {
                     None
                 }
             }
+            TypeInfo::Function {
+                parameters,
+                return_type,
+                span,
+            } => {
+                // The function type of a parameter is mapped to the closure passed to it.
+                for (source_type, dest_type) in self.mapping.iter() {
+                    if let TypeInfo::Function {
+                        span: source_span, ..
+                    } = type_engine.look_up_type_id(*source_type)
+                    {
+                        if source_span == span
+                            && type_engine
+                                .look_up_type_id(*source_type)
+                                .eq(&type_engine.look_up_type_id(type_id), engines)
+                        {
+                            return Some(*dest_type);
+                        }
+                    }
+                }
+                let mut need_to_create_new = false;
+                let mut find_match = |mut type_arg: TypeArgument| {
+                    if let Some(type_id) = self.find_match(type_arg.type_id, engines) {
+                        need_to_create_new = true;
+                        type_arg.type_id = type_id;
+                    }
+                    type_arg
+                };
+                let parameters = parameters.into_iter().map(&mut find_match).collect();
+                let return_type = find_match(return_type);
+                if need_to_create_new {
+                    Some(type_engine.insert_type(
+                        declaration_engine,
+                        TypeInfo::Function {
+                            parameters,
+                            return_type,
+                            span,
+                        },
+                    ))
+                } else {
+                    None
+                }
+            }
+            TypeInfo::Closure {
+                decl_id,
+                parameters,
+                return_type,
+                captures,
+            } => {
+                let mut need_to_create_new = false;
+                let mut find_match = |mut type_arg: TypeArgument| {
+                    if let Some(type_id) = self.find_match(type_arg.type_id, engines) {
+                        need_to_create_new = true;
+                        type_arg.type_id = type_id;
+                    }
+                    type_arg
+                };
+                let parameters = parameters.into_iter().map(&mut find_match).collect();
+                let return_type = find_match(return_type);
+                let captures = captures.into_iter().map(&mut find_match).collect();
+                if !need_to_create_new {
+                    return None;
+                }
+                // The lifted function of the closure is shared by every copy of the closure
+                // type, so it is copied before its types are.
+                let decl_span = decl_id.span();
+                let mut decl = declaration_engine.get_function(decl_id, &decl_span).ok()?;
+                decl.copy_types(self, engines);
+                let decl_id = declaration_engine.insert_function(decl);
+                Some(type_engine.insert_type(
+                    declaration_engine,
+                    TypeInfo::Closure {
+                        decl_id,
+                        parameters,
+                        return_type,
+                        captures,
+                    },
+                ))
+            }
+            TypeInfo::TraitType {
+                ref name,
+                qualifier,
+            } => iter_for_match(engines, self, &type_info).or_else(|| {
+                // The associated type of a generic type follows the generic type.
+                self.find_match(qualifier, engines).map(|qualifier| {
+                    type_engine.insert_type(
+                        declaration_engine,
+                        TypeInfo::TraitType {
+                            name: name.clone(),
+                            qualifier,
+                        },
+                    )
+                })
+            }),
             TypeInfo::Unknown
             | TypeInfo::Str(..)
             | TypeInfo::UnsignedInteger(..)
@@ -266,6 +266,71 @@ impl TypeParameter {
             err(warnings, errors)
         }
     }
+
+    /// Creates a [TypeMapping] from the associated types of the types of
+    /// `type_parameters`, as given by their trait constraints, to the types
+    /// that the implementations of those traits give to them.
+    ///
+    /// This is used once the types of `type_parameters` are known, such as
+    /// after the arguments of a function application are unified with its
+    /// parameters.
+    pub(crate) fn gather_type_mapping_from_trait_constraints(
+        ctx: TypeCheckContext,
+        type_parameters: &[TypeParameter],
+    ) -> TypeMapping {
+        let type_engine = ctx.type_engine;
+        let declaration_engine = ctx.declaration_engine;
+        let engines = ctx.engines();
+
+        let mut source_types = vec![];
+        let mut destination_types = vec![];
+        for type_param in type_parameters.iter() {
+            let mut trait_names = type_param
+                .trait_constraints
+                .iter()
+                .map(|trait_constraint| trait_constraint.trait_name.clone())
+                .collect::<Vec<_>>();
+            while let Some(trait_name) = trait_names.pop() {
+                let trait_decl = match ctx.namespace.resolve_call_path(&trait_name).value {
+                    Some(ty::TyDeclaration::TraitDeclaration(decl_id)) => {
+                        match declaration_engine.get_trait(decl_id.clone(), &trait_name.span()) {
+                            Ok(trait_decl) => trait_decl,
+                            Err(_) => continue,
+                        }
+                    }
+                    _ => continue,
+                };
+                for trait_type in trait_decl.types.iter() {
+                    let associated_type = ctx
+                        .namespace
+                        .find_associated_type_for_type(
+                            type_param.type_id,
+                            &trait_type.name,
+                            ctx.namespace.mod_path(),
+                            engines,
+                        )
+                        .value;
+                    if let Some(associated_type) = associated_type {
+                        source_types.push(type_engine.insert_type(
+                            declaration_engine,
+                            TypeInfo::TraitType {
+                                name: trait_type.name.clone(),
+                                qualifier: type_param.type_id,
+                            },
+                        ));
+                        destination_types.push(associated_type);
+                    }
+                }
+                trait_names.extend(
+                    trait_decl
+                        .supertraits
+                        .iter()
+                        .map(|supertrait| supertrait.name.clone()),
+                );
+            }
+        }
+        TypeMapping::from_type_parameters_and_type_arguments(source_types, destination_types)
+    }
 }
 
 fn handle_trait(
@@ -141,6 +141,60 @@ pub(super) fn unify(
             arguments_are_flipped,
             engines,
         ),
+        // References have a single inner type, just like arrays.
+        (Ref(re), Ref(ee)) => unify::unify_arrays(
+            received,
+            expected,
+            span,
+            help_text,
+            re.type_id,
+            ee.type_id,
+            curried,
+            arguments_are_flipped,
+            engines,
+        ),
+        // A closure, or a function used as a value, can be passed where a function type is
+        // expected as long as their signatures match.
+        (
+            Closure {
+                parameters: rps,
+                return_type: rr,
+                ..
+            }
+            | Function {
+                parameters: rps,
+                return_type: rr,
+                ..
+            },
+            Function {
+                parameters: eps,
+                return_type: er,
+                ..
+            },
+        ) if rps.len() == eps.len() => {
+            unify::unify_tuples(help_text, signature(rps, rr), signature(eps, er), curried)
+        }
+        (
+            Closure {
+                decl_id: rd,
+                parameters: rps,
+                return_type: rr,
+                captures: rcs,
+            },
+            Closure {
+                decl_id: ed,
+                parameters: eps,
+                return_type: er,
+                captures: ecs,
+            },
+        ) if rd.span() == ed.span() && rps.len() == eps.len() && rcs.len() == ecs.len() => {
+            unify::unify_tuples(
+                help_text,
+                signature(rps, rr).into_iter().chain(rcs).collect(),
+                signature(eps, er).into_iter().chain(ecs).collect(),
+                curried,
+            )
+        }
         (
             ref r @ TypeInfo::ContractCaller {
                 abi_name: ref ran,
@@ -201,6 +255,12 @@ pub(super) fn unify(
             // if they are the same, then it's ok
             (vec![], vec![])
         }
+        (ref r @ TypeInfo::TraitType { .. }, ref e @ TypeInfo::TraitType { .. })
+            if r.eq(e, engines) =>
+        {
+            // the same associated type of the same type
+            (vec![], vec![])
+        }
 
         // When we don't know anything about either term, assume that
         // they match and make the one we know nothing about reference the
@@ -389,6 +449,49 @@ pub(super) fn unify_right(
         (Array(re, rc), Array(ee, ec)) if rc.val() == ec.val() => unify::unify_arrays(
             received, expected, span, help_text, re.type_id, ee.type_id, curried, false, engines,
         ),
+        (Ref(re), Ref(ee)) => unify::unify_arrays(
+            received, expected, span, help_text, re.type_id, ee.type_id, curried, false, engines,
+        ),
+        (
+            Closure {
+                parameters: rps,
+                return_type: rr,
+                ..
+            }
+            | Function {
+                parameters: rps,
+                return_type: rr,
+                ..
+            },
+            Function {
+                parameters: eps,
+                return_type: er,
+                ..
+            },
+        ) if rps.len() == eps.len() => {
+            unify::unify_tuples(help_text, signature(rps, rr), signature(eps, er), curried)
+        }
+        (
+            Closure {
+                decl_id: rd,
+                parameters: rps,
+                return_type: rr,
+                captures: rcs,
+            },
+            Closure {
+                decl_id: ed,
+                parameters: eps,
+                return_type: er,
+                captures: ecs,
+            },
+        ) if rd.span() == ed.span() && rps.len() == eps.len() && rcs.len() == ecs.len() => {
+            unify::unify_tuples(
+                help_text,
+                signature(rps, rr).into_iter().chain(rcs).collect(),
+                signature(eps, er).into_iter().chain(ecs).collect(),
+                curried,
+            )
+        }
         (
             TypeInfo::ContractCaller {
                 abi_name: ref ran, ..
@@ -424,6 +527,12 @@ pub(super) fn unify_right(
             // if they are the same, then it's ok
             (vec![], vec![])
         }
+        (ref r @ TypeInfo::TraitType { .. }, ref e @ TypeInfo::TraitType { .. })
+            if r.eq(e, engines) =>
+        {
+            // the same associated type of the same type
+            (vec![], vec![])
+        }
 
         // When we don't know anything about either term, assume that
         // they match and make the one we know nothing about reference the
@@ -527,6 +636,15 @@ where
     (warnings, errors)
 }
 
+/// The types of the parameters of a function signature followed by its return type, to be
+/// unified like the fields of a tuple.
+fn signature(parameters: Vec<TypeArgument>, return_type: TypeArgument) -> Vec<TypeArgument> {
+    parameters
+        .into_iter()
+        .chain(std::iter::once(return_type))
+        .collect()
+}
+
 fn unify_unsigned_ints(
     span: &Span,
     r: IntegerBits,
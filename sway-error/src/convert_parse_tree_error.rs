@@ -55,6 +55,8 @@ pub enum ConvertParseTreeError {
     WildcardPatternsNotSupportedHere { span: Span },
     #[error("tuple patterns not supported in this position")]
     TuplePatternsNotSupportedHere { span: Span },
+    #[error("or patterns not supported in this position")]
+    OrPatternsNotSupportedHere { span: Span },
     #[error("ref patterns not supported in this position")]
     RefPatternsNotSupportedHere { span: Span },
     #[error("constructor patterns require a single argument")]
@@ -97,6 +99,16 @@ pub enum ConvertParseTreeError {
     CannotAnnotateDependency { span: Span },
     #[error("Expected dependency at the beginning before any other items.")]
     ExpectedDependencyAtBeginning { span: Span },
+    #[error("constant \"{name}\" requires a value")]
+    ConstantRequiresExpression { name: Ident, span: Span },
+    #[error("associated types are only allowed in trait implementations")]
+    AssociatedTypeInImplSelf { span: Span },
+    #[error("function types are only allowed as the types of function parameters")]
+    FunctionTypeNotAllowedHere { span: Span },
+    #[error("invalid `cfg` predicate, expected `test` or `feature = \"<name>\"`")]
+    InvalidCfgPredicate { span: Span },
+    #[error("`cfg` attributes are only allowed on module level items and the items of `impl`, `trait` and `abi` blocks")]
+    CfgNotAllowedHere { span: Span },
 }
 
 impl Spanned for ConvertParseTreeError {
@@ -128,6 +140,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::StructPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::WildcardPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::TuplePatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::OrPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::RefPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternOneArg { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternSubPatterns { span } => span.clone(),
@@ -149,6 +162,11 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::CannotDocCommentDependency { span } => span.clone(),
             ConvertParseTreeError::CannotAnnotateDependency { span } => span.clone(),
             ConvertParseTreeError::ExpectedDependencyAtBeginning { span } => span.clone(),
+            ConvertParseTreeError::ConstantRequiresExpression { span, .. } => span.clone(),
+            ConvertParseTreeError::AssociatedTypeInImplSelf { span } => span.clone(),
+            ConvertParseTreeError::FunctionTypeNotAllowedHere { span } => span.clone(),
+            ConvertParseTreeError::InvalidCfgPredicate { span } => span.clone(),
+            ConvertParseTreeError::CfgNotAllowedHere { span } => span.clone(),
         }
     }
 }
@@ -2,6 +2,7 @@ use crate::convert_parse_tree_error::ConvertParseTreeError;
 use crate::lex_error::LexError;
 use crate::parser_error::ParseError;
 use crate::type_error::TypeError;
+use crate::warning::Warning;
 
 use core::fmt;
 use std::path::PathBuf;
@@ -105,6 +106,8 @@ pub enum CompileError {
     NoScriptMainFunction(Span),
     #[error("Function \"{name}\" was already defined in scope.")]
     MultipleDefinitionsOfFunction { name: Ident },
+    #[error("Constant \"{name}\" was already defined in scope.")]
+    MultipleDefinitionsOfConstant { name: Ident, span: Span },
     #[error(
         "Attempted to reassign to a symbol that is not a variable. Symbol {name} is not a mutable \
          variable, it is a {kind}."
@@ -179,6 +182,32 @@ pub enum CompileError {
     #[error("Functions are missing from this trait implementation: {missing_functions}")]
     MissingInterfaceSurfaceMethods {
         missing_functions: String,
+        interface_name: InterfaceName,
+        span: Span,
+    },
+    #[error("Constant \"{name}\" is not a part of {interface_name}'s interface surface.")]
+    ConstantNotAPartOfInterfaceSurface {
+        name: Ident,
+        interface_name: InterfaceName,
+        span: Span,
+    },
+    #[error("Constants are missing from this trait implementation: {missing_constants}")]
+    MissingInterfaceSurfaceConstants {
+        missing_constants: String,
+        span: Span,
+    },
+    #[error("Type \"{name}\" is not a part of {interface_name}'s interface surface.")]
+    TypeNotAPartOfInterfaceSurface {
+        name: Ident,
+        interface_name: InterfaceName,
+        span: Span,
+    },
+    #[error("Types are missing from this trait implementation: {missing_types}")]
+    MissingInterfaceSurfaceTypes { missing_types: String, span: Span },
+    #[error("No associated type named \"{name}\" found for type \"{type_name}\".")]
+    AssociatedTypeNotFound {
+        name: Ident,
+        type_name: String,
         span: Span,
     },
     #[error("Expected {} type {}, but instead found {}.", expected, if *expected == 1usize { "argument" } else { "arguments" }, given)]
@@ -238,6 +267,20 @@ pub enum CompileError {
         struct_name: Ident,
         span: Span,
     },
+    #[error(
+        "The base of a struct update must be of type \"{expected}\", but this expression is of \
+        type \"{received}\"."
+    )]
+    StructUpdateBaseTypeMismatch {
+        expected: String,
+        received: String,
+        span: Span,
+    },
+    #[error(
+        "Field \"{field_name}\" is initialized with the variable of the same name, but there is no \
+        variable \"{field_name}\" in this scope. Consider writing \"{field_name}: <value>\"."
+    )]
+    StructFieldShorthandUnknownVariable { field_name: Ident },
     #[error("No method named \"{method_name}\" found for type \"{type_name}\".")]
     MethodNotFound {
         method_name: Ident,
@@ -534,6 +577,9 @@ pub enum CompileError {
     #[error("Non-exhaustive match expression. Missing patterns {missing_patterns}")]
     MatchExpressionNonExhaustive {
         missing_patterns: String,
+        /// The source code of a pattern matching each of the missing patterns, or `None` if any
+        /// of them can't be written as a pattern, e.g. a range of numbers.
+        missing_pattern_sources: Option<Vec<String>>,
         span: Span,
     },
     #[error("Pattern does not mention {}: {}",
@@ -543,6 +589,81 @@ pub enum CompileError {
         missing_fields: Vec<String>,
         span: Span,
     },
+    #[error("Variable \"{variable}\" is not bound in all alternatives of this or-pattern.")]
+    MatchOrPatternVariableNotBoundInAllAlternatives { variable: Ident, span: Span },
+    #[error(
+        "Values of type \"{ty}\" cannot be referenced. Only values that do not fit in a \
+        register, like structs, enums, tuples, arrays, strings and b256, can be referenced; \
+        pass this value by copy instead."
+    )]
+    RefOfCopyType { ty: String, span: Span },
+    #[error("Only references can be dereferenced, but this expression is of type \"{ty}\".")]
+    DerefOfNonReference { ty: String, span: Span },
+    #[error(
+        "The \"?\" operator can only be applied to an \"Option\" or a \"Result\", but this \
+        expression is of type \"{ty}\"."
+    )]
+    TryOnNonOptionOrResult { ty: String, span: Span },
+    #[error("The \"?\" operator can only be used inside a function.")]
+    TryOutsideOfFunction { span: Span },
+    #[error(
+        "The type of the closure parameter \"{name}\" cannot be inferred. Consider annotating \
+        it, as in \"|{name}: u64|\"."
+    )]
+    ClosureParameterTypeUnknown { name: Ident, span: Span },
+    #[error(
+        "The closure expects {expected} parameter(s) but the function type it is passed as has \
+        {received}."
+    )]
+    ClosureParameterCountMismatch {
+        expected: usize,
+        received: usize,
+        span: Span,
+    },
+    #[error("Generic function \"{name}\" cannot be used as a value.")]
+    GenericFunctionAsValue { name: Ident, span: Span },
+    #[error(
+        "\"{name}\" is of type \"{ty}\", which cannot be called. Only closures and functions \
+        can be called."
+    )]
+    CallOfNonFunctionValue { name: Ident, ty: String, span: Span },
+    #[error(
+        "A closure cannot escape the function it is created in, so it cannot be returned from \
+        a function or be part of the value returned from one."
+    )]
+    ClosureEscapesFunction { span: Span },
+    #[error(
+        "A reference cannot outlive the call of the function it is created in, so it cannot be \
+        returned from a function, be part of the value returned from one, or be assigned to a \
+        `ref mut` parameter."
+    )]
+    ReferenceEscapesFunction { span: Span },
+    #[error("Closures and functions cannot be passed to {what}.")]
+    FunctionTypeParameterNotAllowed { what: &'static str, span: Span },
+    #[error(
+        "The \"?\" operator can only be applied to a value of type \"{kind}\" in a function \
+        that also returns \"{kind}\", but the enclosing function returns \"{fn_return_type}\"."
+    )]
+    TryIncompatibleReturnType {
+        kind: &'static str,
+        fn_return_type: String,
+        span: Span,
+    },
+    #[error(
+        "The \"?\" operator would return the error type \"{error_type}\" from a function whose \
+        error type is \"{fn_error_type}\". The error types of the \"Result\" and of the \
+        function's return type must be the same."
+    )]
+    TryErrorTypeMismatch {
+        error_type: String,
+        fn_error_type: String,
+        span: Span,
+    },
+    #[error(
+        "A \"for\" loop can only iterate over a range, an array, or a value that implements \
+        \"std::iterator::Iterator\", but this expression is of type \"{ty}\"."
+    )]
+    ForLoopNotIterable { ty: String, span: Span },
     #[error(
         "Storage attribute access mismatch. Try giving the surrounding function more access by \
         adding \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}({attrs})]\" to the function declaration."
@@ -674,6 +795,16 @@ pub enum CompileError {
     ConfigTimeConstantNotAConstDecl { span: Span },
     #[error("Configuration-time constant value is not a literal.")]
     ConfigTimeConstantNotALiteral { span: Span },
+    #[error(
+        "Configuration-time constant \"{name}\" is declared with type \"{expected}\", but its value \
+         is of type \"{received}\"."
+    )]
+    ConfigTimeConstantTypeMismatch {
+        name: String,
+        expected: String,
+        received: String,
+        span: Span,
+    },
     #[error("ref mut parameter not allowed for main()")]
     RefMutableNotAllowedInMain { param_name: Ident },
     #[error("Returning a `raw_ptr` from `main()` is not allowed.")]
@@ -711,6 +842,12 @@ pub enum CompileError {
         missing_impl_attribute: bool,
         span: Span,
     },
+    #[error("{warning_content}\nhelp: This is an error because the lint \"{lint}\" is denied.")]
+    DeniedLint {
+        lint: &'static str,
+        warning_content: Warning,
+        span: Span,
+    },
 }
 
 impl std::convert::From<TypeError> for CompileError {
@@ -744,6 +881,7 @@ impl Spanned for CompileError {
             PredicateMainDoesNotReturnBool(span) => span.clone(),
             NoScriptMainFunction(span) => span.clone(),
             MultipleDefinitionsOfFunction { name } => name.span(),
+            MultipleDefinitionsOfConstant { span, .. } => span.clone(),
             ReassignmentToNonVariable { span, .. } => span.clone(),
             AssignmentToNonMutable { name } => name.span(),
             MutableParameterNotSupported { param_name } => param_name.span(),
@@ -758,6 +896,11 @@ impl Spanned for CompileError {
             UnknownTrait { span, .. } => span.clone(),
             FunctionNotAPartOfInterfaceSurface { span, .. } => span.clone(),
             MissingInterfaceSurfaceMethods { span, .. } => span.clone(),
+            ConstantNotAPartOfInterfaceSurface { span, .. } => span.clone(),
+            MissingInterfaceSurfaceConstants { span, .. } => span.clone(),
+            TypeNotAPartOfInterfaceSurface { span, .. } => span.clone(),
+            MissingInterfaceSurfaceTypes { span, .. } => span.clone(),
+            AssociatedTypeNotFound { span, .. } => span.clone(),
             IncorrectNumberOfTypeArguments { span, .. } => span.clone(),
             DoesNotTakeTypeArguments { span, .. } => span.clone(),
             TypeArgumentsNotAllowed { span } => span.clone(),
@@ -768,6 +911,8 @@ impl Spanned for CompileError {
             MethodOnNonValue { span, .. } => span.clone(),
             StructMissingField { span, .. } => span.clone(),
             StructDoesNotHaveField { span, .. } => span.clone(),
+            StructUpdateBaseTypeMismatch { span, .. } => span.clone(),
+            StructFieldShorthandUnknownVariable { field_name } => field_name.span(),
             MethodNotFound { span, .. } => span.clone(),
             ModuleNotFound { span, .. } => span.clone(),
             NotATuple { span, .. } => span.clone(),
@@ -840,6 +985,21 @@ impl Spanned for CompileError {
             MatchWrongType { span, .. } => span.clone(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
             MatchStructPatternMissingFields { span, .. } => span.clone(),
+            MatchOrPatternVariableNotBoundInAllAlternatives { span, .. } => span.clone(),
+            RefOfCopyType { span, .. } => span.clone(),
+            DerefOfNonReference { span, .. } => span.clone(),
+            TryOnNonOptionOrResult { span, .. } => span.clone(),
+            TryOutsideOfFunction { span } => span.clone(),
+            ClosureParameterTypeUnknown { span, .. } => span.clone(),
+            ClosureParameterCountMismatch { span, .. } => span.clone(),
+            GenericFunctionAsValue { span, .. } => span.clone(),
+            CallOfNonFunctionValue { span, .. } => span.clone(),
+            ClosureEscapesFunction { span } => span.clone(),
+            ReferenceEscapesFunction { span } => span.clone(),
+            FunctionTypeParameterNotAllowed { span, .. } => span.clone(),
+            TryIncompatibleReturnType { span, .. } => span.clone(),
+            TryErrorTypeMismatch { span, .. } => span.clone(),
+            ForLoopNotIterable { span, .. } => span.clone(),
             NotAnEnum { span, .. } => span.clone(),
             StorageAccessMismatch { span, .. } => span.clone(),
             TraitDeclPureImplImpure { span, .. } => span.clone(),
@@ -889,6 +1049,7 @@ impl Spanned for CompileError {
             ContinueOutsideLoop { span } => span.clone(),
             ConfigTimeConstantNotAConstDecl { span } => span.clone(),
             ConfigTimeConstantNotALiteral { span } => span.clone(),
+            ConfigTimeConstantTypeMismatch { span, .. } => span.clone(),
             RefMutableNotAllowedInMain { param_name } => param_name.span(),
             PointerReturnNotAllowedInMain { span } => span.clone(),
             NestedSliceReturnNotAllowedInMain { span } => span.clone(),
@@ -898,6 +1059,7 @@ impl Spanned for CompileError {
             DisallowedWhileInPredicate { span } => span.clone(),
             CoinsPassedToNonPayableMethod { span, .. } => span.clone(),
             TraitImplPayabilityMismatch { span, .. } => span.clone(),
+            DeniedLint { span, .. } => span.clone(),
         }
     }
 }
@@ -30,6 +30,8 @@ pub enum ParseErrorKind {
     ExpectedExpression,
     #[error("Unexpected token after array length.")]
     UnexpectedTokenAfterArrayLength,
+    #[error("The base of a struct update, as in `..base`, must come last in a struct expression.")]
+    UnexpectedTokenAfterStructBase,
     #[error("Expected a comma, semicolon or closing bracket when parsing this array.")]
     ExpectedCommaSemicolonOrCloseBracketInArray,
     #[error("Unexpected token after asm return type.")]
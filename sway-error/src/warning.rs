@@ -97,6 +97,128 @@ pub enum Warning {
     StorageReadAfterInteraction {
         block_name: Ident,
     },
+    UnknownLint {
+        lint_name: Ident,
+    },
+}
+
+impl Warning {
+    /// The name of the lint controlling this warning, as used within `allow`, `warn` and `deny`
+    /// attributes and the `[lints]` table of a package manifest.
+    pub fn lint_name(&self) -> &'static str {
+        use Warning::*;
+        match self {
+            NonClassCaseStructName { .. }
+            | NonClassCaseTypeParameter { .. }
+            | NonClassCaseTraitName { .. }
+            | NonClassCaseEnumName { .. }
+            | NonClassCaseEnumVariantName { .. } => NON_CLASS_CASE,
+            NonSnakeCaseStructFieldName { .. } | NonSnakeCaseFunctionName { .. } => NON_SNAKE_CASE,
+            NonScreamingSnakeCaseConstName { .. } => NON_SCREAMING_SNAKE_CASE,
+            LossOfPrecision { .. } => LOSS_OF_PRECISION,
+            UnusedReturnValue { .. } => UNUSED_RETURN_VALUE,
+            SimilarMethodFound { .. } => SIMILAR_METHOD_FOUND,
+            ShadowsOtherSymbol { .. } => SHADOWING,
+            OverridingTraitImplementation => OVERRIDING_TRAIT_IMPLEMENTATION,
+            DeadDeclaration
+            | DeadFunctionDeclaration
+            | DeadStructDeclaration
+            | DeadTrait
+            | DeadEnumVariant { .. }
+            | DeadMethod
+            | StructFieldNeverRead
+            | DeadStorageDeclaration => DEAD_CODE,
+            UnreachableCode | MatchExpressionUnreachableArm => UNREACHABLE_CODE,
+            ShadowingReservedRegister { .. } => SHADOWING_RESERVED_REGISTER,
+            DeadStorageDeclarationForFunction { .. } => STORAGE_ATTRIBUTES,
+            UnrecognizedAttribute { .. } => UNKNOWN_ATTRIBUTES,
+            UnknownLint { .. } => UNKNOWN_LINTS,
+            StorageWriteAfterInteraction { .. } => STORAGE_WRITE_AFTER_INTERACTION,
+            StorageReadAfterInteraction { .. } => STORAGE_READ_AFTER_INTERACTION,
+        }
+    }
+}
+
+pub const NON_CLASS_CASE: &str = "non_class_case";
+pub const NON_SNAKE_CASE: &str = "non_snake_case";
+pub const NON_SCREAMING_SNAKE_CASE: &str = "non_screaming_snake_case";
+pub const LOSS_OF_PRECISION: &str = "loss_of_precision";
+pub const UNUSED_RETURN_VALUE: &str = "unused_return_value";
+pub const SIMILAR_METHOD_FOUND: &str = "similar_method_found";
+pub const SHADOWING: &str = "shadowing";
+pub const OVERRIDING_TRAIT_IMPLEMENTATION: &str = "overriding_trait_implementation";
+pub const DEAD_CODE: &str = "dead_code";
+pub const UNREACHABLE_CODE: &str = "unreachable_code";
+pub const SHADOWING_RESERVED_REGISTER: &str = "shadowing_reserved_register";
+pub const STORAGE_ATTRIBUTES: &str = "storage_attributes";
+pub const UNKNOWN_ATTRIBUTES: &str = "unknown_attributes";
+pub const UNKNOWN_LINTS: &str = "unknown_lints";
+pub const STORAGE_WRITE_AFTER_INTERACTION: &str = "storage_write_after_interaction";
+pub const STORAGE_READ_AFTER_INTERACTION: &str = "storage_read_after_interaction";
+
+/// The lint group containing every lint.
+pub const WARNINGS_LINT_GROUP: &str = "warnings";
+
+/// Every name accepted by the `allow`, `warn` and `deny` attributes, including lint groups.
+pub const LINT_NAMES: &[&str] = &[
+    WARNINGS_LINT_GROUP,
+    NON_CLASS_CASE,
+    NON_SNAKE_CASE,
+    NON_SCREAMING_SNAKE_CASE,
+    LOSS_OF_PRECISION,
+    UNUSED_RETURN_VALUE,
+    SIMILAR_METHOD_FOUND,
+    SHADOWING,
+    OVERRIDING_TRAIT_IMPLEMENTATION,
+    DEAD_CODE,
+    UNREACHABLE_CODE,
+    SHADOWING_RESERVED_REGISTER,
+    STORAGE_ATTRIBUTES,
+    UNKNOWN_ATTRIBUTES,
+    UNKNOWN_LINTS,
+    STORAGE_WRITE_AFTER_INTERACTION,
+    STORAGE_READ_AFTER_INTERACTION,
+];
+
+/// How a lint is reported when it is triggered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintLevel {
+    /// The warning is silenced.
+    Allow,
+    /// The warning is reported as a warning.
+    Warn,
+    /// The warning is reported as an error.
+    Deny,
+}
+
+impl LintLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for LintLevel {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            _ => Err(format!(
+                "invalid lint level \"{s}\", expected one of \"allow\", \"warn\" or \"deny\""
+            )),
+        }
+    }
 }
 
 impl fmt::Display for Warning {
@@ -222,6 +344,7 @@ impl fmt::Display for Warning {
             ),
             MatchExpressionUnreachableArm => write!(f, "This match arm is unreachable."),
             UnrecognizedAttribute {attrib_name} => write!(f, "Unknown attribute: \"{attrib_name}\"."),
+            UnknownLint {lint_name} => write!(f, "Unknown lint: \"{lint_name}\"."),
             StorageWriteAfterInteraction {block_name} => write!(f, "Storage modification after external contract interaction in function or method \"{block_name}\". \
             Consider making all storage writes before calling another contract"),
             StorageReadAfterInteraction {block_name} => write!(f, "Storage read after external contract interaction in function or method \"{block_name}\". \
@@ -280,6 +280,11 @@ impl Instruction {
                 Type::Struct(aggregate) => Some(*aggregate),
                 _otherwise => None,
             },
+            Instruction::IntToPtr(_, ty) => match ty {
+                Type::Array(aggregate) => Some(*aggregate),
+                Type::Struct(aggregate) => Some(*aggregate),
+                _otherwise => None,
+            },
             Instruction::ExtractElement { ty, .. } => {
                 ty.get_elem_type(context).and_then(|ty| match ty {
                     Type::Array(nested_aggregate) => Some(nested_aggregate),
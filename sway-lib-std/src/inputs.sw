@@ -64,8 +64,7 @@ pub enum Input {
 
 /// Get the type of the input at `index`.
 pub fn input_type(index: u64) -> Input {
-    let type = __gtf::<u8>(index, GTF_INPUT_TYPE);
-    match type {
+    match __gtf::<u8>(index, GTF_INPUT_TYPE) {
         0u8 => Input::Coin,
         1u8 => Input::Contract,
         2u8 => Input::Message,
@@ -75,8 +74,7 @@ pub fn input_type(index: u64) -> Input {
 
 /// for either tx type (transaction-script or transaction-create).
 pub fn input_pointer(index: u64) -> u64 {
-    let type = tx_type();
-    match type {
+    match tx_type() {
         Transaction::Script => __gtf::<u64>(index, GTF_SCRIPT_INPUT_AT_INDEX),
         Transaction::Create => __gtf::<u64>(index, GTF_CREATE_INPUT_AT_INDEX),
     }
@@ -85,8 +83,7 @@ pub fn input_pointer(index: u64) -> u64 {
 /// If the input's type is `InputCoin` the owner as an Option::Some(owner).
 /// Otherwise, returns Option::None.
 pub fn input_owner(index: u64) -> Option<Address> {
-    let type = input_type(index);
-    match type {
+    match input_type(index) {
         Input::Coin => Option::Some(Address::from(__gtf::<b256>(index, GTF_INPUT_COIN_OWNER))),
         _ => Option::None,
     }
@@ -97,8 +94,7 @@ pub fn input_owner(index: u64) -> Option<Address> {
 /// return the data as an Option::Some(ptr).
 /// Otherwise, returns Option::None.
 pub fn input_predicate_data_pointer(index: u64) -> Option<raw_ptr> {
-    let type = input_type(index);
-    match type {
+    match input_type(index) {
         Input::Coin => Option::Some(__gtf::<raw_ptr>(index, GTF_INPUT_COIN_PREDICATE_DATA)),
         Input::Message => Option::Some(__gtf::<raw_ptr>(index, GTF_INPUT_MESSAGE_PREDICATE_DATA)),
         _ => Option::None,
@@ -116,8 +112,7 @@ pub fn input_predicate_data<T>(index: u64) -> T {
 /// Get the transaction inputs count for either tx type
 /// (transaction-script or transaction-create).
 pub fn input_count() -> u8 {
-    let type = tx_type();
-    match type {
+    match tx_type() {
         Transaction::Script => __gtf::<u8>(0, GTF_SCRIPT_INPUTS_COUNT),
         Transaction::Create => __gtf::<u8>(0, GTF_CREATE_INPUTS_COUNT),
     }
@@ -0,0 +1,13 @@
+//! Iteration over sequences of values.
+library iterator;
+
+use ::option::Option;
+
+/// A type that produces a sequence of values of type `T`, one at a time.
+///
+/// Anything that implements `Iterator` can be looped over with a `for` loop.
+pub trait Iterator<T> {
+    /// Advances the iterator and returns the next value, or `None` once the
+    /// sequence is exhausted.
+    fn next(ref mut self) -> Option<T>;
+}
@@ -33,6 +33,7 @@ dep vm/mod;
 dep flags;
 dep u128;
 dep u256;
+dep iterator;
 dep vec;
 dep bytes;
 dep message;
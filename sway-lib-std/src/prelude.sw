@@ -10,7 +10,8 @@ use ::identity::Identity;
 
 /* Collections */
 use ::storage::StorageMap;
-use ::vec::Vec;
+use ::vec::{Vec, VecIter};
+use ::iterator::Iterator;
 
 /* Error handling */
 use ::assert::assert;
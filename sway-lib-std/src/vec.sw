@@ -4,6 +4,7 @@ use ::alloc::{alloc, realloc};
 use ::assert::assert;
 use ::option::Option;
 use ::convert::From;
+use ::iterator::Iterator;
 
 struct RawVec<T> {
     ptr: raw_ptr,
@@ -232,6 +233,29 @@ impl<T> Vec<T> {
         self.len == 0
     }
 
+    /// Returns an iterator over the elements of the vector, in order.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// let mut vec = Vec::new();
+    /// vec.push(5);
+    /// vec.push(10);
+    /// let mut sum = 0;
+    /// for value in vec.iter() {
+    ///     sum += value;
+    /// }
+    /// assert(sum == 15);
+    /// ```
+    pub fn iter(self) -> VecIter<T> {
+        VecIter {
+            vec: self,
+            index: 0,
+        }
+    }
+
     /// Removes and returns the element at position `index` within the vector,
     /// shifting all elements after it to the left.
     ///
@@ -424,6 +448,78 @@ impl<T> Vec<T> {
     }
 }
 
+// Need to use a separate impl block for now, so that the methods above can be called: https://github.com/FuelLabs/sway/issues/1548
+impl<T> Vec<T> {
+    /// Returns a new vector holding the results of applying `f` to each
+    /// element of the vector, in order.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// let vec = Vec::new();
+    /// vec.push(5);
+    /// vec.push(10);
+    /// let doubled = vec.map(|x| x * 2);
+    /// assert(doubled.get(0).unwrap() == 10);
+    /// assert(doubled.get(1).unwrap() == 20);
+    /// ```
+    pub fn map<U>(self, f: fn(T) -> U) -> Vec<U> {
+        let mut mapped = Vec::with_capacity(self.len);
+        let mut i = 0;
+        while i < self.len {
+            mapped.push(f(self.buf.ptr().add::<T>(i).read::<T>()));
+            i += 1;
+        }
+        mapped
+    }
+
+    /// Sorts the vector in place, with `less` telling whether an element
+    /// should come before another one. The sort is stable, so equal elements
+    /// keep their order.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// let vec = Vec::new();
+    /// vec.push(10);
+    /// vec.push(5);
+    /// vec.push(15);
+    /// vec.sort_by(|a, b| a > b);
+    /// assert(vec.get(0).unwrap() == 15);
+    /// assert(vec.get(1).unwrap() == 10);
+    /// assert(vec.get(2).unwrap() == 5);
+    /// ```
+    pub fn sort_by(ref mut self, less: fn(T, T) -> bool) {
+        let mut i = 1;
+        while i < self.len {
+            let mut j = i;
+            while j > 0 && less(self.get(j).unwrap(), self.get(j - 1).unwrap()) {
+                self.swap(j, j - 1);
+                j -= 1;
+            }
+            i += 1;
+        }
+    }
+}
+
+/// An iterator over the elements of a `Vec<T>`, created by `Vec::iter`.
+pub struct VecIter<T> {
+    vec: Vec<T>,
+    index: u64,
+}
+
+impl<T> Iterator<T> for VecIter<T> {
+    fn next(ref mut self) -> Option<T> {
+        let value = self.vec.get(self.index);
+        self.index += 1;
+        value
+    }
+}
+
 impl<T> AsRawSlice for Vec<T> {
     /// Returns a raw slice to all of the elements in the vector.
     fn as_raw_slice(self) -> raw_slice {
@@ -0,0 +1,513 @@
+use crate::core::{
+    session::Session,
+    token::{desugared_op, get_range_from_span},
+};
+use std::sync::Arc;
+use sway_core::{
+    declaration_engine::{DeclarationEngine, DeclarationId},
+    language::ty,
+    Engines, TypeInfo,
+};
+use sway_types::{Ident, Span, Spanned};
+use tower_lsp::lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Position, Range,
+    SymbolKind, Url,
+};
+
+/// A function, method or ABI method that can appear in the call hierarchy.
+#[derive(Debug, Clone)]
+struct Callable {
+    name: Ident,
+    /// The span of the whole declaration.
+    span: Span,
+    kind: SymbolKind,
+    /// The ABI, trait or type that the callable belongs to, if any.
+    detail: Option<String>,
+    /// The function selector of contract entry points and ABI methods. Contract calls
+    /// made through an ABI cast are matched to their implementations with this.
+    selector: Option<[u8; 4]>,
+}
+
+impl Callable {
+    /// `is_entry_point` is true for the methods of an ABI implementation for `Contract`, whose
+    /// declarations are not marked as contract calls.
+    fn from_fn_decl(
+        engines: Engines<'_>,
+        fn_decl: &ty::TyFunctionDeclaration,
+        detail: Option<String>,
+        is_entry_point: bool,
+    ) -> Self {
+        let kind = if fn_decl.is_contract_call || fn_decl.implementing_type.is_some() {
+            SymbolKind::METHOD
+        } else {
+            SymbolKind::FUNCTION
+        };
+        let selector = if fn_decl.is_contract_call || is_entry_point {
+            fn_decl.to_fn_selector_value(engines.te()).value
+        } else {
+            None
+        };
+        Self {
+            name: fn_decl.name.clone(),
+            span: fn_decl.span.clone(),
+            kind,
+            detail,
+            selector,
+        }
+    }
+
+    fn is_declared_at(&self, span: &Span) -> bool {
+        self.name.span() == *span
+    }
+
+    fn to_item(&self, session: &Session) -> Option<CallHierarchyItem> {
+        let url = span_to_workspace_url(session, &self.name.span())?;
+        Some(CallHierarchyItem {
+            name: self.name.as_str().to_string(),
+            kind: self.kind,
+            tags: None,
+            detail: self.detail.clone(),
+            uri: url,
+            range: get_range_from_span(&self.span),
+            selection_range: get_range_from_span(&self.name.span()),
+            data: None,
+        })
+    }
+}
+
+/// A single call made from the body of a [Callable].
+#[derive(Debug, Clone)]
+struct Call {
+    callee: Callable,
+    /// The span of the callee's name at the call site.
+    site: Span,
+    /// True if the call goes through an ABI cast to another contract.
+    is_contract_call: bool,
+}
+
+/// All of the callables in the typed program along with the calls each of them makes.
+#[derive(Debug, Default)]
+struct CallGraph {
+    nodes: Vec<(Callable, Vec<Call>)>,
+}
+
+impl CallGraph {
+    fn new(engines: Engines<'_>, typed_program: &ty::TyProgram) -> Self {
+        let mut graph = CallGraph::default();
+        let modules = std::iter::once(&typed_program.root).chain(
+            typed_program
+                .root
+                .submodules_recursive()
+                .map(|(_, submodule)| &submodule.module),
+        );
+        for module in modules {
+            for node in &module.all_nodes {
+                if let ty::TyAstNodeContent::Declaration(decl) = &node.content {
+                    graph.collect_declaration(engines, decl);
+                }
+            }
+        }
+        graph
+    }
+
+    fn collect_declaration(&mut self, engines: Engines<'_>, declaration: &ty::TyDeclaration) {
+        let declaration_engine = engines.de();
+        match declaration {
+            ty::TyDeclaration::FunctionDeclaration(decl_id) => {
+                self.collect_fn(engines, decl_id, None, false);
+            }
+            ty::TyDeclaration::ImplTrait(decl_id) => {
+                if let Ok(impl_trait) =
+                    declaration_engine.get_impl_trait(decl_id.clone(), &decl_id.span())
+                {
+                    let detail = engines
+                        .help_out(impl_trait.implementing_for_type_id)
+                        .to_string();
+                    let is_contract = matches!(
+                        engines
+                            .te()
+                            .look_up_type_id(impl_trait.implementing_for_type_id),
+                        TypeInfo::Contract
+                    );
+                    for method_id in &impl_trait.methods {
+                        self.collect_fn(engines, method_id, Some(detail.clone()), is_contract);
+                    }
+                }
+            }
+            ty::TyDeclaration::TraitDeclaration(decl_id) => {
+                if let Ok(trait_decl) =
+                    declaration_engine.get_trait(decl_id.clone(), &decl_id.span())
+                {
+                    for method_id in &trait_decl.methods {
+                        self.collect_fn(
+                            engines,
+                            method_id,
+                            Some(trait_decl.name.to_string()),
+                            false,
+                        );
+                    }
+                }
+            }
+            ty::TyDeclaration::AbiDeclaration(decl_id) => {
+                if let Ok(abi_decl) = declaration_engine.get_abi(decl_id.clone(), &decl_id.span()) {
+                    let detail = Some(format!("abi {}", abi_decl.name));
+                    for trait_fn_id in &abi_decl.interface_surface {
+                        if let Ok(trait_fn) = declaration_engine
+                            .get_trait_fn(trait_fn_id.clone(), &trait_fn_id.span())
+                        {
+                            let callable = Callable {
+                                span: trait_fn.name.span(),
+                                name: trait_fn.name,
+                                kind: SymbolKind::METHOD,
+                                detail: detail.clone(),
+                                selector: None,
+                            };
+                            self.nodes.push((callable, vec![]));
+                        }
+                    }
+                    for method_id in &abi_decl.methods {
+                        self.collect_fn(engines, method_id, detail.clone(), false);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_fn(
+        &mut self,
+        engines: Engines<'_>,
+        decl_id: &DeclarationId,
+        detail: Option<String>,
+        is_entry_point: bool,
+    ) {
+        if let Ok(fn_decl) = engines.de().get_function(decl_id.clone(), &decl_id.span()) {
+            let callable = Callable::from_fn_decl(engines, &fn_decl, detail, is_entry_point);
+            let mut calls = vec![];
+            collect_calls_in_code_block(engines, &fn_decl.body, &mut calls);
+            self.nodes.push((callable, calls));
+        }
+    }
+
+    /// Iterate over the declared callables of the program followed by the callees of every call.
+    fn callables(&self) -> impl '_ + Iterator<Item = &Callable> {
+        self.nodes.iter().map(|(callable, _)| callable).chain(
+            self.nodes
+                .iter()
+                .flat_map(|(_, calls)| calls.iter().map(|call| &call.callee)),
+        )
+    }
+
+    /// Find the callable whose name is declared at the given [Span].
+    fn callable_declared_at(&self, span: &Span) -> Option<Callable> {
+        self.callables()
+            .find(|callable| callable.is_declared_at(span))
+            .cloned()
+    }
+
+    /// Find the callable whose name is declared at the given [Range] of the file at [Url].
+    fn callable_at_range(&self, url: &Url, range: &Range) -> Option<Callable> {
+        self.callables()
+            .find(|callable| {
+                let span = callable.name.span();
+                span.path().map(|path| path.to_str()) == Some(Some(url.path()))
+                    && get_range_from_span(&span) == *range
+            })
+            .cloned()
+    }
+
+    fn outgoing_calls(&self, callable: &Callable) -> Vec<Call> {
+        self.nodes
+            .iter()
+            .filter(|(caller, _)| caller.is_declared_at(&callable.name.span()))
+            .flat_map(|(_, calls)| calls.iter().cloned())
+            .collect()
+    }
+
+    /// Returns each caller of `callable` along with the calls it makes to it.
+    ///
+    /// Contract entry points are also called by contract calls through an ABI cast whose
+    /// function selector matches, so these are included as well.
+    fn incoming_calls(&self, callable: &Callable) -> Vec<(Callable, Vec<Call>)> {
+        self.nodes
+            .iter()
+            .filter_map(|(caller, calls)| {
+                let calls: Vec<Call> = calls
+                    .iter()
+                    .filter(|call| {
+                        call.callee.is_declared_at(&callable.name.span())
+                            || (call.is_contract_call
+                                && call.callee.selector.is_some()
+                                && call.callee.selector == callable.selector)
+                    })
+                    .cloned()
+                    .collect();
+                (!calls.is_empty()).then(|| (caller.clone(), calls))
+            })
+            .collect()
+    }
+}
+
+pub fn prepare_call_hierarchy(
+    session: Arc<Session>,
+    url: Url,
+    position: Position,
+) -> Option<Vec<CallHierarchyItem>> {
+    let (_, token) = session.token_map().token_at_position(&url, position)?;
+    let decl_ident = token.declared_token_ident(&session.type_engine.read())?;
+    let graph = call_graph(&session)?;
+    let callable = graph.callable_declared_at(&decl_ident.span())?;
+    callable.to_item(&session).map(|item| vec![item])
+}
+
+pub fn incoming_calls(
+    session: Arc<Session>,
+    item: CallHierarchyItem,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let graph = call_graph(&session)?;
+    let url = workspace_to_temp_url(&session, &item.uri);
+    let callable = graph.callable_at_range(&url, &item.selection_range)?;
+
+    let mut incoming = vec![];
+    for (caller, calls) in graph.incoming_calls(&callable) {
+        // Contract calls are reported as separate edges from direct calls.
+        for is_contract_call in [false, true] {
+            let from_ranges: Vec<Range> = calls
+                .iter()
+                .filter(|call| call.is_contract_call == is_contract_call)
+                .map(|call| get_range_from_span(&call.site))
+                .collect();
+            if from_ranges.is_empty() {
+                continue;
+            }
+            if let Some(from) = caller.to_item(&session) {
+                incoming.push(CallHierarchyIncomingCall { from, from_ranges });
+            }
+        }
+    }
+    Some(incoming)
+}
+
+pub fn outgoing_calls(
+    session: Arc<Session>,
+    item: CallHierarchyItem,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let graph = call_graph(&session)?;
+    let url = workspace_to_temp_url(&session, &item.uri);
+    let callable = graph.callable_at_range(&url, &item.selection_range)?;
+
+    // Group the call sites by callee, keeping contract calls as separate edges.
+    let mut outgoing: Vec<(Callable, bool, Vec<Range>)> = vec![];
+    for call in graph.outgoing_calls(&callable) {
+        let range = get_range_from_span(&call.site);
+        match outgoing.iter_mut().find(|(callee, is_contract_call, _)| {
+            callee.is_declared_at(&call.callee.name.span())
+                && *is_contract_call == call.is_contract_call
+        }) {
+            Some((_, _, from_ranges)) => from_ranges.push(range),
+            None => {
+                // Prefer the declared callable, which knows the ABI, trait or type it belongs to.
+                let callee = graph
+                    .callable_declared_at(&call.callee.name.span())
+                    .unwrap_or(call.callee);
+                outgoing.push((callee, call.is_contract_call, vec![range]))
+            }
+        }
+    }
+
+    Some(
+        outgoing
+            .into_iter()
+            .filter_map(|(callee, is_contract_call, from_ranges)| {
+                let mut to = callee.to_item(&session)?;
+                if is_contract_call {
+                    to.detail = Some(match to.detail {
+                        Some(detail) => format!("{} (contract call)", detail),
+                        None => "contract call".to_string(),
+                    });
+                }
+                Some(CallHierarchyOutgoingCall { to, from_ranges })
+            })
+            .collect(),
+    )
+}
+
+/// Build the [CallGraph] of the typed program stored in the session.
+fn call_graph(session: &Session) -> Option<CallGraph> {
+    let program = session.compiled_program.read();
+    let typed_program = program.typed.as_ref()?;
+    let type_engine = session.type_engine.read();
+    let declaration_engine = session.declaration_engine.read();
+    let engines = Engines::new(&type_engine, &declaration_engine);
+    Some(CallGraph::new(engines, typed_program))
+}
+
+/// Convert the [Url] of a workspace file into the [Url] of its copy in the temp folder.
+/// Dependency paths are returned unchanged.
+fn workspace_to_temp_url(session: &Session, url: &Url) -> Url {
+    session
+        .sync
+        .workspace_to_temp_url(url)
+        .unwrap_or_else(|_| url.clone())
+}
+
+fn span_to_workspace_url(session: &Session, span: &Span) -> Option<Url> {
+    let path = span.path()?;
+    let url = Url::from_file_path(path.as_ref()).ok()?;
+    session.sync.to_workspace_url(url)
+}
+
+fn collect_calls_in_code_block(
+    engines: Engines<'_>,
+    code_block: &ty::TyCodeBlock,
+    calls: &mut Vec<Call>,
+) {
+    for node in &code_block.contents {
+        match &node.content {
+            ty::TyAstNodeContent::Declaration(ty::TyDeclaration::VariableDeclaration(variable)) => {
+                collect_calls_in_expression(engines, &variable.body, calls)
+            }
+            ty::TyAstNodeContent::Declaration(ty::TyDeclaration::ConstantDeclaration(decl_id)) => {
+                if let Ok(const_decl) = engines.de().get_constant(decl_id.clone(), &decl_id.span())
+                {
+                    if let Some(value) = &const_decl.value {
+                        collect_calls_in_expression(engines, value, calls);
+                    }
+                }
+            }
+            ty::TyAstNodeContent::Expression(expression)
+            | ty::TyAstNodeContent::ImplicitReturnExpression(expression) => {
+                collect_calls_in_expression(engines, expression, calls)
+            }
+            ty::TyAstNodeContent::Declaration(_) | ty::TyAstNodeContent::SideEffect => {}
+        }
+    }
+}
+
+fn collect_calls_in_expression(
+    engines: Engines<'_>,
+    expression: &ty::TyExpression,
+    calls: &mut Vec<Call>,
+) {
+    let declaration_engine: &DeclarationEngine = engines.de();
+    match &expression.expression {
+        ty::TyExpressionVariant::FunctionApplication {
+            call_path,
+            contract_call_params,
+            arguments,
+            function_decl_id,
+            selector,
+            ..
+        } => {
+            if !desugared_op(&call_path.prefixes) {
+                if let Ok(fn_decl) =
+                    declaration_engine.get_function(function_decl_id.clone(), &call_path.span())
+                {
+                    calls.push(Call {
+                        callee: Callable::from_fn_decl(engines, &fn_decl, None, false),
+                        site: call_path.suffix.span(),
+                        is_contract_call: selector.is_some(),
+                    });
+                }
+            }
+            for exp in contract_call_params.values() {
+                collect_calls_in_expression(engines, exp, calls);
+            }
+            for (_, exp) in arguments {
+                collect_calls_in_expression(engines, exp, calls);
+            }
+        }
+        ty::TyExpressionVariant::LazyOperator { lhs, rhs, .. } => {
+            collect_calls_in_expression(engines, lhs, calls);
+            collect_calls_in_expression(engines, rhs, calls);
+        }
+        ty::TyExpressionVariant::Tuple { fields }
+        | ty::TyExpressionVariant::Closure {
+            captures: fields, ..
+        } => {
+            for exp in fields {
+                collect_calls_in_expression(engines, exp, calls);
+            }
+        }
+        ty::TyExpressionVariant::ClosureCall { closure, arguments } => {
+            collect_calls_in_expression(engines, closure, calls);
+            for (_, exp) in arguments {
+                collect_calls_in_expression(engines, exp, calls);
+            }
+        }
+        ty::TyExpressionVariant::Array { contents } => {
+            for exp in contents {
+                collect_calls_in_expression(engines, exp, calls);
+            }
+        }
+        ty::TyExpressionVariant::ArrayIndex { prefix, index } => {
+            collect_calls_in_expression(engines, prefix, calls);
+            collect_calls_in_expression(engines, index, calls);
+        }
+        ty::TyExpressionVariant::StructExpression { fields, base, .. } => {
+            for field in fields {
+                collect_calls_in_expression(engines, &field.value, calls);
+            }
+            if let Some(base) = base {
+                collect_calls_in_expression(engines, base, calls);
+            }
+        }
+        ty::TyExpressionVariant::CodeBlock(code_block) => {
+            collect_calls_in_code_block(engines, code_block, calls);
+        }
+        ty::TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            collect_calls_in_expression(engines, condition, calls);
+            collect_calls_in_expression(engines, then, calls);
+            if let Some(r#else) = r#else {
+                collect_calls_in_expression(engines, r#else, calls);
+            }
+        }
+        ty::TyExpressionVariant::StructFieldAccess { prefix, .. }
+        | ty::TyExpressionVariant::TupleElemAccess { prefix, .. } => {
+            collect_calls_in_expression(engines, prefix, calls);
+        }
+        ty::TyExpressionVariant::EnumInstantiation { contents, .. } => {
+            if let Some(contents) = contents.as_deref() {
+                collect_calls_in_expression(engines, contents, calls);
+            }
+        }
+        ty::TyExpressionVariant::AbiCast { address, .. } => {
+            collect_calls_in_expression(engines, address, calls);
+        }
+        ty::TyExpressionVariant::IntrinsicFunction(kind) => {
+            for arg in &kind.arguments {
+                collect_calls_in_expression(engines, arg, calls);
+            }
+        }
+        ty::TyExpressionVariant::EnumTag { exp }
+        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+        | ty::TyExpressionVariant::Ref(exp)
+        | ty::TyExpressionVariant::Deref(exp)
+        | ty::TyExpressionVariant::Return(exp) => {
+            collect_calls_in_expression(engines, exp, calls);
+        }
+        ty::TyExpressionVariant::WhileLoop { condition, body } => {
+            collect_calls_in_expression(engines, condition, calls);
+            collect_calls_in_code_block(engines, body, calls);
+        }
+        ty::TyExpressionVariant::Reassignment(reassignment) => {
+            collect_calls_in_expression(engines, &reassignment.rhs, calls);
+        }
+        ty::TyExpressionVariant::StorageReassignment(storage_reassignment) => {
+            collect_calls_in_expression(engines, &storage_reassignment.rhs, calls);
+        }
+        ty::TyExpressionVariant::Literal(_)
+        | ty::TyExpressionVariant::VariableExpression { .. }
+        | ty::TyExpressionVariant::AssociatedConstant { .. }
+        | ty::TyExpressionVariant::FunctionParameter
+        | ty::TyExpressionVariant::AsmExpression { .. }
+        | ty::TyExpressionVariant::StorageAccess(_)
+        | ty::TyExpressionVariant::AbiName(_)
+        | ty::TyExpressionVariant::Break
+        | ty::TyExpressionVariant::Continue => {}
+    }
+}
@@ -1,50 +1,211 @@
 pub mod abi_impl;
+pub mod auto_import;
+pub mod match_arms;
+pub mod struct_constructor;
+pub mod trait_impl;
 
-use crate::core::{session::Session, token::TypedAstToken};
 pub use crate::error::DocumentError;
+use crate::{
+    capabilities::diagnostic::get_range,
+    core::{session::Session, token::TypedAstToken},
+};
 use abi_impl::abi_impl_code_action;
-use std::sync::Arc;
+use auto_import::import_code_actions;
+use match_arms::missing_match_arms_code_action;
+use std::{collections::HashMap, path::Path, sync::Arc};
+use struct_constructor::struct_constructor_code_action;
 use sway_core::{language::ty::TyDeclaration, Engines};
+use sway_error::error::CompileError;
 use sway_types::Spanned;
-use tower_lsp::lsp_types::{CodeActionResponse, Range, TextDocumentIdentifier, Url};
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionResponse, Diagnostic, Position,
+    Range, TextDocumentIdentifier, TextEdit, Url, WorkspaceEdit,
+};
+use trait_impl::missing_methods_code_action;
+
+pub(crate) const TAB: &str = "    ";
 
 pub(crate) fn code_actions(
     session: Arc<Session>,
     range: &Range,
     text_document: TextDocumentIdentifier,
     temp_uri: &Url,
+    diagnostics: &[Diagnostic],
 ) -> Option<CodeActionResponse> {
-    let (_, token) = session
+    let mut actions = session
         .token_map()
-        .token_at_position(temp_uri, range.start)?;
-    token.typed.and_then(|typed_token| {
-        let maybe_decl = match typed_token {
-            TypedAstToken::TypedDeclaration(decl) => Some(decl),
+        .token_at_position(temp_uri, range.start)
+        .and_then(|(_, token)| token.typed)
+        .and_then(|typed_token| match typed_token {
+            TypedAstToken::TypedDeclaration(decl) => {
+                declaration_code_action(&session, decl, &text_document.uri)
+            }
             _ => None,
-        };
-
-        maybe_decl
-            .and_then(|decl| match decl {
-                TyDeclaration::AbiDeclaration(ref decl_id) => Some(
-                    session
-                        .declaration_engine
-                        .read()
-                        .get_abi(decl_id.clone(), &decl_id.span()),
-                ),
-                // Add code actions for other declaration types here
-                _ => None,
-            })
-            .and_then(|result| {
-                result.ok().map(|abi_decl| {
-                    vec![abi_impl_code_action(
-                        Engines::new(
-                            &session.type_engine.read(),
-                            &session.declaration_engine.read(),
-                        ),
-                        abi_decl,
-                        text_document.uri,
-                    )]
-                })
-            })
+        })
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    // Quick fixes for the diagnostics that the client sent along with the request.
+    if let Ok(src) = session.document_text(temp_uri) {
+        for diagnostic in diagnostics {
+            actions.extend(diagnostic_code_actions(
+                &session,
+                diagnostic,
+                &src,
+                temp_uri,
+                &text_document.uri,
+            ));
+        }
+    }
+
+    (!actions.is_empty()).then_some(actions)
+}
+
+fn declaration_code_action(
+    session: &Session,
+    decl: TyDeclaration,
+    uri: &Url,
+) -> Option<CodeActionOrCommand> {
+    let type_engine = session.type_engine.read();
+    let declaration_engine = session.declaration_engine.read();
+    match decl {
+        TyDeclaration::AbiDeclaration(ref decl_id) => declaration_engine
+            .get_abi(decl_id.clone(), &decl_id.span())
+            .ok()
+            .map(|abi_decl| {
+                abi_impl_code_action(
+                    Engines::new(&type_engine, &declaration_engine),
+                    abi_decl,
+                    uri.clone(),
+                )
+            }),
+        TyDeclaration::StructDeclaration(ref decl_id) => declaration_engine
+            .get_struct(decl_id.clone(), &decl_id.span())
+            .ok()
+            .map(|struct_decl| struct_constructor_code_action(struct_decl, uri.clone())),
+        // Add code actions for other declaration types here
+        _ => None,
+    }
+}
+
+fn diagnostic_code_actions(
+    session: &Session,
+    diagnostic: &Diagnostic,
+    src: &Arc<str>,
+    temp_uri: &Url,
+    uri: &Url,
+) -> Vec<CodeActionOrCommand> {
+    let error = match compile_error(session, diagnostic, temp_uri) {
+        Some(error) => error,
+        None => return vec![],
+    };
+    match error {
+        CompileError::MissingInterfaceSurfaceMethods {
+            missing_functions,
+            interface_name,
+            ..
+        } => missing_methods_code_action(
+            session,
+            diagnostic,
+            &missing_functions,
+            &interface_name,
+            src,
+            uri,
+        )
+        .into_iter()
+        .collect(),
+        CompileError::MatchExpressionNonExhaustive {
+            missing_pattern_sources,
+            ..
+        } => vec![missing_match_arms_code_action(
+            diagnostic,
+            missing_pattern_sources.as_deref(),
+            src,
+            uri,
+        )],
+        CompileError::SymbolNotFound { name }
+        | CompileError::UnknownVariable { var_name: name }
+        | CompileError::UnknownVariablePath { var_name: name, .. } => {
+            import_code_actions(session, diagnostic, name.as_str(), src, uri)
+        }
+        CompileError::UnknownTypeName { name, .. } => {
+            import_code_actions(session, diagnostic, &name, src, uri)
+        }
+        _ => vec![],
+    }
+}
+
+/// Find the error of the last parse that `diagnostic` was produced from.
+fn compile_error(
+    session: &Session,
+    diagnostic: &Diagnostic,
+    temp_uri: &Url,
+) -> Option<CompileError> {
+    session.compile_errors().into_iter().find(|error| {
+        let span = error.span();
+        span.path().map(|path| path.as_path()) == Some(Path::new(temp_uri.path()))
+            && get_range(span.line_col()) == diagnostic.range
+            && error.to_string() == diagnostic.message
     })
 }
+
+/// Build a quick fix for `diagnostic` that applies `text_edit` to the document at `uri`.
+pub(crate) fn quick_fix(
+    title: String,
+    diagnostic: &Diagnostic,
+    uri: &Url,
+    text_edit: TextEdit,
+) -> CodeActionOrCommand {
+    let mut text_edit_map = HashMap::new();
+    text_edit_map.insert(uri.clone(), vec![text_edit]);
+
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(text_edit_map),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+/// Build a [TextEdit] that inserts `lines` just before the closing brace that ends at `brace_end`.
+///
+/// Each line is indented one level deeper than the line that holds the closing brace.
+pub(crate) fn insert_before_closing_brace(
+    src: &str,
+    brace_end: Position,
+    lines: &[String],
+) -> TextEdit {
+    let brace_line = src.lines().nth(brace_end.line as usize).unwrap_or_default();
+    let brace_character = brace_end.character.saturating_sub(1);
+    let before_brace: String = brace_line.chars().take(brace_character as usize).collect();
+    let indent: String = brace_line
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    let new_lines: String = lines
+        .iter()
+        .map(|line| match line.is_empty() {
+            true => String::from("\n"),
+            false => format!("{}{}{}\n", indent, TAB, line),
+        })
+        .collect();
+
+    // If the closing brace is on its own line, the new lines go directly above it.
+    // Otherwise the closing brace is moved onto a line of its own.
+    let (position, new_text) = if before_brace.trim().is_empty() {
+        (Position::new(brace_end.line, 0), new_lines)
+    } else {
+        (
+            Position::new(brace_end.line, brace_character),
+            format!("\n{}{}", new_lines, indent),
+        )
+    };
+    TextEdit {
+        range: Range::new(position, position),
+        new_text,
+    }
+}
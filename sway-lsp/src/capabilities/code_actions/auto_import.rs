@@ -0,0 +1,161 @@
+use crate::core::{
+    session::Session,
+    syntax_tree,
+    token::{get_range_from_span, AstToken},
+};
+use forc_pkg::manifest::PackageManifestFile;
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use sway_ast::ItemKind;
+use sway_core::language::{parsed::Declaration, Visibility};
+use sway_types::Spanned;
+use sway_utils::constants::SRC_DIR;
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, Position, Range, TextEdit, Url};
+
+use super::quick_fix;
+
+/// Offer to import each of the declarations in the [TokenMap](crate::core::token_map::TokenMap)
+/// that matches `name`, which `diagnostic` reports as unresolved.
+pub(crate) fn import_code_actions(
+    session: &Session,
+    diagnostic: &Diagnostic,
+    name: &str,
+    src: &Arc<str>,
+    uri: &Url,
+) -> Vec<CodeActionOrCommand> {
+    let current_path = match uri.to_file_path() {
+        Ok(path) => path,
+        Err(_) => return vec![],
+    };
+    let current_manifest = match PackageManifestFile::from_dir(&current_path) {
+        Ok(manifest) => manifest,
+        Err(_) => return vec![],
+    };
+
+    let call_paths: BTreeSet<String> = session
+        .token_map()
+        .iter()
+        .filter(|item| item.key().0.as_str() == name)
+        .filter(|item| is_public_declaration(&item.value().parsed))
+        .filter_map(|item| {
+            let path = workspace_path(session, item.key().1.path()?)?;
+            // Declarations in the current file don't need to be imported.
+            if path == current_path {
+                return None;
+            }
+            let module_path = module_path(&path, &current_manifest)?;
+            Some(format!("{}::{}", module_path, name))
+        })
+        .collect();
+
+    let insertion = import_insertion(src);
+    call_paths
+        .into_iter()
+        .map(|call_path| {
+            let (position, prefix) = insertion.clone();
+            let text_edit = TextEdit {
+                range: Range::new(position, position),
+                new_text: format!("{}use {};\n", prefix, call_path),
+            };
+            quick_fix(
+                format!("Import `{}`", call_path),
+                diagnostic,
+                uri,
+                text_edit,
+            )
+        })
+        .collect()
+}
+
+fn is_public_declaration(token: &AstToken) -> bool {
+    let visibility = match token {
+        AstToken::Declaration(Declaration::StructDeclaration(decl)) => &decl.visibility,
+        AstToken::Declaration(Declaration::EnumDeclaration(decl)) => &decl.visibility,
+        AstToken::Declaration(Declaration::FunctionDeclaration(decl)) => &decl.visibility,
+        AstToken::Declaration(Declaration::TraitDeclaration(decl)) => &decl.visibility,
+        AstToken::Declaration(Declaration::ConstantDeclaration(decl)) => &decl.visibility,
+        AstToken::Declaration(Declaration::TypeAliasDeclaration(decl)) => &decl.visibility,
+        AstToken::Declaration(Declaration::AbiDeclaration(_)) => return true,
+        _ => return false,
+    };
+    matches!(visibility, Visibility::Public)
+}
+
+/// Convert the path of a file in the temp dir of the session to the path of the file in the
+/// workspace. Paths outside of the workspace, e.g. those of dependencies, are returned unchanged.
+pub(crate) fn workspace_path(session: &Session, path: &Path) -> Option<PathBuf> {
+    let url = Url::from_file_path(path).ok()?;
+    session.sync.to_workspace_url(url)?.to_file_path().ok()
+}
+
+/// Get the path of the module that is declared by the file at `path`, as seen from the package
+/// of `current_manifest`.
+///
+/// Modules of the current package are referred to from the root of the package, while modules of
+/// any other package are prefixed with the name that the current package depends on it by.
+fn module_path(path: &Path, current_manifest: &PackageManifestFile) -> Option<String> {
+    let (manifest, module_segments) = package_module(path)?;
+
+    let mut segments = vec![];
+    if manifest.dir() != current_manifest.dir() {
+        let dep_name = current_manifest.deps().find_map(|(dep_name, dep)| {
+            (dep.package().unwrap_or(dep_name) == manifest.project.name).then(|| dep_name.clone())
+        })?;
+        segments.push(dep_name);
+    }
+    segments.extend(module_segments);
+
+    (!segments.is_empty()).then(|| segments.join("::"))
+}
+
+/// Get the manifest of the package that the file at `path` belongs to, along with the segments of
+/// the path of the module that the file declares, relative to the root of that package.
+pub(crate) fn package_module(path: &Path) -> Option<(PackageManifestFile, Vec<String>)> {
+    let manifest = PackageManifestFile::from_dir(path.parent()?).ok()?;
+    let relative_path = path
+        .strip_prefix(manifest.dir().join(SRC_DIR))
+        .ok()?
+        .with_extension("");
+
+    let mut segments: Vec<String> = relative_path
+        .iter()
+        .map(|segment| segment.to_string_lossy().to_string())
+        .collect();
+    // The root module of a package is declared by its entry point.
+    if relative_path.parent() == Some(Path::new("")) {
+        if let Some(file_name) = relative_path.file_name() {
+            if file_name == "lib" || file_name == "main" {
+                segments.pop();
+            }
+        }
+    }
+    Some((manifest, segments))
+}
+
+/// Find where a new `use` statement should be inserted, along with the text that should come
+/// before it. New imports are added after the last `use` statement, or after the module kind
+/// if the file doesn't have any yet.
+fn import_insertion(src: &Arc<str>) -> (Position, String) {
+    let module = match syntax_tree::parse(src.clone()) {
+        Some(module) => module,
+        None => return (Position::new(0, 0), String::new()),
+    };
+    let last_use = module
+        .items
+        .iter()
+        .rev()
+        .find(|item| matches!(item.value, ItemKind::Use(_)));
+    match last_use {
+        Some(item) => {
+            let range = get_range_from_span(&item.value.span());
+            (Position::new(range.end.line + 1, 0), String::new())
+        }
+        None => {
+            let range = get_range_from_span(&module.semicolon_token.span());
+            (Position::new(range.end.line + 1, 0), String::from("\n"))
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, Url};
+
+use super::{insert_before_closing_brace, quick_fix};
+
+const CODE_ACTION_DESCRIPTION: &str = "Add missing match arms";
+
+/// Offer to add an arm to a non-exhaustive match expression for each of the missing patterns.
+///
+/// `diagnostic` is expected to be the `MatchExpressionNonExhaustive` error, which spans the whole
+/// match expression. `missing_pattern_sources` holds the source of a pattern for each of the
+/// witnesses found by the usefulness analysis. If any witness can't be written as a pattern, e.g.
+/// a range of numbers, a single catch-all arm is added instead.
+pub(crate) fn missing_match_arms_code_action(
+    diagnostic: &Diagnostic,
+    missing_pattern_sources: Option<&[String]>,
+    src: &Arc<str>,
+    uri: &Url,
+) -> CodeActionOrCommand {
+    let catch_all = [String::from("_")];
+    let lines: Vec<String> = missing_pattern_sources
+        .unwrap_or(&catch_all)
+        .iter()
+        .map(|pattern| format!("{} => {{}},", pattern))
+        .collect();
+    let text_edit = insert_before_closing_brace(src, diagnostic.range.end, &lines);
+    quick_fix(
+        String::from(CODE_ACTION_DESCRIPTION),
+        diagnostic,
+        uri,
+        text_edit,
+    )
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+use sway_core::language::ty::{TyStructDeclaration, TyStructField};
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Position, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+use super::TAB;
+
+const CODE_ACTION_DESCRIPTION: &str = "Generate `new` constructor";
+
+pub(crate) fn struct_constructor_code_action(
+    struct_decl: TyStructDeclaration,
+    uri: Url,
+) -> CodeActionOrCommand {
+    let (last_line, _) = struct_decl.span.end_pos().line_col();
+    let insertion_position = Position {
+        line: last_line as u32,
+        character: 0,
+    };
+    let text_edit = TextEdit {
+        range: Range {
+            start: insertion_position,
+            end: insertion_position,
+        },
+        new_text: get_constructor_impl_string(&struct_decl),
+    };
+    let mut text_edit_map = HashMap::new();
+    text_edit_map.insert(uri.clone(), vec![text_edit]);
+
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: String::from(CODE_ACTION_DESCRIPTION),
+        kind: Some(CodeActionKind::REFACTOR),
+        edit: Some(WorkspaceEdit {
+            changes: Some(text_edit_map),
+            ..Default::default()
+        }),
+        data: Some(Value::String(uri.to_string())),
+        ..Default::default()
+    })
+}
+
+fn get_param_string(field: &TyStructField) -> String {
+    format!("{}: {}", field.name, field.type_span.as_str())
+}
+
+fn get_constructor_impl_string(struct_decl: &TyStructDeclaration) -> String {
+    let type_params = match struct_decl.type_parameters.is_empty() {
+        true => String::new(),
+        false => format!(
+            "<{}>",
+            struct_decl
+                .type_parameters
+                .iter()
+                .map(|type_param| type_param.name_ident.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ")
+        ),
+    };
+    let param_string = struct_decl
+        .fields
+        .iter()
+        .map(get_param_string)
+        .collect::<Vec<String>>()
+        .join(", ");
+    let field_string = struct_decl
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<&str>>()
+        .join(", ");
+    let struct_expr = match field_string.is_empty() {
+        true => format!("{} {{}}", struct_decl.name),
+        false => format!("{} {{ {} }}", struct_decl.name, field_string),
+    };
+    format!(
+        "\nimpl{} {}{} {{\n{}fn new({}) -> Self {{\n{}{}{}\n{}}}\n}}\n",
+        type_params, struct_decl.name, type_params, TAB, param_string, TAB, TAB, struct_expr, TAB,
+    )
+}
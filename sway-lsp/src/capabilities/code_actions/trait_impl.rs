@@ -0,0 +1,143 @@
+use crate::core::{
+    session::Session,
+    syntax_tree,
+    token::{get_range_from_span, AstToken},
+};
+use std::sync::Arc;
+use sway_ast::ItemKind;
+use sway_core::{
+    language::parsed::{Declaration, FunctionParameter, TraitFn},
+    transform::AttributeKind,
+};
+use sway_error::error::InterfaceName;
+use sway_types::{Ident, Spanned};
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, Url};
+
+use super::{
+    auto_import::{package_module, workspace_path},
+    insert_before_closing_brace, quick_fix,
+};
+
+const CODE_ACTION_DESCRIPTION: &str = "Implement missing methods";
+
+/// Offer to stub out the functions of the trait or ABI that an `impl` block is missing.
+///
+/// `diagnostic` is expected to be the `MissingInterfaceSurfaceMethods` error, which spans the
+/// whole `impl` block. `missing_functions` lists the names of the missing functions on separate
+/// lines, and `interface_name` is the name of the trait or ABI as it is declared.
+pub(crate) fn missing_methods_code_action(
+    session: &Session,
+    diagnostic: &Diagnostic,
+    missing_functions: &str,
+    interface_name: &InterfaceName,
+    src: &Arc<str>,
+    uri: &Url,
+) -> Option<CodeActionOrCommand> {
+    let missing_functions: Vec<&str> = missing_functions.lines().collect();
+
+    let module = syntax_tree::parse(src.clone())?;
+    let item_impl = module.items.iter().find_map(|item| match &item.value {
+        ItemKind::Impl(item_impl) if get_range_from_span(&item_impl.span()) == diagnostic.range => {
+            Some(item_impl)
+        }
+        _ => None,
+    })?;
+    let interface_ident = match interface_name {
+        InterfaceName::Abi(ident) | InterfaceName::Trait(ident) => ident,
+    };
+    let call_path = declaration_call_path(session, interface_ident)?;
+
+    let interface_surface = trait_interface_surface(session, &call_path)?;
+    let mut lines = vec![];
+    if !item_impl.contents.get().is_empty() {
+        lines.push(String::new());
+    }
+    for (i, trait_fn) in interface_surface
+        .iter()
+        .filter(|trait_fn| missing_functions.contains(&trait_fn.name.as_str()))
+        .enumerate()
+    {
+        if i > 0 {
+            lines.push(String::new());
+        }
+        lines.extend(get_function_lines(trait_fn));
+    }
+
+    let text_edit = insert_before_closing_brace(src, diagnostic.range.end, &lines);
+    Some(quick_fix(
+        String::from(CODE_ACTION_DESCRIPTION),
+        diagnostic,
+        uri,
+        text_edit,
+    ))
+}
+
+/// Find the functions that the trait or ABI at `call_path` requires an implementation to provide.
+fn trait_interface_surface(session: &Session, call_path: &str) -> Option<Vec<TraitFn>> {
+    let is_declared_at = |name: &Ident| {
+        call_path.ends_with(name.as_str())
+            && declaration_call_path(session, name).as_deref() == Some(call_path)
+    };
+    session
+        .token_map()
+        .iter()
+        .find_map(|item| match &item.value().parsed {
+            AstToken::Declaration(Declaration::TraitDeclaration(decl))
+                if is_declared_at(&decl.name) =>
+            {
+                Some(decl.interface_surface.clone())
+            }
+            AstToken::Declaration(Declaration::AbiDeclaration(decl))
+                if is_declared_at(&decl.name) =>
+            {
+                Some(decl.interface_surface.clone())
+            }
+            _ => None,
+        })
+}
+
+/// Get the full call path of the declaration named `name`, starting with the name of the package
+/// that it is declared in.
+fn declaration_call_path(session: &Session, name: &Ident) -> Option<String> {
+    let path = workspace_path(session, name.span().path()?)?;
+    let (manifest, mut segments) = package_module(&path)?;
+    segments.insert(0, manifest.project.name.clone());
+    segments.push(name.as_str().to_string());
+    Some(segments.join("::"))
+}
+
+fn get_param_string(param: &FunctionParameter) -> String {
+    if param.name.as_str() == "self" {
+        let reference = if param.is_reference { "ref " } else { "" };
+        let mutable = if param.is_mutable { "mut " } else { "" };
+        return format!("{}{}self", reference, mutable);
+    }
+    format!("{}: {}", param.name, param.type_span.as_str())
+}
+
+fn get_function_lines(trait_fn: &TraitFn) -> Vec<String> {
+    let mut lines: Vec<String> = trait_fn
+        .attributes
+        .get(&AttributeKind::Storage)
+        .into_iter()
+        .flatten()
+        .map(|attr| attr.span.as_str().to_string())
+        .collect();
+    let param_string = trait_fn
+        .parameters
+        .iter()
+        .map(get_param_string)
+        .collect::<Vec<String>>()
+        .join(", ");
+    // Unit is the implicit return type for trait functions.
+    let return_type_string = if trait_fn.return_type.is_unit() {
+        String::new()
+    } else {
+        format!(" -> {}", trait_fn.return_type_span.as_str())
+    };
+    lines.push(format!(
+        "fn {}({}){} {{}}",
+        trait_fn.name, param_string, return_type_string
+    ));
+    lines
+}
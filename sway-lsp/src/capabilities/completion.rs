@@ -35,7 +35,9 @@ pub fn completion_item_kind(symbol_kind: &SymbolKind) -> Option<CompletionItemKi
         SymbolKind::Module => Some(CompletionItemKind::MODULE),
         SymbolKind::Enum => Some(CompletionItemKind::ENUM),
         SymbolKind::Variant => Some(CompletionItemKind::ENUM_MEMBER),
-        SymbolKind::TypeParameter => Some(CompletionItemKind::TYPE_PARAMETER),
+        SymbolKind::TypeParameter | SymbolKind::TypeAlias => {
+            Some(CompletionItemKind::TYPE_PARAMETER)
+        }
         SymbolKind::BoolLiteral
         | SymbolKind::ByteLiteral
         | SymbolKind::StringLiteral
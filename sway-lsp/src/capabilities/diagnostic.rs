@@ -25,7 +25,7 @@ pub fn get_diagnostics(warnings: &[CompileWarning], errors: &[CompileError]) ->
     all
 }
 
-fn get_range((start, end): (LineCol, LineCol)) -> Range {
+pub(crate) fn get_range((start, end): (LineCol, LineCol)) -> Range {
     let pos = |lc: LineCol| Position::new(lc.line as u32 - 1, lc.col as u32 - 1);
     let start = pos(start);
     let end = pos(end);
@@ -0,0 +1,60 @@
+use crate::core::{
+    syntax_tree::{self, SyntaxKind, SyntaxNode},
+    token::get_range_from_span,
+};
+use std::sync::Arc;
+use sway_ast::Module;
+use tower_lsp::lsp_types::{FoldingRange, FoldingRangeKind, Range};
+
+/// Compute the folding ranges of a file from its syntax tree.
+///
+/// Items (including `impl`, `storage` and `abi` blocks and the functions within them) and
+/// match arms fold to their own range. Consecutive `use` statements and comments are folded
+/// together as a group.
+pub fn folding_ranges(src: Arc<str>, module: &Module) -> Vec<FoldingRange> {
+    let nodes = syntax_tree::syntax_nodes(module);
+
+    let mut ranges: Vec<FoldingRange> = nodes
+        .iter()
+        .filter(|node| matches!(node.kind, SyntaxKind::Item | SyntaxKind::MatchArm))
+        .filter_map(|node| folding_range(get_range_from_span(&node.span), None))
+        .collect();
+
+    let uses = nodes.iter().filter(|node| node.kind == SyntaxKind::Use);
+    ranges.extend(grouped_ranges(uses, FoldingRangeKind::Imports));
+
+    let comments = syntax_tree::comments(&src);
+    ranges.extend(grouped_ranges(comments.iter(), FoldingRangeKind::Comment));
+
+    ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    ranges
+}
+
+/// Merge the nodes that follow each other on consecutive lines into a single folding range.
+fn grouped_ranges<'a>(
+    nodes: impl Iterator<Item = &'a SyntaxNode>,
+    kind: FoldingRangeKind,
+) -> Vec<FoldingRange> {
+    let mut groups: Vec<Range> = vec![];
+    for range in nodes.map(|node| get_range_from_span(&node.span)) {
+        match groups.last_mut() {
+            Some(group) if range.start.line <= group.end.line + 1 => group.end = range.end,
+            _ => groups.push(range),
+        }
+    }
+    groups
+        .into_iter()
+        .filter_map(|range| folding_range(range, Some(kind.clone())))
+        .collect()
+}
+
+/// Only ranges that span more than a single line can be folded.
+fn folding_range(range: Range, kind: Option<FoldingRangeKind>) -> Option<FoldingRange> {
+    (range.end.line > range.start.line).then_some(FoldingRange {
+        start_line: range.start.line,
+        start_character: None,
+        end_line: range.end.line,
+        end_character: None,
+        kind,
+    })
+}
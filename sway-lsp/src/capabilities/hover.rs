@@ -64,7 +64,7 @@ fn format_doc_attributes(token: &Token) -> String {
         doc_comment = attributes
             .iter()
             .map(|attribute| {
-                let comment = attribute.args.first().unwrap().as_str();
+                let comment = attribute.args.first().unwrap().name.as_str();
                 format!("{}\n", comment)
             })
             .collect()
@@ -149,6 +149,20 @@ fn hover_format(engines: Engines<'_>, token: &Token, ident: &Ident) -> lsp_types
                         )
                     })
                     .ok(),
+                ty::TyDeclaration::TypeAliasDeclaration(decl_id) => declaration_engine
+                    .get_type_alias(decl_id.clone(), &decl.span())
+                    .map(|type_alias_decl| {
+                        format!(
+                            "{} = {}",
+                            format_visibility_hover(
+                                type_alias_decl.visibility,
+                                "type",
+                                type_alias_decl.name.as_str(),
+                            ),
+                            engines.help_out(type_alias_decl.ty.type_id)
+                        )
+                    })
+                    .ok(),
                 _ => None,
             },
             TypedAstToken::TypedFunctionDeclaration(func) => {
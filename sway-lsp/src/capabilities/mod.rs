@@ -1,13 +1,16 @@
+pub mod call_hierarchy;
 pub mod code_actions;
 pub mod completion;
 pub mod diagnostic;
 pub mod document_symbol;
+pub mod folding_range;
 pub mod formatting;
 pub mod highlight;
 pub mod hover;
 pub mod inlay_hints;
 pub mod rename;
 pub mod runnable;
+pub mod selection_range;
 pub mod semantic_tokens;
 
 pub(crate) use code_actions::code_actions;
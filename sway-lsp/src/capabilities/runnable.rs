@@ -1,13 +1,19 @@
+use crate::core::session::Session;
+use serde::{Deserialize, Serialize};
+use std::{path::PathBuf, sync::Arc};
 use sway_core::language::parsed::TreeType;
-use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::{CodeLens, Command, Position, Range, Url};
+
+/// The command that runs `forc test` for the project, optionally filtered to a single test.
+pub const RUN_TESTS_COMMAND: &str = "sway.runTests";
 
 #[derive(Debug, Eq, PartialEq, Hash)]
 pub enum RunnableType {
     /// This is the main_fn entry point for the predicate or script.
     MainFn,
-    /// Place holder for when we have in language testing supported.
-    /// The field holds the index of the test to run.
-    _TestFn(u8),
+    /// A function with the `#[test]` attribute.
+    /// The field holds the index of the test in the program.
+    TestFn(usize),
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -16,10 +22,94 @@ pub struct Runnable {
     pub range: Range,
     /// The program kind of the current file
     pub tree_type: TreeType,
+    /// The path of the file that declares the runnable
+    pub path: Option<Arc<PathBuf>>,
+    /// The name of the test function, if the runnable is a test
+    pub test_name: Option<String>,
 }
 
 impl Runnable {
-    pub fn new(range: Range, tree_type: TreeType) -> Self {
-        Self { range, tree_type }
+    pub fn new(range: Range, tree_type: TreeType, path: Option<Arc<PathBuf>>) -> Self {
+        Self {
+            range,
+            tree_type,
+            path,
+            test_name: None,
+        }
+    }
+
+    pub fn test(
+        range: Range,
+        tree_type: TreeType,
+        path: Option<Arc<PathBuf>>,
+        test_name: String,
+    ) -> Self {
+        Self {
+            range,
+            tree_type,
+            path,
+            test_name: Some(test_name),
+        }
+    }
+
+    fn is_in_file(&self, url: &Url) -> bool {
+        self.path.as_ref().and_then(|path| path.to_str()) == Some(url.path())
+    }
+}
+
+/// The arguments of the [RUN_TESTS_COMMAND].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunTestsArgs {
+    /// A file within the project whose tests should be run.
+    pub uri: Url,
+    /// The name of the single test to run. All tests are run if this is `None`.
+    pub test_name: Option<String>,
+}
+
+/// Create a code lens for every test in the file at `temp_url`, along with a lens at the
+/// top of the file to run all of the tests in the project.
+pub fn code_lens(session: Arc<Session>, temp_url: &Url, workspace_url: &Url) -> Vec<CodeLens> {
+    let mut lenses: Vec<CodeLens> = session
+        .runnables
+        .iter()
+        .filter_map(|item| {
+            let runnable = item.value();
+            let test_name = runnable.test_name.clone()?;
+            runnable.is_in_file(temp_url).then(|| CodeLens {
+                range: runnable.range,
+                command: Some(run_tests_command(
+                    "▶︎ Run Test",
+                    workspace_url,
+                    Some(test_name),
+                )),
+                data: None,
+            })
+        })
+        .collect();
+
+    if !lenses.is_empty() {
+        lenses.sort_by_key(|lens| lens.range.start);
+        lenses.insert(
+            0,
+            CodeLens {
+                range: Range::new(Position::new(0, 0), Position::new(0, 0)),
+                command: Some(run_tests_command("▶︎ Run All Tests", workspace_url, None)),
+                data: None,
+            },
+        );
+    }
+    lenses
+}
+
+fn run_tests_command(title: &str, uri: &Url, test_name: Option<String>) -> Command {
+    let args = RunTestsArgs {
+        uri: uri.clone(),
+        test_name,
+    };
+    Command {
+        title: title.to_string(),
+        command: RUN_TESTS_COMMAND.to_string(),
+        arguments: serde_json::to_value(args).ok().map(|args| vec![args]),
     }
 }
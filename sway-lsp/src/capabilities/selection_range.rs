@@ -0,0 +1,60 @@
+use crate::core::{syntax_tree, token::get_range_from_span};
+use std::sync::Arc;
+use sway_ast::Module;
+use sway_types::Span;
+use tower_lsp::lsp_types::{Position, Range, SelectionRange};
+
+/// Compute the selection range for each of the `positions` from the syntax tree of a file.
+///
+/// Each step outwards expands the selection to the next enclosing node, e.g. from an
+/// expression to its statement, then to the enclosing block and item, and finally the whole file.
+pub fn selection_ranges(
+    src: Arc<str>,
+    module: &Module,
+    positions: Vec<Position>,
+) -> Vec<SelectionRange> {
+    let mut ranges: Vec<(usize, Range)> = syntax_tree::syntax_nodes(module)
+        .into_iter()
+        .chain(syntax_tree::comments(&src))
+        .map(|node| {
+            (
+                node.span.end() - node.span.start(),
+                get_range_from_span(&node.span),
+            )
+        })
+        .collect();
+    // Sort from the outermost node inwards, so that each node follows the nodes that enclose it.
+    ranges.sort_by(|(len_a, _), (len_b, _)| len_b.cmp(len_a));
+
+    let file_range = Span::new(src.clone(), 0, src.len(), None)
+        .map(|span| get_range_from_span(&span))
+        .unwrap_or_default();
+
+    positions
+        .into_iter()
+        .map(|position| {
+            let mut selection_range = SelectionRange {
+                range: file_range,
+                parent: None,
+            };
+            for (_, range) in &ranges {
+                let current = selection_range.range;
+                if contains(range, position)
+                    && contains(&current, range.start)
+                    && contains(&current, range.end)
+                    && *range != current
+                {
+                    selection_range = SelectionRange {
+                        range: *range,
+                        parent: Some(Box::new(selection_range)),
+                    };
+                }
+            }
+            selection_range
+        })
+        .collect()
+}
+
+fn contains(range: &Range, position: Position) -> bool {
+    range.start <= position && position <= range.end
+}
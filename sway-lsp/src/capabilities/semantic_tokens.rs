@@ -156,6 +156,7 @@ fn semantic_token_type(kind: &SymbolKind) -> SemanticTokenType {
         SymbolKind::Variant => SemanticTokenType::ENUM_MEMBER,
         SymbolKind::Trait => SemanticTokenType::INTERFACE,
         SymbolKind::TypeParameter => SemanticTokenType::TYPE_PARAMETER,
+        SymbolKind::TypeAlias => SemanticTokenType::TYPE,
         SymbolKind::BoolLiteral => SemanticTokenType::new("boolean"),
         SymbolKind::ByteLiteral | SymbolKind::NumericLiteral => SemanticTokenType::NUMBER,
         SymbolKind::StringLiteral => SemanticTokenType::STRING,
@@ -1,5 +1,6 @@
 pub mod document;
 pub mod session;
 pub(crate) mod sync;
+pub mod syntax_tree;
 pub(crate) mod token;
 pub(crate) mod token_map;
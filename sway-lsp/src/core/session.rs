@@ -5,7 +5,7 @@ use crate::{
         runnable::{Runnable, RunnableType},
     },
     core::{
-        document::TextDocument, sync::SyncWorkspace, token::get_range_from_span,
+        document::TextDocument, sync::SyncWorkspace, syntax_tree, token::get_range_from_span,
         token_map::TokenMap,
     },
     error::{DocumentError, LanguageServerError},
@@ -15,7 +15,13 @@ use dashmap::DashMap;
 use forc_pkg::{self as pkg};
 use parking_lot::RwLock;
 use pkg::manifest::ManifestFile;
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use sway_ast::Module;
 use sway_core::{
     declaration_engine::DeclarationEngine,
     language::{
@@ -24,11 +30,12 @@ use sway_core::{
     },
     CompileResult, Engines, TypeEngine,
 };
+use sway_error::error::CompileError;
 use sway_types::Spanned;
-use sway_utils::helpers::get_sway_files;
+use sway_utils::{constants, helpers::get_sway_files};
 use tower_lsp::lsp_types::{
-    CompletionItem, Diagnostic, GotoDefinitionResponse, Location, Position, Range,
-    SymbolInformation, TextDocumentContentChangeEvent, TextEdit, Url,
+    CompletionItem, Diagnostic, FoldingRange, GotoDefinitionResponse, Location, Position, Range,
+    SelectionRange, SymbolInformation, TextDocumentContentChangeEvent, TextEdit, Url,
 };
 
 pub type Documents = DashMap<String, TextDocument>;
@@ -53,6 +60,15 @@ pub struct Session {
     pub type_engine: RwLock<TypeEngine>,
     pub declaration_engine: RwLock<DeclarationEngine>,
     pub sync: SyncWorkspace,
+    /// The typed namespaces of the dependencies that were checked by a previous parse.
+    check_cache: RwLock<pkg::CheckCache>,
+    /// A fingerprint of the project's source as of the last parse, along with the diagnostics
+    /// that it produced. This allows for skipping edits that can't change the program.
+    last_parse: RwLock<Option<(u64, Vec<Diagnostic>)>>,
+    /// The errors that the last parse produced for the project itself.
+    compile_errors: RwLock<Vec<CompileError>>,
+    /// The last syntax tree of each document that parsed successfully.
+    syntax_trees: DashMap<String, Arc<Module>>,
 }
 
 impl Session {
@@ -65,6 +81,10 @@ impl Session {
             type_engine: <_>::default(),
             declaration_engine: <_>::default(),
             sync: SyncWorkspace::new(),
+            check_cache: <_>::default(),
+            last_parse: <_>::default(),
+            compile_errors: <_>::default(),
+            syntax_trees: DashMap::new(),
         }
     }
 
@@ -73,6 +93,11 @@ impl Session {
 
         *self.declaration_engine.write() = <_>::default();
 
+        // The cached namespaces refer to the engines that were just reset.
+        self.check_cache.write().clear();
+        *self.last_parse.write() = None;
+        self.compile_errors.write().clear();
+
         let manifest_dir = PathBuf::from(uri.path());
         // Create a new temp dir that clones the current workspace
         // and store manifest and temp paths
@@ -104,11 +129,25 @@ impl Session {
         &self.token_map
     }
 
-    pub fn parse_project(&self, uri: &Url) -> Result<Vec<Diagnostic>, LanguageServerError> {
-        self.token_map.clear();
-        self.runnables.clear();
+    /// Return the errors that the last parse produced for the project itself.
+    pub fn compile_errors(&self) -> Vec<CompileError> {
+        self.compile_errors.read().clone()
+    }
 
+    pub fn parse_project(&self, uri: &Url) -> Result<Vec<Diagnostic>, LanguageServerError> {
         let manifest_dir = PathBuf::from(uri.path());
+
+        // Skip re-checking the project if the edit can't have changed the program.
+        let fingerprint = project_fingerprint(&manifest_dir);
+        if let (Some(fingerprint), Some((last_fingerprint, diagnostics))) =
+            (fingerprint, &*self.last_parse.read())
+        {
+            if fingerprint == *last_fingerprint {
+                return Ok(diagnostics.clone());
+            }
+        }
+        *self.last_parse.write() = None;
+        self.compile_errors.write().clear();
         let locked = false;
         let offline = false;
 
@@ -140,10 +179,27 @@ impl Session {
         let type_engine = &*self.type_engine.read();
         let declaration_engine = &*self.declaration_engine.read();
         let engines = Engines::new(type_engine, declaration_engine);
-        let results =
-            pkg::check(&plan, true, engines).map_err(LanguageServerError::FailedToCompile)?;
+        let results = pkg::check_with_cache(&plan, true, engines, &mut self.check_cache.write())
+            .map_err(LanguageServerError::FailedToCompile)?;
         let results_len = results.len();
-        for (i, res) in results.into_iter().enumerate() {
+
+        // The tokens that were collected from the cached dependencies are still valid, so only the
+        // tokens of the packages that were checked again need to be collected.
+        let package_dirs: Vec<PathBuf> = plan
+            .manifest_map()
+            .values()
+            .map(|manifest| manifest.dir().to_path_buf())
+            .collect();
+        let cached_dirs: HashSet<PathBuf> = package_dirs
+            .iter()
+            .filter(|dir| results.iter().all(|(checked_dir, _)| checked_dir != *dir))
+            .cloned()
+            .collect();
+        self.token_map
+            .retain_package_tokens(&package_dirs, &cached_dirs);
+        self.runnables.clear();
+
+        for (i, (_, res)) in results.into_iter().enumerate() {
             // We can convert these destructured elements to a Vec<Diagnostic> later on.
             let CompileResult {
                 value,
@@ -161,14 +217,16 @@ impl Session {
             let parsed_res = CompileResult::new(parsed, warnings.clone(), errors.clone());
             let ast_res = CompileResult::new(typed, warnings, errors);
 
-            let parse_program = self.compile_res_to_parse_program(&parsed_res)?;
-            let typed_program = self.compile_res_to_typed_program(&ast_res)?;
-
             // The final element in the results is the main program.
             if i == results_len - 1 {
-                // First, populate our token_map with un-typed ast nodes.
+                *self.compile_errors.write() = ast_res.errors.clone();
+
+                // First, populate our token_map with un-typed ast nodes. This is done even if
+                // type checking failed, so that quick fixes can find the project's declarations.
+                let parse_program = self.compile_res_to_parse_program(&parsed_res)?;
                 let parsed_tree = ParsedTree::new(type_engine, &self.token_map);
                 self.parse_ast_to_tokens(parse_program, |an| parsed_tree.traverse_node(an));
+                let typed_program = self.compile_res_to_typed_program(&ast_res)?;
 
                 // Next, create runnables and populate our token_map with typed ast nodes.
                 self.create_runnables(typed_program);
@@ -182,6 +240,9 @@ impl Session {
                 diagnostics =
                     capabilities::diagnostic::get_diagnostics(&ast_res.warnings, &ast_res.errors);
             } else {
+                let parse_program = self.compile_res_to_parse_program(&parsed_res)?;
+                let typed_program = self.compile_res_to_typed_program(&ast_res)?;
+
                 // Collect tokens from dependencies and the standard library prelude.
                 let dependency = Dependency::new(&self.token_map);
                 self.parse_ast_to_tokens(parse_program, |an| {
@@ -193,6 +254,8 @@ impl Session {
                 });
             }
         }
+        *self.last_parse.write() =
+            fingerprint.map(|fingerprint| (fingerprint, diagnostics.clone()));
         Ok(diagnostics)
     }
 
@@ -242,16 +305,49 @@ impl Session {
     }
 
     pub fn format_text(&self, url: &Url) -> Result<Vec<TextEdit>, LanguageServerError> {
-        let document = self
-            .documents
-            .try_get(url.path())
-            .try_unwrap()
-            .ok_or_else(|| DocumentError::DocumentNotFound {
-                path: url.path().to_string(),
-            })?;
+        let text = self.document_text(url)?;
+        get_page_text_edit(text, &mut <_>::default()).map(|page_text_edit| vec![page_text_edit])
+    }
+
+    pub fn folding_ranges(
+        &self,
+        url: &Url,
+    ) -> Result<Option<Vec<FoldingRange>>, LanguageServerError> {
+        let text = self.document_text(url)?;
+        Ok(self
+            .syntax_tree(url, text.clone())
+            .map(|module| capabilities::folding_range::folding_ranges(text, &module)))
+    }
 
-        get_page_text_edit(Arc::from(document.get_text()), &mut <_>::default())
-            .map(|page_text_edit| vec![page_text_edit])
+    pub fn selection_ranges(
+        &self,
+        url: &Url,
+        positions: Vec<Position>,
+    ) -> Result<Option<Vec<SelectionRange>>, LanguageServerError> {
+        let text = self.document_text(url)?;
+        Ok(self.syntax_tree(url, text.clone()).map(|module| {
+            capabilities::selection_range::selection_ranges(text, &module, positions)
+        }))
+    }
+
+    /// Parse the syntax tree of the document at the given [Url].
+    ///
+    /// While the user is partway through an edit, the document may contain syntax errors that the
+    /// parser can't recover from. The last tree that parsed successfully is returned instead, so
+    /// that the features built on it don't disappear until the edit is complete.
+    fn syntax_tree(&self, url: &Url, text: Arc<str>) -> Option<Arc<Module>> {
+        match syntax_tree::parse(text) {
+            Some(module) => {
+                let module = Arc::new(module);
+                self.syntax_trees
+                    .insert(url.path().to_string(), module.clone());
+                Some(module)
+            }
+            None => self
+                .syntax_trees
+                .get(url.path())
+                .map(|module| module.clone()),
+        }
     }
 
     pub fn handle_open_file(&self, uri: &Url) {
@@ -289,6 +385,17 @@ impl Session {
             .map(|(_, text_document)| text_document)
     }
 
+    /// Get the current text of the document at the given [Url].
+    pub fn document_text(&self, url: &Url) -> Result<Arc<str>, DocumentError> {
+        self.documents
+            .try_get(url.path())
+            .try_unwrap()
+            .map(|document| Arc::from(document.get_text()))
+            .ok_or_else(|| DocumentError::DocumentNotFound {
+                path: url.path().to_string(),
+            })
+    }
+
     /// Store the text document in the session.
     fn store_document(&self, text_document: TextDocument) -> Result<(), DocumentError> {
         let uri = text_document.get_uri().to_string();
@@ -353,16 +460,31 @@ impl Session {
             })
     }
 
-    /// Create runnables if the `TyProgramKind` of the `TyProgram` is a script.
+    /// Create runnables if the `TyProgramKind` of the `TyProgram` is a script,
+    /// as well as a runnable for every test function in the program.
     fn create_runnables(&self, typed_program: &ty::TyProgram) {
+        let tree_type = typed_program.kind.tree_type();
         if let ty::TyProgramKind::Script {
             ref main_function, ..
         } = typed_program.kind
         {
-            let main_fn_location = get_range_from_span(&main_function.name.span());
-            let runnable = Runnable::new(main_fn_location, typed_program.kind.tree_type());
+            let span = main_function.name.span();
+            let main_fn_location = get_range_from_span(&span);
+            let runnable = Runnable::new(main_fn_location, tree_type.clone(), span.path().cloned());
             self.runnables.insert(RunnableType::MainFn, runnable);
         }
+
+        let declaration_engine = self.declaration_engine.read();
+        for (i, (test_fn, _)) in typed_program.test_fns(&declaration_engine).enumerate() {
+            let span = test_fn.name.span();
+            let runnable = Runnable::test(
+                get_range_from_span(&span),
+                tree_type.clone(),
+                span.path().cloned(),
+                test_fn.name.to_string(),
+            );
+            self.runnables.insert(RunnableType::TestFn(i), runnable);
+        }
     }
 
     /// Save the `ParseProgram` AST in the session.
@@ -388,10 +510,30 @@ impl Session {
     }
 }
 
+/// Combine the fingerprints of the manifest and every sway file of the project in `manifest_dir`.
+///
+/// Returns `None` if any of the files can't be read or lexed.
+fn project_fingerprint(manifest_dir: &Path) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    let manifest_path = manifest_dir.join(constants::MANIFEST_FILE_NAME);
+    std::fs::read_to_string(manifest_path)
+        .ok()?
+        .hash(&mut hasher);
+
+    let mut files = get_sway_files(manifest_dir.to_path_buf());
+    files.sort();
+    for file in files {
+        let src: Arc<str> = Arc::from(std::fs::read_to_string(&file).ok()?);
+        file.hash(&mut hasher);
+        syntax_tree::fingerprint(&src)?.hash(&mut hasher);
+    }
+    Some(hasher.finish())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::test::{get_absolute_path, get_url};
+    use crate::utils::test::{get_absolute_path, get_url, sway_workspace_dir};
 
     #[test]
     fn store_document_returns_empty_tuple() {
@@ -429,4 +571,68 @@ mod tests {
             if test_dir == dir
         ));
     }
+
+    #[test]
+    fn parse_project_keeps_tokens_of_cached_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        let std_dir = sway_workspace_dir().join("sway-lib-std");
+        let project_dir = dir.path().join("project");
+        let lib_dir = dir.path().join("shapes");
+        let write = |path: PathBuf, contents: &str| {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        };
+        write(
+            project_dir.join("Forc.toml"),
+            &format!(
+                "[project]\nauthors = [\"Fuel Labs <contact@fuel.sh>\"]\nentry = \"main.sw\"\n\
+                 license = \"Apache-2.0\"\nname = \"project\"\n\n[dependencies]\n\
+                 shapes = {{ path = \"../shapes\" }}\nstd = {{ path = {:?} }}\n",
+                std_dir
+            ),
+        );
+        write(
+            project_dir.join("src/main.sw"),
+            "script;\n\nuse shapes::Point;\n\nfn main() -> u64 {\n    Point { x: 1 }.x\n}\n",
+        );
+        write(
+            lib_dir.join("Forc.toml"),
+            "[project]\nauthors = [\"Fuel Labs <contact@fuel.sh>\"]\nentry = \"lib.sw\"\n\
+             implicit-std = false\nlicense = \"Apache-2.0\"\nname = \"shapes\"\n",
+        );
+        write(
+            lib_dir.join("src/lib.sw"),
+            "library shapes;\n\npub struct Point {\n    x: u64,\n}\n",
+        );
+
+        let session = Session::new();
+        let uri = Url::from_file_path(&project_dir).unwrap();
+        let tokens_in = |dir: &Path| {
+            session
+                .token_map()
+                .iter()
+                .filter(|item| {
+                    item.key()
+                        .1
+                        .path()
+                        .is_some_and(|path| path.starts_with(dir))
+                })
+                .map(|item| item.key().0.as_str().to_string())
+                .collect::<HashSet<_>>()
+        };
+        session.parse_project(&uri).unwrap();
+        let std_tokens = tokens_in(&std_dir);
+        assert!(!std_tokens.is_empty());
+
+        // Change one of the dependencies, so that it's checked again while `std` is cached.
+        // Edits outside of the project don't change its fingerprint, so it's forgotten here.
+        write(
+            lib_dir.join("src/lib.sw"),
+            "library shapes;\n\npub struct Point {\n    x: u64,\n}\n\npub struct Size {}\n",
+        );
+        *session.last_parse.write() = None;
+        session.parse_project(&uri).unwrap();
+        assert_eq!(tokens_in(&std_dir), std_tokens);
+        assert!(tokens_in(&lib_dir).contains("Size"));
+    }
 }
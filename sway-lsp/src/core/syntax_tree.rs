@@ -0,0 +1,444 @@
+//! A lightweight view over the syntax tree that sway-parse produces for a single file.
+//!
+//! Unlike the [TokenMap](crate::core::token_map::TokenMap), this only depends on the file
+//! parsing successfully, so the features built on top of it keep working while the
+//! program fails to type check. The [Session](crate::core::session::Session) keeps the last tree
+//! that parsed successfully for files with syntax errors the parser can't recover from.
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    ops::ControlFlow,
+    sync::Arc,
+};
+use sway_ast::{
+    attribute::Annotated,
+    expr::asm::AsmBlock,
+    punctuated::Punctuated,
+    token::{CommentedTokenStream, CommentedTokenTree, CommentedTree, TokenStream, TokenTree},
+    Braces, CodeBlockContents, Expr, ExprArrayDescriptor, ExprTupleDescriptor, FnSignature,
+    ForIterable, IfCondition, IfExpr, Item, ItemConst, ItemFn, ItemImplItem, ItemKind,
+    ItemTraitItem, MatchBranchKind, Module, Statement,
+};
+use sway_error::handler::Handler;
+use sway_types::{Span, Spanned};
+
+/// The kind of syntax node that a [SyntaxNode] spans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxKind {
+    /// A top level or nested item, e.g. a function, `impl` block, `storage` or `abi` block.
+    Item,
+    /// A `use` statement.
+    Use,
+    /// The braces of a block, along with their contents.
+    Block,
+    Statement,
+    Expression,
+    MatchArm,
+    /// A field of a struct, enum, storage block or struct expression.
+    Field,
+    /// A comment, or a doc comment.
+    Comment,
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxNode {
+    pub kind: SyntaxKind,
+    pub span: Span,
+}
+
+impl SyntaxNode {
+    fn new(kind: SyntaxKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+/// Parse the source of a single file, discarding any errors.
+pub fn parse(src: Arc<str>) -> Option<Module> {
+    let handler = Handler::default();
+    sway_parse::parse_file(&handler, src, None).ok()
+}
+
+/// Hash the tokens of `src` along with the positions they start and end at.
+///
+/// Edits that leave the fingerprint unchanged, such as edits to comments or trailing whitespace,
+/// can't change the meaning of the program or the location of anything within it.
+pub fn fingerprint(src: &Arc<str>) -> Option<u64> {
+    let handler = Handler::default();
+    let token_stream = sway_parse::lex(&handler, src, 0, src.len(), None).ok()?;
+    let mut hasher = DefaultHasher::new();
+    hash_token_stream(&token_stream, &mut hasher);
+    Some(hasher.finish())
+}
+
+fn hash_token_stream(token_stream: &TokenStream, hasher: &mut DefaultHasher) {
+    for token_tree in token_stream.token_trees() {
+        let span = token_tree.span();
+        span.start_pos().line_col().hash(hasher);
+        span.end_pos().line_col().hash(hasher);
+        match token_tree {
+            TokenTree::Group(group) => {
+                group.delimiter.hash(hasher);
+                hash_token_stream(&group.token_stream, hasher);
+            }
+            _ => span.as_str().hash(hasher),
+        }
+    }
+}
+
+/// Collect the nodes of the syntax tree in the order they appear in the source.
+///
+/// Nodes nest in the same way as the source, so the nodes that contain a given position form
+/// a chain from the enclosing item down to the innermost expression.
+pub fn syntax_nodes(module: &Module) -> Vec<SyntaxNode> {
+    let mut nodes = vec![];
+    module
+        .items
+        .iter()
+        .for_each(|item| collect_item(item, &mut nodes));
+    nodes
+}
+
+/// Collect the spans of all comments in `src`, including doc comments.
+pub fn comments(src: &Arc<str>) -> Vec<SyntaxNode> {
+    let handler = Handler::default();
+    let mut nodes = vec![];
+    if let Ok(token_stream) = sway_parse::lex_commented(&handler, src, 0, src.len(), &None) {
+        collect_comments(&token_stream, &mut nodes);
+    }
+    nodes
+}
+
+fn collect_comments(token_stream: &CommentedTokenStream, nodes: &mut Vec<SyntaxNode>) {
+    for token_tree in token_stream.token_trees() {
+        match token_tree {
+            CommentedTokenTree::Comment(comment) => {
+                nodes.push(SyntaxNode::new(SyntaxKind::Comment, comment.span()))
+            }
+            CommentedTokenTree::Tree(CommentedTree::DocComment(doc_comment)) => {
+                nodes.push(SyntaxNode::new(SyntaxKind::Comment, doc_comment.span()))
+            }
+            CommentedTokenTree::Tree(CommentedTree::Group(group)) => {
+                collect_comments(&group.token_stream, nodes)
+            }
+            CommentedTokenTree::Tree(_) => {}
+        }
+    }
+}
+
+fn collect_item(item: &Item, nodes: &mut Vec<SyntaxNode>) {
+    let kind = match &item.value {
+        ItemKind::Use(_) => SyntaxKind::Use,
+        _ => SyntaxKind::Item,
+    };
+    nodes.push(SyntaxNode::new(kind, item.value.span()));
+
+    match &item.value {
+        ItemKind::Struct(item_struct) => {
+            collect_fields(&item_struct.fields, nodes);
+        }
+        ItemKind::Enum(item_enum) => {
+            collect_fields(&item_enum.fields, nodes);
+        }
+        ItemKind::Fn(item_fn) => {
+            collect_block(&item_fn.body, nodes);
+        }
+        ItemKind::Trait(item_trait) => {
+            collect_trait_items(&item_trait.trait_items, nodes);
+            if let Some(trait_defs) = &item_trait.trait_defs_opt {
+                collect_fns(trait_defs, nodes);
+            }
+        }
+        ItemKind::Impl(item_impl) => {
+            collect_impl_items(&item_impl.contents, nodes);
+        }
+        ItemKind::Abi(item_abi) => {
+            collect_fn_signatures(&item_abi.abi_items, nodes);
+            if let Some(abi_defs) = &item_abi.abi_defs_opt {
+                collect_fns(abi_defs, nodes);
+            }
+        }
+        ItemKind::Const(item_const) => {
+            if let Some((_, expr)) = &item_const.expr_opt {
+                collect_expr(expr, nodes);
+            }
+        }
+        ItemKind::Storage(item_storage) => {
+            nodes.push(SyntaxNode::new(
+                SyntaxKind::Block,
+                item_storage.fields.span(),
+            ));
+            for field in &item_storage.fields.inner {
+                let field_span =
+                    Span::join(field.value.name.span(), field.value.initializer.span());
+                nodes.push(SyntaxNode::new(SyntaxKind::Field, field_span));
+                collect_expr(&field.value.initializer, nodes);
+            }
+        }
+        ItemKind::Dependency(_) | ItemKind::Use(_) | ItemKind::TypeAlias(_) => {}
+    }
+}
+
+fn collect_fields<T: Spanned, P>(
+    fields: &Braces<Punctuated<Annotated<T>, P>>,
+    nodes: &mut Vec<SyntaxNode>,
+) {
+    nodes.push(SyntaxNode::new(SyntaxKind::Block, fields.span()));
+    for field in &fields.inner {
+        nodes.push(SyntaxNode::new(SyntaxKind::Field, field.value.span()));
+    }
+}
+
+fn collect_fn_signatures<P>(
+    signatures: &Braces<Vec<(Annotated<FnSignature>, P)>>,
+    nodes: &mut Vec<SyntaxNode>,
+) {
+    nodes.push(SyntaxNode::new(SyntaxKind::Block, signatures.span()));
+    for (signature, _) in &signatures.inner {
+        nodes.push(SyntaxNode::new(SyntaxKind::Item, signature.value.span()));
+    }
+}
+
+fn collect_trait_items(items: &Braces<Vec<Annotated<ItemTraitItem>>>, nodes: &mut Vec<SyntaxNode>) {
+    nodes.push(SyntaxNode::new(SyntaxKind::Block, items.span()));
+    for item in &items.inner {
+        nodes.push(SyntaxNode::new(SyntaxKind::Item, item.value.span()));
+        if let ItemTraitItem::Const(ItemConst {
+            expr_opt: Some((_, expr)),
+            ..
+        }) = &item.value
+        {
+            collect_expr(expr, nodes);
+        }
+    }
+}
+
+fn collect_impl_items(items: &Braces<Vec<Annotated<ItemImplItem>>>, nodes: &mut Vec<SyntaxNode>) {
+    nodes.push(SyntaxNode::new(SyntaxKind::Block, items.span()));
+    for item in &items.inner {
+        nodes.push(SyntaxNode::new(SyntaxKind::Item, item.value.span()));
+        match &item.value {
+            ItemImplItem::Fn(item_fn) => collect_block(&item_fn.body, nodes),
+            ItemImplItem::Const(ItemConst {
+                expr_opt: Some((_, expr)),
+                ..
+            }) => collect_expr(expr, nodes),
+            ItemImplItem::Const(_) | ItemImplItem::Type(_) => {}
+        }
+    }
+}
+
+fn collect_fns(fns: &Braces<Vec<Annotated<ItemFn>>>, nodes: &mut Vec<SyntaxNode>) {
+    nodes.push(SyntaxNode::new(SyntaxKind::Block, fns.span()));
+    for item_fn in &fns.inner {
+        nodes.push(SyntaxNode::new(SyntaxKind::Item, item_fn.value.span()));
+        collect_block(&item_fn.value.body, nodes);
+    }
+}
+
+fn collect_block(block: &Braces<CodeBlockContents>, nodes: &mut Vec<SyntaxNode>) {
+    nodes.push(SyntaxNode::new(SyntaxKind::Block, block.span()));
+    let contents = block.get();
+    for statement in &contents.statements {
+        nodes.push(SyntaxNode::new(SyntaxKind::Statement, statement.span()));
+        match statement {
+            Statement::Let(statement_let) => collect_expr(&statement_let.expr, nodes),
+            Statement::Item(item) => collect_item(item, nodes),
+            Statement::Expr { expr, .. } => collect_expr(expr, nodes),
+        }
+    }
+    if let Some(expr) = &contents.final_expr_opt {
+        collect_expr(expr, nodes);
+    }
+}
+
+fn collect_if_expr(if_expr: &IfExpr, nodes: &mut Vec<SyntaxNode>) {
+    match &if_expr.condition {
+        IfCondition::Expr(condition) => collect_expr(condition, nodes),
+        IfCondition::Let { rhs, .. } => collect_expr(rhs, nodes),
+    }
+    collect_block(&if_expr.then_block, nodes);
+    if let Some((_else_token, tail)) = &if_expr.else_opt {
+        match tail {
+            ControlFlow::Break(block) => collect_block(block, nodes),
+            ControlFlow::Continue(if_expr) => {
+                nodes.push(SyntaxNode::new(SyntaxKind::Expression, if_expr.span()));
+                collect_if_expr(if_expr, nodes);
+            }
+        }
+    }
+}
+
+fn collect_asm_block(asm_block: &AsmBlock, nodes: &mut Vec<SyntaxNode>) {
+    for register in &asm_block.registers.inner {
+        if let Some((_colon_token, value)) = &register.value_opt {
+            collect_expr(value, nodes);
+        }
+    }
+    nodes.push(SyntaxNode::new(
+        SyntaxKind::Block,
+        asm_block.contents.span(),
+    ));
+}
+
+fn collect_expr(expr: &Expr, nodes: &mut Vec<SyntaxNode>) {
+    nodes.push(SyntaxNode::new(SyntaxKind::Expression, expr.span()));
+    match expr {
+        Expr::Error(_)
+        | Expr::Path(_)
+        | Expr::Literal(_)
+        | Expr::Break { .. }
+        | Expr::Continue { .. } => {}
+        Expr::AbiCast { args, .. } => collect_expr(&args.inner.address, nodes),
+        Expr::Struct { fields, .. } => {
+            for field in &fields.inner.fields {
+                nodes.push(SyntaxNode::new(SyntaxKind::Field, field.span()));
+                if let Some((_colon_token, expr)) = &field.expr_opt {
+                    collect_expr(expr, nodes);
+                }
+            }
+            if let Some((_double_dot_token, base)) = &fields.inner.base_opt {
+                collect_expr(base, nodes);
+            }
+        }
+        Expr::Tuple(tuple) => {
+            if let ExprTupleDescriptor::Cons { head, tail, .. } = &tuple.inner {
+                collect_expr(head, nodes);
+                tail.into_iter().for_each(|expr| collect_expr(expr, nodes));
+            }
+        }
+        Expr::Parens(parens) => collect_expr(&parens.inner, nodes),
+        Expr::Block(block) => collect_block(block, nodes),
+        Expr::Array(array) => match &array.inner {
+            ExprArrayDescriptor::Sequence(exprs) => {
+                exprs.into_iter().for_each(|expr| collect_expr(expr, nodes))
+            }
+            ExprArrayDescriptor::Repeat { value, length, .. } => {
+                collect_expr(value, nodes);
+                collect_expr(length, nodes);
+            }
+        },
+        Expr::Asm(asm_block) => collect_asm_block(asm_block, nodes),
+        Expr::Return { expr_opt, .. } => {
+            if let Some(expr) = expr_opt {
+                collect_expr(expr, nodes);
+            }
+        }
+        Expr::If(if_expr) => collect_if_expr(if_expr, nodes),
+        Expr::Match {
+            value, branches, ..
+        } => {
+            collect_expr(value, nodes);
+            nodes.push(SyntaxNode::new(SyntaxKind::Block, branches.span()));
+            for branch in &branches.inner {
+                nodes.push(SyntaxNode::new(SyntaxKind::MatchArm, branch.span()));
+                if let Some((_if_token, guard)) = &branch.guard_opt {
+                    collect_expr(guard, nodes);
+                }
+                match &branch.kind {
+                    MatchBranchKind::Block { block, .. } => collect_block(block, nodes),
+                    MatchBranchKind::Expr { expr, .. } => collect_expr(expr, nodes),
+                }
+            }
+        }
+        Expr::While {
+            condition, block, ..
+        } => {
+            collect_expr(condition, nodes);
+            collect_block(block, nodes);
+        }
+        Expr::For {
+            iterable, block, ..
+        } => {
+            match iterable {
+                ForIterable::Range { start, end, .. } => {
+                    collect_expr(start, nodes);
+                    collect_expr(end, nodes);
+                }
+                ForIterable::Expr(expr) => collect_expr(expr, nodes),
+            }
+            collect_block(block, nodes);
+        }
+        Expr::FuncApp { func, args } => {
+            collect_expr(func, nodes);
+            (&args.inner)
+                .into_iter()
+                .for_each(|expr| collect_expr(expr, nodes));
+        }
+        Expr::Index { target, arg } => {
+            collect_expr(target, nodes);
+            collect_expr(&arg.inner, nodes);
+        }
+        Expr::MethodCall {
+            target,
+            contract_args_opt,
+            args,
+            ..
+        } => {
+            collect_expr(target, nodes);
+            if let Some(contract_args) = contract_args_opt {
+                for field in &contract_args.inner {
+                    if let Some((_colon_token, expr)) = &field.expr_opt {
+                        collect_expr(expr, nodes);
+                    }
+                }
+            }
+            (&args.inner)
+                .into_iter()
+                .for_each(|expr| collect_expr(expr, nodes));
+        }
+        Expr::FieldProjection { target, .. } | Expr::TupleFieldProjection { target, .. } => {
+            collect_expr(target, nodes)
+        }
+        Expr::Closure { body, .. } => collect_expr(body, nodes),
+        Expr::Try { expr, .. }
+        | Expr::Ref { expr, .. }
+        | Expr::Deref { expr, .. }
+        | Expr::Not { expr, .. } => collect_expr(expr, nodes),
+        Expr::Mul { lhs, rhs, .. }
+        | Expr::Div { lhs, rhs, .. }
+        | Expr::Pow { lhs, rhs, .. }
+        | Expr::Modulo { lhs, rhs, .. }
+        | Expr::Add { lhs, rhs, .. }
+        | Expr::Sub { lhs, rhs, .. }
+        | Expr::Shl { lhs, rhs, .. }
+        | Expr::Shr { lhs, rhs, .. }
+        | Expr::BitAnd { lhs, rhs, .. }
+        | Expr::BitXor { lhs, rhs, .. }
+        | Expr::BitOr { lhs, rhs, .. }
+        | Expr::Equal { lhs, rhs, .. }
+        | Expr::NotEqual { lhs, rhs, .. }
+        | Expr::LessThan { lhs, rhs, .. }
+        | Expr::GreaterThan { lhs, rhs, .. }
+        | Expr::LessThanEq { lhs, rhs, .. }
+        | Expr::GreaterThanEq { lhs, rhs, .. }
+        | Expr::LogicalAnd { lhs, rhs, .. }
+        | Expr::LogicalOr { lhs, rhs, .. } => {
+            collect_expr(lhs, nodes);
+            collect_expr(rhs, nodes);
+        }
+        Expr::Reassignment { expr, .. } => collect_expr(expr, nodes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_ignores_comments_but_not_positions() {
+        let fingerprint = |src: &str| fingerprint(&Arc::from(src)).unwrap();
+        let src = "script;\n\nfn main() -> u64 {\n    1 // one\n}\n";
+        assert_eq!(
+            fingerprint(src),
+            fingerprint("script;\n\nfn main() -> u64 {\n    1 // two  \n}\n")
+        );
+        assert_ne!(
+            fingerprint(src),
+            fingerprint("script;\n\nfn main() -> u64 {\n    2 // one\n}\n")
+        );
+        assert_ne!(
+            fingerprint(src),
+            fingerprint("script;\n\n\nfn main() -> u64 {\n    1 // one\n}\n")
+        );
+    }
+}
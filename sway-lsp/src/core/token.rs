@@ -68,6 +68,7 @@ pub enum SymbolKind {
     BuiltinType,
     Module,
     TypeParameter,
+    TypeAlias,
     Unknown,
 }
 
@@ -158,7 +159,7 @@ pub fn type_info_to_symbol_kind(type_engine: &TypeEngine, type_info: &TypeInfo)
         TypeInfo::Numeric | TypeInfo::Str(..) => SymbolKind::NumericLiteral,
         TypeInfo::Custom { .. } | TypeInfo::Struct { .. } => SymbolKind::Struct,
         TypeInfo::Enum { .. } => SymbolKind::Enum,
-        TypeInfo::Array(elem_ty, ..) => {
+        TypeInfo::Array(elem_ty, ..) | TypeInfo::Ref(elem_ty) => {
             let type_info = type_engine.look_up_type_id(elem_ty.type_id);
             type_info_to_symbol_kind(type_engine, &type_info)
         }
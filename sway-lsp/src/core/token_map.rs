@@ -1,5 +1,6 @@
 use crate::core::token::{self, Token, TypedAstToken};
 use dashmap::DashMap;
+use std::{collections::HashSet, path::PathBuf};
 use sway_core::{language::ty, type_system::TypeId, Engines, TypeEngine};
 use sway_types::{Ident, Span, Spanned};
 use tower_lsp::lsp_types::{Position, Url};
@@ -36,6 +37,22 @@ impl TokenMap {
             })
     }
 
+    /// Keep only the tokens of the packages whose directories are in `keep`.
+    ///
+    /// `package_dirs` holds the directories of all of the packages. A file belongs to the innermost
+    /// of these directories that contains it, so that a package nested within the directory of
+    /// another is told apart from it. Tokens of files outside all of them are removed too.
+    pub fn retain_package_tokens(&self, package_dirs: &[PathBuf], keep: &HashSet<PathBuf>) {
+        self.retain(|(_, span), _| match span.path() {
+            Some(path) => package_dirs
+                .iter()
+                .filter(|dir| path.starts_with(dir))
+                .max_by_key(|dir| dir.components().count())
+                .is_some_and(|dir| keep.contains(dir)),
+            None => true,
+        });
+    }
+
     /// Find all references in the TokenMap for a given token.
     ///
     /// This is useful for the highlighting and renaming LSP capabilities.
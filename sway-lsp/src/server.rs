@@ -74,6 +74,53 @@ impl Backend {
         self.publish_diagnostics(&uri, &workspace_uri, session, diagnostics)
             .await;
     }
+
+    /// Run `forc test` in the manifest directory of the project containing `args.uri`,
+    /// reporting the output to the client.
+    async fn run_tests(&self, args: capabilities::runnable::RunTestsArgs) {
+        let manifest_dir = match self
+            .url_to_session(&args.uri)
+            .and_then(|session| session.sync.manifest_dir().map_err(Into::into))
+        {
+            Ok(manifest_dir) => manifest_dir,
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                return;
+            }
+        };
+
+        let output = tokio::task::spawn_blocking(move || {
+            let mut cmd = std::process::Command::new("forc");
+            cmd.arg("test").current_dir(manifest_dir);
+            if let Some(test_name) = args.test_name {
+                cmd.arg(test_name).arg("--exact");
+            }
+            cmd.output()
+        })
+        .await;
+
+        match output {
+            Ok(Ok(output)) => {
+                let log = format!(
+                    "{}{}",
+                    String::from_utf8_lossy(&output.stdout),
+                    String::from_utf8_lossy(&output.stderr)
+                );
+                self.client.log_message(MessageType::INFO, log).await;
+                let (typ, message) = if output.status.success() {
+                    (MessageType::INFO, "Tests passed")
+                } else {
+                    (MessageType::ERROR, "Tests failed")
+                };
+                self.client.show_message(typ, message).await;
+            }
+            Ok(Err(err)) => {
+                let message = format!("Failed to run `forc test`: {}", err);
+                self.client.show_message(MessageType::ERROR, message).await;
+            }
+            Err(err) => tracing::error!("{}", err.to_string()),
+        }
+    }
 }
 
 fn capabilities() -> ServerCapabilities {
@@ -100,10 +147,20 @@ fn capabilities() -> ServerCapabilities {
             ..Default::default()
         }),
         document_formatting_provider: Some(OneOf::Left(true)),
+        folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+        selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
         definition_provider: Some(OneOf::Left(true)),
         inlay_hint_provider: Some(OneOf::Left(true)),
         code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
         hover_provider: Some(HoverProviderCapability::Simple(true)),
+        call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![capabilities::runnable::RUN_TESTS_COMMAND.to_string()],
+            ..Default::default()
+        }),
         ..ServerCapabilities::default()
     }
 }
@@ -314,6 +371,7 @@ impl LanguageServer for Backend {
                 &params.range,
                 params.text_document,
                 &temp_uri,
+                &params.context.diagnostics,
             )),
             Err(err) => {
                 tracing::error!("{}", err.to_string());
@@ -415,6 +473,30 @@ impl LanguageServer for Backend {
             })
     }
 
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> jsonrpc::Result<Option<Vec<FoldingRange>>> {
+        self.get_uri_and_session(&params.text_document.uri)
+            .and_then(|(uri, session)| session.folding_ranges(&uri))
+            .or_else(|err| {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            })
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> jsonrpc::Result<Option<Vec<SelectionRange>>> {
+        self.get_uri_and_session(&params.text_document.uri)
+            .and_then(|(uri, session)| session.selection_ranges(&uri, params.positions))
+            .or_else(|err| {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            })
+    }
+
     async fn rename(&self, params: RenameParams) -> jsonrpc::Result<Option<WorkspaceEdit>> {
         match self.get_uri_and_session(&params.text_document_position.text_document.uri) {
             Ok((uri, session)) => {
@@ -431,6 +513,89 @@ impl LanguageServer for Backend {
         }
     }
 
+    async fn code_lens(&self, params: CodeLensParams) -> jsonrpc::Result<Option<Vec<CodeLens>>> {
+        match self.get_uri_and_session(&params.text_document.uri) {
+            Ok((uri, session)) => Ok(Some(capabilities::runnable::code_lens(
+                session,
+                &uri,
+                &params.text_document.uri,
+            ))),
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> jsonrpc::Result<Option<serde_json::Value>> {
+        match params.command.as_str() {
+            capabilities::runnable::RUN_TESTS_COMMAND => {
+                let args = params
+                    .arguments
+                    .into_iter()
+                    .next()
+                    .and_then(|arg| serde_json::from_value(arg).ok())
+                    .ok_or_else(jsonrpc::Error::invalid_request)?;
+                self.run_tests(args).await;
+                Ok(None)
+            }
+            _ => Err(jsonrpc::Error::method_not_found()),
+        }
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> jsonrpc::Result<Option<Vec<CallHierarchyItem>>> {
+        match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
+            Ok((uri, session)) => {
+                let position = params.text_document_position_params.position;
+                Ok(capabilities::call_hierarchy::prepare_call_hierarchy(
+                    session, uri, position,
+                ))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> jsonrpc::Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        match self.url_to_session(&params.item.uri) {
+            Ok(session) => Ok(capabilities::call_hierarchy::incoming_calls(
+                session,
+                params.item,
+            )),
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> jsonrpc::Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        match self.url_to_session(&params.item.uri) {
+            Ok(session) => Ok(capabilities::call_hierarchy::outgoing_calls(
+                session,
+                params.item,
+            )),
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
     async fn prepare_rename(
         &self,
         params: TextDocumentPositionParams,
@@ -604,7 +769,10 @@ impl Backend {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::test::{doc_comments_dir, e2e_test_dir};
+    use crate::utils::test::{
+        call_hierarchy_dir, code_actions_dir, doc_comments_dir, e2e_test_dir, is_prime_dir,
+        test_attribute_dir,
+    };
     use serde_json::json;
     use serial_test::serial;
     use std::{borrow::Cow, fs, io::Read, path::PathBuf};
@@ -717,6 +885,31 @@ mod tests {
         did_change
     }
 
+    /// Append an incomplete function to the end of the file, which the parser can't recover from.
+    async fn syntax_error_did_change_request(service: &mut LspService<Backend>, uri: &Url) {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+                "version": 2
+            },
+            "contentChanges": [
+                {
+                    "range": {
+                        "start": { "line": 52, "character": 0 },
+                        "end": { "line": 52, "character": 0 }
+                    },
+                    "rangeLength": 0,
+                    "text": "\nfn broken(",
+                }
+            ]
+        });
+        let did_change = Request::build("textDocument/didChange")
+            .params(params)
+            .finish();
+        let response = call_request(service, did_change).await;
+        assert_eq!(response, Ok(None));
+    }
+
     async fn did_close_notification(service: &mut LspService<Backend>) {
         let exit = Request::build("textDocument/didClose").finish();
         let response = call_request(service, exit.clone()).await;
@@ -879,6 +1072,422 @@ mod tests {
         highlight
     }
 
+    fn check_prime_item(uri: &Url) -> serde_json::Value {
+        json!({
+            "name": "check_prime",
+            "kind": 12,
+            "uri": uri,
+            "range": {
+                "start": { "line": 5, "character": 0 },
+                "end": { "line": 21, "character": 1 }
+            },
+            "selectionRange": {
+                "start": { "line": 5, "character": 3 },
+                "end": { "line": 5, "character": 14 }
+            }
+        })
+    }
+
+    async fn prepare_call_hierarchy_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+    ) -> Request {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "position": {
+                "line": 24,
+                "character": 14
+            }
+        });
+        let prepare = build_request_with_id("textDocument/prepareCallHierarchy", params, 1);
+        let response = call_request(service, prepare.clone()).await;
+        let ok = Response::from_ok(1.into(), json!([check_prime_item(uri)]));
+        assert_eq!(response, Ok(Some(ok)));
+        prepare
+    }
+
+    async fn incoming_calls_request(service: &mut LspService<Backend>, uri: &Url) -> Request {
+        let params = json!({ "item": check_prime_item(uri) });
+        let incoming_calls = build_request_with_id("callHierarchy/incomingCalls", params, 1);
+        let response = call_request(service, incoming_calls.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        let calls = response.result().unwrap().as_array().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0]["from"]["name"], "main");
+        assert_eq!(calls[0]["fromRanges"].as_array().unwrap().len(), 15);
+        incoming_calls
+    }
+
+    async fn outgoing_calls_request(service: &mut LspService<Backend>, uri: &Url) -> Request {
+        let params = json!({
+            "item": {
+                "name": "main",
+                "kind": 12,
+                "uri": uri,
+                "range": {
+                    "start": { "line": 23, "character": 0 },
+                    "end": { "line": 40, "character": 1 }
+                },
+                "selectionRange": {
+                    "start": { "line": 23, "character": 3 },
+                    "end": { "line": 23, "character": 7 }
+                }
+            }
+        });
+        let outgoing_calls = build_request_with_id("callHierarchy/outgoingCalls", params, 1);
+        let response = call_request(service, outgoing_calls.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        let calls = response.result().unwrap().as_array().unwrap();
+        let callees: Vec<_> = calls.iter().map(|call| &call["to"]["name"]).collect();
+        assert_eq!(callees, vec!["assert", "check_prime"]);
+        assert_eq!(calls[1]["fromRanges"].as_array().unwrap().len(), 15);
+        outgoing_calls
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> serde_json::Value {
+        json!({
+            "start": { "line": start.0, "character": start.1 },
+            "end": { "line": end.0, "character": end.1 }
+        })
+    }
+
+    async fn contract_call_hierarchy_request(service: &mut LspService<Backend>, uri: &Url) {
+        // Prepare the call hierarchy of the contract's implementation of `MyAbi::value`.
+        let params = json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 7, "character": 9 }
+        });
+        let prepare = build_request_with_id("textDocument/prepareCallHierarchy", params, 1);
+        let response = call_request(service, prepare).await.unwrap().unwrap();
+        let items = response.result().unwrap().as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        let value_item = items[0].clone();
+        assert_eq!(value_item["name"], "value");
+        assert_eq!(value_item["selectionRange"], range((7, 7), (7, 12)));
+
+        // The call through the ABI cast is matched to the implementation by its selector.
+        let params = json!({ "item": value_item });
+        let incoming = build_request_with_id("callHierarchy/incomingCalls", params, 2);
+        let response = call_request(service, incoming).await.unwrap().unwrap();
+        let calls = response.result().unwrap().as_array().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0]["from"]["name"], "call_value");
+        assert_eq!(calls[0]["fromRanges"], json!([range((18, 11), (18, 16))]));
+
+        // The direct call and the contract call are reported as separate edges.
+        let params = json!({
+            "item": {
+                "name": "call_value",
+                "kind": 12,
+                "uri": uri,
+                "range": range((16, 0), (19, 1)),
+                "selectionRange": range((16, 3), (16, 13))
+            }
+        });
+        let outgoing = build_request_with_id("callHierarchy/outgoingCalls", params, 3);
+        let response = call_request(service, outgoing).await.unwrap().unwrap();
+        let calls = response.result().unwrap().as_array().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0]["to"]["name"], "contract_id");
+        assert_eq!(calls[0]["to"].get("detail"), None);
+        assert_eq!(calls[0]["fromRanges"], json!([range((17, 28), (17, 39))]));
+        assert_eq!(calls[1]["to"]["name"], "value");
+        assert_eq!(calls[1]["to"]["detail"], "abi MyAbi (contract call)");
+        assert_eq!(calls[1]["fromRanges"], json!([range((18, 11), (18, 16))]));
+    }
+
+    async fn code_action_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+        position: Position,
+        diagnostics: serde_json::Value,
+    ) -> Response {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "range": {
+                "start": position,
+                "end": position,
+            },
+            "context": {
+                "diagnostics": diagnostics,
+            },
+        });
+        let code_action = build_request_with_id("textDocument/codeAction", params, 1);
+        call_request(service, code_action).await.unwrap().unwrap()
+    }
+
+    async fn struct_constructor_code_action_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+    ) -> Response {
+        let response = code_action_request(service, uri, Position::new(19, 8), json!([])).await;
+        let actions = response.result().unwrap();
+        assert_eq!(actions[0]["title"], "Generate `new` constructor");
+        assert_eq!(
+            actions[0]["edit"]["changes"][uri.as_str()],
+            json!([{
+                "range": {
+                    "start": { "line": 25, "character": 0 },
+                    "end": { "line": 25, "character": 0 }
+                },
+                "newText": "\nimpl Data {\n    fn new(value: NumberOrString, address: u64) -> Self {\n        Data { value, address }\n    }\n}\n"
+            }])
+        );
+        response
+    }
+
+    async fn missing_methods_code_action_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+    ) -> Response {
+        // The implemented trait shares its name with a trait in another module of the project.
+        let diagnostic = json!({
+            "range": {
+                "start": { "line": 10, "character": 0 },
+                "end": { "line": 14, "character": 1 }
+            },
+            "severity": 1,
+            "message": "Functions are missing from this trait implementation: id"
+        });
+        let response =
+            code_action_request(service, uri, Position::new(10, 0), json!([diagnostic])).await;
+        let actions = response.result().unwrap();
+        assert_eq!(actions[0]["title"], "Implement missing methods");
+        assert_eq!(actions[0]["kind"], "quickfix");
+        assert_eq!(
+            actions[0]["edit"]["changes"][uri.as_str()],
+            json!([{
+                "range": {
+                    "start": { "line": 14, "character": 0 },
+                    "end": { "line": 14, "character": 0 }
+                },
+                "newText": "\n    fn id(self) -> u64 {}\n"
+            }])
+        );
+        response
+    }
+
+    async fn missing_match_arms_code_action_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+    ) -> Response {
+        let diagnostic = json!({
+            "range": {
+                "start": { "line": 17, "character": 4 },
+                "end": { "line": 19, "character": 5 }
+            },
+            "severity": 1,
+            "message": "Non-exhaustive match expression. Missing patterns `Color::Green(_)`"
+        });
+        let response =
+            code_action_request(service, uri, Position::new(17, 4), json!([diagnostic])).await;
+        let actions = response.result().unwrap();
+        assert_eq!(actions[0]["title"], "Add missing match arms");
+        assert_eq!(
+            actions[0]["edit"]["changes"][uri.as_str()],
+            json!([{
+                "range": {
+                    "start": { "line": 19, "character": 0 },
+                    "end": { "line": 19, "character": 0 }
+                },
+                "newText": "        Color::Green(_) => {},\n"
+            }])
+        );
+        response
+    }
+
+    async fn import_code_action_request(service: &mut LspService<Backend>, uri: &Url) -> Response {
+        // A declaration in another module of the project.
+        let diagnostic = json!({
+            "range": {
+                "start": { "line": 23, "character": 16 },
+                "end": { "line": 23, "character": 21 }
+            },
+            "severity": 1,
+            "message": "Could not find symbol \"Point\" in this scope."
+        });
+        let response =
+            code_action_request(service, uri, Position::new(23, 16), json!([diagnostic])).await;
+        let actions = response.result().unwrap().as_array().unwrap();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0]["title"], "Import `shapes::Point`");
+        assert_eq!(
+            actions[0]["edit"]["changes"][uri.as_str()],
+            json!([{
+                "range": {
+                    "start": { "line": 1, "character": 0 },
+                    "end": { "line": 1, "character": 0 }
+                },
+                "newText": "\nuse shapes::Point;\n"
+            }])
+        );
+
+        // A declaration in a dependency.
+        let diagnostic = json!({
+            "range": {
+                "start": { "line": 28, "character": 16 },
+                "end": { "line": 28, "character": 21 }
+            },
+            "severity": 1,
+            "message": "Could not find symbol \"Bytes\" in this scope."
+        });
+        let response =
+            code_action_request(service, uri, Position::new(28, 16), json!([diagnostic])).await;
+        let actions = response.result().unwrap().as_array().unwrap();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0]["title"], "Import `std::bytes::Bytes`");
+        response
+    }
+
+    async fn folding_range_request(service: &mut LspService<Backend>, uri: &Url) -> Request {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+        });
+        let folding_range = build_request_with_id("textDocument/foldingRange", params, 1);
+        let response = call_request(service, folding_range.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        let ranges: Vec<_> = response
+            .result()
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|range| {
+                (
+                    range["startLine"].as_u64().unwrap(),
+                    range["endLine"].as_u64().unwrap(),
+                    range["kind"].as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (2, 7, Some("comment")),
+                (8, 13, None),
+                (15, 18, Some("comment")),
+                (19, 24, None),
+                (27, 30, None),
+                (33, 38, None),
+                (41, 51, None),
+                (43, 50, None),
+            ]
+        );
+        folding_range
+    }
+
+    async fn selection_range_request(service: &mut LspService<Backend>, uri: &Url) -> Request {
+        selection_range_request_with_file_end(service, uri, (52, 0)).await
+    }
+
+    /// Request the selection ranges of `20` within `main`, where the file ends at `file_end`.
+    async fn selection_range_request_with_file_end(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+        file_end: (u64, u64),
+    ) -> Request {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "positions": [{ "line": 45, "character": 43 }],
+        });
+        let selection_range = build_request_with_id("textDocument/selectionRange", params, 1);
+        let response = call_request(service, selection_range.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        let mut selection_range_json = &response.result().unwrap()[0];
+        let mut ranges = vec![];
+        while !selection_range_json.is_null() {
+            let range = &selection_range_json["range"];
+            ranges.push((
+                range["start"]["line"].as_u64().unwrap(),
+                range["start"]["character"].as_u64().unwrap(),
+                range["end"]["line"].as_u64().unwrap(),
+                range["end"]["character"].as_u64().unwrap(),
+            ));
+            selection_range_json = &selection_range_json["parent"];
+        }
+        assert_eq!(
+            ranges,
+            vec![
+                // `20`
+                (45, 42, 45, 44),
+                // `NumberOrString::Number(20)`
+                (45, 19, 45, 45),
+                // `value: NumberOrString::Number(20)`
+                (45, 12, 45, 45),
+                // `Data { .. }`
+                (44, 23, 47, 9),
+                // `let mut data = Data { .. };`
+                (44, 8, 47, 10),
+                // The body of `main`
+                (43, 21, 50, 5),
+                // `fn main`
+                (43, 4, 50, 5),
+                // The body of the `impl` block
+                (41, 25, 51, 1),
+                // `impl FooABI for Contract`
+                (41, 0, 51, 1),
+                // The whole file
+                (0, 0, file_end.0, file_end.1),
+            ]
+        );
+        selection_range
+    }
+
+    async fn code_lens_request(service: &mut LspService<Backend>, uri: &Url) -> Request {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+        });
+        let code_lens = build_request_with_id("textDocument/codeLens", params, 1);
+        let response = call_request(service, code_lens.clone()).await;
+        let ok = Response::from_ok(
+            1.into(),
+            json!([
+                {
+                    "command": {
+                        "arguments": [{ "testName": null, "uri": uri }],
+                        "command": "sway.runTests",
+                        "title": "▶︎ Run All Tests"
+                    },
+                    "range": {
+                        "end": { "character": 0, "line": 0 },
+                        "start": { "character": 0, "line": 0 }
+                    }
+                },
+                {
+                    "command": {
+                        "arguments": [{ "testName": "foo", "uri": uri }],
+                        "command": "sway.runTests",
+                        "title": "▶︎ Run Test"
+                    },
+                    "range": {
+                        "end": { "character": 6, "line": 3 },
+                        "start": { "character": 3, "line": 3 }
+                    }
+                }
+            ]),
+        );
+        assert_eq!(response, Ok(Some(ok)));
+        code_lens
+    }
+
     async fn init_and_open(service: &mut LspService<Backend>, manifest_dir: PathBuf) -> Url {
         let _ = initialize_request(service).await;
         initialized_notification(service).await;
@@ -968,6 +1577,18 @@ mod tests {
         shutdown_and_exit(&mut service).await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn syntax_tree_survives_syntax_errors() {
+        let (mut service, _) = LspService::new(Backend::new);
+        let uri = init_and_open(&mut service, doc_comments_dir()).await;
+        let _ = folding_range_request(&mut service, &uri).await;
+        syntax_error_did_change_request(&mut service, &uri).await;
+        let _ = folding_range_request(&mut service, &uri).await;
+        let _ = selection_range_request_with_file_end(&mut service, &uri, (53, 10)).await;
+        shutdown_and_exit(&mut service).await;
+    }
+
     #[tokio::test]
     #[serial]
     async fn lsp_syncs_with_workspace_edits() {
@@ -1000,6 +1621,46 @@ mod tests {
         shutdown_and_exit(&mut service).await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn call_hierarchy() {
+        let (mut service, _) = LspService::new(Backend::new);
+        let uri = init_and_open(&mut service, is_prime_dir()).await;
+        let _ = prepare_call_hierarchy_request(&mut service, &uri).await;
+        let _ = incoming_calls_request(&mut service, &uri).await;
+        let _ = outgoing_calls_request(&mut service, &uri).await;
+        shutdown_and_exit(&mut service).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn contract_call_hierarchy() {
+        let (mut service, _) = LspService::new(Backend::new);
+        let uri = init_and_open(&mut service, call_hierarchy_dir()).await;
+        contract_call_hierarchy_request(&mut service, &uri).await;
+        shutdown_and_exit(&mut service).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn diagnostic_code_actions() {
+        let (mut service, _) = LspService::new(Backend::new);
+        let uri = init_and_open(&mut service, code_actions_dir()).await;
+        let _ = missing_methods_code_action_request(&mut service, &uri).await;
+        let _ = missing_match_arms_code_action_request(&mut service, &uri).await;
+        let _ = import_code_action_request(&mut service, &uri).await;
+        shutdown_and_exit(&mut service).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn code_lens() {
+        let (mut service, _) = LspService::new(Backend::new);
+        let uri = init_and_open(&mut service, test_attribute_dir()).await;
+        let _ = code_lens_request(&mut service, &uri).await;
+        shutdown_and_exit(&mut service).await;
+    }
+
     // This macro allows us to spin up a server / client for testing
     // It initializes and performs the necessary handshake and then loads
     // the sway example that was passed into `example_dir`.
@@ -1030,4 +1691,10 @@ mod tests {
     lsp_capability_test!(format, format_request);
     lsp_capability_test!(hover, hover_request);
     lsp_capability_test!(highlight, highlight_request);
+    lsp_capability_test!(folding_range, folding_range_request);
+    lsp_capability_test!(
+        struct_constructor_code_action,
+        struct_constructor_code_action_request
+    );
+    lsp_capability_test!(selection_range, selection_range_request);
 }
@@ -12,12 +12,13 @@ use sway_core::{
     language::{
         parsed::{
             AbiCastExpression, AmbiguousPathExpression, ArrayIndexExpression, AstNode,
-            AstNodeContent, CodeBlock, Declaration, DelineatedPathExpression, Expression,
-            ExpressionKind, FunctionApplicationExpression, FunctionDeclaration, FunctionParameter,
-            IfExpression, IntrinsicFunctionExpression, LazyOperatorExpression, MatchExpression,
+            AstNodeContent, CodeBlock, ConstantDeclaration, Declaration, DelineatedPathExpression,
+            Expression, ExpressionKind, ForLoopExpression, ForLoopIterable,
+            FunctionApplicationExpression, FunctionDeclaration, FunctionParameter, IfExpression,
+            IntrinsicFunctionExpression, LazyOperatorExpression, MatchExpression,
             MethodApplicationExpression, MethodName, ReassignmentTarget, Scrutinee,
             StorageAccessExpression, StructExpression, StructScrutineeField, SubfieldExpression,
-            TraitFn, TupleIndexExpression, WhileLoopExpression,
+            TraitFn, TraitTypeDeclaration, TupleIndexExpression, WhileLoopExpression,
         },
         Literal,
     },
@@ -136,6 +137,14 @@ impl<'a> ParsedTree<'a> {
                 for func_dec in &trait_decl.methods {
                     self.handle_function_declation(func_dec);
                 }
+
+                for const_decl in &trait_decl.constants {
+                    self.collect_const_decl(const_decl);
+                }
+
+                for type_decl in &trait_decl.types {
+                    self.collect_trait_type_decl(type_decl, declaration);
+                }
             }
             Declaration::StructDeclaration(struct_dec) => {
                 self.tokens.insert(
@@ -238,6 +247,14 @@ impl<'a> ParsedTree<'a> {
                 for func_dec in &impl_trait.functions {
                     self.handle_function_declation(func_dec);
                 }
+
+                for const_decl in &impl_trait.constants {
+                    self.collect_const_decl(const_decl);
+                }
+
+                for type_decl in &impl_trait.types {
+                    self.collect_trait_type_decl(type_decl, declaration);
+                }
             }
             Declaration::ImplSelf(impl_self) => {
                 if let TypeInfo::Custom {
@@ -267,6 +284,10 @@ impl<'a> ParsedTree<'a> {
                 for func_dec in &impl_self.functions {
                     self.handle_function_declation(func_dec);
                 }
+
+                for const_decl in &impl_self.constants {
+                    self.collect_const_decl(const_decl);
+                }
             }
             Declaration::AbiDeclaration(abi_decl) => {
                 self.tokens.insert(
@@ -282,20 +303,7 @@ impl<'a> ParsedTree<'a> {
                 }
             }
             Declaration::ConstantDeclaration(const_decl) => {
-                let token = Token::from_parsed(
-                    AstToken::Declaration(declaration.clone()),
-                    SymbolKind::Const,
-                );
-                self.tokens
-                    .insert(to_ident_key(&const_decl.name), token.clone());
-
-                self.collect_type_info_token(
-                    &token,
-                    &const_decl.type_ascription,
-                    const_decl.type_ascription_span.clone(),
-                    None,
-                );
-                self.handle_expression(&const_decl.value);
+                self.collect_const_decl(const_decl);
             }
             Declaration::StorageDeclaration(storage_decl) => {
                 for field in &storage_decl.fields {
@@ -314,6 +322,28 @@ impl<'a> ParsedTree<'a> {
                     self.handle_expression(&field.initializer);
                 }
             }
+            Declaration::TypeAliasDeclaration(type_alias_decl) => {
+                let token = Token::from_parsed(
+                    AstToken::Declaration(declaration.clone()),
+                    SymbolKind::TypeAlias,
+                );
+                self.tokens
+                    .insert(to_ident_key(&type_alias_decl.name), token.clone());
+
+                self.collect_type_info_token(
+                    &token,
+                    &type_alias_decl.ty,
+                    Some(type_alias_decl.ty_span.clone()),
+                    None,
+                );
+
+                for type_param in &type_alias_decl.type_parameters {
+                    self.collect_type_parameter(
+                        type_param,
+                        AstToken::Declaration(declaration.clone()),
+                    );
+                }
+            }
         }
     }
 
@@ -402,6 +432,7 @@ impl<'a> ParsedTree<'a> {
                 let StructExpression {
                     call_path_binding,
                     fields,
+                    base,
                 } = &**struct_expression;
                 for ident in &call_path_binding.inner.prefixes {
                     self.tokens.insert(
@@ -435,6 +466,9 @@ impl<'a> ParsedTree<'a> {
                     );
                     self.handle_expression(&field.value);
                 }
+                if let Some(base) = base {
+                    self.handle_expression(base);
+                }
             }
             ExpressionKind::CodeBlock(contents) => {
                 for node in &contents.contents {
@@ -459,6 +493,9 @@ impl<'a> ParsedTree<'a> {
                 self.handle_expression(value);
                 for branch in branches {
                     self.collect_scrutinee(&branch.scrutinee);
+                    if let Some(guard) = &branch.guard {
+                        self.handle_expression(guard);
+                    }
                     self.handle_expression(&branch.result);
                 }
             }
@@ -640,6 +677,18 @@ impl<'a> ParsedTree<'a> {
             ExpressionKind::WhileLoop(WhileLoopExpression {
                 body, condition, ..
             }) => self.handle_while_loop(body, condition),
+            ExpressionKind::ForLoop(ForLoopExpression { iterable, body, .. }) => {
+                match iterable {
+                    ForLoopIterable::Range { start, end } => {
+                        self.handle_expression(start);
+                        self.handle_expression(end);
+                    }
+                    ForLoopIterable::Expression(expr) => self.handle_expression(expr),
+                }
+                for node in &body.contents {
+                    self.traverse_node(node);
+                }
+            }
             // TODO: collect these tokens as keywords once the compiler returns the span
             ExpressionKind::Break | ExpressionKind::Continue => {}
             ExpressionKind::Reassignment(reassignment) => {
@@ -662,7 +711,11 @@ impl<'a> ParsedTree<'a> {
                     }
                 }
             }
-            ExpressionKind::Return(expr) => self.handle_expression(expr),
+            ExpressionKind::Return(expr)
+            | ExpressionKind::Try(expr)
+            | ExpressionKind::Ref(expr)
+            | ExpressionKind::Deref(expr) => self.handle_expression(expr),
+            ExpressionKind::Closure(closure) => self.handle_expression(&closure.body),
         }
     }
 
@@ -683,6 +736,7 @@ impl<'a> ParsedTree<'a> {
                     .insert(to_ident_key(&Ident::new(length.span())), token.clone());
                 self.collect_type_arg(type_arg, &token);
             }
+            TypeInfo::Ref(type_arg) => self.collect_type_arg(type_arg, &token),
             TypeInfo::Tuple(type_arguments) => {
                 for type_arg in type_arguments {
                     self.collect_type_arg(type_arg, &token);
@@ -759,7 +813,7 @@ impl<'a> ParsedTree<'a> {
 
                 self.collect_scrutinee(value);
             }
-            Scrutinee::Tuple { elems, .. } => {
+            Scrutinee::Tuple { elems, .. } | Scrutinee::Or { elems, .. } => {
                 for elem in elems {
                     self.collect_scrutinee(elem);
                 }
@@ -791,6 +845,7 @@ impl<'a> ParsedTree<'a> {
                     .insert(to_ident_key(&Ident::new(length.span())), token.clone());
                 self.collect_type_arg(type_arg, &token);
             }
+            TypeInfo::Ref(type_arg) => self.collect_type_arg(type_arg, &token),
             TypeInfo::Tuple(type_arguments) => {
                 for type_arg in type_arguments {
                     self.collect_type_arg(type_arg, &token);
@@ -833,6 +888,38 @@ impl<'a> ParsedTree<'a> {
         );
     }
 
+    fn collect_const_decl(&self, const_decl: &ConstantDeclaration) {
+        let token = Token::from_parsed(
+            AstToken::Declaration(Declaration::ConstantDeclaration(const_decl.clone())),
+            SymbolKind::Const,
+        );
+        self.tokens
+            .insert(to_ident_key(&const_decl.name), token.clone());
+
+        self.collect_type_info_token(
+            &token,
+            &const_decl.type_ascription,
+            const_decl.type_ascription_span.clone(),
+            None,
+        );
+        if let Some(value) = &const_decl.value {
+            self.handle_expression(value);
+        }
+    }
+
+    fn collect_trait_type_decl(&self, type_decl: &TraitTypeDeclaration, declaration: &Declaration) {
+        let token = Token::from_parsed(
+            AstToken::Declaration(declaration.clone()),
+            SymbolKind::TypeAlias,
+        );
+        self.tokens
+            .insert(to_ident_key(&type_decl.name), token.clone());
+
+        if let Some(ty) = &type_decl.ty_opt {
+            self.collect_type_arg(ty, &token);
+        }
+    }
+
     fn collect_trait_fn(&self, trait_fn: &TraitFn) {
         let token = Token::from_parsed(AstToken::TraitFn(trait_fn.clone()), SymbolKind::Function);
         self.tokens
@@ -11,7 +11,7 @@ use sway_core::{
     language::ty::{self, TyEnumVariant},
     Engines, TypeId, TypeInfo,
 };
-use sway_types::{Ident, Span, Spanned};
+use sway_types::{constants::CLOSURE_FN_NAME, Ident, Span, Spanned};
 
 pub struct TypedTree<'a> {
     engines: Engines<'a>,
@@ -69,7 +69,40 @@ impl<'a> TypedTree<'a> {
                         token.typed = Some(TypedAstToken::TypedDeclaration(declaration.clone()));
                         token.type_def = Some(TypeDefinition::Ident(const_decl.name.clone()));
                     }
-                    self.handle_expression(&const_decl.value);
+                    if let Some(value) = &const_decl.value {
+                        self.handle_expression(value);
+                    }
+                }
+            }
+            ty::TyDeclaration::TypeAliasDeclaration(decl_id) => {
+                if let Ok(type_alias_decl) =
+                    declaration_engine.get_type_alias(decl_id.clone(), &decl_id.span())
+                {
+                    let typed_token = TypedAstToken::TypedDeclaration(declaration.clone());
+                    if let Some(mut token) = self
+                        .tokens
+                        .try_get_mut(&to_ident_key(&type_alias_decl.name))
+                        .try_unwrap()
+                    {
+                        token.typed = Some(typed_token.clone());
+                        token.type_def = Some(TypeDefinition::Ident(type_alias_decl.name.clone()));
+                    }
+                    self.collect_type_id(
+                        type_alias_decl.ty.type_id,
+                        &typed_token,
+                        type_alias_decl.ty.span.clone(),
+                    );
+
+                    for type_param in &type_alias_decl.type_parameters {
+                        if let Some(mut token) = self
+                            .tokens
+                            .try_get_mut(&to_ident_key(&type_param.name_ident))
+                            .try_unwrap()
+                        {
+                            token.typed = Some(typed_token.clone());
+                            token.type_def = Some(TypeDefinition::TypeId(type_param.type_id));
+                        }
+                    }
                 }
             }
             ty::TyDeclaration::FunctionDeclaration(decl_id) => {
@@ -354,6 +387,23 @@ impl<'a> TypedTree<'a> {
                     token.type_def = Some(TypeDefinition::Ident(name.clone()));
                 }
             }
+            ty::TyExpressionVariant::AssociatedConstant {
+                call_path,
+                const_decl_id,
+            } => {
+                if let Some(mut token) = self
+                    .tokens
+                    .try_get_mut(&to_ident_key(&call_path.suffix))
+                    .try_unwrap()
+                {
+                    token.typed = Some(TypedAstToken::TypedExpression(expression.clone()));
+                    if let Ok(const_decl) =
+                        declaration_engine.get_constant(const_decl_id.clone(), &call_path.span())
+                    {
+                        token.type_def = Some(TypeDefinition::Ident(const_decl.name));
+                    }
+                }
+            }
             ty::TyExpressionVariant::Tuple { fields } => {
                 for exp in fields {
                     self.handle_expression(exp);
@@ -368,7 +418,9 @@ impl<'a> TypedTree<'a> {
                 self.handle_expression(prefix);
                 self.handle_expression(index);
             }
-            ty::TyExpressionVariant::StructExpression { fields, span, .. } => {
+            ty::TyExpressionVariant::StructExpression {
+                fields, base, span, ..
+            } => {
                 if let Some(mut token) = self
                     .tokens
                     .try_get_mut(&to_ident_key(&Ident::new(span.clone())))
@@ -400,6 +452,9 @@ impl<'a> TypedTree<'a> {
                     }
                     self.handle_expression(&field.value);
                 }
+                if let Some(base) = base {
+                    self.handle_expression(base);
+                }
             }
             ty::TyExpressionVariant::CodeBlock(code_block) => {
                 for node in &code_block.contents {
@@ -572,7 +627,30 @@ impl<'a> TypedTree<'a> {
                 }
                 self.handle_expression(&storage_reassignment.rhs);
             }
-            ty::TyExpressionVariant::Return(exp) => self.handle_expression(exp),
+            ty::TyExpressionVariant::Return(exp)
+            | ty::TyExpressionVariant::Ref(exp)
+            | ty::TyExpressionVariant::Deref(exp) => self.handle_expression(exp),
+            ty::TyExpressionVariant::Closure { decl_id, captures } => {
+                for exp in captures {
+                    self.handle_expression(exp);
+                }
+                if let Ok(function_decl) =
+                    declaration_engine.get_function(decl_id.clone(), &expression.span)
+                {
+                    // functions used as values are traversed where they are declared
+                    if function_decl.name.as_str() == CLOSURE_FN_NAME {
+                        for node in &function_decl.body.contents {
+                            self.traverse_node(node);
+                        }
+                    }
+                }
+            }
+            ty::TyExpressionVariant::ClosureCall { closure, arguments } => {
+                self.handle_expression(closure);
+                for (_, exp) in arguments {
+                    self.handle_expression(exp);
+                }
+            }
         }
     }
 
@@ -636,7 +714,7 @@ impl<'a> TypedTree<'a> {
         let type_info = type_engine.look_up_type_id(type_id);
         let symbol_kind = type_info_to_symbol_kind(type_engine, &type_info);
         match &type_info {
-            TypeInfo::Array(type_arg, ..) => {
+            TypeInfo::Array(type_arg, ..) | TypeInfo::Ref(type_arg) => {
                 self.collect_type_id(
                     type_arg.type_id,
                     &TypedAstToken::TypedArgument(type_arg.clone()),
@@ -26,6 +26,26 @@ pub(crate) fn doc_comments_dir() -> PathBuf {
         .join("doc_comments")
 }
 
+pub(crate) fn is_prime_dir() -> PathBuf {
+    sway_workspace_dir()
+        .join(e2e_language_dir())
+        .join("is_prime")
+}
+
+pub(crate) fn test_attribute_dir() -> PathBuf {
+    sway_workspace_dir()
+        .join(e2e_language_dir())
+        .join("test_attribute")
+}
+
+pub(crate) fn code_actions_dir() -> PathBuf {
+    sway_workspace_dir().join("sway-lsp/test/fixtures/code_actions")
+}
+
+pub(crate) fn call_hierarchy_dir() -> PathBuf {
+    sway_workspace_dir().join("sway-lsp/test/fixtures/call_hierarchy")
+}
+
 pub(crate) fn get_absolute_path(path: &str) -> String {
     sway_workspace_dir().join(path).to_str().unwrap().into()
 }
@@ -0,0 +1,20 @@
+contract;
+
+abi MyAbi {
+    fn value() -> u64;
+}
+
+impl MyAbi for Contract {
+    fn value() -> u64 {
+        42
+    }
+}
+
+fn contract_id() -> b256 {
+    0x0000000000000000000000000000000000000000000000000000000000000001
+}
+
+fn call_value() -> u64 {
+    let my_abi = abi(MyAbi, contract_id());
+    my_abi.value()
+}
@@ -0,0 +1,6 @@
+library labels;
+
+pub trait Describe {
+    fn name(self) -> u64;
+    fn id(self) -> u64;
+}
@@ -0,0 +1,31 @@
+script;
+
+dep shapes;
+dep labels;
+
+enum Color {
+    Red: (),
+    Green: (),
+}
+
+impl labels::Describe for u64 {
+    fn name(self) -> u64 {
+        self
+    }
+}
+
+fn shade(color: Color) -> u64 {
+    match color {
+        Color::Red => 1,
+    }
+}
+
+fn origin() -> u64 {
+    let point = Point { x: 0, y: 0 };
+    point.x
+}
+
+fn main() -> u64 {
+    let bytes = Bytes::new();
+    shade(Color::Green)
+}
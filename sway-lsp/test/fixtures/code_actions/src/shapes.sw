@@ -0,0 +1,10 @@
+library shapes;
+
+pub struct Point {
+    x: u64,
+    y: u64,
+}
+
+pub trait Describe {
+    fn describe(self) -> u64;
+}
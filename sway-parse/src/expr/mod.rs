@@ -4,8 +4,9 @@ use core::ops::ControlFlow;
 use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::expr::{ReassignmentOp, ReassignmentOpVariant};
 use sway_ast::keywords::{
-    AbiToken, AddEqToken, AsmToken, CommaToken, ConstToken, DivEqToken, DoubleColonToken,
-    EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken, LetToken, OpenAngleBracketToken,
+    AbiToken, AddEqToken, AmpersandEqToken, AsmToken, CaretEqToken, CommaToken, ConstToken,
+    DivEqToken, DoubleColonToken, DoubleStarEqToken, EnumToken, EqToken, FalseToken, FnToken,
+    IfToken, ImplToken, LetToken, OpenAngleBracketToken, PercentEqToken, PipeEqToken, PipeToken,
     PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken, StorageToken, StructToken,
     SubEqToken, Token, TraitToken, TrueToken, UseToken,
 };
@@ -13,9 +14,10 @@ use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
 use sway_ast::token::Delimiter;
 use sway_ast::{
-    AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-    ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, LitInt, Literal, MatchBranch,
-    MatchBranchKind, PathExprSegment, Statement, StatementLet,
+    AbiCastArgs, ClosureParam, ClosureParams, CodeBlockContents, Expr, ExprArrayDescriptor,
+    ExprStructField, ExprStructFields, ExprTupleDescriptor, ForIterable, GenericArgs, IfCondition,
+    IfExpr, LitInt, Literal, MatchBranch, MatchBranchKind, PathExprSegment, Statement,
+    StatementLet,
 };
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::{Ident, Span, Spanned};
@@ -231,6 +233,22 @@ fn parse_condition(parser: &mut Parser) -> ParseResult<Expr> {
     parse_reassignment(parser, ctx)
 }
 
+fn parse_for_iterable(parser: &mut Parser) -> ParseResult<ForIterable> {
+    let start = parse_condition(parser)?;
+    match parser.take() {
+        Some(double_dot_token) => {
+            let start = Box::new(start);
+            let end = Box::new(parse_condition(parser)?);
+            Ok(ForIterable::Range {
+                start,
+                double_dot_token,
+                end,
+            })
+        }
+        None => Ok(ForIterable::Expr(Box::new(start))),
+    }
+}
+
 fn parse_statement_expr(parser: &mut Parser) -> ParseResult<Expr> {
     let ctx = ParseExprCtx {
         parsing_conditional: false,
@@ -253,6 +271,16 @@ fn take_reassignment_op(parser: &mut Parser) -> Option<ReassignmentOp> {
         (ReassignmentOpVariant::ShlEquals, shl_eq_token.span())
     } else if let Some(shr_eq_token) = parser.take::<ShrEqToken>() {
         (ReassignmentOpVariant::ShrEquals, shr_eq_token.span())
+    } else if let Some(mod_eq_token) = parser.take::<PercentEqToken>() {
+        (ReassignmentOpVariant::ModEquals, mod_eq_token.span())
+    } else if let Some(bit_and_eq_token) = parser.take::<AmpersandEqToken>() {
+        (ReassignmentOpVariant::BitAndEquals, bit_and_eq_token.span())
+    } else if let Some(bit_or_eq_token) = parser.take::<PipeEqToken>() {
+        (ReassignmentOpVariant::BitOrEquals, bit_or_eq_token.span())
+    } else if let Some(bit_xor_eq_token) = parser.take::<CaretEqToken>() {
+        (ReassignmentOpVariant::BitXorEquals, bit_xor_eq_token.span())
+    } else if let Some(pow_eq_token) = parser.take::<DoubleStarEqToken>() {
+        (ReassignmentOpVariant::PowEquals, pow_eq_token.span())
     } else if let Some(eq_token) = parser.take::<EqToken>() {
         (ReassignmentOpVariant::Equals, eq_token.span())
     } else {
@@ -511,6 +539,13 @@ fn parse_projection(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr>
             expr = Expr::Index { target, arg };
             continue;
         }
+        if let Some(question_mark_token) = parser.take() {
+            expr = Expr::Try {
+                expr: Box::new(expr),
+                question_mark_token,
+            };
+            continue;
+        }
         if let Some(dot_token) = parser.take() {
             let target = Box::new(expr);
 
@@ -699,6 +734,38 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             block,
         });
     }
+    if let Some(for_token) = parser.take() {
+        let value_pattern = parser.parse()?;
+        let in_token = parser.parse()?;
+        let iterable = parse_for_iterable(parser)?;
+        let block = parser.parse()?;
+        return Ok(Expr::For {
+            for_token,
+            value_pattern,
+            in_token,
+            iterable,
+            block,
+        });
+    }
+    if let Some(double_pipe_token) = parser.take() {
+        let body = Box::new(parser.parse()?);
+        return Ok(Expr::Closure {
+            params: ClosureParams::Empty { double_pipe_token },
+            body,
+        });
+    }
+    if let Some(open_pipe_token) = parser.take() {
+        let (params, close_pipe_token) = parse_closure_params(parser)?;
+        let body = Box::new(parser.parse()?);
+        return Ok(Expr::Closure {
+            params: ClosureParams::List {
+                open_pipe_token,
+                params,
+                close_pipe_token,
+            },
+            body,
+        });
+    }
     if parser.peek::<OpenAngleBracketToken>().is_some()
         || parser.peek::<DoubleColonToken>().is_some()
         || parser.peek::<Ident>().is_some()
@@ -717,6 +784,45 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     Err(parser.emit_error(ParseErrorKind::ExpectedExpression))
 }
 
+/// Parses the parameters of a closure up to and including the closing pipe.
+fn parse_closure_params(
+    parser: &mut Parser,
+) -> ParseResult<(Punctuated<ClosureParam, CommaToken>, PipeToken)> {
+    let mut value_separator_pairs = Vec::new();
+    loop {
+        if let Some(close_pipe_token) = parser.take() {
+            let params = Punctuated {
+                value_separator_pairs,
+                final_value_opt: None,
+            };
+            return Ok((params, close_pipe_token));
+        }
+        let param = parser.parse()?;
+        match parser.take() {
+            Some(comma_token) => value_separator_pairs.push((param, comma_token)),
+            None => {
+                let close_pipe_token = parser.parse()?;
+                let params = Punctuated {
+                    value_separator_pairs,
+                    final_value_opt: Some(Box::new(param)),
+                };
+                return Ok((params, close_pipe_token));
+            }
+        }
+    }
+}
+
+impl Parse for ClosureParam {
+    fn parse(parser: &mut Parser) -> ParseResult<ClosureParam> {
+        let name = parser.parse()?;
+        let ty_opt = match parser.take() {
+            Some(colon_token) => Some((colon_token, parser.parse()?)),
+            None => None,
+        };
+        Ok(ClosureParam { name, ty_opt })
+    }
+}
+
 impl Parse for ExprStructField {
     fn parse(parser: &mut Parser) -> ParseResult<ExprStructField> {
         let field_name = parser.parse()?;
@@ -734,6 +840,42 @@ impl Parse for ExprStructField {
     }
 }
 
+impl ParseToEnd for ExprStructFields {
+    fn parse_to_end<'a, 'e>(
+        mut parser: Parser<'a, 'e>,
+    ) -> ParseResult<(ExprStructFields, ParserConsumed<'a>)> {
+        let mut value_separator_pairs = Vec::new();
+        let mut final_value_opt = None;
+        let mut base_opt = None;
+        loop {
+            if let Some(double_dot_token) = parser.take() {
+                let base = parser.parse()?;
+                base_opt = Some((double_dot_token, base));
+                break;
+            }
+            if parser.check_empty().is_some() {
+                break;
+            }
+            let field = parser.parse()?;
+            if parser.check_empty().is_some() {
+                final_value_opt = Some(Box::new(field));
+                break;
+            }
+            let comma_token = parser.parse()?;
+            value_separator_pairs.push((field, comma_token));
+        }
+        let consumed = match parser.check_empty() {
+            Some(consumed) => consumed,
+            None => return Err(parser.emit_error(ParseErrorKind::UnexpectedTokenAfterStructBase)),
+        };
+        let fields = Punctuated {
+            value_separator_pairs,
+            final_value_opt,
+        };
+        Ok((ExprStructFields { fields, base_opt }, consumed))
+    }
+}
+
 impl ParseToEnd for ExprArrayDescriptor {
     fn parse_to_end<'a, 'e>(
         mut parser: Parser<'a, 'e>,
@@ -779,10 +921,15 @@ impl ParseToEnd for ExprArrayDescriptor {
 impl Parse for MatchBranch {
     fn parse(parser: &mut Parser) -> ParseResult<MatchBranch> {
         let pattern = parser.parse()?;
+        let guard_opt = match parser.take() {
+            Some(if_token) => Some((if_token, parser.parse()?)),
+            None => None,
+        };
         let fat_right_arrow_token = parser.parse()?;
         let kind = parser.parse()?;
         Ok(MatchBranch {
             pattern,
+            guard_opt,
             fat_right_arrow_token,
             kind,
         })
@@ -14,16 +14,20 @@ impl Parse for ItemConst {
             }
             None => None,
         };
-        let eq_token = parser.parse()?;
-        let expr = parser.parse()?;
+        let expr_opt = match parser.take() {
+            Some(eq_token) => {
+                let expr = parser.parse()?;
+                Some((eq_token, expr))
+            }
+            None => None,
+        };
         let semicolon_token = parser.parse()?;
         Ok(ItemConst {
             visibility,
             const_token,
             name,
             ty_opt,
-            eq_token,
-            expr,
+            expr_opt,
             semicolon_token,
         })
     }
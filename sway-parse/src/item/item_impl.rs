@@ -1,8 +1,27 @@
 use crate::{Parse, ParseResult, Parser};
 
 use sway_ast::attribute::Annotated;
-use sway_ast::keywords::{OpenAngleBracketToken, WhereToken};
-use sway_ast::{Braces, ItemFn, ItemImpl, Ty};
+use sway_ast::keywords::{ConstToken, OpenAngleBracketToken, TypeToken, WhereToken};
+use sway_ast::{Braces, ItemConst, ItemImpl, ItemImplItem, ItemTypeAlias, Ty};
+
+impl Parse for ItemImplItem {
+    fn parse(parser: &mut Parser) -> ParseResult<ItemImplItem> {
+        let visibility = parser.take();
+        let mut item = if let Some(item) = parser.guarded_parse::<ConstToken, ItemConst>()? {
+            ItemImplItem::Const(item)
+        } else if let Some(item) = parser.guarded_parse::<TypeToken, ItemTypeAlias>()? {
+            ItemImplItem::Type(item)
+        } else {
+            ItemImplItem::Fn(parser.parse()?)
+        };
+        match &mut item {
+            ItemImplItem::Fn(item_fn) => item_fn.fn_signature.visibility = visibility,
+            ItemImplItem::Const(item_const) => item_const.visibility = visibility,
+            ItemImplItem::Type(item_type_alias) => item_type_alias.visibility = visibility,
+        }
+        Ok(item)
+    }
+}
 
 impl Parse for ItemImpl {
     fn parse(parser: &mut Parser) -> ParseResult<ItemImpl> {
@@ -14,10 +33,15 @@ impl Parse for ItemImpl {
             None => (None, Ty::Path(path_type)),
         };
         let where_clause_opt = parser.guarded_parse::<WhereToken, _>()?;
-        let contents: Braces<Vec<Annotated<ItemFn>>> = parser.parse()?;
+        let contents: Braces<Vec<Annotated<ItemImplItem>>> = parser.parse()?;
         if trait_opt.is_some() {
-            for item_fn in contents.get().iter() {
-                parser.ban_visibility_qualifier(&item_fn.value.fn_signature.visibility)?;
+            for item in contents.get().iter() {
+                let visibility = match &item.value {
+                    ItemImplItem::Fn(item_fn) => &item_fn.fn_signature.visibility,
+                    ItemImplItem::Const(item_const) => &item_const.visibility,
+                    ItemImplItem::Type(item_type_alias) => &item_type_alias.visibility,
+                };
+                parser.ban_visibility_qualifier(visibility)?;
             }
         }
         Ok(ItemImpl {
@@ -1,8 +1,43 @@
 use crate::{Parse, ParseBracket, ParseResult, Parser};
 
 use sway_ast::attribute::Annotated;
-use sway_ast::keywords::{OpenAngleBracketToken, WhereToken};
-use sway_ast::{Braces, FnSignature, ItemFn, ItemTrait, Traits};
+use sway_ast::keywords::{ConstToken, OpenAngleBracketToken, TypeToken, WhereToken};
+use sway_ast::{Braces, ItemConst, ItemFn, ItemTrait, ItemTraitItem, TraitType, Traits};
+
+impl Parse for ItemTraitItem {
+    fn parse(parser: &mut Parser) -> ParseResult<ItemTraitItem> {
+        if let Some(item) = parser.guarded_parse::<ConstToken, ItemConst>()? {
+            Ok(ItemTraitItem::Const(item))
+        } else if let Some(item) = parser.guarded_parse::<TypeToken, TraitType>()? {
+            Ok(ItemTraitItem::Type(item))
+        } else {
+            let fn_signature = parser.parse()?;
+            let semicolon_token = parser.parse()?;
+            Ok(ItemTraitItem::Fn(fn_signature, semicolon_token))
+        }
+    }
+}
+
+impl Parse for TraitType {
+    fn parse(parser: &mut Parser) -> ParseResult<TraitType> {
+        let type_token = parser.parse()?;
+        let name = parser.parse()?;
+        let ty_opt = match parser.take() {
+            Some(eq_token) => {
+                let ty = parser.parse()?;
+                Some((eq_token, ty))
+            }
+            None => None,
+        };
+        let semicolon_token = parser.parse()?;
+        Ok(TraitType {
+            type_token,
+            name,
+            ty_opt,
+            semicolon_token,
+        })
+    }
+}
 
 impl Parse for ItemTrait {
     fn parse(parser: &mut Parser) -> ParseResult<ItemTrait> {
@@ -19,10 +54,17 @@ impl Parse for ItemTrait {
         };
         let where_clause_opt = parser.guarded_parse::<WhereToken, _>()?;
 
-        let trait_items: Braces<Vec<(Annotated<FnSignature>, _)>> = parser.parse()?;
+        let trait_items: Braces<Vec<Annotated<ItemTraitItem>>> = parser.parse()?;
         for item in trait_items.get().iter() {
-            let (fn_sig, _) = item;
-            parser.ban_visibility_qualifier(&fn_sig.value.visibility)?;
+            match &item.value {
+                ItemTraitItem::Fn(fn_sig, _) => {
+                    parser.ban_visibility_qualifier(&fn_sig.visibility)?
+                }
+                ItemTraitItem::Const(item_const) => {
+                    parser.ban_visibility_qualifier(&item_const.visibility)?
+                }
+                ItemTraitItem::Type(_) => {}
+            }
         }
 
         let trait_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>> = Braces::try_parse(parser)?;
@@ -0,0 +1,18 @@
+use crate::{Parse, ParseResult, Parser};
+
+use sway_ast::keywords::OpenAngleBracketToken;
+use sway_ast::ItemTypeAlias;
+
+impl Parse for ItemTypeAlias {
+    fn parse(parser: &mut Parser) -> ParseResult<ItemTypeAlias> {
+        Ok(ItemTypeAlias {
+            visibility: parser.take(),
+            type_token: parser.parse()?,
+            name: parser.parse()?,
+            generics: parser.guarded_parse::<OpenAngleBracketToken, _>()?,
+            eq_token: parser.parse()?,
+            ty: parser.parse()?,
+            semicolon_token: parser.parse()?,
+        })
+    }
+}
@@ -2,12 +2,12 @@ use crate::{Parse, ParseResult, ParseToEnd, Parser, ParserConsumed};
 
 use sway_ast::keywords::{
     AbiToken, ClassToken, ConstToken, DepToken, EnumToken, FnToken, ImplToken, MutToken,
-    OpenAngleBracketToken, RefToken, SelfToken, StorageToken, StructToken, TraitToken, UseToken,
-    WhereToken,
+    OpenAngleBracketToken, RefToken, SelfToken, StorageToken, StructToken, TraitToken, TypeToken,
+    UseToken, WhereToken,
 };
 use sway_ast::{
     Dependency, FnArg, FnArgs, FnSignature, ItemConst, ItemEnum, ItemFn, ItemKind, ItemStruct,
-    ItemTrait, ItemUse, TypeField,
+    ItemTrait, ItemTypeAlias, ItemUse, TypeField,
 };
 use sway_error::parser_error::ParseErrorKind;
 
@@ -19,6 +19,7 @@ mod item_impl;
 mod item_storage;
 mod item_struct;
 mod item_trait;
+mod item_type_alias;
 mod item_use;
 
 impl Parse for ItemKind {
@@ -58,6 +59,9 @@ impl Parse for ItemKind {
             ItemKind::Const(item)
         } else if let Some(item) = parser.guarded_parse::<StorageToken, _>()? {
             ItemKind::Storage(item)
+        } else if let Some(mut item) = parser.guarded_parse::<TypeToken, ItemTypeAlias>()? {
+            item.visibility = visibility.take();
+            ItemKind::TypeAlias(item)
         } else {
             return Err(parser.emit_error(ParseErrorKind::ExpectedAnItem));
         };
@@ -164,7 +168,7 @@ impl Parse for FnSignature {
 mod tests {
     use super::*;
     use std::sync::Arc;
-    use sway_ast::{AttributeDecl, Item};
+    use sway_ast::{AttributeDecl, Item, ItemImplItem, ItemTraitItem, Literal};
 
     fn parse_item(input: &str) -> Item {
         let handler = <_>::default();
@@ -188,9 +192,9 @@ mod tests {
                     .map(|att| {
                         (
                             att.name.as_str(),
-                            att.args
-                                .as_ref()
-                                .map(|arg| arg.get().into_iter().map(|a| a.as_str()).collect()),
+                            att.args.as_ref().map(|arg| {
+                                arg.get().into_iter().map(|a| a.name.as_str()).collect()
+                            }),
                         )
                     })
                     .collect()
@@ -445,6 +449,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_attributes_fn_arg_with_value() {
+        let item = parse_item(
+            r#"
+            #[cfg(test, feature = "foo")]
+            fn f() -> bool {
+                false
+            }
+            "#,
+        );
+
+        assert!(matches!(item.value, ItemKind::Fn(_)));
+        assert_eq!(
+            attributes(&item.attribute_list),
+            vec![[("cfg", Some(vec!["test", "feature"]))]]
+        );
+        let attr = item.attribute_list[0]
+            .attribute
+            .get()
+            .into_iter()
+            .next()
+            .unwrap();
+        let args: Vec<_> = attr.args.as_ref().unwrap().get().into_iter().collect();
+        assert!(args[0].value.is_none());
+        assert!(matches!(
+            &args[1].value,
+            Some((_, Literal::String(lit))) if lit.parsed == "foo"
+        ));
+    }
+
     #[test]
     fn parse_attributes_trait() {
         let item = parse_item(
@@ -472,7 +506,7 @@ mod tests {
             let f_sig = decls.next();
             assert!(f_sig.is_some());
             assert_eq!(
-                attributes(&f_sig.unwrap().0.attribute_list),
+                attributes(&f_sig.unwrap().attribute_list),
                 vec![[("foo", Some(vec!["one"]))], [("bar", None)]]
             );
             assert!(decls.next().is_none());
@@ -574,4 +608,84 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn parse_type_alias() {
+        let item = parse_item(
+            r#"
+            /// A pair of values.
+            pub type Pair<T> = (T, T);
+            "#,
+        );
+
+        assert_eq!(
+            attributes(&item.attribute_list),
+            vec![[("doc-comment", Some(vec![" A pair of values."]))]]
+        );
+        match item.value {
+            ItemKind::TypeAlias(item_type_alias) => {
+                assert!(item_type_alias.visibility.is_some());
+                assert_eq!(item_type_alias.name.as_str(), "Pair");
+                assert!(item_type_alias.generics.is_some());
+                assert!(matches!(item_type_alias.ty, sway_ast::Ty::Tuple(_)));
+            }
+            _ => panic!("Parsed item is not a type alias."),
+        }
+    }
+
+    #[test]
+    fn parse_associated_items() {
+        let item = parse_item(
+            r#"
+            trait Token {
+                const DECIMALS: u8;
+                const SYMBOL: str[3] = "TKN";
+                type Balance;
+                fn total_supply() -> Self::Balance;
+            }
+            "#,
+        );
+        match item.value {
+            ItemKind::Trait(item_trait) => {
+                let items = item_trait.trait_items.get();
+                assert_eq!(items.len(), 4);
+                assert!(matches!(
+                    &items[0].value,
+                    ItemTraitItem::Const(item_const) if item_const.expr_opt.is_none()
+                ));
+                assert!(matches!(
+                    &items[1].value,
+                    ItemTraitItem::Const(item_const) if item_const.expr_opt.is_some()
+                ));
+                assert!(matches!(
+                    &items[2].value,
+                    ItemTraitItem::Type(trait_type) if trait_type.ty_opt.is_none()
+                ));
+                assert!(matches!(&items[3].value, ItemTraitItem::Fn(..)));
+            }
+            _ => panic!("Parsed item is not a trait."),
+        }
+
+        let item = parse_item(
+            r#"
+            impl Token for Coin {
+                const DECIMALS: u8 = 9;
+                type Balance = u64;
+                fn total_supply() -> u64 {
+                    0
+                }
+            }
+            "#,
+        );
+        match item.value {
+            ItemKind::Impl(item_impl) => {
+                let items = item_impl.contents.get();
+                assert_eq!(items.len(), 3);
+                assert!(matches!(&items[0].value, ItemImplItem::Const(_)));
+                assert!(matches!(&items[1].value, ItemImplItem::Type(_)));
+                assert!(matches!(&items[2].value, ItemImplItem::Fn(_)));
+            }
+            _ => panic!("Parsed item is not an impl."),
+        }
+    }
 }
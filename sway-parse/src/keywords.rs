@@ -53,6 +53,7 @@ keyword_impls! {
     ForToken,
     AbiToken,
     ConstToken,
+    TypeToken,
     StorageToken,
     StrToken,
     AsmToken,
@@ -63,6 +64,7 @@ keyword_impls! {
     MutToken,
     LetToken,
     WhileToken,
+    InToken,
     WhereToken,
     RefToken,
     DerefToken,
@@ -127,6 +129,11 @@ token_impls! {
     DivEqToken,
     ShlEqToken,
     ShrEqToken,
+    PercentEqToken,
+    AmpersandEqToken,
+    PipeEqToken,
+    CaretEqToken,
+    DoubleStarEqToken,
     FatRightArrowToken,
     DotToken,
     DoubleDotToken,
@@ -146,7 +153,8 @@ token_impls! {
     DoubleAmpersandToken,
     DoublePipeToken,
     UnderscoreToken,
-    HashToken
+    HashToken,
+    QuestionMarkToken
 }
 
 // Keep this in sync with the list in `sway-ast/keywords.rs` defined by define_keyword!
@@ -168,6 +176,7 @@ pub(crate) const RESERVED_KEYWORDS: phf::Set<&'static str> = phf::phf_set! {
     "for",
     "abi",
     "const",
+    "type",
     "storage",
     "str",
     "asm",
@@ -178,6 +187,7 @@ pub(crate) const RESERVED_KEYWORDS: phf::Set<&'static str> = phf::phf_set! {
     "mut",
     "let",
     "while",
+    "in",
     "where",
     "ref",
     "deref",
@@ -57,6 +57,7 @@ impl CharExt for char {
             '|' => Some(PunctKind::Pipe),
             '_' => Some(PunctKind::Underscore),
             '#' => Some(PunctKind::Sharp),
+            '?' => Some(PunctKind::QuestionMark),
             _ => None,
         }
     }
@@ -43,6 +43,25 @@ impl Parse for Ty {
         if let Some(underscore_token) = parser.take() {
             return Ok(Ty::Infer { underscore_token });
         }
+        if let Some(ampersand_token) = parser.take() {
+            let ty = Box::new(parser.parse()?);
+            return Ok(Ty::Ref {
+                ampersand_token,
+                ty,
+            });
+        }
+        if let Some(fn_token) = parser.take() {
+            let arguments = parser.parse()?;
+            let return_type_opt = match parser.take() {
+                Some(right_arrow_token) => Some((right_arrow_token, Box::new(parser.parse()?))),
+                None => None,
+            };
+            return Ok(Ty::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            });
+        }
         if parser.peek::<OpenAngleBracketToken>().is_some()
             || parser.peek::<DoubleColonToken>().is_some()
             || parser.peek::<Ident>().is_some()
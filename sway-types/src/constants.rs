@@ -27,6 +27,20 @@ pub const DESTRUCTURE_PREFIX: &str = "__destructure_";
 /// The default prefix for the compiler generated names of match
 pub const MATCH_RETURN_VAR_NAME_PREFIX: &str = "__match_return_var_name_";
 
+/// The default prefixes for the compiler generated names of the variables of `for` loops
+pub const FOR_LOOP_ITERABLE_VAR_NAME_PREFIX: &str = "__for_iterable_";
+pub const FOR_LOOP_INDEX_VAR_NAME_PREFIX: &str = "__for_index_";
+pub const FOR_LOOP_NEXT_VAR_NAME_PREFIX: &str = "__for_next_";
+pub const FOR_LOOP_ELEMENT_VAR_NAME_PREFIX: &str = "__for_element_";
+
+/// The compiler generated names of the variables bound by the `?` operator
+pub const TRY_SCRUTINEE_VAR_NAME: &str = "__try_scrutinee";
+pub const TRY_VALUE_VAR_NAME: &str = "__try_value";
+pub const TRY_ERROR_VAR_NAME: &str = "__try_error";
+
+/// The compiler generated name of the functions closures are lifted into
+pub const CLOSURE_FN_NAME: &str = "__closure";
+
 /// The valid attribute strings related to storage and purity.
 pub const STORAGE_PURITY_ATTRIBUTE_NAME: &str = "storage";
 pub const STORAGE_PURITY_READ_NAME: &str = "read";
@@ -49,6 +63,16 @@ pub const TEST_ATTRIBUTE_NAME: &str = "test";
 /// The valid attribute string used for payable functions.
 pub const PAYABLE_ATTRIBUTE_NAME: &str = "payable";
 
+/// The valid attribute strings used to set lint levels.
+pub const ALLOW_ATTRIBUTE_NAME: &str = "allow";
+pub const WARN_ATTRIBUTE_NAME: &str = "warn";
+pub const DENY_ATTRIBUTE_NAME: &str = "deny";
+
+/// The valid attribute strings used for conditional compilation.
+pub const CFG_ATTRIBUTE_NAME: &str = "cfg";
+pub const CFG_TEST_NAME: &str = "test";
+pub const CFG_FEATURE_NAME: &str = "feature";
+
 /// The list of valid attributes.
 pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
     STORAGE_PURITY_ATTRIBUTE_NAME,
@@ -57,4 +81,8 @@ pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
     TEST_ATTRIBUTE_NAME,
     INLINE_ATTRIBUTE_NAME,
     PAYABLE_ATTRIBUTE_NAME,
+    ALLOW_ATTRIBUTE_NAME,
+    WARN_ATTRIBUTE_NAME,
+    DENY_ATTRIBUTE_NAME,
+    CFG_ATTRIBUTE_NAME,
 ];
@@ -137,6 +137,22 @@ pub const TEST: u16 = 10;
         assert!(test_stability(formatted_sway_code, formatter));
     }
 
+    #[test]
+    fn test_type_alias() {
+        let sway_code_to_format = r#"library aliases;
+pub type   Pair<T>=(T,T);
+type Balances=StorageMap<(Identity,ContractId),u64>;"#;
+        let correct_sway_code = r#"library aliases;
+pub type Pair<T> = (T, T);
+type Balances = StorageMap<(Identity, ContractId), u64>;
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert_eq!(correct_sway_code, formatted_sway_code);
+        assert!(test_stability(formatted_sway_code, formatter));
+    }
+
     #[test]
     fn test_struct_alignment() {
         let sway_code_to_format = r#"contract;
@@ -293,7 +309,7 @@ enum TestTy {
     ],
     PathType     : root::
 example::
-    type,
+    ty,
     TupleNil: (),
     Tuple: (   u64,
         u32
@@ -305,7 +321,7 @@ enum TestTy {
     Infer: _,
     Array: [u8; 40],
     String: str[4],
-    PathType: root::example::type,
+    PathType: root::example::ty,
     TupleNil: (),
     Tuple: (u64, u32),
 }
@@ -1250,6 +1266,53 @@ pub trait Foo {
     fn bar(self, other: Self) {}
 }
 
+fn main() {}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert_eq!(correct_sway_code, formatted_sway_code);
+        assert!(test_stability(formatted_sway_code, formatter));
+    }
+
+    #[test]
+    fn test_associated_consts_and_types() {
+        let sway_code_to_format = r#"script;
+
+trait Token {
+    const DECIMALS  :u8;
+    const SUPPLY: u64=1000;
+    type Item;
+    type Index  =  u64;
+    fn first(self) -> Self::Item;
+}
+
+impl Token for Foo {
+    const DECIMALS: u8 =   9;
+    type Item=bool;
+    fn first(self) -> bool { true }
+}
+
+fn main() {}
+"#;
+        let correct_sway_code = r#"script;
+
+trait Token {
+    const DECIMALS: u8;
+    const SUPPLY: u64 = 1000;
+    type Item;
+    type Index = u64;
+    fn first(self) -> Self::Item;
+}
+
+impl Token for Foo {
+    const DECIMALS: u8 = 9;
+    type Item = bool;
+    fn first(self) -> bool {
+        true
+    }
+}
+
 fn main() {}
 "#;
         let mut formatter = Formatter::default();
@@ -1375,6 +1438,27 @@ fn foo() {}
 #[test]
 #[inline(always)]
 fn foo() {}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert_eq!(correct_sway_code, formatted_sway_code);
+        assert!(test_stability(formatted_sway_code, formatter));
+    }
+
+    #[test]
+    fn test_attribute_args_with_values() {
+        let sway_code_to_format = r#"library my_lib;
+
+#[cfg(test,feature="foo")]
+#[cfg( feature  =  "bar" )]
+fn foo() {}
+"#;
+        let correct_sway_code = r#"library my_lib;
+
+#[cfg(test, feature = "foo")]
+#[cfg(feature = "bar")]
+fn foo() {}
 "#;
         let mut formatter = Formatter::default();
         let formatted_sway_code =
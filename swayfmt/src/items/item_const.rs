@@ -30,10 +30,11 @@ impl Format for ItemConst {
             ty.format(formatted_code, formatter)?;
         }
 
-        // ` = `
-        write!(formatted_code, " {} ", self.eq_token.ident().as_str())?;
-
-        self.expr.format(formatted_code, formatter)?;
+        // ` = expr`
+        if let Some((eq_token, expr)) = &self.expr_opt {
+            write!(formatted_code, " {} ", eq_token.ident().as_str())?;
+            expr.format(formatted_code, formatter)?;
+        }
         write!(formatted_code, "{}", self.semicolon_token.ident().as_str())?;
 
         Ok(())
@@ -51,8 +52,10 @@ impl LeafSpans for ItemConst {
         if let Some(ty) = &self.ty_opt {
             collected_spans.append(&mut ty.leaf_spans());
         }
-        collected_spans.push(ByteSpan::from(self.eq_token.span()));
-        collected_spans.append(&mut self.expr.leaf_spans());
+        if let Some((eq_token, expr)) = &self.expr_opt {
+            collected_spans.push(ByteSpan::from(eq_token.span()));
+            collected_spans.append(&mut expr.leaf_spans());
+        }
         collected_spans.push(ByteSpan::from(self.semicolon_token.span()));
         collected_spans
     }
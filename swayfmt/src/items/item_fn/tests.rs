@@ -147,3 +147,15 @@ fmt_test!(  fn_nested_if_lets
     };
 }"
 );
+
+fmt_test!(  fn_reference_parameter
+"fn sum(p: &Point) -> u64 {
+    let p = deref p;
+    p.x + p.y
+}",
+            intermediate_whitespace
+"fn sum ( p : & Point ) -> u64 {
+    let p = deref   p ;
+    p.x + p.y
+}"
+);
@@ -7,7 +7,7 @@ use crate::{
     },
 };
 use std::fmt::Write;
-use sway_ast::{token::Delimiter, ItemImpl};
+use sway_ast::{token::Delimiter, ItemImpl, ItemImplItem};
 use sway_types::Spanned;
 
 #[cfg(test)]
@@ -51,6 +51,22 @@ impl Format for ItemImpl {
     }
 }
 
+impl Format for ItemImplItem {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            ItemImplItem::Fn(item_fn) => item_fn.format(formatted_code, formatter),
+            ItemImplItem::Const(item_const) => item_const.format(formatted_code, formatter),
+            ItemImplItem::Type(item_type_alias) => {
+                item_type_alias.format(formatted_code, formatter)
+            }
+        }
+    }
+}
+
 impl CurlyBrace for ItemImpl {
     fn open_curly_brace(
         line: &mut FormattedCode,
@@ -114,3 +130,13 @@ impl LeafSpans for ItemImpl {
         collected_spans
     }
 }
+
+impl LeafSpans for ItemImplItem {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        match self {
+            ItemImplItem::Fn(item_fn) => item_fn.leaf_spans(),
+            ItemImplItem::Const(item_const) => item_const.leaf_spans(),
+            ItemImplItem::Type(item_type_alias) => item_type_alias.leaf_spans(),
+        }
+    }
+}
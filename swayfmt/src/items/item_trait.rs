@@ -7,7 +7,7 @@ use crate::{
     },
 };
 use std::fmt::Write;
-use sway_ast::{keywords::Token, token::Delimiter, ItemTrait, Traits};
+use sway_ast::{keywords::Token, token::Delimiter, ItemTrait, ItemTraitItem, TraitType, Traits};
 use sway_types::Spanned;
 
 impl Format for ItemTrait {
@@ -58,15 +58,15 @@ impl Format for ItemTrait {
             write!(formatted_code, " ")?;
         }
         Self::open_curly_brace(formatted_code, formatter)?;
-        for (fn_signature, semicolon_token) in self.trait_items.get() {
-            // format `Annotated<FnSignature>`
+        for trait_item in self.trait_items.get() {
+            // format `Annotated<ItemTraitItem>`
             write!(
                 formatted_code,
                 "{}",
                 formatter.shape.indent.to_string(&formatter.config)?,
             )?;
-            fn_signature.format(formatted_code, formatter)?;
-            writeln!(formatted_code, "{}", semicolon_token.ident().as_str())?;
+            trait_item.format(formatted_code, formatter)?;
+            writeln!(formatted_code)?;
         }
         formatted_code.pop(); // pop last ending newline
         Self::close_curly_brace(formatted_code, formatter)?;
@@ -88,6 +88,45 @@ impl Format for ItemTrait {
     }
 }
 
+impl Format for ItemTraitItem {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            ItemTraitItem::Fn(fn_signature, semicolon_token) => {
+                fn_signature.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", semicolon_token.ident().as_str())?;
+            }
+            ItemTraitItem::Const(item_const) => item_const.format(formatted_code, formatter)?,
+            ItemTraitItem::Type(trait_type) => trait_type.format(formatted_code, formatter)?,
+        }
+
+        Ok(())
+    }
+}
+
+impl Format for TraitType {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        // `type Name`
+        write!(formatted_code, "{} ", self.type_token.span().as_str())?;
+        self.name.format(formatted_code, formatter)?;
+        // ` = ty`
+        if let Some((eq_token, ty)) = &self.ty_opt {
+            write!(formatted_code, " {} ", eq_token.ident().as_str())?;
+            ty.format(formatted_code, formatter)?;
+        }
+        write!(formatted_code, "{}", self.semicolon_token.ident().as_str())?;
+
+        Ok(())
+    }
+}
+
 impl CurlyBrace for ItemTrait {
     fn open_curly_brace(
         line: &mut FormattedCode,
@@ -157,6 +196,35 @@ impl LeafSpans for ItemTrait {
     }
 }
 
+impl LeafSpans for ItemTraitItem {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        match self {
+            ItemTraitItem::Fn(fn_signature, semicolon_token) => {
+                let mut collected_spans = fn_signature.leaf_spans();
+                collected_spans.push(ByteSpan::from(semicolon_token.span()));
+                collected_spans
+            }
+            ItemTraitItem::Const(item_const) => item_const.leaf_spans(),
+            ItemTraitItem::Type(trait_type) => trait_type.leaf_spans(),
+        }
+    }
+}
+
+impl LeafSpans for TraitType {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = vec![
+            ByteSpan::from(self.type_token.span()),
+            ByteSpan::from(self.name.span()),
+        ];
+        if let Some((eq_token, ty)) = &self.ty_opt {
+            collected_spans.push(ByteSpan::from(eq_token.span()));
+            collected_spans.append(&mut ty.leaf_spans());
+        }
+        collected_spans.push(ByteSpan::from(self.semicolon_token.span()));
+        collected_spans
+    }
+}
+
 impl LeafSpans for Traits {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         let mut collected_spans = self.prefix.leaf_spans();
@@ -258,6 +258,10 @@ impl Format for MatchBranch {
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         self.pattern.format(formatted_code, formatter)?;
+        if let Some((if_token, guard)) = &self.guard_opt {
+            write!(formatted_code, " {} ", if_token.span().as_str())?;
+            guard.format(formatted_code, formatter)?;
+        }
         write!(
             formatted_code,
             " {} ",
@@ -397,6 +401,10 @@ impl LeafSpans for MatchBranch {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         let mut collected_spans = Vec::new();
         collected_spans.append(&mut self.pattern.leaf_spans());
+        if let Some((if_token, guard)) = &self.guard_opt {
+            collected_spans.push(ByteSpan::from(if_token.span()));
+            collected_spans.append(&mut guard.leaf_spans());
+        }
         collected_spans.push(ByteSpan::from(self.fat_right_arrow_token.span()));
         collected_spans.append(&mut self.kind.leaf_spans());
         collected_spans
@@ -14,7 +14,8 @@ use sway_ast::{
     keywords::{CommaToken, DotToken},
     punctuated::Punctuated,
     token::Delimiter,
-    Braces, CodeBlockContents, Expr, ExprStructField, MatchBranch, PathExpr, PathExprSegment,
+    Braces, ClosureParams, CodeBlockContents, Expr, ExprStructField, ExprStructFields, ForIterable,
+    MatchBranch, PathExpr, PathExprSegment,
 };
 use sway_types::Spanned;
 
@@ -61,7 +62,7 @@ impl Format for Expr {
 
                         // get the largest field size and the size of the body
                         let (field_width, body_width) =
-                            get_field_width(fields.get(), &mut formatter.clone())?;
+                            get_struct_fields_width(fields.get(), &mut formatter.clone())?;
 
                         // changes to the actual formatter
                         let expr_width = buf.chars().count();
@@ -180,6 +181,21 @@ impl Format for Expr {
                 block.get().format(formatted_code, formatter)?;
                 CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
             }
+            Self::For {
+                for_token,
+                value_pattern,
+                in_token,
+                iterable,
+                block,
+            } => {
+                write!(formatted_code, "{} ", for_token.span().as_str())?;
+                value_pattern.format(formatted_code, formatter)?;
+                write!(formatted_code, " {} ", in_token.span().as_str())?;
+                iterable.format(formatted_code, formatter)?;
+                CodeBlockContents::open_curly_brace(formatted_code, formatter)?;
+                block.get().format(formatted_code, formatter)?;
+                CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
+            }
             Self::FuncApp { func, args } => {
                 formatter.with_shape(
                     formatter.shape.with_default_code_line(),
@@ -288,6 +304,13 @@ impl Format for Expr {
                     field_span.as_str(),
                 )?;
             }
+            Self::Try {
+                expr,
+                question_mark_token,
+            } => {
+                expr.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", question_mark_token.span().as_str())?;
+            }
             Self::Ref { ref_token, expr } => {
                 write!(formatted_code, "{} ", ref_token.span().as_str())?;
                 expr.format(formatted_code, formatter)?;
@@ -554,6 +577,36 @@ impl Format for Expr {
             Self::Continue { continue_token } => {
                 write!(formatted_code, "{}", continue_token.span().as_str())?;
             }
+            Self::Closure { params, body } => {
+                match params {
+                    ClosureParams::Empty { double_pipe_token } => {
+                        write!(formatted_code, "{}", double_pipe_token.span().as_str())?;
+                    }
+                    ClosureParams::List {
+                        open_pipe_token,
+                        params,
+                        close_pipe_token,
+                    } => {
+                        write!(formatted_code, "{}", open_pipe_token.span().as_str())?;
+                        for (index, param) in params.into_iter().enumerate() {
+                            if index > 0 {
+                                write!(formatted_code, ", ")?;
+                            }
+                            write!(formatted_code, "{}", param.name.as_str())?;
+                            if let Some((colon_token, ty)) = &param.ty_opt {
+                                write!(formatted_code, "{} ", colon_token.span().as_str())?;
+                                ty.format(formatted_code, formatter)?;
+                            }
+                        }
+                        write!(formatted_code, "{}", close_pipe_token.span().as_str())?;
+                    }
+                }
+                // blocks are preceded by a space already
+                if !matches!(**body, Expr::Block(_)) {
+                    write!(formatted_code, " ")?;
+                }
+                body.format(formatted_code, formatter)?;
+            }
         }
 
         Ok(())
@@ -611,7 +664,7 @@ pub(super) fn debug_expr(
 
 fn format_expr_struct(
     path: &PathExpr,
-    fields: &Braces<Punctuated<ExprStructField, CommaToken>>,
+    fields: &Braces<ExprStructFields>,
     formatted_code: &mut FormattedCode,
     formatter: &mut Formatter,
 ) -> Result<(), FormatterError> {
@@ -714,6 +767,27 @@ fn get_field_width(
     Ok((largest_field, body_width))
 }
 
+/// Like `get_field_width`, also taking into account the base of a struct update, if any.
+fn get_struct_fields_width(
+    fields: &ExprStructFields,
+    formatter: &mut Formatter,
+) -> Result<(usize, usize), FormatterError> {
+    let (mut largest_field, mut body_width) = get_field_width(&fields.fields, formatter)?;
+    if let Some((double_dot_token, base)) = &fields.base_opt {
+        let mut buf = String::new();
+        write!(buf, "{}", double_dot_token.span().as_str())?;
+        base.format(&mut buf, formatter)?;
+        let base_length = buf.chars().count();
+        body_width += base_length + 2; // accounting for the comma before it and the following space
+
+        if base_length > largest_field {
+            largest_field = base_length;
+        }
+    }
+
+    Ok((largest_field, body_width))
+}
+
 // Leaf Spans
 
 // TODO: Find a better way of handling Boxed version
@@ -729,6 +803,47 @@ impl LeafSpans for Expr {
     }
 }
 
+impl Format for ForIterable {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Range {
+                start,
+                double_dot_token,
+                end,
+            } => {
+                start.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", double_dot_token.span().as_str())?;
+                end.format(formatted_code, formatter)?;
+            }
+            Self::Expr(expr) => expr.format(formatted_code, formatter)?,
+        }
+
+        Ok(())
+    }
+}
+
+impl LeafSpans for ForIterable {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        match self {
+            ForIterable::Range {
+                start,
+                double_dot_token,
+                end,
+            } => {
+                let mut collected_spans = start.leaf_spans();
+                collected_spans.push(ByteSpan::from(double_dot_token.span()));
+                collected_spans.append(&mut end.leaf_spans());
+                collected_spans
+            }
+            ForIterable::Expr(expr) => expr.leaf_spans(),
+        }
+    }
+}
+
 /// Collects various expr field's ByteSpans.
 fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
     match expr {
@@ -781,6 +896,20 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut block.leaf_spans());
             collected_spans
         }
+        Expr::For {
+            for_token,
+            value_pattern,
+            in_token,
+            iterable,
+            block,
+        } => {
+            let mut collected_spans = vec![ByteSpan::from(for_token.span())];
+            collected_spans.append(&mut value_pattern.leaf_spans());
+            collected_spans.push(ByteSpan::from(in_token.span()));
+            collected_spans.append(&mut iterable.leaf_spans());
+            collected_spans.append(&mut block.leaf_spans());
+            collected_spans
+        }
         Expr::FuncApp { func, args } => {
             let mut collected_spans = Vec::new();
             collected_spans.append(&mut func.leaf_spans());
@@ -833,6 +962,14 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.push(ByteSpan::from(field_span.clone()));
             collected_spans
         }
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => {
+            let mut collected_spans = expr.leaf_spans();
+            collected_spans.push(ByteSpan::from(question_mark_token.span()));
+            collected_spans
+        }
         Expr::Ref { ref_token, expr } => {
             let mut collected_spans = vec![ByteSpan::from(ref_token.span())];
             collected_spans.append(&mut expr.leaf_spans());
@@ -1054,5 +1191,30 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
         Expr::Continue { continue_token } => {
             vec![ByteSpan::from(continue_token.span())]
         }
+        Expr::Closure { params, body } => {
+            let mut collected_spans = match params {
+                ClosureParams::Empty { double_pipe_token } => {
+                    vec![ByteSpan::from(double_pipe_token.span())]
+                }
+                ClosureParams::List {
+                    open_pipe_token,
+                    params,
+                    close_pipe_token,
+                } => {
+                    let mut collected_spans = vec![ByteSpan::from(open_pipe_token.span())];
+                    for param in params {
+                        collected_spans.push(ByteSpan::from(param.name.span()));
+                        if let Some((colon_token, ty)) = &param.ty_opt {
+                            collected_spans.push(ByteSpan::from(colon_token.span()));
+                            collected_spans.append(&mut ty.leaf_spans());
+                        }
+                    }
+                    collected_spans.push(ByteSpan::from(close_pipe_token.span()));
+                    collected_spans
+                }
+            };
+            collected_spans.append(&mut body.leaf_spans());
+            collected_spans
+        }
     }
 }
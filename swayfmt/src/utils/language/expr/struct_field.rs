@@ -7,7 +7,7 @@ use crate::{
     },
 };
 use std::fmt::Write;
-use sway_ast::{token::Delimiter, ExprStructField};
+use sway_ast::{token::Delimiter, ExprStructField, ExprStructFields};
 use sway_types::Spanned;
 
 impl Format for ExprStructField {
@@ -26,6 +26,58 @@ impl Format for ExprStructField {
     }
 }
 
+impl Format for ExprStructFields {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let (double_dot_token, base) = match &self.base_opt {
+            Some(base) => base,
+            None => return self.fields.format(formatted_code, formatter),
+        };
+        // the base of a struct update comes last and cannot be followed by a comma
+        match formatter.shape.code_line.line_style {
+            LineStyle::Multiline => {
+                writeln!(formatted_code)?;
+                for field in &self.fields {
+                    write!(
+                        formatted_code,
+                        "{}",
+                        &formatter.shape.indent.to_string(&formatter.config)?
+                    )?;
+                    field.format(formatted_code, formatter)?;
+                    writeln!(formatted_code, ",")?;
+                }
+                write!(
+                    formatted_code,
+                    "{}{}",
+                    &formatter.shape.indent.to_string(&formatter.config)?,
+                    double_dot_token.span().as_str()
+                )?;
+                base.format(formatted_code, formatter)?;
+                writeln!(formatted_code)?;
+            }
+            line_style => {
+                if line_style == LineStyle::Inline {
+                    write!(formatted_code, " ")?;
+                }
+                for field in &self.fields {
+                    field.format(formatted_code, formatter)?;
+                    write!(formatted_code, ", ")?;
+                }
+                write!(formatted_code, "{}", double_dot_token.span().as_str())?;
+                base.format(formatted_code, formatter)?;
+                if line_style == LineStyle::Inline {
+                    write!(formatted_code, " ")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl CurlyBrace for ExprStructField {
     fn open_curly_brace(
         line: &mut String,
@@ -78,3 +130,14 @@ impl LeafSpans for ExprStructField {
         collected_spans
     }
 }
+
+impl LeafSpans for ExprStructFields {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = self.fields.leaf_spans();
+        if let Some((double_dot_token, base)) = &self.base_opt {
+            collected_spans.push(ByteSpan::from(double_dot_token.span()));
+            collected_spans.append(&mut base.leaf_spans());
+        }
+        collected_spans
+    }
+}
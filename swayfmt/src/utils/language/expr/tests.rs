@@ -96,7 +96,12 @@ fmt_test!(  basic_func_app          "foo()",
             ) "
 );
 
-fmt_test!(  nested_args_func_app    "foo(a_struct { hello: \"hi\" }, a_var, foo.bar.baz.quux)",
+fmt_test!(  try_operator            "foo(a)?.bar()?.baz?",
+            intermediate_whitespace "foo ( a ) ? . bar ( ) ?
+            . baz ?"
+);
+
+fmt_test!(  nested_args_func_app   "foo(a_struct { hello: \"hi\" }, a_var, foo.bar.baz.quux)",
             intermediate_whitespace "foo(a_struct {
                     hello  :  \"hi\"
             }, a_var  , foo . bar . baz . quux)"
@@ -186,6 +191,23 @@ fmt_test!(  if_else_multiline
 fmt_test!(  small_if_let "if let Result::Ok(x) = x { 100 } else { 1 }",
             intermediate_whitespace "if    let    Result   ::   Ok( x ) =    x {     100 }   else  {    1 }"
 );
+fmt_test!(  for_range
+"for i in 0..10 {
+    foo(i);
+}",
+            intermediate_whitespace
+"for   i   in  0 ..  10   {
+        foo( i ) ;
+}"
+);
+fmt_test!(  for_iterator
+"for (a, b) in pairs.iter() {
+    foo(a, b);
+}",
+            intermediate_whitespace
+"for  ( a ,  b )  in   pairs . iter ( )   {
+    foo( a , b ) ;   }"
+);
 
 fmt_test!(  match_nested_conditional
 "match foo {
@@ -237,6 +259,27 @@ fmt_test!(  match_branch_kind
 quux();\n    }\n\n\n}"
 );
 
+fmt_test!(  match_or_pattern_and_guard
+"match foo {
+    Foo::foo | Foo::bar => {
+        foo();
+    }
+    Foo::baz(x) if x > 10 => {
+        bar(x);
+    }
+    _ => {}
+}",
+            intermediate_whitespace
+"match  foo  {
+    Foo::foo|   Foo::bar  =>  {
+        foo( ) ;
+    }
+    Foo::baz( x )   if   x>10 =>   {
+        bar(x) ;   }
+    _   => { }
+}"
+);
+
 fmt_test!(  basic_array             "[1, 2, 3, 4, 5]",
             intermediate_whitespace " \n [ 1 , 2 , 3 , 4 , 5 ]  \n"
 );
@@ -278,3 +321,43 @@ fmt_test!(  nested_array
      ] ,
   ]"
 );
+
+fmt_test!(  closure                 "apply(|x| x + 1, |a: u64, b: u64| a * b)",
+            intermediate_whitespace "apply( | x |   x+1 ,|a :u64,b:   u64|a*b )"
+);
+
+fmt_test!(  closure_block
+"|x| {
+    let y = x + 1;
+    y * 2
+}",
+            intermediate_whitespace
+"| x |{  let y = x+1;
+        y*2 }"
+);
+
+fmt_test!(  modulo_assignment       "x %= 8",
+            intermediate_whitespace "x   %=8"
+);
+
+fmt_test!(  bitwise_assignment      "storage.flags.mask |= 0x4",
+            intermediate_whitespace "storage . flags . mask|=  0x4"
+);
+
+fmt_test!(  pow_assignment          "self.inner.x **= 2",
+            intermediate_whitespace "self . inner . x**=2"
+);
+
+fmt_test!(  struct_update           "Foo { a: 1, ..b }",
+            intermediate_whitespace "Foo {a :1 ,  .. b}"
+);
+
+fmt_test!(  struct_update_multiline
+"Config {
+    owner: Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)),
+    ..Config::default()
+}",
+            intermediate_whitespace
+"Config { owner:Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)),
+  ..Config::default() }"
+);
@@ -115,6 +115,15 @@ impl Format for Pattern {
                     },
                 )?;
             }
+            Self::Or {
+                lhs,
+                pipe_token,
+                rhs,
+            } => {
+                lhs.format(formatted_code, formatter)?;
+                write!(formatted_code, " {} ", pipe_token.span().as_str())?;
+                rhs.format(formatted_code, formatter)?;
+            }
         }
         Ok(())
     }
@@ -292,6 +301,15 @@ impl LeafSpans for Pattern {
             Pattern::Tuple(tuple) => {
                 collected_spans.append(&mut tuple.leaf_spans());
             }
+            Pattern::Or {
+                lhs,
+                pipe_token,
+                rhs,
+            } => {
+                collected_spans.append(&mut lhs.leaf_spans());
+                collected_spans.push(ByteSpan::from(pipe_token.span()));
+                collected_spans.append(&mut rhs.leaf_spans());
+            }
         }
         collected_spans
     }
@@ -36,6 +36,37 @@ impl Format for Ty {
                 write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
                 Ok(())
             }
+            Self::Ref {
+                ampersand_token,
+                ty,
+            } => {
+                write!(formatted_code, "{}", ampersand_token.span().as_str())?;
+                ty.format(formatted_code, formatter)
+            }
+            Self::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            } => {
+                write!(
+                    formatted_code,
+                    "{}{}",
+                    fn_token.span().as_str(),
+                    Delimiter::Parenthesis.as_open_char()
+                )?;
+                for (index, ty) in arguments.get().into_iter().enumerate() {
+                    if index > 0 {
+                        write!(formatted_code, ", ")?;
+                    }
+                    ty.format(formatted_code, formatter)?;
+                }
+                write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
+                if let Some((right_arrow_token, ty)) = return_type_opt {
+                    write!(formatted_code, " {} ", right_arrow_token.span().as_str())?;
+                    ty.format(formatted_code, formatter)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -118,6 +149,27 @@ impl LeafSpans for Ty {
                 collected_spans
             }
             Ty::Infer { underscore_token } => vec![ByteSpan::from(underscore_token.span())],
+            Ty::Ref {
+                ampersand_token,
+                ty,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(ampersand_token.span())];
+                collected_spans.append(&mut ty.leaf_spans());
+                collected_spans
+            }
+            Ty::Fn {
+                fn_token,
+                arguments,
+                return_type_opt,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(fn_token.span())];
+                collected_spans.append(&mut arguments.leaf_spans());
+                if let Some((right_arrow_token, ty)) = return_type_opt {
+                    collected_spans.push(ByteSpan::from(right_arrow_token.span()));
+                    collected_spans.append(&mut ty.leaf_spans());
+                }
+                collected_spans
+            }
         }
     }
 }
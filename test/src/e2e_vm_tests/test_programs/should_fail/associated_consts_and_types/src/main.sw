@@ -0,0 +1,32 @@
+script;
+
+trait Token {
+    const DECIMALS: u8;
+    const SUPPLY: u64 = 1000;
+    type Unit;
+}
+
+struct Missing {}
+
+impl Token for Missing {}
+
+struct Extra {}
+
+impl Token for Extra {
+    const DECIMALS: u8 = 9;
+    const NAME: u64 = 1;
+    type Unit = u64;
+    type Other = bool;
+}
+
+struct Mismatched {}
+
+impl Token for Mismatched {
+    const DECIMALS: u8 = 9;
+    const SUPPLY: bool = true;
+    type Unit = u64;
+}
+
+fn main() {
+    let x = Extra::MISSING;
+}
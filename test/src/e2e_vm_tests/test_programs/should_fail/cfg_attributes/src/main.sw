@@ -0,0 +1,20 @@
+script;
+
+struct Foo {
+    #[cfg(test)]
+    value: u64,
+}
+
+#[cfg(tests)]
+fn misspelled_test() {}
+
+#[cfg(feature)]
+fn missing_feature_name() {}
+
+#[cfg(feature = 42)]
+fn non_string_feature_name() {}
+
+#[cfg]
+fn missing_predicate() {}
+
+fn main() {}
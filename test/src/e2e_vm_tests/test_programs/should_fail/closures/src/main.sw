@@ -0,0 +1,27 @@
+script;
+
+fn apply(f: fn(u64) -> u64, x: u64) -> u64 {
+    f(x)
+}
+
+fn id<T>(value: T) -> T {
+    value
+}
+
+fn main() {
+    let untyped = |x| x + 1;
+
+    let _ = apply(|a, b| a + b, 1);
+
+    let _ = apply(id, 1);
+
+    let number = 5;
+    let _ = number(1);
+
+    let add_one = |x: u64| x + 1;
+    let _ = id(add_one);
+
+    let _ = |x: u64| |y: u64| x + y;
+
+    let _ = add_one(1, 2);
+}
@@ -0,0 +1,25 @@
+script;
+
+struct Cursor {
+    position: u64,
+}
+
+// Has a `next` method, but doesn't implement `Iterator`.
+impl Cursor {
+    fn next(ref mut self) -> Option<u64> {
+        self.position += 1;
+        Option::Some(self.position)
+    }
+}
+
+fn main() {
+    let cursor = Cursor { position: 0 };
+    for position in cursor {
+        log(position);
+    }
+
+    let count: u64 = 3;
+    for i in count {
+        log(i);
+    }
+}
@@ -0,0 +1,5 @@
+script;
+
+fn main(f: fn(u64) -> u64) -> u64 {
+    f(1)
+}
@@ -0,0 +1,16 @@
+script;
+
+#[deny(dead_code)]
+fn denied_unused_fn() -> u64 {
+    1
+}
+
+#[deny(warnings)]
+fn denied_unused_fn_with_warnings_group() -> u64 {
+    2
+}
+
+fn main() -> u64 {
+    return 3;
+    4
+}
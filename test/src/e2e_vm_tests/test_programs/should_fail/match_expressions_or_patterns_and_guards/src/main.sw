@@ -0,0 +1,39 @@
+script;
+
+enum Shape {
+    Circle: u64,
+    Square: u64,
+    Triangle: u64,
+}
+
+fn guarded(n: u64) -> u64 {
+    match n {
+        x if x > 10 => 1,
+        0 | 1 => 0,
+    }
+}
+
+fn unreachable_alternatives(n: u64) -> u64 {
+    match n {
+        0 | 1 => 0,
+        1 | 0 => 1,
+        _ => 2,
+    }
+}
+
+fn missing_variant(shape: Shape) -> u64 {
+    match shape {
+        Shape::Circle(s) | Shape::Square(s) => s,
+    }
+}
+
+fn unbound(shape: Shape) -> u64 {
+    match shape {
+        Shape::Circle(s) | Shape::Square(_) => s,
+        _ => 0,
+    }
+}
+
+fn main() -> u64 {
+    guarded(0) + unreachable_alternatives(0) + missing_variant(Shape::Circle(0)) + unbound(Shape::Triangle(0))
+}
@@ -0,0 +1,31 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Wrapper {
+    point: &Point,
+}
+
+fn local_ref() -> &Point {
+    let p = Point { x: 1, y: 2 };
+    ref p
+}
+
+fn param_ref(p: Point) -> Wrapper {
+    Wrapper { point: ref p }
+}
+
+fn out_param(ref mut out: &Point) {
+    let p = Point { x: 3, y: 4 };
+    out = ref p;
+}
+
+fn main() -> u64 {
+    let p = Point { x: 5, y: 6 };
+    let mut r = ref p;
+    out_param(r);
+    (deref local_ref()).x + (deref param_ref(p).point).x + (deref r).x
+}
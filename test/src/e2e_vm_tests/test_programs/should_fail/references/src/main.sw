@@ -0,0 +1,16 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn main() -> &Point {
+    let x = 5;
+    let r = ref x;
+
+    let p = Point { x: 1, y: 2 };
+    let q = deref p;
+
+    ref p
+}
@@ -0,0 +1,24 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Other {
+    x: u64,
+    y: u64,
+}
+
+fn main() {
+    let p = Point { x: 1, y: 2 };
+    let o = Other { x: 1, y: 2 };
+
+    let _ = Point { x: 3, ..o };
+
+    let _ = Point { x: 3, ..5 };
+
+    let _ = Point { x, ..p };
+
+    let _ = Point { z: 3, ..p };
+}
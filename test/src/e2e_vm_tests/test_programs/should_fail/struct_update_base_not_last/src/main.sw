@@ -0,0 +1,11 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn main() {
+    let p = Point { x: 1, y: 2 };
+    let _ = Point { ..p, x: 3 };
+}
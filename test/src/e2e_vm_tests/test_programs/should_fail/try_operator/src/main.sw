@@ -0,0 +1,35 @@
+script;
+
+fn maybe() -> Option<u64> {
+    Option::Some(1)
+}
+
+fn fallible() -> Result<u64, u64> {
+    Result::Ok(1)
+}
+
+fn not_wrapped() -> Option<u64> {
+    let x: u64 = 5;
+    Option::Some(x?)
+}
+
+fn option_in_result() -> Result<u64, u64> {
+    Result::Ok(maybe()?)
+}
+
+fn result_in_u64() -> u64 {
+    fallible()?
+}
+
+fn other_error_type() -> Result<u64, bool> {
+    Result::Ok(fallible()?)
+}
+
+const OUTSIDE: u64 = fallible()?;
+
+fn main() {
+    let _ = not_wrapped();
+    let _ = option_in_result();
+    let _ = result_in_u64();
+    let _ = other_error_type();
+}
@@ -0,0 +1,19 @@
+script;
+
+// Has the same name and variants as `std::option::Option`, but is a different enum.
+enum Option<T> {
+    Some: T,
+    None: (),
+}
+
+fn maybe() -> Option<u64> {
+    Option::Some(1)
+}
+
+fn unwrap() -> Option<u64> {
+    Option::Some(maybe()?)
+}
+
+fn main() {
+    let _ = unwrap();
+}
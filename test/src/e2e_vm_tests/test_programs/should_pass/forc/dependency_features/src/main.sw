@@ -0,0 +1,12 @@
+script;
+
+use features_lib::{log_level, network, verbosity};
+
+#[cfg(feature = "unused")]
+fn main() -> u64 {
+    undefined_symbol
+}
+
+fn main() -> u64 {
+    network().chain_id() + log_level() + verbosity()
+}
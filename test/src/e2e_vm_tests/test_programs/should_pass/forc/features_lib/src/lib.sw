@@ -0,0 +1,49 @@
+library features_lib;
+
+pub struct Network {
+    id: u64,
+}
+
+impl Network {
+    #[cfg(feature = "mainnet")]
+    pub fn chain_id(self) -> u64 {
+        1
+    }
+
+    #[cfg(feature = "testnet")]
+    pub fn chain_id(self) -> u64 {
+        2
+    }
+}
+
+pub fn network() -> Network {
+    Network { id: 0 }
+}
+
+// Enabled by the `verbose` feature.
+#[cfg(feature = "logging")]
+pub fn log_level() -> u64 {
+    3
+}
+
+#[cfg(feature = "verbose")]
+pub fn verbosity() -> u64 {
+    4
+}
+
+// Never compiled, as no dependent enables the `unused` feature.
+#[cfg(feature = "unused")]
+pub fn unused() -> u64 {
+    undefined_symbol
+}
+
+#[cfg(test, feature = "logging")]
+fn expected_log_level() -> u64 {
+    3
+}
+
+#[cfg(feature = "logging")]
+#[test]
+fn test_log_level() {
+    let _ = log_level() == expected_log_level();
+}
@@ -0,0 +1,160 @@
+script;
+
+trait Token {
+    const DECIMALS: u8;
+    const SYMBOL: str[3];
+    const SUPPLY: u64 = 1000;
+
+    fn scale(self) -> u64;
+} {
+    fn scaled_supply(self) -> u64 {
+        Self::SUPPLY * self.scale()
+    }
+}
+
+struct Fuel {}
+
+impl Token for Fuel {
+    const DECIMALS: u8 = 9;
+    const SYMBOL: str[3] = "FUL";
+
+    fn scale(self) -> u64 {
+        10
+    }
+}
+
+struct Ether {}
+
+impl Token for Ether {
+    const DECIMALS: u8 = 18;
+    const SYMBOL: str[3] = "ETH";
+    const SUPPLY: u64 = 21;
+
+    fn scale(self) -> u64 {
+        Self::SUPPLY
+    }
+}
+
+fn decimals<T>() -> u8 where T: Token {
+    T::DECIMALS
+}
+
+fn supply<T>() -> u64 where T: Token {
+    T::SUPPLY
+}
+
+struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    const STEP: u64 = 5;
+
+    fn next(self) -> Counter {
+        Counter {
+            value: self.value + Self::STEP,
+        }
+    }
+}
+
+trait Container {
+    type Item;
+    type Index = u64;
+
+    fn first(self) -> Self::Item;
+    fn index(self) -> Self::Index;
+}
+
+struct Pair {
+    a: u32,
+    b: u32,
+}
+
+impl Container for Pair {
+    type Item = u32;
+
+    fn first(self) -> u32 {
+        self.a
+    }
+
+    fn index(self) -> Self::Index {
+        0
+    }
+}
+
+struct Flags {
+    value: bool,
+}
+
+impl Container for Flags {
+    type Item = bool;
+    type Index = u8;
+
+    fn first(self) -> Self::Item {
+        self.value
+    }
+
+    fn index(self) -> u8 {
+        1
+    }
+}
+
+struct Item {
+    value: u64,
+}
+
+struct Wrapper {
+    item: Item,
+}
+
+impl Container for Wrapper {
+    type Item = u64;
+
+    fn first(self) -> Self::Item {
+        self.item.value
+    }
+
+    fn index(self) -> Self::Index {
+        2
+    }
+}
+
+fn first_of<T>(container: T) -> T::Item where T: Container {
+    container.first()
+}
+
+fn main() -> bool {
+    assert(decimals::<Fuel>() == 9);
+    assert(decimals::<Ether>() == 18);
+    assert(supply::<Fuel>() == 1000);
+    assert(supply::<Ether>() == 21);
+    assert(Fuel::DECIMALS == 9);
+    assert(Ether::SUPPLY == 21);
+    assert(Fuel {}.scaled_supply() == 10000);
+    assert(Ether {}.scaled_supply() == 441);
+
+    let counter = Counter { value: 1 }.next().next();
+    assert(counter.value == 11);
+    assert(Counter::STEP == 5);
+
+    let pair = Pair { a: 3, b: 4 };
+    assert(pair.first() == 3);
+    assert(pair.b == 4);
+    assert(pair.index() == 0);
+
+    let flags = Flags { value: true };
+    assert(flags.first());
+    assert(flags.index() == 1);
+
+    let wrapper = Wrapper {
+        item: Item { value: 42 },
+    };
+    assert(wrapper.first() == 42);
+    assert(wrapper.index() == 2);
+
+    assert(first_of(pair) == 3);
+    assert(first_of(flags));
+    assert(first_of(wrapper) == 42);
+
+    true
+}
@@ -0,0 +1,101 @@
+script;
+
+use std::vec::Vec;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+impl Point {
+    fn scaled_x(self, factor: u64) -> u64 {
+        apply(|v| v * factor + self.y, self.x)
+    }
+}
+
+fn apply(f: fn(u64) -> u64, x: u64) -> u64 {
+    f(x)
+}
+
+fn apply_twice(f: fn(u64) -> u64, x: u64) -> u64 {
+    apply(f, apply(f, x))
+}
+
+fn compose(f: fn(u64) -> u64, g: fn(u64) -> u64, x: u64) -> u64 {
+    g(f(x))
+}
+
+fn fold<T>(values: Vec<T>, init: T, combine: fn(T, T) -> T) -> T {
+    let mut acc = init;
+    let mut i = 0;
+    while i < values.len() {
+        acc = combine(acc, values.get(i).unwrap());
+        i += 1;
+    }
+    acc
+}
+
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+fn first_or(value: Option<u64>, default: u64) -> u64 {
+    match value {
+        Option::Some(v) => v,
+        Option::None => default,
+    }
+}
+
+fn main() -> bool {
+    // Captured variables are copied when the closure is created.
+    let mut offset = 10;
+    let add_offset = |x: u64| x + offset;
+    offset = 100;
+    assert(add_offset(5) == 15);
+    assert(offset == 100);
+
+    // The types of the parameters are inferred from the function type.
+    assert(apply(|x| x + 1, 41) == 42);
+    assert(apply_twice(|x| x * 3, 2) == 18);
+    assert(compose(|x| x + 1, |x| x * 10, 1) == 20);
+    assert(apply(|x| {
+        let y = x + offset;
+        if y > 150 {
+            return 0;
+        }
+        y * 2
+    }, 1) == 202);
+
+    // Functions can be used as values too.
+    assert(apply(double, 21) == 42);
+    let f = double;
+    assert(f(4) == 8);
+
+    // Closures can be passed along to other functions and nested.
+    assert(apply(|x| apply(|y| y + x, x), 4) == 8);
+    let p = Point { x: 3, y: 1 };
+    assert(p.scaled_x(5) == 16);
+
+    let mut v = Vec::new();
+    v.push(3);
+    v.push(1);
+    v.push(2);
+    let shifted = v.map(|x| x + offset);
+    assert(shifted.get(0).unwrap() == 103);
+    assert(shifted.get(2).unwrap() == 102);
+    let big = v.map(|x| x > 1);
+    assert(big.get(0).unwrap() && !big.get(1).unwrap());
+    assert(fold(v, 0, |a, b| a + b) == 6);
+
+    v.sort_by(|a, b| a < b);
+    assert(v.get(0).unwrap() == 1 && v.get(1).unwrap() == 2 && v.get(2).unwrap() == 3);
+    v.sort_by(|a, b| a > b);
+    assert(v.get(0).unwrap() == 3 && v.get(2).unwrap() == 1);
+
+    let default = 7;
+    let none: Option<u64> = Option::None;
+    assert(apply(|x| first_or(none, default) + x, 1) == 8);
+    assert(apply(|x| first_or(Option::Some(x), default), 1) == 1);
+
+    true
+}
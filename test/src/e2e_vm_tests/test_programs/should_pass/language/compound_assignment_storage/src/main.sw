@@ -0,0 +1,38 @@
+contract;
+
+use std::math::*;
+
+struct Flags {
+    mask: u64,
+}
+
+struct Packed {
+    flags: Flags,
+    count: u64,
+}
+
+storage {
+    packed: Packed = Packed {
+        flags: Flags { mask: 0 },
+        count: 0,
+    },
+    value: u64 = 0,
+}
+
+abi CompoundAssignment {
+    #[storage(read, write)]
+    fn update(mask: u64) -> u64;
+}
+
+impl CompoundAssignment for Contract {
+    #[storage(read, write)]
+    fn update(mask: u64) -> u64 {
+        storage.value %= 10;
+        storage.value **= 2;
+        storage.packed.flags.mask |= mask;
+        storage.packed.flags.mask &= 0xff;
+        storage.packed.flags.mask ^= 0x1;
+        storage.packed.count %= 3;
+        storage.packed.flags.mask + storage.value
+    }
+}
@@ -0,0 +1,109 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Countdown {
+    from: u64,
+}
+
+impl Iterator<u64> for Countdown {
+    fn next(ref mut self) -> Option<u64> {
+        if self.from == 0 {
+            return Option::None;
+        }
+        self.from -= 1;
+        Option::Some(self.from)
+    }
+}
+
+struct Log {
+    digits: u64,
+}
+
+impl Log {
+    fn record(ref mut self, digit: u64) -> u64 {
+        self.digits = self.digits * 10 + digit;
+        digit
+    }
+}
+
+fn sum_range(start: u64, end: u64) -> u64 {
+    let mut sum = 0;
+    for i in start..end {
+        sum += i;
+    }
+    sum
+}
+
+fn main() -> bool {
+    // Ranges are half-open, and an empty range doesn't run the body.
+    assert(sum_range(0, 5) == 10);
+    assert(sum_range(3, 3) == 0);
+
+    // The start of a range is evaluated before its end.
+    let mut log = Log { digits: 0 };
+    let mut sum = 0;
+    for i in log.record(1)..log.record(3) {
+        sum += i;
+    }
+    assert(sum == 3);
+    assert(log.digits == 13);
+
+    // Arrays, including destructuring the elements with the loop's pattern.
+    let mut sum = 0;
+    for x in [1, 2, 3, 4] {
+        sum += x;
+    }
+    assert(sum == 10);
+
+    let points = [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+    let mut sum = 0;
+    for Point { x, y } in points {
+        sum += x * y;
+    }
+    assert(sum == 14);
+
+    // `break` and `continue` step through the loop as usual.
+    let mut sum = 0;
+    for i in 0..100 {
+        if i % 2 == 0 {
+            continue;
+        }
+        if i > 8 {
+            break;
+        }
+        sum += i;
+    }
+    assert(sum == 16);
+
+    // Nested loops each keep their own state.
+    let mut count = 0;
+    for i in 0..3 {
+        for j in 0..i {
+            count += j + 1;
+        }
+    }
+    assert(count == 4);
+
+    // Anything that implements `Iterator`.
+    let countdown = Countdown { from: 4 };
+    let mut sum = 0;
+    for i in countdown {
+        sum += i;
+    }
+    assert(sum == 6);
+
+    let mut vec = Vec::new();
+    vec.push(5);
+    vec.push(7);
+    let mut sum = 0;
+    for value in vec.iter() {
+        sum += value;
+    }
+    assert(sum == 12);
+
+    true
+}
@@ -0,0 +1,5 @@
+library helpers;
+
+pub fn unused_helper() -> u64 {
+    5
+}
@@ -0,0 +1,49 @@
+script;
+
+#[allow(dead_code)]
+dep helpers;
+
+struct Foo {
+    value: u64,
+}
+
+#[allow(dead_code)]
+impl Foo {
+    fn get(self) -> u64 {
+        self.value
+    }
+
+    #[warn(dead_code)]
+    fn reported_unused_method(self) -> u64 {
+        0
+    }
+}
+
+#[allow(dead_code)]
+fn allowed_unused_fn() -> u64 {
+    1
+}
+
+#[allow(warnings)]
+fn allowed_unused_fn_with_warnings_group() -> u64 {
+    2
+}
+
+fn reported_unused_fn() -> u64 {
+    3
+}
+
+#[allow(non_snake_case)]
+fn allowedName(x: u64) -> u64 {
+    x
+}
+
+#[allow(some_unknown_lint)]
+fn unknown_lint() {}
+
+fn main() -> u64 {
+    let x = Foo { value: 4 };
+    let x = x.get();
+    unknown_lint();
+    allowedName(x)
+}
@@ -0,0 +1,91 @@
+script;
+
+enum Shape {
+    Circle: u64,
+    Square: u64,
+    Rectangle: (u64, u64),
+    Empty: (),
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn classify(n: u64) -> u64 {
+    match n {
+        0 | 1 => 1,
+        2 | 3 | 4 => 2,
+        x if x > 100 => 4,
+        _ => 3,
+    }
+}
+
+fn size(shape: Shape) -> u64 {
+    match shape {
+        Shape::Circle(s) | Shape::Square(s) => s,
+        Shape::Rectangle((w, h)) if w == h => w,
+        Shape::Rectangle((w, h)) => w * h,
+        Shape::Empty => 0,
+    }
+}
+
+fn on_axis(p: Point) -> bool {
+    match p {
+        Point { x: 0, y: _ } | Point { x: _, y: 0 } => true,
+        _ => false,
+    }
+}
+
+fn first_some(pair: (Option<u64>, Option<u64>)) -> u64 {
+    match pair {
+        (Option::Some(v), _) | (Option::None, Option::Some(v)) => v,
+        (Option::None, Option::None) => 0,
+    }
+}
+
+fn nested(opt: Option<u64>) -> u64 {
+    match opt {
+        Option::Some(1 | 2) => 12,
+        Option::Some(v) if v % 2 == 0 => 20,
+        Option::Some(_) => 30,
+        Option::None => 0,
+    }
+}
+
+fn main() -> bool {
+    assert(classify(0) == 1);
+    assert(classify(1) == 1);
+    assert(classify(3) == 2);
+    assert(classify(4) == 2);
+    assert(classify(5) == 3);
+    assert(classify(101) == 4);
+
+    assert(size(Shape::Circle(3)) == 3);
+    assert(size(Shape::Square(4)) == 4);
+    assert(size(Shape::Rectangle((5, 5))) == 5);
+    assert(size(Shape::Rectangle((2, 3))) == 6);
+    assert(size(Shape::Empty) == 0);
+
+    assert(on_axis(Point { x: 0, y: 7 }));
+    assert(on_axis(Point { x: 7, y: 0 }));
+    assert(!on_axis(Point { x: 7, y: 7 }));
+
+    let none: Option<u64> = Option::None;
+    assert(first_some((Option::Some(1), Option::Some(2))) == 1);
+    assert(first_some((none, Option::Some(2))) == 2);
+    assert(first_some((none, none)) == 0);
+
+    assert(nested(Option::Some(1)) == 12);
+    assert(nested(Option::Some(2)) == 12);
+    assert(nested(Option::Some(4)) == 20);
+    assert(nested(Option::Some(5)) == 30);
+    assert(nested(none) == 0);
+
+    let x = Option::Some(42);
+    if let Option::Some(41 | 42) = x {
+        true
+    } else {
+        false
+    }
+}
@@ -1,6 +1,16 @@
 script;
 
 use std::assert::assert;
+use std::math::*;
+
+struct Flags {
+    mask: u64,
+}
+
+struct Packed {
+    flags: Flags,
+    count: u64,
+}
 
 fn main() -> u64 {
     let mut a = 0;
@@ -25,5 +35,36 @@ fn main() -> u64 {
     a <<= 2;
     assert(a == 1996);
 
+    a %= 1000;
+    assert(a == 996);
+
+    a &= 0xff;
+    assert(a == 228);
+
+    a |= 0x3;
+    assert(a == 231);
+
+    a ^= 0x7;
+    assert(a == 224);
+
+    a = 3;
+    a **= 4;
+    assert(a == 81);
+
+    let mut packed = Packed {
+        flags: Flags { mask: 0b1100 },
+        count: 7,
+    };
+    packed.flags.mask |= 0b0011;
+    assert(packed.flags.mask == 0b1111);
+    packed.flags.mask &= 0b1010;
+    assert(packed.flags.mask == 0b1010);
+    packed.flags.mask ^= 0b0001;
+    assert(packed.flags.mask == 0b1011);
+    packed.count %= 4;
+    assert(packed.count == 3);
+    packed.count **= 2;
+    assert(packed.count == 9);
+
     1
 }
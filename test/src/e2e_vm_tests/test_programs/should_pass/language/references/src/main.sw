@@ -0,0 +1,68 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Big {
+    id: b256,
+    origin: Point,
+    values: [u64; 4],
+}
+
+enum Shape {
+    Circle: u64,
+    Square: Point,
+}
+
+fn sum(p: &Point) -> u64 {
+    let p = deref p;
+    p.x + p.y
+}
+
+fn total(big: &Big) -> u64 {
+    let big = deref big;
+    big.origin.x + big.origin.y + big.values[0] + big.values[3]
+}
+
+fn area(shape: &Shape) -> u64 {
+    match deref shape {
+        Shape::Circle(r) => 3 * r * r,
+        Shape::Square(p) => p.x * p.y,
+    }
+}
+
+fn first<T>(pair: &(T, T)) -> T {
+    let (a, _) = deref pair;
+    a
+}
+
+fn main() -> bool {
+    let p = Point { x: 1, y: 2 };
+    let r = ref p;
+    assert(sum(r) == 3);
+    assert((deref r).y == 2);
+
+    let big = Big {
+        id: 0x0000000000000000000000000000000000000000000000000000000000000001,
+        origin: Point { x: 3, y: 4 },
+        values: [5, 6, 7, 8],
+    };
+    assert(total(ref big) == 20);
+    let big_ref: &Big = ref big;
+    assert((deref big_ref).id == 0x0000000000000000000000000000000000000000000000000000000000000001);
+
+    let circle = Shape::Circle(2);
+    let square = Shape::Square(Point { x: 3, y: 5 });
+    assert(area(ref circle) == 12);
+    assert(area(ref square) == 15);
+
+    let pair = (10, 20);
+    assert(first(ref pair) == 10);
+
+    let refs = (ref p, ref big);
+    assert(sum(refs.0) + total(refs.1) == 23);
+
+    true
+}
@@ -0,0 +1,62 @@
+script;
+
+struct Inner {
+    x: u64,
+    y: u64,
+}
+
+struct Config {
+    fee: u64,
+    owner: b256,
+    inner: Inner,
+    enabled: bool,
+}
+
+struct Wrapper<T> {
+    value: T,
+    count: u64,
+}
+
+fn default_config() -> Config {
+    Config {
+        fee: 1,
+        owner: 0x0000000000000000000000000000000000000000000000000000000000000001,
+        inner: Inner { x: 2, y: 3 },
+        enabled: false,
+    }
+}
+
+const BASE: Inner = Inner { x: 10, y: 20 };
+const UPDATED: Inner = Inner { y: 30, ..BASE };
+
+fn main() -> bool {
+    let base = default_config();
+    let updated = Config {
+        fee: 5,
+        enabled: true,
+        ..base
+    };
+    assert(updated.fee == 5);
+    assert(updated.enabled);
+    assert(updated.inner.x == 2 && updated.inner.y == 3);
+    assert(updated.owner == 0x0000000000000000000000000000000000000000000000000000000000000001);
+    // the base is left untouched
+    assert(base.fee == 1 && !base.enabled);
+
+    let copy = Config { ..default_config() };
+    assert(copy.fee == 1);
+
+    let nested = Config {
+        inner: Inner { y: 7, ..base.inner },
+        ..updated
+    };
+    assert(nested.inner.x == 2 && nested.inner.y == 7 && nested.fee == 5);
+
+    let w = Wrapper { value: 3u32, count: 1 };
+    let w2 = Wrapper { count: 9, ..w };
+    assert(w2.value == 3u32 && w2.count == 9);
+
+    assert(UPDATED.x == 10 && UPDATED.y == 30);
+
+    true
+}
@@ -0,0 +1,87 @@
+script;
+
+enum Error {
+    TooLarge: u64,
+    Zero: (),
+}
+
+struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    fn checked_next(self) -> Option<Counter> {
+        if self.value < 3 {
+            Option::Some(Counter {
+                value: self.value + 1,
+            })
+        } else {
+            Option::None
+        }
+    }
+}
+
+fn non_zero(value: u64) -> Result<u64, Error> {
+    if value == 0 {
+        Result::Err(Error::Zero)
+    } else {
+        Result::Ok(value)
+    }
+}
+
+fn small(value: u64) -> Result<u64, Error> {
+    if value > 10 {
+        Result::Err(Error::TooLarge(value))
+    } else {
+        Result::Ok(value)
+    }
+}
+
+fn checked_small(value: u64) -> Result<u64, Error> {
+    let value = small(non_zero(value)?)?;
+    Result::Ok(value * 2)
+}
+
+fn deeply_nested(value: u64) -> Result<u64, Error> {
+    small(small(small(small(small(small(small(small(non_zero(value)?)?)?)?)?)?)?)?)
+}
+
+fn third(counter: Counter) -> Option<u64> {
+    Option::Some(counter.checked_next()?.checked_next()?.checked_next()?.value)
+}
+
+fn first_some(values: [Option<u64>; 3]) -> Option<u64> {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < 3 {
+        sum += values[i]?;
+        i += 1;
+    }
+    Option::Some(sum)
+}
+
+fn main() -> bool {
+    assert(checked_small(4).unwrap() == 8);
+    match checked_small(0) {
+        Result::Err(Error::Zero) => (),
+        _ => revert(0),
+    }
+    match checked_small(11) {
+        Result::Err(Error::TooLarge(value)) => assert(value == 11),
+        _ => revert(0),
+    }
+
+    assert(deeply_nested(7).unwrap() == 7);
+    match deeply_nested(0) {
+        Result::Err(Error::Zero) => (),
+        _ => revert(0),
+    }
+
+    assert(third(Counter { value: 0 }).unwrap() == 3);
+    assert(third(Counter { value: 1 }).is_none());
+
+    assert(first_some([Option::Some(1), Option::Some(2), Option::Some(3)]).unwrap() == 6);
+    assert(first_some([Option::Some(1), Option::None, Option::Some(3)]).is_none());
+
+    true
+}
@@ -0,0 +1,24 @@
+library aliases;
+
+pub struct Point<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+/// A pair of values of the same type.
+pub type Pair<T> = (T, T);
+
+/// A point with `u64` coordinates.
+pub type U64Point = Point<u64>;
+
+type Hidden = u32;
+
+pub fn hidden() -> Hidden {
+    7
+}
@@ -0,0 +1,47 @@
+script;
+
+dep aliases;
+
+use aliases::{hidden, Pair, Point, U64Point};
+
+type Amount = u64;
+type Wrapped<T> = Option<T>;
+type Points = [U64Point; 2];
+
+fn sum(pair: Pair<Amount>) -> Amount {
+    pair.0 + pair.1
+}
+
+fn swap<T>(pair: Pair<T>) -> Pair<T> {
+    (pair.1, pair.0)
+}
+
+fn unwrap_or_zero(value: Wrapped<Amount>) -> Amount {
+    match value {
+        Option::Some(v) => v,
+        Option::None => 0,
+    }
+}
+
+fn main() -> bool {
+    let amount: Amount = 40;
+    let pair: Pair<Amount> = (amount, 2);
+    assert(sum(pair) == 42);
+
+    let swapped = swap::<bool>((true, false));
+    assert(!swapped.0 && swapped.1);
+
+    let point: U64Point = U64Point::new(1, 2);
+    let points: Points = [point, Point::new(3, 4)];
+    assert(points[0].x + points[1].y == 5);
+
+    let some: Wrapped<Amount> = Option::Some(5);
+    let none: Wrapped<Amount> = Option::None;
+    assert(unwrap_or_zero(some) == 5);
+    assert(unwrap_or_zero(none) == 0);
+
+    let nested: Wrapped<Pair<u8>> = Option::Some((1u8, 2u8));
+    assert(nested.unwrap().1 == 2u8);
+
+    hidden() == 7u32
+}
@@ -0,0 +1,45 @@
+contract;
+
+use std::storage::StorageMap;
+
+type Amount = u64;
+type Key = b256;
+type Pair<T> = (T, T);
+type Balances = StorageMap<Key, Amount>;
+
+struct Entry<T> {
+    key: Key,
+    value: T,
+}
+
+type U64Entry = Entry<Amount>;
+
+storage {
+    balances: Balances = StorageMap {},
+}
+
+abi AliasedAbi {
+    #[storage(read)]
+    fn balance_of(key: Key) -> Amount;
+
+    #[storage(write)]
+    fn set_balance(entry: U64Entry);
+
+    fn swap(pair: Pair<Amount>) -> Pair<Amount>;
+}
+
+impl AliasedAbi for Contract {
+    #[storage(read)]
+    fn balance_of(key: Key) -> Amount {
+        storage.balances.get(key)
+    }
+
+    #[storage(write)]
+    fn set_balance(entry: U64Entry) {
+        storage.balances.insert(entry.key, entry.value);
+    }
+
+    fn swap(pair: (u64, u64)) -> Pair<u64> {
+        (pair.1, pair.0)
+    }
+}
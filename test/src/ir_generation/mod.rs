@@ -300,6 +300,9 @@ fn compile_core(engines: Engines<'_>) -> namespace::Module {
         offline_mode: true,
         terse_mode: true,
         locked: false,
+        packages: vec![],
+        exclude: vec![],
+        features: vec![],
     };
 
     let res = forc::test::forc_check::check(check_cmd, engines)